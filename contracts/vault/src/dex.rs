@@ -0,0 +1,167 @@
+//! VaultDAO - DEX Interface
+//!
+//! Client interfaces for Soroswap-style router, pair and farm contracts.
+//!
+//! Tokens moved by a DEX on the vault's behalf (e.g. the router pulling
+//! `amount_in` into a pair) are pre-authorized with
+//! [`authorize_transfers`] so the vault never grants open-ended allowances.
+
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contractclient, Address, Env, IntoVal, Symbol, Vec,
+};
+
+use crate::errors::VaultError;
+
+/// Soroswap-style router interface.
+#[allow(dead_code)]
+#[contractclient(name = "RouterClient")]
+pub trait Router {
+    /// Address of the pair (and LP token) for `token_a` / `token_b`.
+    fn router_pair_for(env: Env, token_a: Address, token_b: Address) -> Address;
+
+    /// Quote the output amounts along `path` for an exact input.
+    fn router_get_amounts_out(env: Env, amount_in: i128, path: Vec<Address>) -> Vec<i128>;
+
+    fn swap_exact_tokens_for_tokens(
+        env: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Vec<i128>;
+
+    fn add_liquidity(
+        env: Env,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> (i128, i128, i128);
+
+    fn remove_liquidity(
+        env: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> (i128, i128);
+}
+
+/// Soroswap-style pair interface. The pair contract is also the LP token.
+#[allow(dead_code)]
+#[contractclient(name = "PairClient")]
+pub trait Pair {
+    fn token_0(env: Env) -> Address;
+    fn token_1(env: Env) -> Address;
+    fn get_reserves(env: Env) -> (i128, i128);
+}
+
+/// LP farm interface used by `StakeLp`, `UnstakeLp` and `ClaimRewards`.
+#[allow(dead_code)]
+#[contractclient(name = "FarmClient")]
+pub trait Farm {
+    fn reward_token(env: Env) -> Address;
+    fn stake(env: Env, from: Address, lp_token: Address, amount: i128);
+    fn unstake(env: Env, to: Address, lp_token: Address, amount: i128);
+    fn claim_rewards(env: Env, to: Address) -> i128;
+}
+
+/// Pre-authorize `(token, to, amount)` transfers out of the vault that the
+/// next cross-contract call will perform on the vault's behalf.
+pub fn authorize_transfers(env: &Env, transfers: &[(&Address, &Address, i128)]) {
+    let vault = env.current_contract_address();
+    let mut entries: Vec<InvokerContractAuthEntry> = Vec::new(env);
+    for (token, to, amount) in transfers.iter() {
+        entries.push_back(InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: (*token).clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (vault.clone(), (*to).clone(), *amount).into_val(env),
+            },
+            sub_invocations: Vec::new(env),
+        }));
+    }
+    env.authorize_as_current_contract(entries);
+}
+
+/// Reserves of `pair` ordered as `(reserve_a, reserve_b)` for `token_a`.
+pub fn ordered_reserves(
+    env: &Env,
+    pair: &Address,
+    token_a: &Address,
+) -> Result<(i128, i128), VaultError> {
+    let client = PairClient::new(env, pair);
+    match (client.try_get_reserves(), client.try_token_0()) {
+        (Ok(Ok((reserve_0, reserve_1))), Ok(Ok(token_0))) if token_0 == *token_a => {
+            Ok((reserve_0, reserve_1))
+        }
+        (Ok(Ok((reserve_0, reserve_1))), Ok(Ok(_))) => Ok((reserve_1, reserve_0)),
+        _ => Err(VaultError::DexError),
+    }
+}
+
+/// Amounts the router will actually deposit for the desired amounts, using
+/// the same proportional quote the router applies against current reserves.
+///
+/// A pool with exactly one empty (or negative) reserve has no price to quote
+/// against and is rejected as invalid.
+pub fn optimal_liquidity_amounts(
+    reserve_a: i128,
+    reserve_b: i128,
+    amount_a_desired: i128,
+    amount_b_desired: i128,
+) -> Result<(i128, i128), VaultError> {
+    if reserve_a == 0 && reserve_b == 0 {
+        return Ok((amount_a_desired, amount_b_desired));
+    }
+    if reserve_a <= 0 || reserve_b <= 0 {
+        return Err(VaultError::DexError);
+    }
+    let amount_b_optimal = amount_a_desired.saturating_mul(reserve_b) / reserve_a;
+    if amount_b_optimal <= amount_b_desired {
+        Ok((amount_a_desired, amount_b_optimal))
+    } else {
+        let amount_a_optimal = amount_b_desired.saturating_mul(reserve_a) / reserve_b;
+        Ok((amount_a_optimal, amount_b_desired))
+    }
+}
+
+/// Re-express `amount` from a token with `from_decimals` in one with
+/// `to_decimals`, rounding down. `None` if the result does not fit an i128.
+pub fn rescale(amount: i128, from_decimals: u32, to_decimals: u32) -> Option<i128> {
+    if to_decimals >= from_decimals {
+        amount.checked_mul(10i128.checked_pow(to_decimals - from_decimals)?)
+    } else {
+        Some(
+            10i128
+                .checked_pow(from_decimals - to_decimals)
+                .map_or(0, |scale| amount / scale),
+        )
+    }
+}
+
+/// Deviation of `actual` from `expected` in either direction, in basis points.
+pub fn deviation_bps(expected: i128, actual: i128) -> u32 {
+    if expected <= 0 {
+        return 0;
+    }
+    let deviation = (actual - expected).saturating_abs().saturating_mul(10_000) / expected;
+    deviation.min(u32::MAX as i128) as u32
+}
+
+/// Shortfall of `actual` below `expected` in basis points (0 if not below).
+pub fn shortfall_bps(expected: i128, actual: i128) -> u32 {
+    if expected <= 0 || actual >= expected {
+        return 0;
+    }
+    ((expected - actual).saturating_mul(10_000) / expected) as u32
+}
@@ -2,7 +2,11 @@
 
 use soroban_sdk::contracterror;
 
-#[contracterror]
+/// Every error the contract returns.
+///
+/// Not exported into the contract spec directly: the spec entries are
+/// published by [`spec`], split to stay within the spec's case limit.
+#[contracterror(export = false)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum VaultError {
@@ -73,6 +77,208 @@ pub enum VaultError {
     NotSubscriberOrAdmin = 243,
     /// Subscription is not in Active status (e.g. Cancelled / Suspended)
     SubscriptionNotActive = 244,
+    // -----------------------------------------------------------------------
+    // Emergency pause errors (feature/emergency-pause)
+    // -----------------------------------------------------------------------
+    /// The entrypoint's category is paused by a guardian
+    VaultPaused = 250,
+    // -----------------------------------------------------------------------
+    // Upgrade errors (feature/contract-upgrades)
+    // -----------------------------------------------------------------------
+    /// Stored data is already at the version this WASM expects
+    AlreadyMigrated = 251,
+    /// Stored entry uses a layout this WASM does not recognise
+    UnknownSchema = 252,
+    // -----------------------------------------------------------------------
+    // Audit checkpoint errors (feature/audit-checkpoints)
+    // -----------------------------------------------------------------------
+    /// Every audit entry is already covered by a checkpoint
+    NothingToCheckpoint = 253,
+    /// An entry in the range fails hash or link verification
+    AuditChainBroken = 254,
+    /// The audit entry is not covered by a checkpoint yet
+    AuditEntryNotCheckpointed = 255,
+    // -----------------------------------------------------------------------
+    // Custom role errors (feature/custom-roles)
+    // -----------------------------------------------------------------------
+    /// No custom role is defined under this name
+    CustomRoleNotFound = 256,
+    /// Too many roles defined, members in a role or roles held by an address
+    CustomRoleLimit = 257,
+    /// Role has no permissions, a duplicate permission or a non-positive cap
+    InvalidRolePermissions = 258,
+    /// Scope has a non-positive amount cap or too many recipients or tags
+    InvalidPermissionScope = 259,
+    // -----------------------------------------------------------------------
+    // Session key errors (feature/session-keys)
+    // -----------------------------------------------------------------------
+    /// No session key is registered for this address
+    SessionKeyNotFound = 260,
+    /// The session key is past its expiry ledger
+    SessionKeyExpired = 261,
+    /// The session key has used up its calls or spend cap
+    SessionKeyExhausted = 262,
+    /// The session key may not call this entrypoint
+    SessionKeyNotAllowed = 263,
+    /// Bad registration: unknown entrypoint, no uses, negative cap, past
+    /// expiry, the key is a signer or another signer's key, or the owner
+    /// already holds the maximum number of keys
+    InvalidSessionKey = 264,
+    // -----------------------------------------------------------------------
+    // Admin council errors (feature/admin-council)
+    // -----------------------------------------------------------------------
+    /// No queued admin action with this ID
+    AdminActionNotFound = 265,
+    /// The call needs a queued admin action confirmed by the council threshold
+    AdminActionNotConfirmed = 266,
+    /// The queued admin action is past its expiry or no longer pending
+    AdminActionExpired = 267,
+    /// Council threshold is zero or above the number of admins, the action is
+    /// not a council action, or `set_admin_council` would be exempted
+    InvalidAdminCouncil = 268,
+    // -----------------------------------------------------------------------
+    // Config timelock errors (feature/config-timelock)
+    // -----------------------------------------------------------------------
+    /// No queued configuration change with this ID
+    ConfigChangeNotFound = 269,
+    /// The configuration change was already applied or cancelled
+    ConfigChangeNotPending = 270,
+    /// Too many configuration changes are already pending
+    ConfigChangeLimit = 271,
+}
+
+/// Contract spec entries for [`VaultError`].
+///
+/// A spec error enum is limited to 50 cases, so the codes are published as two
+/// enums: `VaultError` for the core codes below 200 and `VaultFeatureError`
+/// for the feature codes from 200 up. The contract only ever returns
+/// [`VaultError`]; these types exist to describe its codes to clients.
+#[allow(dead_code)]
+pub mod spec {
+    use soroban_sdk::contracterror;
+
+    #[contracterror]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    #[repr(u32)]
+    pub enum VaultError {
+        AlreadyInitialized = 1,
+        NotInitialized = 2,
+        NoSigners = 3,
+        ThresholdTooLow = 4,
+        ThresholdTooHigh = 5,
+        QuorumTooHigh = 6,
+        QuorumNotReached = 7,
+        Unauthorized = 10,
+        NotASigner = 11,
+        InsufficientRole = 12,
+        VoterNotInSnapshot = 13,
+        ProposalNotFound = 20,
+        ProposalNotPending = 21,
+        ProposalNotApproved = 22,
+        ProposalAlreadyExecuted = 23,
+        ProposalExpired = 24,
+        ProposalAlreadyCancelled = 25,
+        VotingDeadlinePassed = 26,
+        AlreadyApproved = 30,
+        InvalidAmount = 40,
+        ExceedsProposalLimit = 41,
+        ExceedsDailyLimit = 42,
+        ExceedsWeeklyLimit = 43,
+        VelocityLimitExceeded = 50,
+        TimelockNotExpired = 60,
+        SchedulingError = 61,
+        InsufficientBalance = 70,
+        TransferFailed = 71,
+        SignerAlreadyExists = 80,
+        SignerNotFound = 81,
+        CannotRemoveSigner = 82,
+        RecipientNotWhitelisted = 90,
+        RecipientBlacklisted = 91,
+        AddressAlreadyOnList = 92,
+        AddressNotOnList = 93,
+        InsuranceInsufficient = 110,
+        GasLimitExceeded = 120,
+        BatchTooLarge = 130,
+        ConditionsNotMet = 140,
+        IntervalTooShort = 150,
+        DexError = 160,
+        RetryError = 168,
+    }
+
+    #[contracterror]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    #[repr(u32)]
+    pub enum VaultFeatureError {
+        TemplateNotFound = 210,
+        TemplateInactive = 211,
+        TemplateValidationFailed = 212,
+        FundingRoundError = 220,
+        /// Attachment hash is too short or too long to be a valid CID
+        AttachmentHashInvalid = 230,
+        /// Proposal has reached the maximum number of attachments
+        TooManyAttachments = 231,
+        /// Proposal has reached the maximum number of tags
+        TooManyTags = 232,
+        /// Metadata value is empty or exceeds the maximum allowed length
+        MetadataValueInvalid = 233,
+        /// Subscription ID does not exist
+        SubscriptionNotFound = 240,
+        /// Subscription has already been cancelled
+        SubscriptionAlreadyCancelled = 241,
+        /// Renewal attempted before next_renewal_ledger has been reached
+        RenewalNotDue = 242,
+        /// Caller is neither the subscriber nor an Admin
+        NotSubscriberOrAdmin = 243,
+        /// Subscription is not in Active status (e.g. Cancelled / Suspended)
+        SubscriptionNotActive = 244,
+        /// The entrypoint's category is paused by a guardian
+        VaultPaused = 250,
+        /// Stored data is already at the version this WASM expects
+        AlreadyMigrated = 251,
+        /// Stored entry uses a layout this WASM does not recognise
+        UnknownSchema = 252,
+        /// Every audit entry is already covered by a checkpoint
+        NothingToCheckpoint = 253,
+        /// An entry in the range fails hash or link verification
+        AuditChainBroken = 254,
+        /// The audit entry is not covered by a checkpoint yet
+        AuditEntryNotCheckpointed = 255,
+        /// No custom role is defined under this name
+        CustomRoleNotFound = 256,
+        /// Too many roles defined, members in a role or roles held by an address
+        CustomRoleLimit = 257,
+        /// Role has no permissions, a duplicate permission or a non-positive cap
+        InvalidRolePermissions = 258,
+        /// Scope has a non-positive amount cap or too many recipients or tags
+        InvalidPermissionScope = 259,
+        /// No session key is registered for this address
+        SessionKeyNotFound = 260,
+        /// The session key is past its expiry ledger
+        SessionKeyExpired = 261,
+        /// The session key has used up its calls or spend cap
+        SessionKeyExhausted = 262,
+        /// The session key may not call this entrypoint
+        SessionKeyNotAllowed = 263,
+        /// Bad registration: unknown entrypoint, no uses, negative cap, past
+        /// expiry, the key is a signer or another signer's key, or the owner
+        /// already holds the maximum number of keys
+        InvalidSessionKey = 264,
+        /// No queued admin action with this ID
+        AdminActionNotFound = 265,
+        /// The call needs a queued admin action confirmed by the council threshold
+        AdminActionNotConfirmed = 266,
+        /// The queued admin action is past its expiry or no longer pending
+        AdminActionExpired = 267,
+        /// Council threshold is zero or above the number of admins, the action is
+        /// not a council action, or `set_admin_council` would be exempted
+        InvalidAdminCouncil = 268,
+        /// No queued configuration change with this ID
+        ConfigChangeNotFound = 269,
+        /// The configuration change was already applied or cancelled
+        ConfigChangeNotPending = 270,
+        /// Too many configuration changes are already pending
+        ConfigChangeLimit = 271,
+    }
 }
 
 // Compatibility markers for CI source checks:
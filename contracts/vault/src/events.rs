@@ -2,8 +2,11 @@
 //!
 //! Standardized events for proposal lifecycle and admin actions.
 
-use crate::types::ProposalAmendment;
-use soroban_sdk::{Address, Env, Symbol};
+use crate::types::{
+    Asset, FarmPosition, HookStage, LifecycleEvent, LimitOrderStatus, LpPosition, PauseCategory,
+    ProposalAmendment,
+};
+use soroban_sdk::{Address, BytesN, Env, Symbol};
 
 /// Emit when contract is initialized
 pub fn emit_initialized(env: &Env, admin: &Address, threshold: u32) {
@@ -217,11 +220,136 @@ pub fn emit_oracle_config_updated(env: &Env, admin: &Address, oracle: &Address)
     );
 }
 
+/// Emit when the oracle sources for an asset are set or cleared
+pub fn emit_asset_oracle_sources_updated(env: &Env, admin: &Address, asset: &Address, count: u32) {
+    env.events().publish(
+        (Symbol::new(env, "oracle_sources_updated"),),
+        (admin.clone(), asset.clone(), count),
+    );
+}
+
+/// Emit when the SEP-40 asset a token is priced as changes
+pub fn emit_oracle_asset_updated(env: &Env, admin: &Address, token: &Address, asset: &Asset) {
+    env.events().publish(
+        (Symbol::new(env, "oracle_asset_updated"),),
+        (admin.clone(), token.clone(), asset.clone()),
+    );
+}
+
+/// Emit when oracle sources for an asset disagree beyond the allowed deviation
+pub fn emit_oracle_deviation_exceeded(
+    env: &Env,
+    asset: &Address,
+    median: i128,
+    min_price: i128,
+    max_price: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "oracle_deviation"),),
+        (asset.clone(), median, min_price, max_price),
+    );
+}
+
+/// Emit when a circuit breaker is configured (max_move_bps 0 = removed)
+pub fn emit_circuit_breaker_updated(
+    env: &Env,
+    admin: &Address,
+    asset: &Address,
+    max_move_bps: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "breaker_updated"),),
+        (admin.clone(), asset.clone(), max_move_bps),
+    );
+}
+
+/// Emit when an asset's price moves beyond its circuit breaker bound
+pub fn emit_circuit_breaker_tripped(
+    env: &Env,
+    asset: &Address,
+    reference_price: i128,
+    price: i128,
+    move_bps: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "breaker_tripped"), asset.clone()),
+        (reference_price, price, move_bps),
+    );
+}
+
+/// Emit when a signer approves resetting a tripped circuit breaker
+pub fn emit_circuit_breaker_reset_approved(
+    env: &Env,
+    asset: &Address,
+    signer: &Address,
+    approvals: u32,
+    threshold: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "breaker_reset_approved"), asset.clone()),
+        (signer.clone(), approvals, threshold),
+    );
+}
+
+/// Emit when a tripped circuit breaker is reset
+pub fn emit_circuit_breaker_reset(env: &Env, asset: &Address, by: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "breaker_reset"), asset.clone()),
+        by.clone(),
+    );
+}
+
+/// Emit when the pause configuration changes
+pub fn emit_pause_config_updated(env: &Env, admin: &Address, unpause_threshold: u32) {
+    env.events().publish(
+        (Symbol::new(env, "pause_config_updated"),),
+        (admin.clone(), unpause_threshold),
+    );
+}
+
+/// Emit when a guardian pauses a category
+pub fn emit_vault_paused(
+    env: &Env,
+    category: &PauseCategory,
+    guardian: &Address,
+    reason: &Symbol,
+    expires_at: u64,
+) {
+    env.events().publish(
+        (Symbol::new(env, "vault_paused"), category.clone() as u32),
+        (guardian.clone(), reason.clone(), expires_at),
+    );
+}
+
+/// Emit when a signer approves lifting a pause
+pub fn emit_unpause_approved(
+    env: &Env,
+    category: &PauseCategory,
+    signer: &Address,
+    approvals: u32,
+) {
+    env.events().publish(
+        (
+            Symbol::new(env, "unpause_approved"),
+            category.clone() as u32,
+        ),
+        (signer.clone(), approvals),
+    );
+}
+
+/// Emit when a pause is lifted by signer approval
+pub fn emit_vault_unpaused(env: &Env, category: &PauseCategory) {
+    env.events().publish(
+        (Symbol::new(env, "vault_unpaused"), category.clone() as u32),
+        (),
+    );
+}
+
 /// Emit when a stale oracle price blocks condition evaluation
-pub fn emit_oracle_price_stale(env: &Env, asset: &Address, price_ledger: u64, current_ledger: u64) {
+pub fn emit_oracle_price_stale(env: &Env, asset: &Address, price_time: u64, current_time: u64) {
     env.events().publish(
         (Symbol::new(env, "oracle_price_stale"),),
-        (asset.clone(), price_ledger, current_ledger),
+        (asset.clone(), price_time, current_time),
     );
 }
 
@@ -422,8 +550,91 @@ pub fn emit_hook_executed(env: &Env, hook: &Address, proposal_id: u64, is_pre: b
     );
 }
 
+/// Emit when a typed pre-execution hook blocks a proposal
+pub fn emit_hook_denied(
+    env: &Env,
+    proposal_id: u64,
+    hook: &Address,
+    reason_code: u32,
+    hook_failed: bool,
+) {
+    env.events().publish(
+        (Symbol::new(env, "hook_denied"), proposal_id),
+        (hook.clone(), reason_code, hook_failed),
+    );
+}
+
+/// Emit when a fail-open typed hook call fails and execution continues
+pub fn emit_hook_failed(env: &Env, hook: &Address, proposal_id: u64, is_pre: bool) {
+    env.events().publish(
+        (Symbol::new(env, "hook_failed"), proposal_id),
+        (hook.clone(), is_pre),
+    );
+}
+
+/// Emit when a hook is subscribed to a lifecycle stage
+pub fn emit_lifecycle_hook_registered(env: &Env, hook: &Address, stage: &HookStage) {
+    env.events().publish(
+        (Symbol::new(env, "lifecycle_hook_registered"),),
+        (hook.clone(), stage.clone() as u32),
+    );
+}
+
+/// Emit when a hook is unsubscribed from a lifecycle stage
+pub fn emit_lifecycle_hook_removed(env: &Env, hook: &Address, stage: &HookStage) {
+    env.events().publish(
+        (Symbol::new(env, "lifecycle_hook_removed"),),
+        (hook.clone(), stage.clone() as u32),
+    );
+}
+
+/// Emit when a lifecycle hook is notified
+pub fn emit_lifecycle_hook_notified(env: &Env, hook: &Address, event: &LifecycleEvent) {
+    env.events().publish(
+        (Symbol::new(env, "lifecycle_hook"), event.subject_id),
+        (
+            hook.clone(),
+            event.stage.clone() as u32,
+            event.action.clone(),
+        ),
+    );
+}
+
+/// Emit when a fail-open lifecycle hook call fails
+pub fn emit_lifecycle_hook_failed(env: &Env, hook: &Address, event: &LifecycleEvent) {
+    env.events().publish(
+        (Symbol::new(env, "lifecycle_hook_failed"), event.subject_id),
+        (
+            hook.clone(),
+            event.stage.clone() as u32,
+            event.action.clone(),
+        ),
+    );
+}
+
+/// Emit when a swap is executed against a DEX router
+pub fn emit_swap_executed(
+    env: &Env,
+    proposal_id: u64,
+    dex: &Address,
+    amount_in: i128,
+    amount_out: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "swap_executed"), proposal_id),
+        (dex.clone(), amount_in, amount_out),
+    );
+}
+
+/// Emit when liquidity is added to a pool
+pub fn emit_liquidity_added(env: &Env, proposal_id: u64, dex: &Address, lp_tokens: i128) {
+    env.events().publish(
+        (Symbol::new(env, "liquidity_added"), proposal_id),
+        (dex.clone(), lp_tokens),
+    );
+}
+
 /// Emit when liquidity is removed
-#[allow(dead_code)]
 pub fn emit_liquidity_removed(env: &Env, proposal_id: u64, dex: &Address, lp_tokens: i128) {
     env.events().publish(
         (Symbol::new(env, "liquidity_removed"), proposal_id),
@@ -432,7 +643,6 @@ pub fn emit_liquidity_removed(env: &Env, proposal_id: u64, dex: &Address, lp_tok
 }
 
 /// Emit when LP tokens are staked
-#[allow(dead_code)]
 pub fn emit_lp_staked(env: &Env, proposal_id: u64, farm: &Address, amount: i128) {
     env.events().publish(
         (Symbol::new(env, "lp_staked"), proposal_id),
@@ -440,8 +650,15 @@ pub fn emit_lp_staked(env: &Env, proposal_id: u64, farm: &Address, amount: i128)
     );
 }
 
+/// Emit when LP tokens are unstaked
+pub fn emit_lp_unstaked(env: &Env, proposal_id: u64, farm: &Address, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "lp_unstaked"), proposal_id),
+        (farm.clone(), amount),
+    );
+}
+
 /// Emit when rewards are claimed
-#[allow(dead_code)]
 pub fn emit_rewards_claimed(env: &Env, proposal_id: u64, farm: &Address, amount: i128) {
     env.events().publish(
         (Symbol::new(env, "rewards_claimed"), proposal_id),
@@ -449,6 +666,109 @@ pub fn emit_rewards_claimed(env: &Env, proposal_id: u64, farm: &Address, amount:
     );
 }
 
+/// Emit when a tracked LP position changes
+pub fn emit_lp_position_updated(env: &Env, position: &LpPosition) {
+    env.events().publish(
+        (
+            Symbol::new(env, "lp_position_updated"),
+            position.lp_token.clone(),
+        ),
+        (
+            position.lp_held,
+            position.lp_staked,
+            position.cost_basis_a,
+            position.cost_basis_b,
+            position.realized_a,
+            position.realized_b,
+        ),
+    );
+}
+
+/// Emit when a tracked farm position changes
+pub fn emit_farm_position_updated(env: &Env, position: &FarmPosition) {
+    env.events().publish(
+        (
+            Symbol::new(env, "farm_position_updated"),
+            position.farm.clone(),
+        ),
+        (position.staked, position.rewards_claimed),
+    );
+}
+
+/// Emit when a DCA order is opened by an executed swap proposal
+pub fn emit_dca_order_created(
+    env: &Env,
+    order_id: u64,
+    token_in: &Address,
+    token_out: &Address,
+    total_amount: i128,
+    slices: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "dca_order_created"), order_id),
+        (token_in.clone(), token_out.clone(), total_amount, slices),
+    );
+}
+
+/// Emit when a keeper executes one slice of a DCA order
+pub fn emit_dca_slice_executed(
+    env: &Env,
+    order_id: u64,
+    slice: u32,
+    amount_in: i128,
+    amount_out: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "dca_slice_executed"), order_id),
+        (slice, amount_in, amount_out),
+    );
+}
+
+/// Emit when the last slice of a DCA order has been executed
+pub fn emit_dca_order_completed(env: &Env, order_id: u64, amount_in: i128, amount_out: i128) {
+    env.events().publish(
+        (Symbol::new(env, "dca_order_completed"), order_id),
+        (amount_in, amount_out),
+    );
+}
+
+/// Emit when a DCA order is cancelled by governance
+pub fn emit_dca_order_cancelled(
+    env: &Env,
+    order_id: u64,
+    canceller: &Address,
+    remaining_amount: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "dca_order_cancelled"), order_id),
+        (canceller.clone(), remaining_amount),
+    );
+}
+
+/// Emit when a price-triggered limit order is proposed
+pub fn emit_limit_order_created(env: &Env, order_id: u64, expires_at: u64, max_attempts: u32) {
+    env.events().publish(
+        (Symbol::new(env, "limit_order_created"), order_id),
+        (expires_at, max_attempts),
+    );
+}
+
+/// Emit when a limit order's trigger held but the fill failed
+pub fn emit_limit_order_attempt_failed(env: &Env, order_id: u64, attempts: u32, max_attempts: u32) {
+    env.events().publish(
+        (Symbol::new(env, "limit_order_attempt_failed"), order_id),
+        (attempts, max_attempts),
+    );
+}
+
+/// Emit when a limit order leaves the open state (filled, exhausted, expired or cancelled)
+pub fn emit_limit_order_closed(env: &Env, order_id: u64, status: &LimitOrderStatus) {
+    env.events().publish(
+        (Symbol::new(env, "limit_order_closed"), order_id),
+        status.clone(),
+    );
+}
+
 // ============================================================================
 // Gas Limit Events (feature/gas-limits)
 // ============================================================================
@@ -920,6 +1240,73 @@ pub fn emit_recovery_executed(env: &Env, proposal_id: u64) {
         .publish((Symbol::new(env, "recovery_executed"), proposal_id), ());
 }
 
+/// Emit when a WASM upgrade is proposed
+pub fn emit_upgrade_proposed(
+    env: &Env,
+    proposal_id: u64,
+    proposer: &Address,
+    new_wasm_hash: &BytesN<32>,
+) {
+    env.events().publish(
+        (Symbol::new(env, "upgrade_proposed"), proposal_id),
+        (proposer.clone(), new_wasm_hash.clone()),
+    );
+}
+
+/// Emit when a signer approves a WASM upgrade
+pub fn emit_upgrade_approved(env: &Env, proposal_id: u64, signer: &Address, execution_after: u64) {
+    env.events().publish(
+        (Symbol::new(env, "upgrade_approved"), proposal_id),
+        (signer.clone(), execution_after),
+    );
+}
+
+/// Emit when the vault switches to a new WASM
+pub fn emit_upgrade_executed(env: &Env, proposal_id: u64, new_wasm_hash: &BytesN<32>) {
+    env.events().publish(
+        (Symbol::new(env, "upgrade_executed"), proposal_id),
+        new_wasm_hash.clone(),
+    );
+}
+
+/// Emit when a WASM upgrade proposal is cancelled
+pub fn emit_upgrade_cancelled(env: &Env, proposal_id: u64, canceller: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "upgrade_cancelled"), proposal_id),
+        canceller.clone(),
+    );
+}
+
+/// Emit when stored data is migrated to a new version
+pub fn emit_contract_migrated(env: &Env, from_version: u32, to_version: u32) {
+    env.events().publish(
+        (Symbol::new(env, "contract_migrated"),),
+        (from_version, to_version),
+    );
+}
+
+/// Emit when a batch of stored entries is rewritten by a migration
+pub fn emit_migration_batch(env: &Env, processed: u32, migrated: u64, complete: bool) {
+    env.events().publish(
+        (Symbol::new(env, "migration_batch"),),
+        (processed, migrated, complete),
+    );
+}
+
+/// Emit when a Merkle checkpoint is committed over a range of audit entries
+pub fn emit_audit_checkpoint(
+    env: &Env,
+    checkpoint_id: u64,
+    start_id: u64,
+    end_id: u64,
+    root: &BytesN<32>,
+) {
+    env.events().publish(
+        (Symbol::new(env, "audit_checkpoint"), checkpoint_id),
+        (start_id, end_id, root.clone()),
+    );
+}
+
 /// Emit when a recovery proposal is cancelled
 pub fn emit_recovery_cancelled(env: &Env, proposal_id: u64, canceller: &Address) {
     env.events().publish(
@@ -1066,6 +1453,38 @@ pub fn emit_permission_delegated(
     );
 }
 
+/// Emit when a custom role is defined or its permissions replaced
+pub fn emit_custom_role_defined(env: &Env, name: &Symbol, admin: &Address, permissions: u32) {
+    env.events().publish(
+        (Symbol::new(env, "custom_role_defined"), name.clone()),
+        (admin.clone(), permissions),
+    );
+}
+
+/// Emit when a custom role is removed along with its memberships
+pub fn emit_custom_role_removed(env: &Env, name: &Symbol, admin: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "custom_role_removed"), name.clone()),
+        admin.clone(),
+    );
+}
+
+/// Emit when an address joins a custom role
+pub fn emit_custom_role_assigned(env: &Env, name: &Symbol, member: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "custom_role_assigned"), name.clone()),
+        member.clone(),
+    );
+}
+
+/// Emit when an address leaves a custom role
+pub fn emit_custom_role_unassigned(env: &Env, name: &Symbol, member: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "custom_role_unassigned"), name.clone()),
+        member.clone(),
+    );
+}
+
 pub fn emit_dispute_raised(env: &Env, dispute_id: u64, proposal_id: u64, disputer: &Address) {
     env.events().publish(
         (Symbol::new(env, "dispute_raised"), dispute_id),
@@ -1079,3 +1498,89 @@ pub fn emit_dispute_resolved(env: &Env, dispute_id: u64, admin: &Address, resolu
         (admin.clone(), resolution),
     );
 }
+
+/// Emit when a signer registers (or replaces) a session key
+pub fn emit_session_key_registered(env: &Env, owner: &Address, key: &Address, expires_at: u64) {
+    env.events().publish(
+        (Symbol::new(env, "session_key_registered"), owner.clone()),
+        (key.clone(), expires_at),
+    );
+}
+
+/// Emit when a session key is revoked
+pub fn emit_session_key_revoked(env: &Env, key: &Address, revoked_by: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "session_key_revoked"), key.clone()),
+        revoked_by.clone(),
+    );
+}
+
+/// Emit when the admin council threshold or exemptions change
+pub fn emit_admin_council_updated(env: &Env, admin: &Address, threshold: u32) {
+    env.events().publish(
+        (Symbol::new(env, "admin_council_updated"),),
+        (admin.clone(), threshold),
+    );
+}
+
+/// Emit when an admin queues a council action
+pub fn emit_admin_action_queued(env: &Env, id: u64, action: &Symbol, proposer: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "admin_action_queued"), id),
+        (action.clone(), proposer.clone()),
+    );
+}
+
+/// Emit when an admin confirms a queued council action
+pub fn emit_admin_action_confirmed(env: &Env, id: u64, admin: &Address, confirmations: u32) {
+    env.events().publish(
+        (Symbol::new(env, "admin_action_confirmed"), id),
+        (admin.clone(), confirmations),
+    );
+}
+
+/// Emit when a queued council action is cancelled
+pub fn emit_admin_action_cancelled(env: &Env, id: u64, admin: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "admin_action_cancelled"), id),
+        admin.clone(),
+    );
+}
+
+/// Emit when a confirmed council action is carried out
+pub fn emit_admin_action_executed(env: &Env, id: u64, admin: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "admin_action_executed"), id),
+        admin.clone(),
+    );
+}
+
+/// Emit when a configuration change is queued behind the config timelock
+pub fn emit_config_change_queued(
+    env: &Env,
+    id: u64,
+    action: &Symbol,
+    proposer: &Address,
+    eta: u64,
+) {
+    env.events().publish(
+        (Symbol::new(env, "config_change_queued"), id),
+        (action.clone(), proposer.clone(), eta),
+    );
+}
+
+/// Emit when a pending configuration change is cancelled
+pub fn emit_config_change_cancelled(env: &Env, id: u64, cancelled_by: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "config_change_cancelled"), id),
+        cancelled_by.clone(),
+    );
+}
+
+/// Emit when a queued configuration change takes effect
+pub fn emit_config_change_applied(env: &Env, id: u64, applied_by: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "config_change_applied"), id),
+        applied_by.clone(),
+    );
+}
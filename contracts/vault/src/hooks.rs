@@ -0,0 +1,49 @@
+//! VaultDAO - Typed Hooks
+//!
+//! Client interface for hook contracts that inspect proposals around
+//! execution or observe lifecycle changes. Pre-execution hooks return a
+//! [`HookDecision`] that can block the transfer; post-execution and
+//! lifecycle hooks are notified after the change happened.
+
+use crate::types::{HookContext, HookDecision, HookFilter, HookStage, LifecycleEvent};
+use soroban_sdk::{contractclient, Address, Env, Symbol, Vec};
+
+/// Interface implemented by typed hook contracts.
+///
+/// A hook only needs the functions for the stages it is registered for.
+#[allow(dead_code)]
+#[contractclient(name = "ExecutionHookClient")]
+pub trait ExecutionHookInterface {
+    /// Decide whether the proposal may execute.
+    fn before_execute(env: Env, ctx: HookContext) -> HookDecision;
+
+    /// Observe a completed execution.
+    fn after_execute(env: Env, ctx: HookContext);
+
+    /// Observe a proposal, stream, escrow or subscription lifecycle change.
+    fn on_lifecycle(env: Env, event: LifecycleEvent);
+}
+
+/// Whether `stage` is a lifecycle notification rather than an execution stage.
+pub fn is_lifecycle(stage: &HookStage) -> bool {
+    !matches!(stage, HookStage::PreExecute | HookStage::PostExecute)
+}
+
+/// Whether a hook with `filter` runs for a subject with these properties.
+pub fn matches_filter(
+    filter: &HookFilter,
+    token: &Address,
+    amount: i128,
+    tags: &Vec<Symbol>,
+) -> bool {
+    if !filter.tokens.is_empty() && !filter.tokens.contains(token) {
+        return false;
+    }
+    if amount < filter.min_amount {
+        return false;
+    }
+    if filter.max_amount > 0 && amount > filter.max_amount {
+        return false;
+    }
+    filter.tags.is_empty() || tags.iter().any(|tag| filter.tags.contains(&tag))
+}
@@ -13,27 +13,38 @@
 // mod bridge; // Feature incomplete
 #[cfg(feature = "bridge")]
 mod bridge;
+mod dex;
 mod errors;
 mod events;
+mod hooks;
+mod oracle;
 mod storage;
 mod token;
 mod types;
 
 use errors::VaultError;
-use soroban_sdk::{contract, contractimpl, Address, Env, IntoVal, Map, String, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, Address, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val,
+    Vec,
+};
 use types::{
-    AuditAction, AuditEntry, BatchExecutionResult, BatchOperation, BatchStatus, BatchTransaction,
-    CancellationRecord, Comment, Condition, ConditionLogic, Config, CrossVaultConfig,
-    CrossVaultProposal, CrossVaultStatus, Delegation, DelegationHistory, DexConfig, Dispute,
-    DisputeResolution, DisputeStatus, Escrow, EscrowStatus, ExecutionFeeEstimate, FundingMilestone,
-    FundingMilestoneStatus, FundingRound, FundingRoundConfig, FundingRoundStatus, GasConfig,
-    InitConfig, InsuranceConfig, ListMode, Milestone, NotificationPreferences,
-    OptionalVaultOracleConfig, Priority, Proposal, ProposalAmendment, ProposalStatus,
-    ProposalTemplate, RecoveryConfig, RecoveryProposal, RecoveryStatus, RecurringPayment,
-    Reputation, RetryConfig, RetryState, Role, RoleAssignment, StreamStatus, StreamingPayment,
-    Subscription, SubscriptionStatus, SubscriptionTier, SwapProposal, SwapResult,
-    TemplateOverrides, ThresholdStrategy, TransferDetails, VaultAction, VaultMetrics,
-    VaultOracleConfig, VaultPriceData, VotingStrategy,
+    AdminAction, AdminActionStatus, AdminCouncil, Asset, AssetOracleSources, AuditAction,
+    AuditCheckpoint, AuditEntry, AuditFilter, AuditPage, AuditProof, BatchExecutionResult,
+    BatchOperation, BatchStatus, BatchTransaction, CancellationRecord, CircuitBreakerConfig,
+    CircuitBreakerState, Comment, Condition, ConditionLogic, Config, ConfigChange,
+    ConfigChangeStatus, CrossVaultConfig, CrossVaultProposal, CrossVaultStatus, DcaOrder,
+    DcaStatus, Delegation, DelegationHistory, DexConfig, Dispute, DisputeResolution, DisputeStatus,
+    Escrow, EscrowStatus, ExecutionFeeEstimate, ExecutionHook, ExecutionOutcome, FarmPosition,
+    FundingMilestone, FundingMilestoneStatus, FundingRound, FundingRoundConfig, FundingRoundStatus,
+    GasConfig, HookContext, HookDenial, HookFailurePolicy, HookStage, InitConfig, InsuranceConfig,
+    LifecycleEvent, LimitOrder, LimitOrderStatus, ListMode, LpPosition, MigrationProgress,
+    Milestone, NotificationPreferences, OptionalVaultOracleConfig, PauseCategory, PauseConfig,
+    PauseState, PreflightCheck, PreflightReport, Priority, Proposal, ProposalAmendment,
+    ProposalStatus, ProposalTemplate, RecoveryConfig, RecoveryProposal, RecoveryStatus,
+    RecurringPayment, Reputation, RetryConfig, RetryState, Role, RoleAssignment, SessionKey,
+    StreamStatus, StreamingPayment, Subscription, SubscriptionStatus, SubscriptionTier,
+    SwapProposal, SwapResult, TemplateOverrides, ThresholdStrategy, TransferDetails,
+    UpgradeProposal, UpgradeStatus, VaultAction, VaultMetrics, VaultOracleConfig, VotingStrategy,
 };
 
 /// The main contract structure for VaultDAO.
@@ -76,6 +87,125 @@ const MAX_ATTACHMENT_LEN: u32 = 128;
 /// Prevents near-instant repeated draining of the vault.
 const MIN_RECURRING_INTERVAL: u64 = 720;
 
+/// Maximum number of time buckets returned by a runway projection
+const MAX_RUNWAY_BUCKETS: u64 = 104;
+
+/// Most recent IDs of each schedule type a runway projection reads
+const MAX_RUNWAY_SCAN: u64 = 200;
+
+/// Maximum nesting depth of condition groups
+const MAX_CONDITION_DEPTH: u32 = 3;
+
+/// Data layout version this WASM expects; bump with each migration step
+const CONTRACT_VERSION: u32 = 4;
+
+/// Maximum number of stored entries rewritten per `migrate_batch` call
+const MAX_MIGRATION_BATCH: u32 = 50;
+
+/// Minimum delay between approving and executing a WASM upgrade: ~24 hours
+const UPGRADE_TIMELOCK: u64 = 17_280;
+
+/// How long an upgrade may wait for approvals, and then for execution once
+/// its timelock has passed: ~7 days
+const UPGRADE_EXPIRY: u64 = 120_960;
+
+/// Maximum number of audit entries committed by one checkpoint
+const AUDIT_CHECKPOINT_SIZE: u64 = 32;
+
+/// Maximum number of audit entries returned by one query page
+const MAX_AUDIT_QUERY_RESULTS: u32 = 50;
+
+/// Maximum number of audit entries inspected by one query page
+const MAX_AUDIT_QUERY_SCAN: u64 = 200;
+
+/// Maximum number of custom roles a vault can define
+const MAX_CUSTOM_ROLES: u32 = 20;
+
+/// Maximum number of holders per custom role
+const MAX_CUSTOM_ROLE_MEMBERS: u32 = 50;
+
+/// Maximum number of custom roles one address can hold
+const MAX_CUSTOM_ROLES_PER_ADDRESS: u32 = 5;
+
+/// Maximum recipients, and separately tags, in a permission scope
+const MAX_SCOPE_ENTRIES: u32 = 20;
+
+/// Maximum number of session keys one signer can hold
+const MAX_SESSION_KEYS_PER_OWNER: u32 = 10;
+
+/// Entrypoints that accept session keys in place of their owner
+const SESSION_KEY_ENTRYPOINTS: [&str; 2] = ["execute_proposal", "execute_scheduled_proposal"];
+
+/// How long a queued admin action stays open: ~7 days in ledgers
+const ADMIN_ACTION_EXPIRY_LEDGERS: u64 = 120_960;
+
+/// Admin entrypoints that need council confirmation once its threshold is
+/// above 1, unless exempted in the council configuration
+const COUNCIL_ACTIONS: [&str; 56] = [
+    "update_threshold",
+    "update_limits",
+    "update_quorum",
+    "update_voting_strategy",
+    "withdraw_insurance_pool",
+    "withdraw_stake_pool",
+    "update_staking_config",
+    "set_role",
+    "remove_signer",
+    "set_list_mode",
+    "add_to_whitelist",
+    "remove_from_whitelist",
+    "add_to_blacklist",
+    "remove_from_blacklist",
+    "set_insurance_config",
+    "set_fee_structure",
+    "withdraw_fees",
+    "set_gas_config",
+    "update_oracle_config",
+    "set_dex_config",
+    "rollback_execution",
+    "set_recovery_config",
+    "grant_permission",
+    "revoke_permission",
+    "define_custom_role",
+    "remove_custom_role",
+    "assign_custom_role",
+    "unassign_custom_role",
+    "set_admin_council",
+    "set_config_delay",
+    "cancel_dca_order",
+    "cancel_upgrade",
+    "extend_voting_deadline",
+    "set_pause_config",
+    "set_asset_oracle_sources",
+    "remove_asset_oracle_sources",
+    "set_oracle_asset",
+    "set_circuit_breaker",
+    "remove_circuit_breaker",
+    "reset_circuit_breaker",
+    "register_pre_hook",
+    "register_post_hook",
+    "remove_pre_hook",
+    "remove_post_hook",
+    "register_execution_hook",
+    "remove_execution_hook",
+    "cancel_limit_order",
+    "create_template",
+    "set_template_status",
+    "resolve_escrow_dispute",
+    "set_time_weighted_config",
+    "cancel_recovery",
+    "approve_funding_round",
+    "verify_milestone",
+    "cancel_funding_round",
+    "set_cross_vault_config",
+];
+
+/// Longest configurable config timelock: ~7 days in ledgers
+const MAX_CONFIG_DELAY_LEDGERS: u64 = 120_960;
+
+/// Most configuration changes that can wait out the timelock at once
+const MAX_PENDING_CONFIG_CHANGES: u32 = 20;
+
 const REP_EXEC_PROPOSER: u32 = 10;
 const REP_EXEC_APPROVER: u32 = 5;
 const REP_REJECTION_PENALTY: u32 = 20;
@@ -112,6 +242,38 @@ mod test_subscriptions;
 mod test_voting_deadline;
 #[cfg(test)]
 mod test_streaming;
+#[cfg(test)]
+mod test_runway;
+#[cfg(test)]
+mod test_dex;
+#[cfg(test)]
+mod test_oracle;
+#[cfg(test)]
+mod test_conditions;
+#[cfg(test)]
+mod test_preflight;
+#[cfg(test)]
+mod test_execution_hooks;
+#[cfg(test)]
+mod test_pause;
+#[cfg(test)]
+mod test_upgrade;
+#[cfg(test)]
+mod test_schema_migration;
+#[cfg(test)]
+mod test_audit_checkpoint;
+#[cfg(test)]
+mod test_audit_query;
+#[cfg(test)]
+mod test_custom_roles;
+#[cfg(test)]
+mod test_permission_scopes;
+#[cfg(test)]
+mod test_session_keys;
+#[cfg(test)]
+mod test_admin_council;
+#[cfg(test)]
+mod test_config_timelock;
 
 #[contractimpl]
 #[allow(clippy::too_many_arguments)]
@@ -185,10 +347,17 @@ impl VaultDAO {
             storage::add_role_index_address(&env, &signer);
         }
         storage::set_initialized(&env);
+        storage::set_contract_version(&env, CONTRACT_VERSION);
         storage::extend_instance_ttl(&env);
 
         // Create audit entry
-        storage::create_audit_entry(&env, AuditAction::Initialize, &admin, 0);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Initialize,
+            &admin,
+            0,
+            config_storage.clone(),
+        );
 
         // Emit event
         events::emit_initialized(&env, &admin, config.threshold);
@@ -344,6 +513,7 @@ impl VaultDAO {
         depends_on: Vec<u64>,
         execution_time: Option<u64>,
     ) -> Result<u64, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Proposals)?;
         // 1. Verify identity
         proposer.require_auth();
 
@@ -351,8 +521,13 @@ impl VaultDAO {
         let config = storage::get_config(&env)?;
 
         // 3. Check permission
-        let role = storage::get_role(&env, &proposer);
-        if role != Role::Treasurer && role != Role::Admin {
+        let request = PermissionRequest::transfer(&env, &token_addr, amount, &recipient);
+        if !Self::check_permission(
+            &env,
+            &proposer,
+            &types::Permission::CreateProposal,
+            Some(&request),
+        ) {
             return Err(VaultError::InsufficientRole);
         }
 
@@ -368,6 +543,7 @@ impl VaultDAO {
         if amount <= 0 {
             return Err(VaultError::InvalidAmount);
         }
+        Self::validate_conditions(&conditions, 0)?;
 
         // 7. Check per-proposal spending limit with reputation boost
         // High reputation (800+) gets 2x limit, very high (900+) gets 3x
@@ -555,7 +731,13 @@ impl VaultDAO {
         storage::extend_instance_ttl(&env);
 
         // Create audit entry
-        storage::create_audit_entry(&env, AuditAction::ProposeTransfer, &proposer, proposal_id);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::ProposeTransfer,
+            &proposer,
+            proposal_id,
+            (&recipient, &token_addr, amount),
+        );
         // 13. Emit events
         // 15. Emit events
         if actual_insurance > 0 {
@@ -579,6 +761,16 @@ impl VaultDAO {
             amount,
             actual_insurance,
         );
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::proposal_lifecycle_event(
+                &env,
+                HookStage::ProposalCreated,
+                "created",
+                &proposal,
+                &proposer,
+            ),
+        );
 
         // Update reputation for creating proposal
         Self::update_reputation_on_propose(&env, &proposer);
@@ -622,16 +814,31 @@ impl VaultDAO {
         condition_logic: ConditionLogic,
         insurance_amount: i128,
     ) -> Result<Vec<u64>, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Proposals)?;
         proposer.require_auth();
 
         if transfers.len() > MAX_BATCH_SIZE {
             return Err(VaultError::BatchTooLarge);
         }
+        Self::validate_conditions(&conditions, 0)?;
 
         let config = storage::get_config(&env)?;
-        let role = storage::get_role(&env, &proposer);
-        if role != Role::Treasurer && role != Role::Admin {
-            return Err(VaultError::InsufficientRole);
+        // Each transfer becomes its own proposal and must be covered on its own
+        for transfer in transfers.iter() {
+            let request = PermissionRequest::transfer(
+                &env,
+                &transfer.token,
+                transfer.amount,
+                &transfer.recipient,
+            );
+            if !Self::check_permission(
+                &env,
+                &proposer,
+                &types::Permission::CreateProposal,
+                Some(&request),
+            ) {
+                return Err(VaultError::InsufficientRole);
+            }
         }
 
         // Velocity check once for the batch
@@ -779,6 +986,13 @@ impl VaultDAO {
             Self::persist_execution_fee_estimate(&env, &proposal);
             storage::add_to_priority_queue(&env, priority.clone() as u32, proposal_id);
             proposal_ids.push_back(proposal_id);
+            storage::create_audit_entry(
+                &env,
+                AuditAction::ProposeTransfer,
+                &proposer,
+                proposal_id,
+                (&transfer.recipient, &transfer.token, transfer.amount),
+            );
 
             events::emit_proposal_created(
                 &env,
@@ -789,6 +1003,16 @@ impl VaultDAO {
                 transfer.amount,
                 insurance_per_proposal,
             );
+            Self::notify_lifecycle_hooks(
+                &env,
+                Self::proposal_lifecycle_event(
+                    &env,
+                    HookStage::ProposalCreated,
+                    "created",
+                    &proposal,
+                    &proposer,
+                ),
+            );
         }
 
         storage::extend_instance_ttl(&env);
@@ -956,7 +1180,13 @@ impl VaultDAO {
 
         storage::set_proposal(&env, &proposal);
         storage::extend_instance_ttl(&env);
-        storage::create_audit_entry(&env, AuditAction::ApproveProposal, &signer, proposal_id);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::ApproveProposal,
+            &signer,
+            proposal_id,
+            approval_count,
+        );
 
         events::emit_proposal_approved(
             &env,
@@ -965,6 +1195,18 @@ impl VaultDAO {
             approval_count,
             config.threshold,
         );
+        if proposal.status != ProposalStatus::Pending {
+            Self::notify_lifecycle_hooks(
+                &env,
+                Self::proposal_lifecycle_event(
+                    &env,
+                    HookStage::ProposalApproved,
+                    "approved",
+                    &proposal,
+                    &signer,
+                ),
+            );
+        }
 
         Ok(())
     }
@@ -1105,16 +1347,28 @@ impl VaultDAO {
 
         storage::set_proposal(&env, &proposal);
         storage::extend_instance_ttl(&env);
-        storage::create_audit_entry(&env, AuditAction::AbstainProposal, &signer, proposal_id);
-
-        events::emit_proposal_abstained(
+        storage::create_audit_entry(
             &env,
-            proposal_id,
+            AuditAction::AbstainProposal,
             &signer,
-            abstention_count as u32,
-            quorum_votes as u32,
+            proposal_id,
+            abstention_count,
         );
 
+        events::emit_proposal_abstained(&env, proposal_id, &signer, abstention_count, quorum_votes);
+        if proposal.status != ProposalStatus::Pending {
+            Self::notify_lifecycle_hooks(
+                &env,
+                Self::proposal_lifecycle_event(
+                    &env,
+                    HookStage::ProposalApproved,
+                    "approved",
+                    &proposal,
+                    &signer,
+                ),
+            );
+        }
+
         Ok(())
     }
     /// Finalizes and executes an approved proposal.
@@ -1133,11 +1387,18 @@ impl VaultDAO {
     /// # Arguments
     /// * `executor` - The address triggering the final transfer (must authorize).
     /// * `proposal_id` - ID of the proposal to execute.
+    ///
+    /// # Returns
+    /// `Executed` when the transfer was made. A scheduled retry, a typed hook
+    /// denial or a circuit breaker trip also returns Ok so its record
+    /// persists, as `RetryScheduled`, `HookDenied` or `CircuitBreakerTripped`;
+    /// the proposal stays `Approved`.
     pub fn execute_proposal(
         env: Env,
         executor: Address,
         proposal_id: u64,
-    ) -> Result<(), VaultError> {
+    ) -> Result<ExecutionOutcome, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
         // Executor must authorize (to prevent griefing)
         executor.require_auth();
 
@@ -1155,6 +1416,19 @@ impl VaultDAO {
             return Err(VaultError::ProposalNotApproved);
         }
 
+        // Executor, or the signer behind its session key, must hold
+        // ExecuteProposal covering this proposal
+        let authority = Self::session_authority(&env, &executor, "execute_proposal")?;
+        let request = PermissionRequest::proposal(&env, &proposal);
+        if !Self::check_permission(
+            &env,
+            &authority,
+            &types::Permission::ExecuteProposal,
+            Some(&request),
+        ) {
+            return Err(VaultError::InsufficientRole);
+        }
+
         // Check expiration (even approved proposals can expire)
         let current_ledger = env.ledger().sequence() as u64;
         if current_ledger > proposal.expires_at {
@@ -1183,6 +1457,13 @@ impl VaultDAO {
             return Err(VaultError::TimelockNotExpired);
         }
 
+        // A trip detected now returns Ok so the latch persists; the proposal
+        // stays Approved and the session key keeps its use.
+        if Self::circuit_breaker_tripped(&env, &proposal.token)? {
+            Self::release_session_use(&env, &executor);
+            return Ok(ExecutionOutcome::CircuitBreakerTripped);
+        }
+
         // Dependencies must be fully executed before this proposal can execute.
         for dependency_id in proposal.depends_on.iter() {
             if let Ok(dep_proposal) = storage::get_proposal(&env, dependency_id) {
@@ -1212,6 +1493,24 @@ impl VaultDAO {
             }
         }
 
+        // Typed pre-hooks may deny execution. Return Ok so the recorded denial
+        // persists; the proposal stays Approved and can be executed later.
+        // Nothing was executed, so a session key keeps its use.
+        let hook_ctx = Self::hook_context(&proposal);
+        if let Some(denial) = Self::run_pre_execution_hooks(&env, &hook_ctx, true) {
+            events::emit_hook_denied(
+                &env,
+                proposal_id,
+                &denial.hook,
+                denial.reason_code,
+                denial.hook_failed,
+            );
+            storage::set_hook_denial(&env, proposal_id, &denial);
+            Self::release_session_use(&env, &executor);
+            return Ok(ExecutionOutcome::HookDenied);
+        }
+        storage::remove_hook_denial(&env, proposal_id);
+
         // Execute pre-hooks
         for hook in config.pre_execution_hooks.iter() {
             Self::call_hook(&env, &hook, proposal_id, true);
@@ -1234,10 +1533,13 @@ impl VaultDAO {
 
         match exec_result {
             Ok(()) => {
+                Self::charge_session_key(&env, &executor, proposal.amount)?;
+
                 // Execute post-hooks
                 for hook in config.post_execution_hooks.iter() {
                     Self::call_hook(&env, &hook, proposal_id, false);
                 }
+                Self::run_post_execution_hooks(&env, &hook_ctx)?;
 
                 // Update proposal status
                 proposal.status = ProposalStatus::Executed;
@@ -1276,9 +1578,10 @@ impl VaultDAO {
                     AuditAction::ExecuteProposal,
                     &executor,
                     proposal_id,
+                    (&proposal.recipient, &proposal.token, proposal.amount),
                 );
 
-                Ok(())
+                Ok(ExecutionOutcome::Executed)
             }
             Err(err) if Self::is_retryable_error(&err) => {
                 // Check if retry is configured
@@ -1296,11 +1599,64 @@ impl VaultDAO {
                     current_ledger,
                     &err,
                 )?;
-                Ok(())
+                Ok(ExecutionOutcome::RetryScheduled)
             }
             Err(err) => Err(err),
         }
     }
+
+    /// Mark a proposal past its expiry ledger as `Expired` (callable by anyone).
+    ///
+    /// Approving or executing an expired proposal fails with
+    /// `ProposalExpired`, which reverts its state changes, so keepers call
+    /// this to record the expiry, release the reserved spending and notify
+    /// `ProposalExpired` hooks.
+    pub fn expire_proposal(env: Env, caller: Address, proposal_id: u64) -> Result<(), VaultError> {
+        caller.require_auth();
+        let mut proposal = storage::get_proposal(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Pending && proposal.status != ProposalStatus::Approved
+        {
+            return Err(VaultError::ProposalNotPending);
+        }
+        let current_ledger = env.ledger().sequence() as u64;
+        if proposal.expires_at == 0 || current_ledger <= proposal.expires_at {
+            return Err(VaultError::ConditionsNotMet);
+        }
+
+        storage::refund_spending_limits(&env, proposal.amount);
+        proposal.status = ProposalStatus::Expired;
+        storage::set_proposal(&env, &proposal);
+        storage::metrics_on_expiry(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::RejectProposal,
+            &caller,
+            proposal_id,
+            (Symbol::new(&env, "expired"), proposal.expires_at),
+        );
+        events::emit_proposal_expired(&env, proposal_id, proposal.expires_at);
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::proposal_lifecycle_event(
+                &env,
+                HookStage::ProposalExpired,
+                "expired",
+                &proposal,
+                &caller,
+            ),
+        );
+
+        let metrics = storage::get_metrics(&env);
+        events::emit_metrics_updated(
+            &env,
+            metrics.executed_count,
+            metrics.rejected_count,
+            metrics.expired_count,
+            metrics.success_rate_bps(),
+        );
+        Ok(())
+    }
+
     pub fn get_retry_state(env: Env, proposal_id: u64) -> Option<RetryState> {
         storage::get_retry_state(&env, proposal_id)
     }
@@ -1376,6 +1732,13 @@ impl VaultDAO {
             changed_at: env.ledger().sequence() as u64,
         };
         storage::add_delegation_history(&env, &history);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::DelegateVoting,
+            &delegator,
+            0,
+            (&delegate, expiry_ledger),
+        );
 
         Ok(())
     }
@@ -1420,6 +1783,13 @@ impl VaultDAO {
                 changed_at: env.ledger().sequence() as u64,
             };
             storage::add_delegation_history(&env, &history);
+            storage::create_audit_entry(
+                &env,
+                AuditAction::DelegateVoting,
+                &delegator,
+                0,
+                (&history.previous_delegate, &delegator),
+            );
             Ok(())
         } else {
             Err(VaultError::ProposalNotFound) // No delegation to revoke
@@ -1541,7 +1911,24 @@ impl VaultDAO {
             }
         }
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::VetoProposal,
+            &vetoer,
+            proposal_id,
+            (&proposal.proposer, proposal.amount),
+        );
         events::emit_proposal_vetoed(&env, proposal_id, &vetoer);
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::proposal_lifecycle_event(
+                &env,
+                HookStage::ProposalVetoed,
+                "vetoed",
+                &proposal,
+                &vetoer,
+            ),
+        );
 
         Ok(())
     }
@@ -1604,8 +1991,24 @@ impl VaultDAO {
             // ── Slash stake ──────────────────────────────────────────────────
             Self::slash_stake_on_rejection(&env, &proposal);
 
-            storage::create_audit_entry(&env, AuditAction::RejectProposal, &canceller, proposal_id);
+            storage::create_audit_entry(
+                &env,
+                AuditAction::RejectProposal,
+                &canceller,
+                proposal_id,
+                (&proposal.proposer, proposal.amount),
+            );
             events::emit_proposal_rejected(&env, proposal_id, &canceller, &proposal.proposer);
+            Self::notify_lifecycle_hooks(
+                &env,
+                Self::proposal_lifecycle_event(
+                    &env,
+                    HookStage::ProposalCancelled,
+                    "rejected",
+                    &proposal,
+                    &canceller,
+                ),
+            );
 
             storage::metrics_on_rejection(&env);
             let metrics = storage::get_metrics(&env);
@@ -1644,7 +2047,13 @@ impl VaultDAO {
             storage::add_to_cancellation_history(&env, proposal_id);
             storage::extend_instance_ttl(&env);
 
-            storage::create_audit_entry(&env, AuditAction::RejectProposal, &canceller, proposal_id);
+            storage::create_audit_entry(
+                &env,
+                AuditAction::RejectProposal,
+                &canceller,
+                proposal_id,
+                (&reason, proposal.amount),
+            );
 
             events::emit_proposal_cancelled(
                 &env,
@@ -1653,6 +2062,16 @@ impl VaultDAO {
                 &reason,
                 proposal.amount,
             );
+            Self::notify_lifecycle_hooks(
+                &env,
+                Self::proposal_lifecycle_event(
+                    &env,
+                    HookStage::ProposalCancelled,
+                    "cancelled",
+                    &proposal,
+                    &canceller,
+                ),
+            );
 
             // ── Refund insurance in full ─────────────────────────────────────
             if proposal.insurance_amount > 0 {
@@ -1726,6 +2145,7 @@ impl VaultDAO {
         new_amount: i128,
         new_memo: Symbol,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Proposals)?;
         proposer.require_auth();
 
         let config = storage::get_config(&env)?;
@@ -1796,6 +2216,17 @@ impl VaultDAO {
         storage::add_amendment_record(&env, &amendment);
         storage::extend_instance_ttl(&env);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateProposal,
+            &amendment.amended_by,
+            proposal_id,
+            (
+                Symbol::new(&env, "amend"),
+                (&amendment.old_recipient, amendment.old_amount),
+                (&amendment.new_recipient, amendment.new_amount),
+            ),
+        );
         events::emit_proposal_amended(&env, &amendment);
 
         Ok(())
@@ -1813,12 +2244,19 @@ impl VaultDAO {
     ///
     /// Only Admin can update threshold.
     pub fn update_threshold(env: Env, admin: Address, threshold: u32) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "update_threshold",
+            (threshold,).into_val(&env),
+        )?;
 
         let mut config = storage::get_config(&env)?;
 
@@ -1829,12 +2267,19 @@ impl VaultDAO {
             return Err(VaultError::ThresholdTooHigh);
         }
 
+        let previous_threshold = config.threshold;
         config.threshold = threshold;
         storage::set_config(&env, &config);
         storage::extend_instance_ttl(&env);
 
         // Create audit entry
-        storage::create_audit_entry(&env, AuditAction::UpdateThreshold, &admin, 0);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateThreshold,
+            &admin,
+            0,
+            (previous_threshold, threshold),
+        );
 
         events::emit_config_updated(&env, &admin);
 
@@ -1845,7 +2290,8 @@ impl VaultDAO {
     ///
     /// Allows an admin to update the per-proposal, daily, and weekly spending caps
     /// in a single atomic call. All three values must be positive and internally
-    /// consistent (`spending_limit <= daily_limit <= weekly_limit`).
+    /// consistent (`spending_limit <= daily_limit <= weekly_limit`). With a
+    /// config delay set, the change is queued instead (see `set_config_delay`).
     ///
     /// # Arguments
     /// * `admin`         - Caller; must hold the `Admin` role and authorize.
@@ -1865,12 +2311,19 @@ impl VaultDAO {
         daily_limit: i128,
         weekly_limit: i128,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         // Admin-only
         if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "update_limits",
+            (spending_limit, daily_limit, weekly_limit).into_val(&env),
+        )?;
 
         // All values must be positive
         if spending_limit <= 0 || daily_limit <= 0 || weekly_limit <= 0 {
@@ -1882,18 +2335,48 @@ impl VaultDAO {
             return Err(VaultError::InvalidAmount);
         }
 
-        let mut config = storage::get_config(&env)?;
+        if Self::defer_config_change(
+            &env,
+            &admin,
+            "update_limits",
+            (spending_limit, daily_limit, weekly_limit).into_val(&env),
+        )? {
+            return Ok(());
+        }
+        Self::apply_limits(&env, &admin, spending_limit, daily_limit, weekly_limit)
+    }
+
+    /// Store validated spending limits; also used by the config timelock.
+    fn apply_limits(
+        env: &Env,
+        admin: &Address,
+        spending_limit: i128,
+        daily_limit: i128,
+        weekly_limit: i128,
+    ) -> Result<(), VaultError> {
+        let mut config = storage::get_config(env)?;
+        let previous_limits = (
+            config.spending_limit,
+            config.daily_limit,
+            config.weekly_limit,
+        );
         config.spending_limit = spending_limit;
         config.daily_limit = daily_limit;
         config.weekly_limit = weekly_limit;
-        storage::set_config(&env, &config);
-        storage::extend_instance_ttl(&env);
+        storage::set_config(env, &config);
+        storage::extend_instance_ttl(env);
 
         // Audit trail
-        storage::create_audit_entry(&env, AuditAction::UpdateLimits, &admin, 0);
+        storage::create_audit_entry(
+            env,
+            AuditAction::UpdateLimits,
+            admin,
+            0,
+            (previous_limits, (spending_limit, daily_limit, weekly_limit)),
+        );
 
         // Event
-        events::emit_config_updated(&env, &admin);
+        events::emit_config_updated(env, admin);
 
         Ok(())
     }
@@ -1905,12 +2388,14 @@ impl VaultDAO {
     ///
     /// Only Admin can update quorum.
     pub fn update_quorum(env: Env, admin: Address, quorum: u32) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(&env, &admin, "update_quorum", (quorum,).into_val(&env))?;
 
         let mut config = storage::get_config(&env)?;
         let old_quorum = config.quorum;
@@ -1924,6 +2409,13 @@ impl VaultDAO {
         storage::set_config(&env, &config);
         storage::extend_instance_ttl(&env);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateVotingConfig,
+            &admin,
+            0,
+            (Symbol::new(&env, "quorum"), old_quorum, quorum),
+        );
         events::emit_config_updated(&env, &admin);
         events::emit_quorum_updated(&env, &admin, old_quorum, quorum);
 
@@ -1938,15 +2430,30 @@ impl VaultDAO {
         admin: Address,
         strategy: VotingStrategy,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "update_voting_strategy",
+            (strategy.clone(),).into_val(&env),
+        )?;
 
+        let previous_strategy = storage::get_voting_strategy(&env);
         storage::set_voting_strategy(&env, &strategy);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateVotingConfig,
+            &admin,
+            0,
+            (Symbol::new(&env, "strategy"), previous_strategy, strategy),
+        );
         events::emit_config_updated(&env, &admin);
 
         Ok(())
@@ -1965,6 +2472,12 @@ impl VaultDAO {
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "extend_voting_deadline",
+            (proposal_id, new_deadline).into_val(&env),
+        )?;
 
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
 
@@ -1977,6 +2490,14 @@ impl VaultDAO {
         storage::set_proposal(&env, &proposal);
         storage::extend_instance_ttl(&env);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateVotingConfig,
+            &admin,
+            proposal_id,
+            (Symbol::new(&env, "deadline"), old_deadline, new_deadline),
+        );
+
         events::emit_voting_deadline_extended(
             &env,
             proposal_id,
@@ -1996,6 +2517,7 @@ impl VaultDAO {
         recipient: Address,
         amount: i128,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
         // Implementation from original logic before the issue.
         admin.require_auth();
 
@@ -2003,6 +2525,12 @@ impl VaultDAO {
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "withdraw_insurance_pool",
+            (token_addr.clone(), recipient.clone(), amount).into_val(&env),
+        )?;
 
         if amount <= 0 {
             return Err(VaultError::InvalidAmount);
@@ -2018,6 +2546,18 @@ impl VaultDAO {
 
         // Execute actual token transfer from vault mapping
         token::transfer(&env, &token_addr, &recipient, amount);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::WithdrawPool,
+            &admin,
+            0,
+            (
+                Symbol::new(&env, "insurance"),
+                &token_addr,
+                &recipient,
+                amount,
+            ),
+        );
 
         Ok(())
     }
@@ -2030,12 +2570,19 @@ impl VaultDAO {
         recipient: Address,
         amount: i128,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "withdraw_stake_pool",
+            (token_addr.clone(), recipient.clone(), amount).into_val(&env),
+        )?;
 
         if amount <= 0 {
             return Err(VaultError::InvalidAmount);
@@ -2048,6 +2595,13 @@ impl VaultDAO {
 
         storage::subtract_from_stake_pool(&env, &token_addr, amount);
         token::transfer(&env, &token_addr, &recipient, amount);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::WithdrawPool,
+            &admin,
+            0,
+            (Symbol::new(&env, "stake"), &token_addr, &recipient, amount),
+        );
 
         Ok(())
     }
@@ -2058,15 +2612,30 @@ impl VaultDAO {
         admin: Address,
         config: types::StakingConfig,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "update_staking_config",
+            (config.clone(),).into_val(&env),
+        )?;
 
+        let previous = storage::get_staking_config(&env);
         storage::set_staking_config(&env, &config);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateTreasuryConfig,
+            &admin,
+            0,
+            (Symbol::new(&env, "staking"), previous, config),
+        );
 
         events::emit_config_updated(&env, &admin);
 
@@ -2184,6 +2753,7 @@ impl VaultDAO {
         target: Address,
         role: Role,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         // Require explicit authorization from the caller
         admin.require_auth();
 
@@ -2196,16 +2766,32 @@ impl VaultDAO {
         if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_role",
+            (target.clone(), role.clone()).into_val(&env),
+        )?;
+
+        // Demoting an admin must leave enough admins to reach the council threshold
+        let council_threshold = storage::get_admin_council(&env).threshold;
+        if council_threshold > 1
+            && role != Role::Admin
+            && storage::get_role(&env, &target) == Role::Admin
+            && storage::count_admins(&env) <= council_threshold
+        {
+            return Err(VaultError::InvalidAdminCouncil);
+        }
 
         // Persist the new role
         storage::set_role(&env, &target, role.clone());
         storage::extend_instance_ttl(&env);
 
         // Emit role-assignment event
-        events::emit_role_assigned(&env, &target, role as u32);
+        events::emit_role_assigned(&env, &target, role.clone() as u32);
 
         // Append to the tamper-evident audit trail
-        storage::create_audit_entry(&env, AuditAction::SetRole, &admin, 0);
+        storage::create_audit_entry(&env, AuditAction::SetRole, &admin, 0, (&target, role));
 
         Ok(())
     }
@@ -2242,11 +2828,18 @@ impl VaultDAO {
     /// Only Admin can call this. Rejects removal if it would leave fewer signers
     /// than the current threshold, making the vault unable to reach quorum.
     pub fn remove_signer(env: Env, admin: Address, signer: Address) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "remove_signer",
+            (signer.clone(),).into_val(&env),
+        )?;
 
         let mut config = storage::get_config(&env)?;
 
@@ -2267,7 +2860,13 @@ impl VaultDAO {
         config.signers.remove(found_idx.unwrap());
         storage::set_config(&env, &config);
         storage::extend_instance_ttl(&env);
-        storage::create_audit_entry(&env, AuditAction::RemoveSigner, &admin, 0);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::RemoveSigner,
+            &admin,
+            0,
+            (&signer, config.signers.len()),
+        );
 
         events::emit_config_updated(&env, &admin);
 
@@ -2345,10 +2944,16 @@ impl VaultDAO {
         memo: Symbol,
         interval: u64,
     ) -> Result<u64, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Proposals)?;
         proposer.require_auth();
 
-        let role = storage::get_role(&env, &proposer);
-        if role != Role::Treasurer && role != Role::Admin {
+        let request = PermissionRequest::transfer(&env, &token_addr, amount, &recipient);
+        if !Self::check_permission(
+            &env,
+            &proposer,
+            &types::Permission::ManageRecurring,
+            Some(&request),
+        ) {
             return Err(VaultError::InsufficientRole);
         }
 
@@ -2381,6 +2986,18 @@ impl VaultDAO {
         };
 
         storage::set_recurring_payment(&env, &payment);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::SchedulePayment,
+            &proposer,
+            id,
+            (
+                &payment.recipient,
+                &payment.token,
+                payment.amount,
+                payment.interval,
+            ),
+        );
 
         Ok(id)
     }
@@ -2388,7 +3005,13 @@ impl VaultDAO {
     /// Execute a scheduled recurring payment
     ///
     /// Can be called by anyone (keeper/bot) if the schedule is due.
-    pub fn execute_recurring_payment(env: Env, payment_id: u64) -> Result<(), VaultError> {
+    /// Returns `CircuitBreakerTripped` without paying when the call trips the
+    /// token's circuit breaker, so the trip stays latched.
+    pub fn execute_recurring_payment(
+        env: Env,
+        payment_id: u64,
+    ) -> Result<ExecutionOutcome, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
         let mut payment = storage::get_recurring_payment(&env, payment_id)?;
 
         if !payment.is_active {
@@ -2422,6 +3045,10 @@ impl VaultDAO {
             return Err(VaultError::InsufficientBalance);
         }
 
+        if Self::circuit_breaker_tripped(&env, &payment.token)? {
+            return Ok(ExecutionOutcome::CircuitBreakerTripped);
+        }
+
         // Revalidate recipient against current whitelist/blacklist policies.
         // Policies may have changed since scheduling; block execution if the
         // recipient is no longer permitted.
@@ -2439,8 +3066,16 @@ impl VaultDAO {
         payment.payment_count += 1;
         storage::set_recurring_payment(&env, &payment);
         storage::extend_instance_ttl(&env);
+        // Keeper-triggered, so the vault itself is recorded as the actor.
+        storage::create_audit_entry(
+            &env,
+            AuditAction::ExecuteRecurringPayment,
+            &env.current_contract_address(),
+            payment_id,
+            (&payment.recipient, &payment.token, payment.amount),
+        );
 
-        Ok(())
+        Ok(ExecutionOutcome::Executed)
     }
 
     /// Get a recurring payment by ID
@@ -2518,11 +3153,17 @@ impl VaultDAO {
         total_amount: i128,
         duration_secs: u64,
     ) -> Result<u64, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Proposals)?;
         sender.require_auth();
 
-        // Role check: only Treasurer or Admin may create streams
-        let role = storage::get_role(&env, &sender);
-        if role != Role::Treasurer && role != Role::Admin {
+        // Permission check: Treasurer, Admin, or a grant/custom role covering the total
+        let request = PermissionRequest::transfer(&env, &token_addr, total_amount, &recipient);
+        if !Self::check_permission(
+            &env,
+            &sender,
+            &types::Permission::ManageRecurring,
+            Some(&request),
+        ) {
             return Err(VaultError::InsufficientRole);
         }
 
@@ -2558,14 +3199,41 @@ impl VaultDAO {
         storage::set_streaming_payment(&env, &stream);
         storage::extend_instance_ttl(&env);
 
-        events::emit_stream_created(&env, id, &sender, &recipient, &token_addr, total_amount, rate);
-
-        Ok(id)
-    }
-
-    /// Claim accumulated tokens from a stream.
-    ///
-    /// Calculates claimable tokens based on elapsed active seconds since the
+        storage::create_audit_entry(
+            &env,
+            AuditAction::CreateStream,
+            &sender,
+            id,
+            (&recipient, &token_addr, total_amount, rate),
+        );
+        events::emit_stream_created(
+            &env,
+            id,
+            &sender,
+            &recipient,
+            &token_addr,
+            total_amount,
+            rate,
+        );
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::lifecycle_event(
+                &env,
+                HookStage::StreamChanged,
+                id,
+                "created",
+                &sender,
+                &token_addr,
+                total_amount,
+            ),
+        );
+
+        Ok(id)
+    }
+
+    /// Claim accumulated tokens from a stream.
+    ///
+    /// Calculates claimable tokens based on elapsed active seconds since the
     /// last claim, transfers them to the recipient, and marks the stream
     /// `Completed` if all tokens have been claimed.
     ///
@@ -2582,6 +3250,7 @@ impl VaultDAO {
         recipient: Address,
         stream_id: u64,
     ) -> Result<i128, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
         recipient.require_auth();
 
         let mut stream = storage::get_streaming_payment(&env, stream_id)?;
@@ -2644,7 +3313,27 @@ impl VaultDAO {
 
         storage::set_streaming_payment(&env, &stream);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::ClaimStream,
+            &recipient,
+            stream_id,
+            (&stream.token_addr, claimable),
+        );
+
         events::emit_stream_claimed(&env, stream_id, &recipient, claimable);
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::lifecycle_event(
+                &env,
+                HookStage::StreamChanged,
+                stream_id,
+                "claimed",
+                &recipient,
+                &stream.token_addr,
+                claimable,
+            ),
+        );
 
         Ok(claimable)
     }
@@ -2691,7 +3380,27 @@ impl VaultDAO {
 
         storage::set_streaming_payment(&env, &stream);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateStream,
+            &caller,
+            stream_id,
+            (Symbol::new(&env, "pause"), stream.accumulated_seconds),
+        );
+
         events::emit_stream_status_updated(&env, stream_id, StreamStatus::Paused as u32, &caller);
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::lifecycle_event(
+                &env,
+                HookStage::StreamChanged,
+                stream_id,
+                "paused",
+                &caller,
+                &stream.token_addr,
+                stream.total_amount,
+            ),
+        );
 
         Ok(())
     }
@@ -2729,7 +3438,27 @@ impl VaultDAO {
 
         storage::set_streaming_payment(&env, &stream);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateStream,
+            &caller,
+            stream_id,
+            (Symbol::new(&env, "resume"), stream.accumulated_seconds),
+        );
+
         events::emit_stream_status_updated(&env, stream_id, StreamStatus::Active as u32, &caller);
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::lifecycle_event(
+                &env,
+                HookStage::StreamChanged,
+                stream_id,
+                "resumed",
+                &caller,
+                &stream.token_addr,
+                stream.total_amount,
+            ),
+        );
 
         Ok(())
     }
@@ -2790,12 +3519,11 @@ impl VaultDAO {
         // Refund = total committed − everything earned (claimed + unclaimed earned)
         let refund_amount = stream.total_amount - gross_earned;
 
-        if refund_amount > 0 {
-            if token::try_transfer(&env, &stream.token_addr, &stream.sender, refund_amount)
+        if refund_amount > 0
+            && token::try_transfer(&env, &stream.token_addr, &stream.sender, refund_amount)
                 .is_err()
-            {
-                return Err(VaultError::TransferFailed);
-            }
+        {
+            return Err(VaultError::TransferFailed);
         }
 
         stream.last_update_timestamp = now;
@@ -2803,12 +3531,32 @@ impl VaultDAO {
 
         storage::set_streaming_payment(&env, &stream);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateStream,
+            &caller,
+            stream_id,
+            (Symbol::new(&env, "cancel"), &stream.sender, refund_amount),
+        );
+
         events::emit_stream_status_updated(
             &env,
             stream_id,
             StreamStatus::Cancelled as u32,
             &caller,
         );
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::lifecycle_event(
+                &env,
+                HookStage::StreamChanged,
+                stream_id,
+                "cancelled",
+                &caller,
+                &stream.token_addr,
+                refund_amount,
+            ),
+        );
 
         Ok(refund_amount)
     }
@@ -2823,17 +3571,46 @@ impl VaultDAO {
 
     /// Set the recipient list mode (Disabled, Whitelist, or Blacklist)
     ///
-    /// Only Admin can change the list mode.
+    /// Only Admin can change the list mode. With a config delay set, the change
+    /// is queued instead (see `set_config_delay`).
     pub fn set_list_mode(env: Env, admin: Address, mode: ListMode) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_list_mode",
+            (mode.clone(),).into_val(&env),
+        )?;
+
+        if Self::defer_config_change(
+            &env,
+            &admin,
+            "set_list_mode",
+            (mode.clone(),).into_val(&env),
+        )? {
+            return Ok(());
+        }
+        Self::apply_list_mode(&env, &admin, mode)
+    }
 
-        storage::set_list_mode(&env, mode);
-        storage::extend_instance_ttl(&env);
+    /// Store the list mode; also used by the config timelock.
+    fn apply_list_mode(env: &Env, admin: &Address, mode: ListMode) -> Result<(), VaultError> {
+        let previous_mode = storage::get_list_mode(env);
+        storage::set_list_mode(env, mode.clone());
+        storage::extend_instance_ttl(env);
+        storage::create_audit_entry(
+            env,
+            AuditAction::UpdateList,
+            admin,
+            0,
+            (Symbol::new(env, "mode"), previous_mode, mode),
+        );
 
         Ok(())
     }
@@ -2847,12 +3624,19 @@ impl VaultDAO {
     ///
     /// Only Admin can add to whitelist.
     pub fn add_to_whitelist(env: Env, admin: Address, addr: Address) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "add_to_whitelist",
+            (addr.clone(),).into_val(&env),
+        )?;
 
         if storage::is_whitelisted(&env, &addr) {
             return Err(VaultError::AddressAlreadyOnList);
@@ -2860,6 +3644,13 @@ impl VaultDAO {
 
         storage::add_to_whitelist(&env, &addr);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateList,
+            &admin,
+            0,
+            (Symbol::new(&env, "whitelist_add"), &addr),
+        );
 
         Ok(())
     }
@@ -2872,12 +3663,19 @@ impl VaultDAO {
         admin: Address,
         addr: Address,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "remove_from_whitelist",
+            (addr.clone(),).into_val(&env),
+        )?;
 
         if !storage::is_whitelisted(&env, &addr) {
             return Err(VaultError::AddressNotOnList);
@@ -2885,6 +3683,13 @@ impl VaultDAO {
 
         storage::remove_from_whitelist(&env, &addr);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateList,
+            &admin,
+            0,
+            (Symbol::new(&env, "whitelist_remove"), &addr),
+        );
 
         Ok(())
     }
@@ -2898,12 +3703,19 @@ impl VaultDAO {
     ///
     /// Only Admin can add to blacklist.
     pub fn add_to_blacklist(env: Env, admin: Address, addr: Address) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "add_to_blacklist",
+            (addr.clone(),).into_val(&env),
+        )?;
 
         if storage::is_blacklisted(&env, &addr) {
             return Err(VaultError::AddressAlreadyOnList);
@@ -2911,6 +3723,13 @@ impl VaultDAO {
 
         storage::add_to_blacklist(&env, &addr);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateList,
+            &admin,
+            0,
+            (Symbol::new(&env, "blacklist_add"), &addr),
+        );
 
         Ok(())
     }
@@ -2923,12 +3742,19 @@ impl VaultDAO {
         admin: Address,
         addr: Address,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "remove_from_blacklist",
+            (addr.clone(),).into_val(&env),
+        )?;
 
         if !storage::is_blacklisted(&env, &addr) {
             return Err(VaultError::AddressNotOnList);
@@ -2936,6 +3762,13 @@ impl VaultDAO {
 
         storage::remove_from_blacklist(&env, &addr);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateList,
+            &admin,
+            0,
+            (Symbol::new(&env, "blacklist_remove"), &addr),
+        );
 
         Ok(())
     }
@@ -3008,6 +3841,14 @@ impl VaultDAO {
         storage::add_comment_to_proposal(&env, proposal_id, comment_id);
         storage::extend_instance_ttl(&env);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Comment,
+            &author,
+            proposal_id,
+            (comment_id, &comment.text, parent_id),
+        );
+
         events::emit_comment_added(&env, comment_id, proposal_id, &author);
 
         Ok(comment_id)
@@ -3035,6 +3876,14 @@ impl VaultDAO {
         storage::set_comment(&env, &comment);
         storage::extend_instance_ttl(&env);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Comment,
+            &author,
+            comment.proposal_id,
+            (comment_id, &comment.text),
+        );
+
         events::emit_comment_edited(&env, comment_id, &author);
 
         Ok(())
@@ -3095,6 +3944,104 @@ impl VaultDAO {
         entries
     }
 
+    /// Query the audit trail with a filter, one cursor-paginated page at a time.
+    ///
+    /// Walks the most selective secondary index the filter allows (actor,
+    /// then target, then a single action type) instead of the whole trail,
+    /// and starts from `from_ledger` by binary search. Pass cursor 0 for the
+    /// first page and `next_cursor` afterwards. Each page returns at most
+    /// `limit` entries (capped at 50; 0 uses the cap) and inspects at most 200,
+    /// so a page can come back short while `next_cursor` is still set.
+    /// Entries written before indexing existed appear once the schema
+    /// migration has indexed them.
+    pub fn query_audit_trail(env: Env, filter: AuditFilter, cursor: u64, limit: u32) -> AuditPage {
+        let limit = if limit == 0 || limit > MAX_AUDIT_QUERY_RESULTS {
+            MAX_AUDIT_QUERY_RESULTS
+        } else {
+            limit
+        };
+        let index = if let Some(actor) = filter.actor.clone() {
+            Some(storage::AuditIndex::Actor(actor))
+        } else if let Some(target) = filter.target.filter(|target| *target != 0) {
+            Some(storage::AuditIndex::Target(target))
+        } else if filter.actions.len() == 1 {
+            Some(storage::AuditIndex::Action(filter.actions.get_unchecked(0)))
+        } else {
+            None
+        };
+        let len = match &index {
+            Some(index) => storage::get_audit_index_len(&env, index),
+            None => storage::get_next_audit_id(&env) - 1,
+        };
+
+        // Entry ID at `position` in the walked sequence, reusing the last page read.
+        let mut page: Option<(u64, Vec<u64>)> = None;
+        let mut entry_at = |position: u64| -> Option<AuditEntry> {
+            let id = match &index {
+                Some(index) => {
+                    let number = position / storage::AUDIT_INDEX_PAGE_SIZE;
+                    if page.as_ref().map(|(n, _)| *n) != Some(number) {
+                        page = Some((number, storage::get_audit_index_page(&env, index, number)));
+                    }
+                    page.as_ref()?
+                        .1
+                        .get((position % storage::AUDIT_INDEX_PAGE_SIZE) as u32)?
+                }
+                None => position + 1,
+            };
+            storage::get_audit_entry(&env, id).ok()
+        };
+
+        // Entries are appended in ledger order, so the first page can skip
+        // straight to `from_ledger`.
+        let mut position = cursor;
+        if cursor == 0 && filter.from_ledger > 0 {
+            let (mut low, mut high) = (0, len);
+            while low < high {
+                let mid = low + (high - low) / 2;
+                match entry_at(mid) {
+                    Some(entry) if entry.timestamp < filter.from_ledger => low = mid + 1,
+                    _ => high = mid,
+                }
+            }
+            position = low;
+        }
+
+        let mut entries = Vec::new(&env);
+        let mut scanned = 0;
+        while position < len && entries.len() < limit && scanned < MAX_AUDIT_QUERY_SCAN {
+            let entry = entry_at(position);
+            position += 1;
+            scanned += 1;
+            let entry = match entry {
+                Some(entry) => entry,
+                None => continue,
+            };
+            if filter.to_ledger != 0 && entry.timestamp > filter.to_ledger {
+                position = len;
+                break;
+            }
+            if Self::audit_filter_matches(&filter, &entry) {
+                entries.push_back(entry);
+            }
+        }
+
+        AuditPage {
+            entries,
+            next_cursor: (position < len).then_some(position),
+        }
+    }
+
+    fn audit_filter_matches(filter: &AuditFilter, entry: &AuditEntry) -> bool {
+        filter
+            .actor
+            .as_ref()
+            .is_none_or(|actor| *actor == entry.actor)
+            && filter.target.is_none_or(|target| target == entry.target)
+            && (filter.actions.is_empty() || filter.actions.contains(&entry.action))
+            && entry.timestamp >= filter.from_ledger
+    }
+
     /// Get audit entry by ID
     pub fn get_audit_entry(env: Env, entry_id: u64) -> Result<AuditEntry, VaultError> {
         storage::get_audit_entry(&env, entry_id)
@@ -3117,7 +4064,7 @@ impl VaultDAO {
         }
 
         let mut expected_prev_hash = if from_id == 1 {
-            0
+            BytesN::from_array(&env, &[0; 32])
         } else if let Ok(prev_entry) = storage::get_audit_entry(&env, from_id - 1) {
             prev_entry.hash
         } else {
@@ -3135,15 +4082,7 @@ impl VaultDAO {
                 return false;
             }
 
-            let computed_hash = storage::compute_audit_hash(
-                &env,
-                &entry.action,
-                &entry.actor,
-                entry.target,
-                entry.timestamp,
-                entry.prev_hash,
-            );
-            if computed_hash != entry.hash {
+            if storage::compute_audit_hash(&env, &entry) != entry.hash {
                 return false;
             }
 
@@ -3177,27 +4116,127 @@ impl VaultDAO {
         }
         for id in 1..count {
             let entry = storage::get_audit_entry(&env, id)?;
-            let computed = storage::compute_audit_hash(
-                &env,
-                &entry.action,
-                &entry.actor,
-                entry.target,
-                entry.timestamp,
-                entry.prev_hash,
-            );
-            if computed != entry.hash {
+            if storage::compute_audit_hash(&env, &entry) != entry.hash {
                 return Ok(Some(id));
             }
-            if id > 1 {
-                let prev = storage::get_audit_entry(&env, id - 1)?;
-                if entry.prev_hash != prev.hash {
-                    return Ok(Some(id));
-                }
+            let expected_prev_hash = if id > 1 {
+                storage::get_audit_entry(&env, id - 1)?.hash
+            } else {
+                BytesN::from_array(&env, &[0; 32])
+            };
+            if entry.prev_hash != expected_prev_hash {
+                return Ok(Some(id));
             }
         }
         Ok(None)
     }
 
+    /// Commit a Merkle root over the next audit entries not yet checkpointed.
+    ///
+    /// Can be called by anyone (keeper/bot). Covers at most
+    /// `AUDIT_CHECKPOINT_SIZE` entries, verifying their hashes and links
+    /// first, so each checkpoint attests to an intact stretch of the chain.
+    /// Entries are proven against it with `get_audit_proof` and
+    /// `verify_audit_proof` instead of replaying the whole trail. Checkpoints
+    /// are bookkeeping over existing entries and add no audit entry themselves.
+    pub fn checkpoint_audit_trail(env: Env) -> Result<AuditCheckpoint, VaultError> {
+        let count = storage::get_audit_checkpoint_count(&env);
+        let start_id = if count == 0 {
+            1
+        } else {
+            storage::get_audit_checkpoint(&env, count)?.end_id + 1
+        };
+        let last_audit_id = storage::get_next_audit_id(&env) - 1;
+        if start_id > last_audit_id {
+            return Err(VaultError::NothingToCheckpoint);
+        }
+        let end_id = last_audit_id.min(start_id + AUDIT_CHECKPOINT_SIZE - 1);
+
+        let mut expected_prev_hash = if start_id == 1 {
+            BytesN::from_array(&env, &[0; 32])
+        } else {
+            storage::get_audit_entry(&env, start_id - 1)?.hash
+        };
+        let mut leaves = Vec::new(&env);
+        for id in start_id..=end_id {
+            let entry = storage::get_audit_entry(&env, id)?;
+            if entry.prev_hash != expected_prev_hash
+                || storage::compute_audit_hash(&env, &entry) != entry.hash
+            {
+                return Err(VaultError::AuditChainBroken);
+            }
+            expected_prev_hash = entry.hash.clone();
+            leaves.push_back(entry.hash);
+        }
+
+        let checkpoint = AuditCheckpoint {
+            id: storage::increment_audit_checkpoint_id(&env),
+            start_id,
+            end_id,
+            root: storage::merkle_root(&env, &leaves).ok_or(VaultError::NothingToCheckpoint)?,
+            created_at: env.ledger().sequence() as u64,
+        };
+        storage::set_audit_checkpoint(&env, &checkpoint);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_audit_checkpoint(&env, checkpoint.id, start_id, end_id, &checkpoint.root);
+        Ok(checkpoint)
+    }
+
+    /// Get an audit checkpoint by ID.
+    pub fn get_audit_checkpoint(
+        env: Env,
+        checkpoint_id: u64,
+    ) -> Result<AuditCheckpoint, VaultError> {
+        storage::get_audit_checkpoint(&env, checkpoint_id)
+    }
+
+    /// Number of audit checkpoints committed so far.
+    pub fn get_audit_checkpoint_count(env: Env) -> u64 {
+        storage::get_audit_checkpoint_count(&env)
+    }
+
+    /// Sibling path proving `entry_id` is covered by its checkpoint's root.
+    ///
+    /// Fails with `AuditEntryNotCheckpointed` until a checkpoint covers the entry.
+    pub fn get_audit_proof(env: Env, entry_id: u64) -> Result<AuditProof, VaultError> {
+        let checkpoint = storage::find_audit_checkpoint(&env, entry_id)?;
+        let mut leaves = Vec::new(&env);
+        for id in checkpoint.start_id..=checkpoint.end_id {
+            leaves.push_back(storage::get_audit_entry(&env, id)?.hash);
+        }
+        let index = (entry_id - checkpoint.start_id) as u32;
+        Ok(AuditProof {
+            checkpoint_id: checkpoint.id,
+            entry_id,
+            siblings: storage::merkle_path(&env, &leaves, index),
+        })
+    }
+
+    /// Check that `entry` was recorded, using only its checkpoint root.
+    ///
+    /// Recomputes the entry's hash from its fields, folds it with the proof's
+    /// sibling path and compares the result with the stored root, so a single
+    /// action can be proven without replaying the chain. Read-only.
+    pub fn verify_audit_proof(env: Env, entry: AuditEntry, proof: AuditProof) -> bool {
+        let checkpoint = match storage::get_audit_checkpoint(&env, proof.checkpoint_id) {
+            Ok(checkpoint) => checkpoint,
+            Err(_) => return false,
+        };
+        if entry.id != proof.entry_id
+            || entry.id < checkpoint.start_id
+            || entry.id > checkpoint.end_id
+            || storage::compute_audit_hash(&env, &entry) != entry.hash
+        {
+            return false;
+        }
+
+        let index = (entry.id - checkpoint.start_id) as u32;
+        let count = (checkpoint.end_id - checkpoint.start_id + 1) as u32;
+        storage::merkle_fold(&env, &entry.hash, index, count, &proof.siblings)
+            == Some(checkpoint.root)
+    }
+
     // ========================================================================
     // Batch Execution
     // ========================================================================
@@ -3211,6 +4250,7 @@ impl VaultDAO {
         executor: Address,
         proposal_ids: Vec<u64>,
     ) -> Result<(Vec<u64>, u32), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
         executor.require_auth();
         // Load config once (gas optimization — avoids repeated storage reads)
         let config = storage::get_config(&env)?;
@@ -3235,6 +4275,17 @@ impl VaultDAO {
                 failed_count += 1;
                 continue;
             }
+            // Skip if the executor's ExecuteProposal does not cover it
+            let request = PermissionRequest::proposal(&env, &proposal);
+            if !Self::check_permission(
+                &env,
+                &executor,
+                &types::Permission::ExecuteProposal,
+                Some(&request),
+            ) {
+                failed_count += 1;
+                continue;
+            }
             // Skip if approvals/quorum are no longer satisfied
             if Self::ensure_vote_requirements_satisfied(&env, &config, &proposal).is_err() {
                 failed_count += 1;
@@ -3247,6 +4298,16 @@ impl VaultDAO {
                 storage::set_proposal(&env, &proposal);
                 storage::metrics_on_expiry(&env);
                 events::emit_proposal_expired(&env, proposal_id, proposal.expires_at);
+                Self::notify_lifecycle_hooks(
+                    &env,
+                    Self::proposal_lifecycle_event(
+                        &env,
+                        HookStage::ProposalExpired,
+                        "expired",
+                        &proposal,
+                        &executor,
+                    ),
+                );
 
                 let metrics = storage::get_metrics(&env);
                 events::emit_metrics_updated(
@@ -3353,6 +4414,13 @@ impl VaultDAO {
                 proposal.amount,
                 current_ledger,
             );
+            storage::create_audit_entry(
+                &env,
+                AuditAction::ExecuteProposal,
+                &executor,
+                proposal_id,
+                (&proposal.recipient, &proposal.token, proposal.amount),
+            );
             Self::update_reputation_on_execution(&env, &proposal);
             let exec_time = current_ledger.saturating_sub(proposal.created_at);
             storage::metrics_on_execution(&env, fee_estimate.total_fee, exec_time);
@@ -3398,9 +4466,21 @@ impl VaultDAO {
         storage::remove_from_priority_queue(&env, proposal.priority.clone() as u32, proposal_id);
         storage::add_to_priority_queue(&env, new_priority.clone() as u32, proposal_id);
 
+        let previous_priority = proposal.priority.clone();
         proposal.priority = new_priority;
         storage::set_proposal(&env, &proposal);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateProposal,
+            &caller,
+            proposal_id,
+            (
+                Symbol::new(&env, "priority"),
+                previous_priority,
+                proposal.priority,
+            ),
+        );
 
         Ok(())
     }
@@ -3444,9 +4524,16 @@ impl VaultDAO {
         if attachments.contains(attachment.clone()) {
             return Err(VaultError::AttachmentHashInvalid);
         }
-        attachments.push_back(attachment);
+        attachments.push_back(attachment.clone());
         storage::set_attachments(&env, proposal_id, &attachments);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateProposal,
+            &caller,
+            proposal_id,
+            (Symbol::new(&env, "attach"), attachment),
+        );
 
         Ok(())
     }
@@ -3471,9 +4558,17 @@ impl VaultDAO {
         if index >= attachments.len() {
             return Err(VaultError::ProposalNotFound); // reuse as "index out of range"
         }
+        let removed = attachments.get(index).unwrap();
         attachments.remove(index);
         storage::set_attachments(&env, proposal_id, &attachments);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateProposal,
+            &caller,
+            proposal_id,
+            (Symbol::new(&env, "detach"), removed),
+        );
 
         Ok(())
     }
@@ -3512,9 +4607,16 @@ impl VaultDAO {
             return Err(VaultError::ExceedsProposalLimit);
         }
 
-        proposal.metadata.set(key, value);
+        proposal.metadata.set(key.clone(), value.clone());
         storage::set_proposal(&env, &proposal);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateProposal,
+            &caller,
+            proposal_id,
+            (Symbol::new(&env, "meta_set"), key, value),
+        );
 
         Ok(())
     }
@@ -3537,9 +4639,16 @@ impl VaultDAO {
             return Err(VaultError::Unauthorized);
         }
 
-        proposal.metadata.remove(key);
+        proposal.metadata.remove(key.clone());
         storage::set_proposal(&env, &proposal);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateProposal,
+            &caller,
+            proposal_id,
+            (Symbol::new(&env, "meta_remove"), key),
+        );
 
         Ok(())
     }
@@ -3598,9 +4707,16 @@ impl VaultDAO {
             return Err(VaultError::TooManyTags);
         }
 
-        proposal.tags.push_back(tag);
+        proposal.tags.push_back(tag.clone());
         storage::set_proposal(&env, &proposal);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateProposal,
+            &caller,
+            proposal_id,
+            (Symbol::new(&env, "tag_add"), tag),
+        );
 
         Ok(())
     }
@@ -3638,6 +4754,13 @@ impl VaultDAO {
 
         storage::set_proposal(&env, &proposal);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateProposal,
+            &caller,
+            proposal_id,
+            (Symbol::new(&env, "tag_remove"), tag),
+        );
 
         Ok(())
     }
@@ -3676,15 +4799,30 @@ impl VaultDAO {
         admin: Address,
         config: InsuranceConfig,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_insurance_config",
+            (config.clone(),).into_val(&env),
+        )?;
 
+        let previous = storage::get_insurance_config(&env);
         storage::set_insurance_config(&env, &config);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateTreasuryConfig,
+            &admin,
+            0,
+            (Symbol::new(&env, "insurance"), previous, config),
+        );
 
         events::emit_insurance_config_updated(&env, &admin);
 
@@ -3702,7 +4840,8 @@ impl VaultDAO {
 
     /// Configure the dynamic fee structure.
     ///
-    /// Only Admin can update fee configuration.
+    /// Only Admin can update fee configuration. With a config delay set, the
+    /// change is queued instead (see `set_config_delay`).
     ///
     /// # Arguments
     /// * `admin` - Admin address (must authorize)
@@ -3712,12 +4851,19 @@ impl VaultDAO {
         admin: Address,
         fee_structure: types::FeeStructure,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_fee_structure",
+            (fee_structure.clone(),).into_val(&env),
+        )?;
 
         // Validate fee structure
         if fee_structure.base_fee_bps > 10_000 {
@@ -3740,10 +4886,35 @@ impl VaultDAO {
             return Err(VaultError::InvalidAmount);
         }
 
-        storage::set_fee_structure(&env, &fee_structure);
-        storage::extend_instance_ttl(&env);
+        if Self::defer_config_change(
+            &env,
+            &admin,
+            "set_fee_structure",
+            (fee_structure.clone(),).into_val(&env),
+        )? {
+            return Ok(());
+        }
+        Self::apply_fee_structure(&env, &admin, fee_structure)
+    }
+
+    /// Store a validated fee structure; also used by the config timelock.
+    fn apply_fee_structure(
+        env: &Env,
+        admin: &Address,
+        fee_structure: types::FeeStructure,
+    ) -> Result<(), VaultError> {
+        let previous = storage::get_fee_structure(env);
+        storage::set_fee_structure(env, &fee_structure);
+        storage::extend_instance_ttl(env);
+        storage::create_audit_entry(
+            env,
+            AuditAction::UpdateTreasuryConfig,
+            admin,
+            0,
+            (Symbol::new(env, "fees"), previous, fee_structure.clone()),
+        );
 
-        events::emit_fee_structure_updated(&env, &admin, fee_structure.enabled);
+        events::emit_fee_structure_updated(env, admin, fee_structure.enabled);
 
         Ok(())
     }
@@ -3796,10 +4967,17 @@ impl VaultDAO {
         token: Address,
         recipient: Address,
     ) -> Result<i128, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
         admin.require_auth();
         if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "withdraw_fees",
+            (token.clone(), recipient.clone()).into_val(&env),
+        )?;
 
         let amount = storage::get_fees_collected(&env, &token);
         if amount == 0 {
@@ -3813,6 +4991,13 @@ impl VaultDAO {
         token::transfer(&env, &token, &recipient, amount);
 
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::WithdrawPool,
+            &admin,
+            0,
+            (Symbol::new(&env, "fees"), &token, &recipient, amount),
+        );
         Ok(amount)
     }
 
@@ -3854,7 +5039,14 @@ impl VaultDAO {
 
         storage::set_notification_prefs(&env, &caller, &prefs);
         storage::extend_instance_ttl(&env);
-
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateNotificationPreferences,
+            &caller,
+            0,
+            prefs,
+        );
+
         events::emit_notification_prefs_updated(&env, &caller);
 
         Ok(())
@@ -3873,15 +5065,30 @@ impl VaultDAO {
     ///
     /// Only Admin can change gas settings.
     pub fn set_gas_config(env: Env, admin: Address, config: GasConfig) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_gas_config",
+            (config.clone(),).into_val(&env),
+        )?;
 
+        let previous = storage::get_gas_config(&env);
         storage::set_gas_config(&env, &config);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateTreasuryConfig,
+            &admin,
+            0,
+            (Symbol::new(&env, "gas"), previous, config.clone()),
+        );
 
         events::emit_gas_config_updated(&env, &admin);
 
@@ -3907,6 +5114,502 @@ impl VaultDAO {
         storage::get_execution_fee_estimate(&env, proposal_id)
     }
 
+    /// Dry-run `execute_proposal` without changing state.
+    ///
+    /// Runs every check execution would run, in the same order, and reports
+    /// each one with its numbers instead of stopping at the first failure.
+    /// Typed pre-execution hooks are asked for their decision; legacy hooks
+    /// and post-execution hooks are listed by count but not invoked.
+    ///
+    /// The daily limit, weekly limit and recipient checks are informational:
+    /// limits are reserved and the recipient is validated when the proposal
+    /// is created, so they report current state without affecting
+    /// `executable`.
+    pub fn preflight_proposal(env: Env, proposal_id: u64) -> Result<PreflightReport, VaultError> {
+        let proposal = storage::get_proposal(&env, proposal_id)?;
+        let config = storage::get_config(&env)?;
+        let current_ledger = env.ledger().sequence() as u64;
+        let mut checks: Vec<PreflightCheck> = Vec::new(&env);
+        let mut push = |name: &str,
+                        blocking: bool,
+                        result: Result<(), VaultError>,
+                        actual: i128,
+                        required: i128| {
+            checks.push_back(PreflightCheck {
+                name: Symbol::new(&env, name),
+                passed: result.is_ok(),
+                blocking,
+                error_code: result.err().map(|e| e as u32).unwrap_or(0),
+                actual,
+                required,
+            });
+        };
+
+        let pause = Self::active_pause(&env, &PauseCategory::Outflows);
+        push(
+            "paused",
+            true,
+            Self::ensure_not_paused(&env, PauseCategory::Outflows),
+            pause.map(|state| state.expires_at as i128).unwrap_or(0),
+            0,
+        );
+
+        let status_result = match proposal.status {
+            ProposalStatus::Approved => Ok(()),
+            ProposalStatus::Executed => Err(VaultError::ProposalAlreadyExecuted),
+            _ => Err(VaultError::ProposalNotApproved),
+        };
+        push(
+            "status",
+            true,
+            status_result,
+            proposal.status.clone() as i128,
+            ProposalStatus::Approved as i128,
+        );
+
+        let expiry_result = if current_ledger > proposal.expires_at {
+            Err(VaultError::ProposalExpired)
+        } else {
+            Ok(())
+        };
+        push(
+            "expiry",
+            true,
+            expiry_result,
+            current_ledger as i128,
+            proposal.expires_at as i128,
+        );
+
+        let timelock_result =
+            if proposal.unlock_ledger > 0 && current_ledger < proposal.unlock_ledger {
+                Err(VaultError::TimelockNotExpired)
+            } else {
+                Ok(())
+            };
+        push(
+            "timelock",
+            true,
+            timelock_result,
+            current_ledger as i128,
+            proposal.unlock_ledger as i128,
+        );
+
+        let breaker_result = Self::preflight_circuit_breaker(&env, &proposal.token);
+        push(
+            "circuit_breaker",
+            true,
+            breaker_result.0,
+            breaker_result.1,
+            breaker_result.2,
+        );
+
+        let mut executed_deps = 0u32;
+        let mut deps_result = Ok(());
+        for dependency_id in proposal.depends_on.iter() {
+            match storage::get_proposal(&env, dependency_id) {
+                Ok(dep) if dep.status == ProposalStatus::Executed => executed_deps += 1,
+                Ok(_) => {
+                    if deps_result.is_ok() {
+                        deps_result = Err(VaultError::ProposalNotApproved);
+                    }
+                }
+                Err(_) => {
+                    if deps_result.is_ok() {
+                        deps_result = Err(VaultError::ProposalNotFound);
+                    }
+                }
+            }
+        }
+        push(
+            "dependencies",
+            true,
+            deps_result,
+            executed_deps as i128,
+            proposal.depends_on.len() as i128,
+        );
+
+        let required_approvals =
+            Self::calculate_threshold(&env, &config, &proposal.amount, proposal.created_at);
+        let approvals_result = if Self::is_threshold_reached(&env, &config, &proposal) {
+            Ok(())
+        } else {
+            Err(VaultError::ProposalNotApproved)
+        };
+        push(
+            "approvals",
+            true,
+            approvals_result,
+            proposal.approvals.len() as i128,
+            required_approvals as i128,
+        );
+
+        let quorum_votes = proposal.approvals.len() + proposal.abstentions.len();
+        let quorum_result = if config.quorum == 0 || quorum_votes >= config.quorum {
+            Ok(())
+        } else {
+            Err(VaultError::QuorumNotReached)
+        };
+        push(
+            "quorum",
+            true,
+            quorum_result,
+            quorum_votes as i128,
+            config.quorum as i128,
+        );
+
+        let retry_state = storage::get_retry_state(&env, proposal_id);
+        let (retry_count, next_retry_ledger) = retry_state
+            .map(|state| (state.retry_count, state.next_retry_ledger))
+            .unwrap_or((0, 0));
+        let retry_limit_result = if retry_count > 0
+            && config.retry_config.enabled
+            && retry_count >= config.retry_config.max_retries
+        {
+            Err(VaultError::RetryError)
+        } else {
+            Ok(())
+        };
+        push(
+            "retry_limit",
+            true,
+            retry_limit_result,
+            retry_count as i128,
+            config.retry_config.max_retries as i128,
+        );
+        let retry_backoff_result = if retry_count > 0 && current_ledger < next_retry_ledger {
+            Err(VaultError::RetryError)
+        } else {
+            Ok(())
+        };
+        push(
+            "retry_backoff",
+            true,
+            retry_backoff_result,
+            current_ledger as i128,
+            next_retry_ledger as i128,
+        );
+
+        let hook_ctx = Self::hook_context(&proposal);
+        let pre_hooks_result = match Self::run_pre_execution_hooks(&env, &hook_ctx, false) {
+            Some(_) => Err(VaultError::ConditionsNotMet),
+            None => Ok(()),
+        };
+        push(
+            "pre_hooks",
+            true,
+            pre_hooks_result,
+            (config.pre_execution_hooks.len()
+                + Self::matching_hook_count(&env, &hook_ctx, HookStage::PreExecute))
+                as i128,
+            0,
+        );
+
+        let mut condition_results = Vec::new(&env);
+        for cond in proposal.conditions.iter() {
+            let single = Vec::from_array(&env, [cond]);
+            condition_results.push_back(matches!(
+                Self::evaluate_condition_group(&env, &proposal, &single, &ConditionLogic::And, 0),
+                Ok(true)
+            ));
+        }
+        let conditions_result = if proposal.conditions.is_empty() {
+            Ok(())
+        } else {
+            Self::evaluate_conditions(&env, &proposal)
+        };
+        let passed_conditions = condition_results.iter().filter(|passed| *passed).count();
+        push(
+            "conditions",
+            true,
+            conditions_result,
+            passed_conditions as i128,
+            proposal.conditions.len() as i128,
+        );
+
+        let fee_estimate = Self::calculate_execution_fee(&env, &proposal);
+        let gas_result = if proposal.gas_limit > 0 && fee_estimate.total_fee > proposal.gas_limit {
+            Err(VaultError::GasLimitExceeded)
+        } else {
+            Ok(())
+        };
+        push(
+            "gas_limit",
+            true,
+            gas_result,
+            fee_estimate.total_fee as i128,
+            proposal.gas_limit as i128,
+        );
+
+        let fee = Self::calculate_fee_internal(
+            &env,
+            &proposal.proposer,
+            &proposal.token,
+            proposal.amount,
+        )
+        .final_fee;
+        let balance = token::balance(&env, &proposal.token);
+        let total_required = proposal.amount + proposal.insurance_amount + fee;
+        let balance_result = if balance < total_required {
+            Err(VaultError::InsufficientBalance)
+        } else {
+            Ok(())
+        };
+        push("balance", true, balance_result, balance, total_required);
+
+        let spent_today = storage::get_daily_spent(&env, storage::get_day_number(&env));
+        let daily_result = if spent_today > config.daily_limit {
+            Err(VaultError::ExceedsDailyLimit)
+        } else {
+            Ok(())
+        };
+        push(
+            "daily_limit",
+            false,
+            daily_result,
+            spent_today,
+            config.daily_limit,
+        );
+
+        let spent_week = storage::get_weekly_spent(&env, storage::get_week_number(&env));
+        let weekly_result = if spent_week > config.weekly_limit {
+            Err(VaultError::ExceedsWeeklyLimit)
+        } else {
+            Ok(())
+        };
+        push(
+            "weekly_limit",
+            false,
+            weekly_result,
+            spent_week,
+            config.weekly_limit,
+        );
+
+        push(
+            "recipient",
+            false,
+            Self::validate_recipient(&env, &proposal.recipient),
+            storage::get_list_mode(&env) as i128,
+            0,
+        );
+
+        push(
+            "post_hooks",
+            true,
+            Ok(()),
+            (config.post_execution_hooks.len()
+                + Self::matching_hook_count(&env, &hook_ctx, HookStage::PostExecute))
+                as i128,
+            0,
+        );
+
+        let executable = checks.iter().all(|check| check.passed || !check.blocking);
+        Ok(PreflightReport {
+            proposal_id,
+            executable,
+            checks,
+            condition_results,
+            evaluated_at: current_ledger,
+        })
+    }
+
+    // ========================================================================
+    // Emergency Pause (Issue: feature/emergency-pause)
+    // ========================================================================
+
+    /// Configure pause guardians, the unpause threshold and the maximum pause
+    /// duration (Admin only).
+    ///
+    /// The unpause threshold cannot be lower than the vault's approval
+    /// threshold, so lifting a pause always takes at least as many signers as
+    /// spending does. Not blocked by a `Config` pause, so a compromised
+    /// guardian can be removed while its pause is in force.
+    pub fn set_pause_config(
+        env: Env,
+        admin: Address,
+        config: PauseConfig,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_pause_config",
+            (config.clone(),).into_val(&env),
+        )?;
+
+        let vault_config = storage::get_config(&env)?;
+        if config.unpause_threshold < vault_config.threshold.max(1) {
+            return Err(VaultError::ThresholdTooLow);
+        }
+        if config.unpause_threshold > vault_config.signers.len() {
+            return Err(VaultError::ThresholdTooHigh);
+        }
+        if config.max_duration == 0 {
+            return Err(VaultError::IntervalTooShort);
+        }
+
+        let previous = storage::get_pause_config(&env);
+        storage::set_pause_config(&env, &config);
+        storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::EmergencyPause,
+            &admin,
+            0,
+            (Symbol::new(&env, "config"), previous, config.clone()),
+        );
+        events::emit_pause_config_updated(&env, &admin, config.unpause_threshold);
+        Ok(())
+    }
+
+    /// Get the pause configuration, if set.
+    pub fn get_pause_config(env: Env) -> Option<PauseConfig> {
+        storage::get_pause_config(&env)
+    }
+
+    /// Pause one or more categories (guardians only).
+    ///
+    /// A `duration` of 0 or above the configured maximum pauses for the
+    /// maximum. The pause lapses on its own after that; lifting it earlier
+    /// takes `unpause_threshold` signer approvals. Categories that are
+    /// already paused cannot be paused again until the pause ends, and then
+    /// only after a cooldown of `max_duration` ledgers, so guardians cannot
+    /// keep a category paused more than half the time.
+    pub fn pause(
+        env: Env,
+        guardian: Address,
+        categories: Vec<PauseCategory>,
+        duration: u64,
+        reason: Symbol,
+    ) -> Result<(), VaultError> {
+        guardian.require_auth();
+        let config = storage::get_pause_config(&env).ok_or(VaultError::Unauthorized)?;
+        if !config.guardians.contains(&guardian) {
+            return Err(VaultError::Unauthorized);
+        }
+        if categories.is_empty() {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        let current_ledger = env.ledger().sequence() as u64;
+        let duration = if duration == 0 {
+            config.max_duration
+        } else {
+            duration.min(config.max_duration)
+        };
+        let expires_at = current_ledger + duration;
+
+        for category in categories.iter() {
+            if Self::active_pause(&env, &category).is_some() {
+                return Err(VaultError::VaultPaused);
+            }
+            if current_ledger < storage::get_pause_cooldown(&env, &category) {
+                return Err(VaultError::IntervalTooShort);
+            }
+            storage::set_pause_cooldown(&env, &category, expires_at + config.max_duration);
+            storage::set_pause_state(
+                &env,
+                &PauseState {
+                    category: category.clone(),
+                    paused_by: guardian.clone(),
+                    reason: reason.clone(),
+                    paused_at: current_ledger,
+                    expires_at,
+                    unpause_approvals: Vec::new(&env),
+                },
+            );
+            events::emit_vault_paused(&env, &category, &guardian, &reason, expires_at);
+        }
+        storage::create_audit_entry(
+            &env,
+            AuditAction::EmergencyPause,
+            &guardian,
+            0,
+            (Symbol::new(&env, "pause"), categories, &reason, expires_at),
+        );
+
+        storage::extend_instance_ttl(&env);
+        Ok(())
+    }
+
+    /// Approve lifting the pause on `category` (signers only).
+    ///
+    /// Returns `true` once `unpause_threshold` current signers have approved
+    /// and the pause is lifted.
+    pub fn approve_unpause(
+        env: Env,
+        signer: Address,
+        category: PauseCategory,
+    ) -> Result<bool, VaultError> {
+        signer.require_auth();
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+
+        let mut state = Self::active_pause(&env, &category).ok_or(VaultError::ConditionsNotMet)?;
+        if state.unpause_approvals.contains(&signer) {
+            return Err(VaultError::AlreadyApproved);
+        }
+        state.unpause_approvals.push_back(signer.clone());
+        // Signers removed since approving no longer count
+        let approvals = state
+            .unpause_approvals
+            .iter()
+            .filter(|approver| config.signers.contains(approver))
+            .count() as u32;
+        events::emit_unpause_approved(&env, &category, &signer, approvals);
+
+        let pause_config = storage::get_pause_config(&env).ok_or(VaultError::NotInitialized)?;
+        let lifted = approvals >= pause_config.unpause_threshold;
+        if lifted {
+            storage::remove_pause_state(&env, &category);
+            let current_ledger = env.ledger().sequence() as u64;
+            storage::set_pause_cooldown(
+                &env,
+                &category,
+                current_ledger + pause_config.max_duration,
+            );
+            events::emit_vault_unpaused(&env, &category);
+        } else {
+            storage::set_pause_state(&env, &state);
+        }
+
+        storage::create_audit_entry(
+            &env,
+            AuditAction::EmergencyPause,
+            &signer,
+            0,
+            (Symbol::new(&env, "unpause"), category, lifted),
+        );
+        storage::extend_instance_ttl(&env);
+        Ok(lifted)
+    }
+
+    /// Get the active pause on `category`, if any. Lapsed pauses return `None`.
+    pub fn get_pause_state(env: Env, category: PauseCategory) -> Option<PauseState> {
+        Self::active_pause(&env, &category)
+    }
+
+    /// Whether `category` is currently paused.
+    pub fn is_paused(env: Env, category: PauseCategory) -> bool {
+        Self::active_pause(&env, &category).is_some()
+    }
+
+    fn active_pause(env: &Env, category: &PauseCategory) -> Option<PauseState> {
+        storage::get_pause_state(env, category)
+            .filter(|state| env.ledger().sequence() as u64 <= state.expires_at)
+    }
+
+    fn ensure_not_paused(env: &Env, category: PauseCategory) -> Result<(), VaultError> {
+        if Self::active_pause(env, &category).is_some() {
+            return Err(VaultError::VaultPaused);
+        }
+        Ok(())
+    }
+
     // ========================================================================
     // Performance Metrics (Issue: feature/performance-metrics)
     // ========================================================================
@@ -4221,85 +5924,178 @@ impl VaultDAO {
 
     /// Evaluate whether all/any execution conditions are satisfied.
     fn evaluate_conditions(env: &Env, proposal: &Proposal) -> Result<(), VaultError> {
+        let all_passed = Self::evaluate_condition_group(
+            env,
+            proposal,
+            &proposal.conditions,
+            &proposal.condition_logic,
+            0,
+        )?;
+
+        if all_passed {
+            Ok(())
+        } else {
+            Err(VaultError::ProposalNotApproved) // repurpose for "conditions not met"
+        }
+    }
+
+    /// Evaluate `conditions` combined with `logic`, recursing into groups.
+    ///
+    /// Every condition is evaluated, so a stale oracle price fails the whole
+    /// check with `ConditionsNotMet` even when another branch would decide it.
+    fn evaluate_condition_group(
+        env: &Env,
+        proposal: &Proposal,
+        conditions: &Vec<Condition>,
+        logic: &ConditionLogic,
+        depth: u32,
+    ) -> Result<bool, VaultError> {
+        if depth > MAX_CONDITION_DEPTH {
+            return Err(VaultError::ConditionsNotMet);
+        }
         let current_ledger = env.ledger().sequence() as u64;
+        let now = env.ledger().timestamp();
         let mut results = Vec::new(env);
 
-        for i in 0..proposal.conditions.len() {
-            if let Some(cond) = proposal.conditions.get(i) {
-                let satisfied = match cond {
-                    Condition::BalanceAbove(min_balance) => {
-                        token::balance(env, &proposal.token) > min_balance
-                    }
-                    Condition::DateAfter(after_ledger) => current_ledger > after_ledger,
-                    Condition::DateBefore(before_ledger) => current_ledger < before_ledger,
-                    Condition::PriceAbove(asset, threshold) => {
-                        match Self::get_asset_price(env, asset.clone()) {
-                            Ok(price) => price >= threshold,
-                            Err(VaultError::ConditionsNotMet) => {
-                                return Err(VaultError::ConditionsNotMet)
-                            }
-                            Err(_) => false,
+        for cond in conditions.iter() {
+            let satisfied = match cond {
+                Condition::BalanceAbove(min_balance) => {
+                    token::balance(env, &proposal.token) > min_balance
+                }
+                Condition::BalanceBelow(max_balance) => {
+                    token::balance(env, &proposal.token) < max_balance
+                }
+                Condition::TokenBalanceAbove(token_addr, min_balance) => {
+                    token::balance(env, &token_addr) > min_balance
+                }
+                Condition::TokenBalanceBelow(token_addr, max_balance) => {
+                    token::balance(env, &token_addr) < max_balance
+                }
+                Condition::DateAfter(after_ledger) => current_ledger > after_ledger,
+                Condition::DateBefore(before_ledger) => current_ledger < before_ledger,
+                Condition::TimeAfter(after) => now > after,
+                Condition::TimeBefore(before) => now < before,
+                Condition::ProposalExecuted(proposal_id) => {
+                    matches!(
+                        storage::get_proposal(env, proposal_id),
+                        Ok(ref other) if other.status == ProposalStatus::Executed
+                    )
+                }
+                Condition::PriceAbove(asset, threshold) => {
+                    match Self::get_asset_price(env, asset.clone()) {
+                        Ok(price) => price >= threshold,
+                        Err(VaultError::ConditionsNotMet) => {
+                            return Err(VaultError::ConditionsNotMet)
                         }
+                        Err(_) => false,
                     }
-                    Condition::PriceBelow(asset, threshold) => {
-                        match Self::get_asset_price(env, asset.clone()) {
-                            Ok(price) => price <= threshold,
-                            Err(VaultError::ConditionsNotMet) => {
-                                return Err(VaultError::ConditionsNotMet)
-                            }
-                            Err(_) => false,
+                }
+                Condition::PriceBelow(asset, threshold) => {
+                    match Self::get_asset_price(env, asset.clone()) {
+                        Ok(price) => price <= threshold,
+                        Err(VaultError::ConditionsNotMet) => {
+                            return Err(VaultError::ConditionsNotMet)
                         }
+                        Err(_) => false,
                     }
-                };
-                results.push_back(satisfied);
-            }
+                }
+                // A predicate that fails or returns a non-bool is unsatisfied.
+                Condition::Predicate(contract) => matches!(
+                    env.try_invoke_contract::<bool, soroban_sdk::Error>(
+                        &contract,
+                        &Symbol::new(env, "check_condition"),
+                        (env.current_contract_address(), proposal.id).into_val(env),
+                    ),
+                    Ok(Ok(true))
+                ),
+                Condition::Group(group_logic, group) => {
+                    Self::evaluate_condition_group(env, proposal, &group, &group_logic, depth + 1)?
+                }
+            };
+            results.push_back(satisfied);
         }
 
-        let all_passed = match proposal.condition_logic {
-            ConditionLogic::And => {
-                let mut all = true;
-                for i in 0..results.len() {
-                    if !results.get(i).unwrap_or(false) {
-                        all = false;
-                        break;
-                    }
-                }
-                all
-            }
-            ConditionLogic::Or => {
-                let mut any = false;
-                for i in 0..results.len() {
-                    if results.get(i).unwrap_or(false) {
-                        any = true;
-                        break;
-                    }
+        Ok(match logic {
+            ConditionLogic::And => results.iter().all(|satisfied| satisfied),
+            ConditionLogic::Or => results.iter().any(|satisfied| satisfied),
+        })
+    }
+
+    /// Reject empty groups and groups nested deeper than `MAX_CONDITION_DEPTH`.
+    fn validate_conditions(conditions: &Vec<Condition>, depth: u32) -> Result<(), VaultError> {
+        for cond in conditions.iter() {
+            if let Condition::Group(_, group) = cond {
+                if group.is_empty() || depth >= MAX_CONDITION_DEPTH {
+                    return Err(VaultError::ConditionsNotMet);
                 }
-                any
+                Self::validate_conditions(&group, depth + 1)?;
             }
-        };
-
-        if all_passed {
-            Ok(())
-        } else {
-            Err(VaultError::ProposalNotApproved) // repurpose for "conditions not met"
         }
+        Ok(())
+    }
+
+    /// Number of leaf conditions, counting those inside groups.
+    fn condition_count(conditions: &Vec<Condition>) -> u32 {
+        let mut count = 0u32;
+        for cond in conditions.iter() {
+            count = count.saturating_add(match cond {
+                Condition::Group(_, group) => Self::condition_count(&group),
+                _ => 1,
+            });
+        }
+        count
     }
 
     /// Update the oracle configuration.
+    ///
+    /// With a config delay set, the change is queued instead (see
+    /// `set_config_delay`).
     pub fn update_oracle_config(
         env: Env,
         admin: Address,
         oracle_config: crate::VaultOracleConfig,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
         if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::InsufficientRole);
         }
-        storage::set_oracle_config(
+        Self::require_council(
+            &env,
+            &admin,
+            "update_oracle_config",
+            (oracle_config.clone(),).into_val(&env),
+        )?;
+        if Self::defer_config_change(
             &env,
+            &admin,
+            "update_oracle_config",
+            (oracle_config.clone(),).into_val(&env),
+        )? {
+            return Ok(());
+        }
+        Self::apply_oracle_config(&env, &admin, oracle_config)
+    }
+
+    /// Store the oracle configuration; also used by the config timelock.
+    fn apply_oracle_config(
+        env: &Env,
+        admin: &Address,
+        oracle_config: crate::VaultOracleConfig,
+    ) -> Result<(), VaultError> {
+        let previous = storage::get_oracle_config(env);
+        storage::set_oracle_config(
+            env,
             &crate::OptionalVaultOracleConfig::Some(oracle_config.clone()),
         );
-        events::emit_oracle_config_updated(&env, &admin, &oracle_config.address);
+        storage::create_audit_entry(
+            env,
+            AuditAction::UpdateOracleConfig,
+            admin,
+            0,
+            (Symbol::new(env, "config"), previous, oracle_config.clone()),
+        );
+        events::emit_oracle_config_updated(env, admin, &oracle_config.address);
         Ok(())
     }
 
@@ -4312,184 +6108,724 @@ impl VaultDAO {
         admin: Address,
         oracle_config: crate::VaultOracleConfig,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         Self::update_oracle_config(env, admin, oracle_config)
     }
 
-    /// Get the current price of an asset in USD from the configured oracle.
+    /// Set the oracle sources aggregated for `asset`.
+    ///
+    /// Once set, `get_asset_price` returns the median of the non-stale
+    /// answers from these sources instead of querying `VaultOracleConfig`.
+    pub fn set_asset_oracle_sources(
+        env: Env,
+        admin: Address,
+        asset: Address,
+        sources: AssetOracleSources,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_asset_oracle_sources",
+            (asset.clone(), sources.clone()).into_val(&env),
+        )?;
+
+        let count = sources.sources.len();
+        if sources.min_sources == 0 || sources.min_sources > count {
+            return Err(VaultError::InvalidAmount);
+        }
+        for i in 0..count {
+            let source = sources.sources.get(i).unwrap();
+            if sources.sources.last_index_of(&source) != Some(i) {
+                return Err(VaultError::AddressAlreadyOnList);
+            }
+        }
+
+        let previous = storage::get_asset_oracle_sources(&env, &asset);
+        storage::set_asset_oracle_sources(&env, &asset, &sources);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateOracleConfig,
+            &admin,
+            0,
+            (Symbol::new(&env, "sources"), &asset, previous, sources),
+        );
+        events::emit_asset_oracle_sources_updated(&env, &admin, &asset, count);
+        Ok(())
+    }
+
+    /// Remove the per-asset oracle sources, falling back to `VaultOracleConfig`.
+    pub fn remove_asset_oracle_sources(
+        env: Env,
+        admin: Address,
+        asset: Address,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+        Self::require_council(
+            &env,
+            &admin,
+            "remove_asset_oracle_sources",
+            (asset.clone(),).into_val(&env),
+        )?;
+        let previous = storage::get_asset_oracle_sources(&env, &asset);
+        storage::remove_asset_oracle_sources(&env, &asset);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateOracleConfig,
+            &admin,
+            0,
+            (Symbol::new(&env, "sources"), &asset, previous, ()),
+        );
+        events::emit_asset_oracle_sources_updated(&env, &admin, &asset, 0);
+        Ok(())
+    }
+
+    /// Get the oracle sources configured for `asset`, if any.
+    pub fn get_asset_oracle_sources(env: Env, asset: Address) -> Option<AssetOracleSources> {
+        storage::get_asset_oracle_sources(&env, &asset)
+    }
+
+    /// Price `token` as a different SEP-40 asset (e.g. a wrapped token as
+    /// `Other("BTC")`). Setting `Asset::Stellar(token)` restores the default.
+    pub fn set_oracle_asset(
+        env: Env,
+        admin: Address,
+        token: Address,
+        asset: Asset,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_oracle_asset",
+            (token.clone(), asset.clone()).into_val(&env),
+        )?;
+        let previous = storage::get_oracle_asset(&env, &token);
+        storage::set_oracle_asset(&env, &token, &asset);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateOracleConfig,
+            &admin,
+            0,
+            (Symbol::new(&env, "asset"), &token, previous, asset.clone()),
+        );
+        events::emit_oracle_asset_updated(&env, &admin, &token, &asset);
+        Ok(())
+    }
+
+    /// Get the SEP-40 asset `token` is priced as.
+    pub fn get_oracle_asset(env: Env, token: Address) -> Asset {
+        storage::get_oracle_asset(&env, &token)
+    }
+
+    /// Get the current price of an asset in USD from the configured oracle,
+    /// normalized to 7 decimals.
+    ///
+    /// Assets with their own oracle sources use the median of those instead.
+    /// Fails with `ConditionsNotMet` when the price is stale or the oracle
+    /// errors.
     pub fn get_asset_price(env: &Env, asset: Address) -> Result<i128, VaultError> {
+        if let Some(sources) = storage::get_asset_oracle_sources(env, &asset) {
+            return Self::median_asset_price(env, &asset, &sources);
+        }
+
         let oracle_cfg = match storage::get_oracle_config(env) {
             crate::OptionalVaultOracleConfig::Some(cfg) => cfg,
             crate::OptionalVaultOracleConfig::None => return Err(VaultError::NotInitialized),
         };
 
-        // Interface with standard Oracle contract
-        // lastprice(asset: Address) -> Option<VaultPriceData>
-        let price_data: Option<VaultPriceData> = env.invoke_contract(
-            &oracle_cfg.address,
-            &Symbol::new(env, "lastprice"),
-            Vec::from_array(env, [asset.clone().into_val(env)]),
-        );
-
-        match price_data {
-            Some(data) => {
-                // Compare ledger sequences: max_staleness is in ledgers, data.timestamp is the
-                // ledger sequence at which the price was recorded.
-                let current_ledger = env.ledger().sequence() as u64;
-                if current_ledger.saturating_sub(data.timestamp) > oracle_cfg.max_staleness as u64 {
-                    events::emit_oracle_price_stale(env, &asset, data.timestamp, current_ledger);
+        // A feed that errors is unavailable, like a stale one.
+        let feed = oracle::PriceFeedClient::new(env, &oracle_cfg.address);
+        match feed.try_lastprice(&storage::get_oracle_asset(env, &asset)) {
+            Ok(Ok(Some(data))) => {
+                if oracle::is_stale(env, data.timestamp, oracle_cfg.max_staleness) {
+                    events::emit_oracle_price_stale(
+                        env,
+                        &asset,
+                        data.timestamp,
+                        env.ledger().timestamp(),
+                    );
                     return Err(VaultError::ConditionsNotMet);
                 }
-                Ok(data.price)
+                match feed.try_decimals() {
+                    Ok(Ok(decimals)) => Ok(oracle::normalize_price(data.price, decimals)),
+                    _ => Err(VaultError::ConditionsNotMet),
+                }
             }
-            None => Err(VaultError::InvalidAmount), // Price not found
+            Ok(Ok(None)) => Err(VaultError::InvalidAmount), // Price not found
+            _ => Err(VaultError::ConditionsNotMet),
         }
     }
 
-    /// Convert a token amount to USD using the oracle price.
+    /// Check whether `asset`'s oracle sources agree (anyone; keeper/bot).
     ///
-    /// # Units & Scaling
-    /// - Input `amount`: Token amount in stroops (smallest unit, 7 decimals)
-    /// - Oracle price: USD price scaled by 10^7 (standard Stellar convention)
-    /// - Output: USD value in cents (scaled by 10^7 for precision)
-    /// - Formula: `(amount * price) / 10_000_000`
+    /// Returns `false` and emits `oracle_deviation` when an answer deviates
+    /// from the median by more than `max_deviation_bps`; pricing fails closed
+    /// meanwhile. Calls that fail on the deviation revert their events, so
+    /// this is where disagreement is reported.
     ///
     /// # Errors
-    /// - `NotInitialized` - Oracle not configured
-    /// - `InvalidAmount` - Asset price not found
-    /// - `RetryError` - Price data is stale
-    pub fn convert_to_usd(env: &Env, asset: Address, amount: i128) -> Result<i128, VaultError> {
-        if amount == 0 {
-            return Ok(0);
+    /// - `ConditionsNotMet` if the asset has no oracle sources or fewer than
+    ///   `min_sources` usable answers.
+    pub fn check_oracle_sources(env: Env, asset: Address) -> Result<bool, VaultError> {
+        let config =
+            storage::get_asset_oracle_sources(&env, &asset).ok_or(VaultError::ConditionsNotMet)?;
+        let (median, min_price, max_price) = Self::source_price_spread(&env, &asset, &config)?;
+        if Self::sources_disagree(&config, median, min_price, max_price) {
+            events::emit_oracle_deviation_exceeded(&env, &asset, median, min_price, max_price);
+            return Ok(false);
         }
-        let price = Self::get_asset_price(env, asset)?;
-        // Price is in USD scaled by 10^7, amount is in stroops (10^-7 units)
-        // Result: (amount * price) / 10^7 = USD value in cents
-        Ok(amount.saturating_mul(price) / 10_000_000)
+        Ok(true)
     }
 
-    /// Get the total USD valuation of the vault's holdings across multiple assets.
-    ///
-    /// # Parameters
-    /// - `assets`: Vector of token contract addresses to include in valuation
-    ///
-    /// # Returns
-    /// Total portfolio value in USD (scaled by 10^7 for precision)
-    ///
-    /// # Behavior
-    /// - Skips assets with zero balance
-    /// - Uses saturating arithmetic to prevent overflow
-    /// - Queries oracle for current price of each asset
-    /// - Returns error if any asset price cannot be determined
-    ///
-    /// # Units & Scaling
-    /// - Input: Asset addresses (any token contract)
-    /// - Output: Total USD value (scaled by 10^7)
-    /// - Each asset balance: stroops (10^-7 units)
-    /// - Each asset price: USD per token (scaled by 10^7)
+    /// Median of the non-stale answers from an asset's oracle sources.
     ///
-    /// # Errors
-    /// - `NotInitialized` - Oracle not configured
-    /// - `InvalidAmount` - Any asset price not found
-    /// - `RetryError` - Any asset price is stale
-    ///
-    /// # Example
-    /// ```ignore
-    /// let assets = vec![usdc_address, xlm_address];
-    /// let total_usd = VaultDAO::get_portfolio_valuation(env, assets)?;
-    /// // total_usd is in USD cents (scaled by 10^7)
-    /// ```
-    pub fn get_portfolio_valuation(env: Env, assets: Vec<Address>) -> Result<i128, VaultError> {
-        // Empty asset list is valid and returns 0
-        if assets.is_empty() {
-            return Ok(0);
+    /// Fails closed with `ConditionsNotMet` when fewer than `min_sources`
+    /// answers are usable or any answer deviates from the median by more
+    /// than `max_deviation_bps`; see `check_oracle_sources`.
+    fn median_asset_price(
+        env: &Env,
+        asset: &Address,
+        config: &AssetOracleSources,
+    ) -> Result<i128, VaultError> {
+        let (median, min_price, max_price) = Self::source_price_spread(env, asset, config)?;
+        if Self::sources_disagree(config, median, min_price, max_price) {
+            return Err(VaultError::ConditionsNotMet);
         }
+        Ok(median)
+    }
 
-        let mut total_usd = 0i128;
+    /// Median, lowest and highest of the non-stale answers from an asset's
+    /// oracle sources. Sources that error are skipped.
+    fn source_price_spread(
+        env: &Env,
+        asset: &Address,
+        config: &AssetOracleSources,
+    ) -> Result<(i128, i128, i128), VaultError> {
+        let feed_asset = storage::get_oracle_asset(env, asset);
+        let mut prices: Vec<i128> = Vec::new(env);
+
+        for source in config.sources.iter() {
+            let feed = oracle::PriceFeedClient::new(env, &source);
+            let data = match feed.try_lastprice(&feed_asset) {
+                Ok(Ok(Some(data))) => data,
+                _ => continue,
+            };
+            let decimals = match feed.try_decimals() {
+                Ok(Ok(decimals)) => decimals,
+                _ => continue,
+            };
+            if oracle::is_stale(env, data.timestamp, config.max_staleness) {
+                events::emit_oracle_price_stale(
+                    env,
+                    asset,
+                    data.timestamp,
+                    env.ledger().timestamp(),
+                );
+                continue;
+            }
+            let price = oracle::normalize_price(data.price, decimals);
+            if price <= 0 {
+                continue;
+            }
 
-        for asset in assets.into_iter() {
-            let balance = token::balance(&env, &asset);
-            // Skip zero balances to avoid unnecessary oracle queries
-            if balance > 0 {
-                let usd_value = Self::convert_to_usd(&env, asset, balance)?;
-                total_usd = total_usd.saturating_add(usd_value);
+            // Insertion keeps `prices` sorted ascending.
+            let mut idx = prices.len();
+            while idx > 0 && prices.get(idx - 1).unwrap() > price {
+                idx -= 1;
             }
+            prices.insert(idx, price);
         }
 
-        Ok(total_usd)
-    }
+        let count = prices.len();
+        if count == 0 || count < config.min_sources {
+            return Err(VaultError::ConditionsNotMet);
+        }
 
-    /// Award small reputation boost when a proposal is created.
-    fn update_reputation_on_propose(env: &Env, proposer: &Address) {
-        let mut rep = storage::get_reputation(env, proposer);
-        storage::apply_reputation_decay(env, &mut rep);
-        rep.proposals_created += 1;
-        storage::set_reputation(env, proposer, &rep);
+        let median = if count % 2 == 1 {
+            prices.get(count / 2).unwrap()
+        } else {
+            (prices.get(count / 2 - 1).unwrap() + prices.get(count / 2).unwrap()) / 2
+        };
+        Ok((median, prices.first().unwrap(), prices.last().unwrap()))
     }
 
-    /// Award small reputation boost when a signer approves a proposal.
-    fn update_reputation_on_approval(env: &Env, signer: &Address) {
-        let mut rep = storage::get_reputation(env, signer);
-        storage::apply_reputation_decay(env, &mut rep);
-        let old_score = rep.score;
-        rep.score = (rep.score + REP_APPROVAL_BONUS).min(1000);
-        rep.approvals_given = rep.approvals_given.saturating_add(1);
-        rep.participation_count = rep.participation_count.saturating_add(1);
-        rep.last_participation_ledger = env.ledger().sequence() as u64;
-        let new_score = rep.score;
-        storage::set_reputation(env, signer, &rep);
-        if old_score != new_score {
-            events::emit_reputation_updated(
-                env,
-                signer,
-                old_score,
-                new_score,
-                Symbol::new(env, "approved"),
-            );
-        }
+    /// Whether any answer deviates from the median by more than allowed.
+    fn sources_disagree(
+        config: &AssetOracleSources,
+        median: i128,
+        min_price: i128,
+        max_price: i128,
+    ) -> bool {
+        let max_deviation = (median - min_price).max(max_price - median);
+        max_deviation.saturating_mul(10_000)
+            > median.saturating_mul(config.max_deviation_bps as i128)
     }
 
-    /// Track signer participation for abstentions.
-    fn update_reputation_on_abstention(env: &Env, signer: &Address) {
-        let mut rep = storage::get_reputation(env, signer);
-        storage::apply_reputation_decay(env, &mut rep);
-        rep.abstentions_given = rep.abstentions_given.saturating_add(1);
-        rep.participation_count = rep.participation_count.saturating_add(1);
-        rep.last_participation_ledger = env.ledger().sequence() as u64;
-        storage::set_reputation(env, signer, &rep);
-    }
+    /// Configure the oracle circuit breaker for `asset`.
+    ///
+    /// Once the price moves more than `max_move_bps` away from a reference
+    /// recorded at most `window_ledgers` ago, executions involving the asset
+    /// are blocked until the breaker is reset.
+    pub fn set_circuit_breaker(
+        env: Env,
+        admin: Address,
+        asset: Address,
+        config: CircuitBreakerConfig,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_circuit_breaker",
+            (asset.clone(), config.clone()).into_val(&env),
+        )?;
+        if config.max_move_bps == 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        if config.window_ledgers == 0 {
+            return Err(VaultError::IntervalTooShort);
+        }
 
-    /// Reward proposer and all approvers on successful execution.
-    fn update_reputation_on_execution(env: &Env, proposal: &Proposal) {
-        // Reward proposer
-        {
-            let mut rep = storage::get_reputation(env, &proposal.proposer);
-            storage::apply_reputation_decay(env, &mut rep);
-            let old_score = rep.score;
-            rep.score = (rep.score + REP_EXEC_PROPOSER).min(1000);
-            rep.proposals_executed += 1;
-            let new_score = rep.score;
-            storage::set_reputation(env, &proposal.proposer, &rep);
-            if old_score != new_score {
-                events::emit_reputation_updated(
-                    env,
-                    &proposal.proposer,
-                    old_score,
-                    new_score,
-                    Symbol::new(env, "executed"),
-                );
+        // A tripped breaker stays tripped; otherwise start a fresh window.
+        if let Some(state) = storage::get_circuit_breaker_state(&env, &asset) {
+            if !state.tripped {
+                storage::remove_circuit_breaker_state(&env, &asset);
             }
         }
+        let previous = storage::get_circuit_breaker(&env, &asset);
+        storage::set_circuit_breaker(&env, &asset, &config);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateCircuitBreaker,
+            &admin,
+            0,
+            (
+                Symbol::new(&env, "config"),
+                &asset,
+                previous,
+                config.clone(),
+            ),
+        );
+        events::emit_circuit_breaker_updated(&env, &admin, &asset, config.max_move_bps);
+        Ok(())
+    }
 
-        // Reward each approver
-        for i in 0..proposal.approvals.len() {
-            if let Some(approver) = proposal.approvals.get(i) {
-                let mut rep = storage::get_reputation(env, &approver);
-                storage::apply_reputation_decay(env, &mut rep);
-                let old_score = rep.score;
-                rep.score = (rep.score + REP_EXEC_APPROVER).min(1000);
-                let new_score = rep.score;
-                storage::set_reputation(env, &approver, &rep);
-                if old_score != new_score {
+    /// Remove the circuit breaker for `asset`, unblocking it if tripped.
+    pub fn remove_circuit_breaker(
+        env: Env,
+        admin: Address,
+        asset: Address,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+        Self::require_council(
+            &env,
+            &admin,
+            "remove_circuit_breaker",
+            (asset.clone(),).into_val(&env),
+        )?;
+        let previous = storage::get_circuit_breaker(&env, &asset);
+        storage::remove_circuit_breaker(&env, &asset);
+        storage::remove_circuit_breaker_state(&env, &asset);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateCircuitBreaker,
+            &admin,
+            0,
+            (Symbol::new(&env, "config"), &asset, previous, ()),
+        );
+        events::emit_circuit_breaker_updated(&env, &admin, &asset, 0);
+        Ok(())
+    }
+
+    /// Check `asset` against its circuit breaker, latching a trip.
+    ///
+    /// Can be called by anyone (keeper/bot). Executions latch a trip they
+    /// detect too; this records one without executing anything. Returns
+    /// whether the breaker is tripped.
+    pub fn check_circuit_breaker(env: Env, asset: Address) -> Result<bool, VaultError> {
+        Self::evaluate_circuit_breaker(&env, &asset)
+    }
+
+    /// Reset a tripped circuit breaker (Admin only).
+    pub fn reset_circuit_breaker(
+        env: Env,
+        admin: Address,
+        asset: Address,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+        Self::require_council(
+            &env,
+            &admin,
+            "reset_circuit_breaker",
+            (asset.clone(),).into_val(&env),
+        )?;
+        match storage::get_circuit_breaker_state(&env, &asset) {
+            Some(state) if state.tripped => {}
+            _ => return Err(VaultError::ConditionsNotMet),
+        }
+        storage::remove_circuit_breaker_state(&env, &asset);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateCircuitBreaker,
+            &admin,
+            0,
+            (Symbol::new(&env, "reset"), &asset),
+        );
+        events::emit_circuit_breaker_reset(&env, &asset, &admin);
+        Ok(())
+    }
+
+    /// Approve resetting a tripped circuit breaker.
+    ///
+    /// The breaker resets once approvals from current signers reach the
+    /// vault threshold. Returns whether this approval reset it.
+    pub fn approve_circuit_breaker_reset(
+        env: Env,
+        signer: Address,
+        asset: Address,
+    ) -> Result<bool, VaultError> {
+        signer.require_auth();
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+        let mut state = match storage::get_circuit_breaker_state(&env, &asset) {
+            Some(state) if state.tripped => state,
+            _ => return Err(VaultError::ConditionsNotMet),
+        };
+        if state.reset_approvals.contains(&signer) {
+            return Err(VaultError::AlreadyApproved);
+        }
+
+        state.reset_approvals.push_back(signer.clone());
+        // Signers removed since approving no longer count
+        let approvals = state
+            .reset_approvals
+            .iter()
+            .filter(|approver| config.signers.contains(approver))
+            .count() as u32;
+        events::emit_circuit_breaker_reset_approved(
+            &env,
+            &asset,
+            &signer,
+            approvals,
+            config.threshold,
+        );
+        let reset = approvals >= config.threshold;
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateCircuitBreaker,
+            &signer,
+            0,
+            (Symbol::new(&env, "reset_approve"), &asset, reset),
+        );
+        if !reset {
+            storage::set_circuit_breaker_state(&env, &asset, &state);
+            return Ok(false);
+        }
+
+        storage::remove_circuit_breaker_state(&env, &asset);
+        events::emit_circuit_breaker_reset(&env, &asset, &signer);
+        Ok(true)
+    }
+
+    /// Get the circuit breaker configured for `asset`, if any.
+    pub fn get_circuit_breaker(env: Env, asset: Address) -> Option<CircuitBreakerConfig> {
+        storage::get_circuit_breaker(&env, &asset)
+    }
+
+    /// Get the circuit breaker reference and trip state for `asset`, if any.
+    pub fn get_circuit_breaker_state(env: Env, asset: Address) -> Option<CircuitBreakerState> {
+        storage::get_circuit_breaker_state(&env, &asset)
+    }
+
+    /// Compare the current price of `asset` with its breaker reference.
+    ///
+    /// Records the first reference and replaces it once it is older than the
+    /// window; within the window, trips when the move exceeds the bound.
+    /// Returns whether the breaker is tripped. Assets without a breaker are
+    /// never tripped; an unavailable price fails closed.
+    fn evaluate_circuit_breaker(env: &Env, asset: &Address) -> Result<bool, VaultError> {
+        let config = match storage::get_circuit_breaker(env, asset) {
+            Some(config) => config,
+            None => return Ok(false),
+        };
+        let state = storage::get_circuit_breaker_state(env, asset);
+        if let Some(ref state) = state {
+            if state.tripped {
+                return Ok(true);
+            }
+        }
+
+        let price = Self::get_asset_price(env, asset.clone())?;
+        let current_ledger = env.ledger().sequence() as u64;
+        let mut state = match state {
+            Some(state) => state,
+            None => {
+                let state = CircuitBreakerState {
+                    reference_price: price,
+                    reference_ledger: current_ledger,
+                    tripped: false,
+                    tripped_at: 0,
+                    trip_price: 0,
+                    reset_approvals: Vec::new(env),
+                };
+                storage::set_circuit_breaker_state(env, asset, &state);
+                return Ok(false);
+            }
+        };
+
+        // A reference older than the window says nothing about the recent
+        // move, so it only starts a fresh window.
+        if current_ledger.saturating_sub(state.reference_ledger) >= config.window_ledgers {
+            state.reference_price = price;
+            state.reference_ledger = current_ledger;
+            storage::set_circuit_breaker_state(env, asset, &state);
+            return Ok(false);
+        }
+
+        let move_bps = Self::price_move_bps(price, state.reference_price);
+        if move_bps > config.max_move_bps as i128 {
+            state.tripped = true;
+            state.tripped_at = current_ledger;
+            state.trip_price = price;
+            storage::set_circuit_breaker_state(env, asset, &state);
+            events::emit_circuit_breaker_tripped(
+                env,
+                asset,
+                state.reference_price,
+                price,
+                move_bps,
+            );
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// Move of `price` away from `reference`, in basis points.
+    fn price_move_bps(price: i128, reference: i128) -> i128 {
+        (price - reference).abs().saturating_mul(10_000) / reference.max(1)
+    }
+
+    /// Circuit breaker gate for an execution moving `token`.
+    ///
+    /// A breaker that is already latched fails with `ConditionsNotMet`. A trip
+    /// detected by this call is recorded and returns `Ok(true)`: the caller
+    /// must refuse the execution on an Ok path, or the error would revert the
+    /// latch along with everything else.
+    fn circuit_breaker_tripped(env: &Env, token: &Address) -> Result<bool, VaultError> {
+        if storage::get_circuit_breaker_state(env, token).is_some_and(|state| state.tripped) {
+            return Err(VaultError::ConditionsNotMet);
+        }
+        Self::evaluate_circuit_breaker(env, token)
+    }
+
+    /// Read-only circuit breaker check for preflight: the result with the
+    /// current move and the allowed move in basis points.
+    fn preflight_circuit_breaker(
+        env: &Env,
+        token: &Address,
+    ) -> (Result<(), VaultError>, i128, i128) {
+        let config = match storage::get_circuit_breaker(env, token) {
+            Some(config) => config,
+            None => return (Ok(()), 0, 0),
+        };
+        let max_move = config.max_move_bps as i128;
+        let state = match storage::get_circuit_breaker_state(env, token) {
+            Some(state) => state,
+            None => return (Ok(()), 0, max_move),
+        };
+        if state.tripped {
+            let move_bps = Self::price_move_bps(state.trip_price, state.reference_price);
+            return (Err(VaultError::ConditionsNotMet), move_bps, max_move);
+        }
+        let reference_age = (env.ledger().sequence() as u64).saturating_sub(state.reference_ledger);
+        match Self::get_asset_price(env, token.clone()) {
+            Ok(_) if reference_age >= config.window_ledgers => (Ok(()), 0, max_move),
+            Ok(price) => {
+                let move_bps = Self::price_move_bps(price, state.reference_price);
+                if move_bps > max_move {
+                    (Err(VaultError::ConditionsNotMet), move_bps, max_move)
+                } else {
+                    (Ok(()), move_bps, max_move)
+                }
+            }
+            Err(e) => (Err(e), 0, max_move),
+        }
+    }
+
+    /// Convert a token amount to USD using the oracle price.
+    ///
+    /// # Units & Scaling
+    /// - Input `amount`: Token amount in stroops (smallest unit, 7 decimals)
+    /// - Oracle price: USD price scaled by 10^7 (standard Stellar convention)
+    /// - Output: USD value in cents (scaled by 10^7 for precision)
+    /// - Formula: `(amount * price) / 10_000_000`
+    ///
+    /// # Errors
+    /// - `NotInitialized` - Oracle not configured
+    /// - `InvalidAmount` - Asset price not found, or the value overflows
+    /// - `RetryError` - Price data is stale
+    pub fn convert_to_usd(env: &Env, asset: Address, amount: i128) -> Result<i128, VaultError> {
+        if amount == 0 {
+            return Ok(0);
+        }
+        let price = Self::get_asset_price(env, asset)?;
+        // Price is in USD scaled by 10^7, amount is in stroops (10^-7 units)
+        // Result: (amount * price) / 10^7 = USD value in cents
+        amount
+            .checked_mul(price)
+            .map(|value| value / 10_000_000)
+            .ok_or(VaultError::InvalidAmount)
+    }
+
+    /// Get the total USD valuation of the vault's holdings across multiple assets.
+    ///
+    /// # Parameters
+    /// - `assets`: Vector of token contract addresses to include in valuation
+    ///
+    /// # Returns
+    /// Total portfolio value in USD (scaled by 10^7 for precision)
+    ///
+    /// # Behavior
+    /// - Skips assets with zero balance
+    /// - Uses saturating arithmetic to prevent overflow
+    /// - Queries oracle for current price of each asset
+    /// - Returns error if any asset price cannot be determined
+    ///
+    /// # Units & Scaling
+    /// - Input: Asset addresses (any token contract)
+    /// - Output: Total USD value (scaled by 10^7)
+    /// - Each asset balance: stroops (10^-7 units)
+    /// - Each asset price: USD per token (scaled by 10^7)
+    ///
+    /// # Errors
+    /// - `NotInitialized` - Oracle not configured
+    /// - `InvalidAmount` - Any asset price not found
+    /// - `RetryError` - Any asset price is stale
+    ///
+    /// # Example
+    /// ```ignore
+    /// let assets = vec![usdc_address, xlm_address];
+    /// let total_usd = VaultDAO::get_portfolio_valuation(env, assets)?;
+    /// // total_usd is in USD cents (scaled by 10^7)
+    /// ```
+    pub fn get_portfolio_valuation(env: Env, assets: Vec<Address>) -> Result<i128, VaultError> {
+        // Empty asset list is valid and returns 0
+        if assets.is_empty() {
+            return Ok(0);
+        }
+
+        let mut total_usd = 0i128;
+
+        for asset in assets.into_iter() {
+            let balance = token::balance(&env, &asset);
+            // Skip zero balances to avoid unnecessary oracle queries
+            if balance > 0 {
+                let usd_value = Self::convert_to_usd(&env, asset, balance)?;
+                total_usd = total_usd.saturating_add(usd_value);
+            }
+        }
+
+        Ok(total_usd)
+    }
+
+    /// Award small reputation boost when a proposal is created.
+    fn update_reputation_on_propose(env: &Env, proposer: &Address) {
+        let mut rep = storage::get_reputation(env, proposer);
+        storage::apply_reputation_decay(env, &mut rep);
+        rep.proposals_created += 1;
+        storage::set_reputation(env, proposer, &rep);
+    }
+
+    /// Award small reputation boost when a signer approves a proposal.
+    fn update_reputation_on_approval(env: &Env, signer: &Address) {
+        let mut rep = storage::get_reputation(env, signer);
+        storage::apply_reputation_decay(env, &mut rep);
+        let old_score = rep.score;
+        rep.score = (rep.score + REP_APPROVAL_BONUS).min(1000);
+        rep.approvals_given = rep.approvals_given.saturating_add(1);
+        rep.participation_count = rep.participation_count.saturating_add(1);
+        rep.last_participation_ledger = env.ledger().sequence() as u64;
+        let new_score = rep.score;
+        storage::set_reputation(env, signer, &rep);
+        if old_score != new_score {
+            events::emit_reputation_updated(
+                env,
+                signer,
+                old_score,
+                new_score,
+                Symbol::new(env, "approved"),
+            );
+        }
+    }
+
+    /// Track signer participation for abstentions.
+    fn update_reputation_on_abstention(env: &Env, signer: &Address) {
+        let mut rep = storage::get_reputation(env, signer);
+        storage::apply_reputation_decay(env, &mut rep);
+        rep.abstentions_given = rep.abstentions_given.saturating_add(1);
+        rep.participation_count = rep.participation_count.saturating_add(1);
+        rep.last_participation_ledger = env.ledger().sequence() as u64;
+        storage::set_reputation(env, signer, &rep);
+    }
+
+    /// Reward proposer and all approvers on successful execution.
+    fn update_reputation_on_execution(env: &Env, proposal: &Proposal) {
+        // Reward proposer
+        {
+            let mut rep = storage::get_reputation(env, &proposal.proposer);
+            storage::apply_reputation_decay(env, &mut rep);
+            let old_score = rep.score;
+            rep.score = (rep.score + REP_EXEC_PROPOSER).min(1000);
+            rep.proposals_executed += 1;
+            let new_score = rep.score;
+            storage::set_reputation(env, &proposal.proposer, &rep);
+            if old_score != new_score {
+                events::emit_reputation_updated(
+                    env,
+                    &proposal.proposer,
+                    old_score,
+                    new_score,
+                    Symbol::new(env, "executed"),
+                );
+            }
+        }
+
+        // Reward each approver
+        for i in 0..proposal.approvals.len() {
+            if let Some(approver) = proposal.approvals.get(i) {
+                let mut rep = storage::get_reputation(env, &approver);
+                storage::apply_reputation_decay(env, &mut rep);
+                let old_score = rep.score;
+                rep.score = (rep.score + REP_EXEC_APPROVER).min(1000);
+                let new_score = rep.score;
+                storage::set_reputation(env, &approver, &rep);
+                if old_score != new_score {
                     events::emit_reputation_updated(
                         env,
                         &approver,
@@ -4649,12 +6985,27 @@ impl VaultDAO {
         admin: Address,
         dex_config: DexConfig,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_dex_config",
+            (dex_config.clone(),).into_val(&env),
+        )?;
+        let previous = storage::get_dex_config(&env);
         storage::set_dex_config(&env, &dex_config);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateDexConfig,
+            &admin,
+            0,
+            (previous, dex_config),
+        );
         events::emit_dex_config_updated(&env, &admin);
         Ok(())
     }
@@ -4674,45 +7025,94 @@ impl VaultDAO {
         insurance_amount: i128,
     ) -> Result<u64, VaultError> {
         proposer.require_auth();
-        let config = storage::get_config(&env)?;
-        let role = storage::get_role(&env, &proposer);
-        if role != Role::Treasurer && role != Role::Admin {
+        Self::create_swap_proposal(
+            &env,
+            &proposer,
+            &swap_op,
+            priority,
+            conditions,
+            condition_logic,
+            insurance_amount,
+            None,
+        )
+    }
+
+    /// Shared body of `propose_swap` and `propose_limit_order`; the caller
+    /// has already authenticated `proposer`. `expires_at` overrides the
+    /// priority-based expiry.
+    #[allow(clippy::too_many_arguments)]
+    fn create_swap_proposal(
+        env: &Env,
+        proposer: &Address,
+        swap_op: &SwapProposal,
+        priority: Priority,
+        conditions: Vec<Condition>,
+        condition_logic: ConditionLogic,
+        insurance_amount: i128,
+        expires_at: Option<u64>,
+    ) -> Result<u64, VaultError> {
+        Self::ensure_not_paused(env, PauseCategory::Proposals)?;
+        let config = storage::get_config(env)?;
+        let (token_in, amount_in) = Self::swap_input(swap_op);
+        let request = PermissionRequest {
+            token: token_in,
+            amount: amount_in,
+            recipients: Vec::new(env),
+            tags: Vec::new(env),
+        };
+        if !Self::check_permission(
+            env,
+            proposer,
+            &types::Permission::CreateProposal,
+            Some(&request),
+        ) {
             return Err(VaultError::InsufficientRole);
         }
+        Self::validate_conditions(&conditions, 0)?;
 
-        let dex_config = storage::get_dex_config(&env).ok_or(VaultError::DexError)?;
-        let dex_addr = match &swap_op {
+        let dex_config = storage::get_dex_config(env).ok_or(VaultError::DexError)?;
+        let dex_addr = match swap_op {
             SwapProposal::Swap(dex, ..) => dex,
             SwapProposal::AddLiquidity(dex, ..) => dex,
             SwapProposal::RemoveLiquidity(dex, ..) => dex,
             SwapProposal::StakeLp(farm, ..) => farm,
             SwapProposal::UnstakeLp(farm, ..) => farm,
             SwapProposal::ClaimRewards(farm) => farm,
+            SwapProposal::Dca(dex, ..) => dex,
         };
         if !dex_config.enabled_dexs.contains(dex_addr) {
             return Err(VaultError::DexError);
         }
+        if let SwapProposal::Dca(_, _, _, total_amount, slices, interval, _) = swap_op {
+            if *total_amount <= 0 || *slices == 0 || *total_amount < *slices as i128 {
+                return Err(VaultError::InvalidAmount);
+            }
+            if *interval == 0 {
+                return Err(VaultError::IntervalTooShort);
+            }
+        }
 
         let current_ledger = env.ledger().sequence() as u64;
-        let proposal_id = storage::increment_proposal_id(&env);
+        let proposal_id = storage::increment_proposal_id(env);
         let proposal = Proposal {
             id: proposal_id,
             proposer: proposer.clone(),
             recipient: env.current_contract_address(),
             token: env.current_contract_address(),
             amount: 0,
-            memo: Symbol::new(&env, "swap"),
-            metadata: Map::new(&env),
-            tags: Vec::new(&env),
-            approvals: Vec::new(&env),
-            abstentions: Vec::new(&env),
-            attachments: Vec::new(&env),
+            memo: Symbol::new(env, "swap"),
+            metadata: Map::new(env),
+            tags: Vec::new(env),
+            approvals: Vec::new(env),
+            abstentions: Vec::new(env),
+            attachments: Vec::new(env),
             status: ProposalStatus::Pending,
             priority: priority.clone(),
             conditions,
             condition_logic,
             created_at: current_ledger,
-            expires_at: calculate_expiration_ledger(&config, &priority, current_ledger),
+            expires_at: expires_at
+                .unwrap_or_else(|| calculate_expiration_ledger(&config, &priority, current_ledger)),
             unlock_ledger: 0,
             execution_time: None,
             insurance_amount,
@@ -4721,7 +7121,7 @@ impl VaultDAO {
             gas_used: 0,
             snapshot_ledger: current_ledger,
             snapshot_signers: config.signers.clone(),
-            depends_on: Vec::new(&env),
+            depends_on: Vec::new(env),
             is_swap: true,
             voting_deadline: if config.default_voting_deadline > 0 {
                 current_ledger + config.default_voting_deadline
@@ -4730,26 +7130,43 @@ impl VaultDAO {
             },
         };
 
-        storage::set_proposal(&env, &proposal);
-        Self::persist_execution_fee_estimate(&env, &proposal);
-        storage::set_swap_proposal(&env, proposal_id, &swap_op);
-        storage::add_to_priority_queue(&env, priority as u32, proposal_id);
+        storage::set_proposal(env, &proposal);
+        Self::persist_execution_fee_estimate(env, &proposal);
+        storage::set_swap_proposal(env, proposal_id, swap_op);
+        storage::add_to_priority_queue(env, priority as u32, proposal_id);
+        storage::create_audit_entry(
+            env,
+            AuditAction::ProposeSwap,
+            proposer,
+            proposal_id,
+            swap_op.clone(),
+        );
         events::emit_proposal_created(
-            &env,
+            env,
             proposal_id,
-            &proposer,
+            proposer,
             &env.current_contract_address(),
             &env.current_contract_address(),
             0,
             0,
         );
-        Self::update_reputation_on_propose(&env, &proposer);
-        storage::metrics_on_proposal(&env);
+        Self::notify_lifecycle_hooks(
+            env,
+            Self::proposal_lifecycle_event(
+                env,
+                HookStage::ProposalCreated,
+                "created",
+                &proposal,
+                proposer,
+            ),
+        );
+        Self::update_reputation_on_propose(env, proposer);
+        storage::metrics_on_proposal(env);
 
         // Emit metrics update event
-        let metrics = storage::get_metrics(&env);
+        let metrics = storage::get_metrics(env);
         events::emit_metrics_updated(
-            &env,
+            env,
             metrics.executed_count,
             metrics.rejected_count,
             metrics.expired_count,
@@ -4759,8 +7176,34 @@ impl VaultDAO {
         Ok(proposal_id)
     }
 
+    /// Token and amount of vault funds a DEX operation commits up front
+    /// (none for claims).
+    fn swap_input(swap_op: &SwapProposal) -> (Option<Address>, i128) {
+        match swap_op {
+            SwapProposal::Swap(_, token_in, _, amount, _) => (Some(token_in.clone()), *amount),
+            SwapProposal::AddLiquidity(_, token_a, _, amount_a, _, _) => {
+                (Some(token_a.clone()), *amount_a)
+            }
+            SwapProposal::RemoveLiquidity(_, lp_token, amount, _, _)
+            | SwapProposal::StakeLp(_, lp_token, amount)
+            | SwapProposal::UnstakeLp(_, lp_token, amount) => (Some(lp_token.clone()), *amount),
+            SwapProposal::ClaimRewards(_) => (None, 0),
+            SwapProposal::Dca(_, token_in, _, total_amount, _, _, _) => {
+                (Some(token_in.clone()), *total_amount)
+            }
+        }
+    }
+
     /// Execute a swap proposal (executors only)
-    pub fn execute_swap(env: Env, executor: Address, proposal_id: u64) -> Result<(), VaultError> {
+    ///
+    /// Returns `CircuitBreakerTripped` without swapping when the call trips
+    /// the breaker of a token involved, so the trip stays latched.
+    pub fn execute_swap(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+    ) -> Result<ExecutionOutcome, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
         executor.require_auth();
 
         // Get proposal
@@ -4770,6 +7213,10 @@ impl VaultDAO {
         if !proposal.is_swap {
             return Err(VaultError::DexError);
         }
+        // Limit orders fill only through `execute_limit_order` once triggered
+        if storage::get_limit_order(&env, proposal_id).is_some() {
+            return Err(VaultError::DexError);
+        }
         if proposal.status != ProposalStatus::Approved {
             return Err(VaultError::ProposalNotApproved);
         }
@@ -4791,17 +7238,83 @@ impl VaultDAO {
         if proposal.unlock_ledger > 0 && current_ledger < proposal.unlock_ledger {
             return Err(VaultError::TimelockNotExpired);
         }
+        if !proposal.conditions.is_empty() {
+            Self::evaluate_conditions(&env, &proposal).map_err(|_| VaultError::ConditionsNotMet)?;
+        }
 
         // Get DEX config and swap details
         let dex_config = storage::get_dex_config(&env).ok_or(VaultError::DexError)?;
         let swap_proposal =
             storage::get_swap_proposal(&env, proposal_id).ok_or(VaultError::DexError)?;
+        for token in Self::swap_tokens(&env, &swap_proposal).iter() {
+            if Self::circuit_breaker_tripped(&env, &token)? {
+                return Ok(ExecutionOutcome::CircuitBreakerTripped);
+            }
+        }
 
-        // Perform the swap (mock implementation - in real implementation, call DEX contract)
-        let swap_result = Self::perform_swap(&env, &dex_config, &swap_proposal)?;
-
-        // Store result
-        storage::set_swap_result(&env, proposal_id, &swap_result);
+        if let SwapProposal::Dca(
+            dex,
+            token_in,
+            token_out,
+            total_amount,
+            slices,
+            interval,
+            min_amount_out_per_slice,
+        ) = swap_proposal
+        {
+            // DCA orders only open here; keepers fill them via `execute_dca_slice`.
+            let order = DcaOrder {
+                id: proposal_id,
+                dex,
+                token_in,
+                token_out,
+                total_amount,
+                slices,
+                interval,
+                min_amount_out_per_slice,
+                slices_executed: 0,
+                amount_in_filled: 0,
+                amount_out_filled: 0,
+                remaining_amount: total_amount,
+                average_price: 0,
+                next_slice_ledger: current_ledger,
+                status: DcaStatus::Active,
+                created_at: current_ledger,
+            };
+            storage::set_dca_order(&env, &order);
+            storage::add_active_dca_order(&env, proposal_id);
+            storage::create_audit_entry(
+                &env,
+                AuditAction::ExecuteSwap,
+                &executor,
+                proposal_id,
+                (
+                    Symbol::new(&env, "dca_open"),
+                    &order.token_in,
+                    &order.token_out,
+                    total_amount,
+                ),
+            );
+            events::emit_dca_order_created(
+                &env,
+                proposal_id,
+                &order.token_in,
+                &order.token_out,
+                total_amount,
+                slices,
+            );
+        } else {
+            // Perform the operation against the DEX and record measured amounts
+            let swap_result = Self::perform_swap(&env, &dex_config, proposal_id, &swap_proposal)?;
+            storage::set_swap_result(&env, proposal_id, &swap_result);
+            storage::create_audit_entry(
+                &env,
+                AuditAction::ExecuteSwap,
+                &executor,
+                proposal_id,
+                swap_result,
+            );
+        }
 
         // Update proposal status
         proposal.status = ProposalStatus::Executed;
@@ -4824,58 +7337,465 @@ impl VaultDAO {
         let execution_time = current_ledger.saturating_sub(proposal.created_at);
         storage::metrics_on_execution(&env, proposal.gas_used, execution_time);
 
-        Ok(())
+        Ok(ExecutionOutcome::Executed)
+    }
+
+    /// Tokens whose prices a DEX operation is exposed to.
+    fn swap_tokens(env: &Env, swap_proposal: &SwapProposal) -> Vec<Address> {
+        match swap_proposal {
+            SwapProposal::Swap(_, token_in, token_out, ..)
+            | SwapProposal::Dca(_, token_in, token_out, ..) => {
+                Vec::from_array(env, [token_in.clone(), token_out.clone()])
+            }
+            SwapProposal::AddLiquidity(_, token_a, token_b, ..) => {
+                Vec::from_array(env, [token_a.clone(), token_b.clone()])
+            }
+            SwapProposal::RemoveLiquidity(_, lp_token, ..)
+            | SwapProposal::StakeLp(_, lp_token, _)
+            | SwapProposal::UnstakeLp(_, lp_token, _) => Vec::from_array(env, [lp_token.clone()]),
+            SwapProposal::ClaimRewards(_) => Vec::new(env),
+        }
     }
 
-    /// Perform the actual swap operation (mock implementation)
+    /// Perform a DEX operation against the configured router or farm.
+    ///
+    /// Vault balances are measured before and after each call, so the returned
+    /// `SwapResult` reports what actually moved rather than what was quoted.
+    /// Minimum-output bounds are enforced on the measured amounts, and swap
+    /// quotes and fills are checked against the oracle price of both tokens.
     fn perform_swap(
         env: &Env,
         dex_config: &DexConfig,
+        proposal_id: u64,
         swap_proposal: &SwapProposal,
     ) -> Result<SwapResult, VaultError> {
+        let vault = env.current_contract_address();
+        let deadline = env.ledger().timestamp();
+        let executed_at = env.ledger().sequence() as u64;
+
         match swap_proposal {
             SwapProposal::Swap(dex, token_in, token_out, amount_in, min_amount_out) => {
-                // Check if DEX is enabled
+                Self::swap_exact_in(
+                    env,
+                    dex_config,
+                    proposal_id,
+                    dex,
+                    token_in,
+                    token_out,
+                    *amount_in,
+                    *min_amount_out,
+                )
+            }
+            SwapProposal::AddLiquidity(dex, token_a, token_b, amount_a, amount_b, min_lp) => {
+                if !dex_config.enabled_dexs.contains(dex) {
+                    return Err(VaultError::DexError);
+                }
+                if *amount_a <= 0 || *amount_b <= 0 {
+                    return Err(VaultError::InvalidAmount);
+                }
+
+                let router = dex::RouterClient::new(env, dex);
+                let pair = match router.try_router_pair_for(token_a, token_b) {
+                    Ok(Ok(pair)) => pair,
+                    _ => return Err(VaultError::DexError),
+                };
+                let (reserve_a, reserve_b) = dex::ordered_reserves(env, &pair, token_a)?;
+                let (deposit_a, deposit_b) =
+                    dex::optimal_liquidity_amounts(reserve_a, reserve_b, *amount_a, *amount_b)?;
+
+                let a_before = token::balance(env, token_a);
+                let b_before = token::balance(env, token_b);
+                let lp_before = token::balance(env, &pair);
+
+                dex::authorize_transfers(
+                    env,
+                    &[(token_a, &pair, deposit_a), (token_b, &pair, deposit_b)],
+                );
+                if !matches!(
+                    router.try_add_liquidity(
+                        token_a, token_b, &deposit_a, &deposit_b, &deposit_a, &deposit_b, &vault,
+                        &deadline,
+                    ),
+                    Ok(Ok(_))
+                ) {
+                    return Err(VaultError::DexError);
+                }
+
+                let spent_a = a_before - token::balance(env, token_a);
+                let spent_b = b_before - token::balance(env, token_b);
+                let minted = token::balance(env, &pair) - lp_before;
+                if minted < *min_lp {
+                    return Err(VaultError::DexError);
+                }
+
+                let mut position = Self::load_lp_position(env, &pair);
+                let (basis_a, basis_b) = if position.token_a == *token_a {
+                    (spent_a, spent_b)
+                } else {
+                    (spent_b, spent_a)
+                };
+                position.lp_held += minted;
+                position.cost_basis_a += basis_a;
+                position.cost_basis_b += basis_b;
+                position.updated_at = executed_at;
+                storage::set_lp_position(env, &position);
+
+                events::emit_liquidity_added(env, proposal_id, dex, minted);
+                events::emit_lp_position_updated(env, &position);
+
+                Ok(SwapResult {
+                    amount_in: spent_a,
+                    amount_out: minted,
+                    amount_b: spent_b,
+                    price_impact_bps: 0,
+                    oracle_price: 0,
+                    executed_at,
+                })
+            }
+            SwapProposal::RemoveLiquidity(dex, lp_token, amount, min_a, min_b) => {
                 if !dex_config.enabled_dexs.contains(dex) {
                     return Err(VaultError::DexError);
                 }
+                if *amount <= 0 {
+                    return Err(VaultError::InvalidAmount);
+                }
+
+                let pair = dex::PairClient::new(env, lp_token);
+                let (token_a, token_b) = match (pair.try_token_0(), pair.try_token_1()) {
+                    (Ok(Ok(token_a)), Ok(Ok(token_b))) => (token_a, token_b),
+                    _ => return Err(VaultError::DexError),
+                };
+
+                let lp_before = token::balance(env, lp_token);
+                let a_before = token::balance(env, &token_a);
+                let b_before = token::balance(env, &token_b);
+
+                dex::authorize_transfers(env, &[(lp_token, lp_token, *amount)]);
+                let router = dex::RouterClient::new(env, dex);
+                if !matches!(
+                    router.try_remove_liquidity(
+                        &token_a, &token_b, amount, min_a, min_b, &vault, &deadline,
+                    ),
+                    Ok(Ok(_))
+                ) {
+                    return Err(VaultError::DexError);
+                }
+
+                let burned = lp_before - token::balance(env, lp_token);
+                let received_a = token::balance(env, &token_a) - a_before;
+                let received_b = token::balance(env, &token_b) - b_before;
+                if received_a < *min_a || received_b < *min_b {
+                    return Err(VaultError::DexError);
+                }
+
+                // Release cost basis pro rata to the tracked LP that was burned.
+                let mut position = Self::load_lp_position(env, lp_token);
+                let tracked = position.lp_held + position.lp_staked;
+                let (released_a, released_b) = if tracked > 0 {
+                    let share = burned.min(tracked);
+                    (
+                        position.cost_basis_a.saturating_mul(share) / tracked,
+                        position.cost_basis_b.saturating_mul(share) / tracked,
+                    )
+                } else {
+                    (0, 0)
+                };
+                position.lp_held = (position.lp_held - burned).max(0);
+                position.cost_basis_a -= released_a;
+                position.cost_basis_b -= released_b;
+                position.realized_a += received_a - released_a;
+                position.realized_b += received_b - released_b;
+                position.updated_at = executed_at;
+                storage::set_lp_position(env, &position);
+
+                events::emit_liquidity_removed(env, proposal_id, dex, burned);
+                events::emit_lp_position_updated(env, &position);
+
+                Ok(SwapResult {
+                    amount_in: burned,
+                    amount_out: received_a,
+                    amount_b: received_b,
+                    price_impact_bps: 0,
+                    oracle_price: 0,
+                    executed_at,
+                })
+            }
+            SwapProposal::StakeLp(farm, lp_token, amount) => {
+                if !dex_config.enabled_dexs.contains(farm) {
+                    return Err(VaultError::DexError);
+                }
+                if *amount <= 0 {
+                    return Err(VaultError::InvalidAmount);
+                }
+
+                let lp_before = token::balance(env, lp_token);
+                dex::authorize_transfers(env, &[(lp_token, farm, *amount)]);
+                let farm_client = dex::FarmClient::new(env, farm);
+                if !matches!(farm_client.try_stake(&vault, lp_token, amount), Ok(Ok(_))) {
+                    return Err(VaultError::DexError);
+                }
+                let staked = lp_before - token::balance(env, lp_token);
+
+                let mut position = Self::load_lp_position(env, lp_token);
+                position.lp_held = (position.lp_held - staked).max(0);
+                position.lp_staked += staked;
+                position.updated_at = executed_at;
+                storage::set_lp_position(env, &position);
+                let mut farm_position = Self::load_farm_position(env, farm);
+                farm_position.staked += staked;
+                farm_position.updated_at = executed_at;
+                storage::set_farm_position(env, &farm_position);
+
+                events::emit_lp_staked(env, proposal_id, farm, staked);
+                events::emit_lp_position_updated(env, &position);
+                events::emit_farm_position_updated(env, &farm_position);
 
-                // Mock: assume swap succeeds with 1% slippage
-                let mock_price_impact_bps = 100; // 1%
-                if mock_price_impact_bps > dex_config.max_price_impact_bps {
+                Ok(SwapResult {
+                    amount_in: staked,
+                    amount_out: 0,
+                    amount_b: 0,
+                    price_impact_bps: 0,
+                    oracle_price: 0,
+                    executed_at,
+                })
+            }
+            SwapProposal::UnstakeLp(farm, lp_token, amount) => {
+                if !dex_config.enabled_dexs.contains(farm) {
                     return Err(VaultError::DexError);
                 }
+                if *amount <= 0 {
+                    return Err(VaultError::InvalidAmount);
+                }
 
-                let mock_amount_out = *amount_in * 99 / 100; // 1% slippage
-                if mock_amount_out < *min_amount_out {
+                let lp_before = token::balance(env, lp_token);
+                let farm_client = dex::FarmClient::new(env, farm);
+                if !matches!(farm_client.try_unstake(&vault, lp_token, amount), Ok(Ok(_))) {
                     return Err(VaultError::DexError);
                 }
+                let returned = token::balance(env, lp_token) - lp_before;
+
+                let mut position = Self::load_lp_position(env, lp_token);
+                position.lp_staked = (position.lp_staked - returned).max(0);
+                position.lp_held += returned;
+                position.updated_at = executed_at;
+                storage::set_lp_position(env, &position);
+                let mut farm_position = Self::load_farm_position(env, farm);
+                farm_position.staked = (farm_position.staked - returned).max(0);
+                farm_position.updated_at = executed_at;
+                storage::set_farm_position(env, &farm_position);
+
+                events::emit_lp_unstaked(env, proposal_id, farm, returned);
+                events::emit_lp_position_updated(env, &position);
+                events::emit_farm_position_updated(env, &farm_position);
 
                 Ok(SwapResult {
-                    amount_in: *amount_in,
-                    amount_out: mock_amount_out,
-                    price_impact_bps: mock_price_impact_bps,
-                    executed_at: env.ledger().sequence() as u64,
+                    amount_in: 0,
+                    amount_out: returned,
+                    amount_b: 0,
+                    price_impact_bps: 0,
+                    oracle_price: 0,
+                    executed_at,
                 })
             }
-            _ => {
-                // For other swap types, just return a mock result
+            SwapProposal::ClaimRewards(farm) => {
+                if !dex_config.enabled_dexs.contains(farm) {
+                    return Err(VaultError::DexError);
+                }
+
+                let farm_client = dex::FarmClient::new(env, farm);
+                let reward_token = match farm_client.try_reward_token() {
+                    Ok(Ok(reward_token)) => reward_token,
+                    _ => return Err(VaultError::DexError),
+                };
+                let reward_before = token::balance(env, &reward_token);
+                if !matches!(farm_client.try_claim_rewards(&vault), Ok(Ok(_))) {
+                    return Err(VaultError::DexError);
+                }
+                let claimed = token::balance(env, &reward_token) - reward_before;
+
+                let mut farm_position = Self::load_farm_position(env, farm);
+                farm_position.reward_token = Some(reward_token);
+                farm_position.rewards_claimed += claimed;
+                farm_position.updated_at = executed_at;
+                storage::set_farm_position(env, &farm_position);
+
+                events::emit_rewards_claimed(env, proposal_id, farm, claimed);
+                events::emit_farm_position_updated(env, &farm_position);
+
                 Ok(SwapResult {
-                    amount_in: 1000,
-                    amount_out: 990,
-                    price_impact_bps: 100,
-                    executed_at: env.ledger().sequence() as u64,
+                    amount_in: 0,
+                    amount_out: claimed,
+                    amount_b: 0,
+                    price_impact_bps: 0,
+                    oracle_price: 0,
+                    executed_at,
                 })
             }
+            // Opened by `execute_swap` and filled slice by slice via `execute_dca_slice`.
+            SwapProposal::Dca(..) => Err(VaultError::DexError),
+        }
+    }
+
+    /// Swap an exact `amount_in` of `token_in` for `token_out` through `dex`.
+    ///
+    /// The router quote and the measured fill are both checked against the
+    /// output implied by the oracle prices of the two tokens.
+    #[allow(clippy::too_many_arguments)]
+    fn swap_exact_in(
+        env: &Env,
+        dex_config: &DexConfig,
+        proposal_id: u64,
+        dex: &Address,
+        token_in: &Address,
+        token_out: &Address,
+        amount_in: i128,
+        min_amount_out: i128,
+    ) -> Result<SwapResult, VaultError> {
+        let vault = env.current_contract_address();
+        let deadline = env.ledger().timestamp();
+        let executed_at = env.ledger().sequence() as u64;
+
+        if !dex_config.enabled_dexs.contains(dex) {
+            return Err(VaultError::DexError);
+        }
+        if amount_in <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        // Reference output implied by the oracle prices of both tokens.
+        let price_in = Self::get_asset_price(env, token_in.clone())?;
+        let price_out = Self::get_asset_price(env, token_out.clone())?;
+        if price_in <= 0 || price_out <= 0 {
+            return Err(VaultError::DexError);
+        }
+        let (decimals_in, decimals_out) = match (
+            token::decimals(env, token_in),
+            token::decimals(env, token_out),
+        ) {
+            (Some(decimals_in), Some(decimals_out)) => (decimals_in, decimals_out),
+            _ => return Err(VaultError::DexError),
+        };
+        // Overflowing amounts or prices fail closed rather than saturate.
+        let oracle_price = price_in
+            .checked_mul(10_000_000)
+            .ok_or(VaultError::DexError)?
+            / price_out;
+        // Prices are per whole token, so convert between the tokens' decimals.
+        let expected_out = amount_in
+            .checked_mul(price_in)
+            .and_then(|value| dex::rescale(value, decimals_in, decimals_out))
+            .ok_or(VaultError::DexError)?
+            / price_out;
+
+        let router = dex::RouterClient::new(env, dex);
+        let path = Vec::from_array(env, [token_in.clone(), token_out.clone()]);
+        let quoted_out = match router.try_router_get_amounts_out(&amount_in, &path) {
+            Ok(Ok(amounts)) => amounts.last().unwrap_or(0),
+            _ => return Err(VaultError::DexError),
+        };
+        if dex::deviation_bps(expected_out, quoted_out) > dex_config.max_price_impact_bps {
+            return Err(VaultError::DexError);
+        }
+        let pair = match router.try_router_pair_for(token_in, token_out) {
+            Ok(Ok(pair)) => pair,
+            _ => return Err(VaultError::DexError),
+        };
+
+        let in_before = token::balance(env, token_in);
+        let out_before = token::balance(env, token_out);
+
+        dex::authorize_transfers(env, &[(token_in, &pair, amount_in)]);
+        if !matches!(
+            router.try_swap_exact_tokens_for_tokens(
+                &amount_in,
+                &min_amount_out,
+                &path,
+                &vault,
+                &deadline,
+            ),
+            Ok(Ok(_))
+        ) {
+            return Err(VaultError::DexError);
+        }
+
+        let spent = in_before - token::balance(env, token_in);
+        let received = token::balance(env, token_out) - out_before;
+        if received < min_amount_out {
+            return Err(VaultError::DexError);
+        }
+
+        let price_impact_bps = dex::shortfall_bps(expected_out, received);
+        if price_impact_bps
+            > dex_config
+                .max_price_impact_bps
+                .saturating_add(dex_config.max_slippage_bps)
+        {
+            return Err(VaultError::DexError);
         }
+
+        events::emit_swap_executed(env, proposal_id, dex, spent, received);
+
+        Ok(SwapResult {
+            amount_in: spent,
+            amount_out: received,
+            amount_b: 0,
+            price_impact_bps,
+            oracle_price,
+            executed_at,
+        })
+    }
+
+    /// Tracked position for `lp_token`, or an empty one if none exists yet.
+    fn load_lp_position(env: &Env, lp_token: &Address) -> LpPosition {
+        storage::get_lp_position(env, lp_token).unwrap_or_else(|| {
+            let pair = dex::PairClient::new(env, lp_token);
+            let token_a = match pair.try_token_0() {
+                Ok(Ok(token)) => token,
+                _ => lp_token.clone(),
+            };
+            let token_b = match pair.try_token_1() {
+                Ok(Ok(token)) => token,
+                _ => lp_token.clone(),
+            };
+            LpPosition {
+                lp_token: lp_token.clone(),
+                token_a,
+                token_b,
+                lp_held: 0,
+                lp_staked: 0,
+                cost_basis_a: 0,
+                cost_basis_b: 0,
+                realized_a: 0,
+                realized_b: 0,
+                updated_at: 0,
+            }
+        })
+    }
+
+    /// Tracked position for `farm`, or an empty one if none exists yet.
+    fn load_farm_position(env: &Env, farm: &Address) -> FarmPosition {
+        storage::get_farm_position(env, farm).unwrap_or(FarmPosition {
+            farm: farm.clone(),
+            staked: 0,
+            reward_token: None,
+            rewards_claimed: 0,
+            updated_at: 0,
+        })
     }
 
     pub fn register_pre_hook(env: Env, admin: Address, hook: Address) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "register_pre_hook",
+            (hook.clone(),).into_val(&env),
+        )?;
 
         let mut config = storage::get_config(&env)?;
         if config.pre_execution_hooks.contains(&hook) {
@@ -4885,16 +7805,30 @@ impl VaultDAO {
         config.pre_execution_hooks.push_back(hook.clone());
         storage::set_config(&env, &config);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateHooks,
+            &admin,
+            0,
+            (Symbol::new(&env, "pre_add"), &hook),
+        );
         events::emit_hook_registered(&env, &hook, true);
         Ok(())
     }
 
     pub fn register_post_hook(env: Env, admin: Address, hook: Address) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "register_post_hook",
+            (hook.clone(),).into_val(&env),
+        )?;
 
         let mut config = storage::get_config(&env)?;
         if config.post_execution_hooks.contains(&hook) {
@@ -4904,16 +7838,30 @@ impl VaultDAO {
         config.post_execution_hooks.push_back(hook.clone());
         storage::set_config(&env, &config);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateHooks,
+            &admin,
+            0,
+            (Symbol::new(&env, "post_add"), &hook),
+        );
         events::emit_hook_registered(&env, &hook, false);
         Ok(())
     }
 
     pub fn remove_pre_hook(env: Env, admin: Address, hook: Address) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "remove_pre_hook",
+            (hook.clone(),).into_val(&env),
+        )?;
 
         let mut config = storage::get_config(&env)?;
         let mut found_idx: Option<u32> = None;
@@ -4928,16 +7876,30 @@ impl VaultDAO {
         config.pre_execution_hooks.remove(idx);
         storage::set_config(&env, &config);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateHooks,
+            &admin,
+            0,
+            (Symbol::new(&env, "pre_remove"), &hook),
+        );
         events::emit_hook_removed(&env, &hook, true);
         Ok(())
     }
 
     pub fn remove_post_hook(env: Env, admin: Address, hook: Address) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "remove_post_hook",
+            (hook.clone(),).into_val(&env),
+        )?;
 
         let mut config = storage::get_config(&env)?;
         let mut found_idx: Option<u32> = None;
@@ -4952,6 +7914,13 @@ impl VaultDAO {
         config.post_execution_hooks.remove(idx);
         storage::set_config(&env, &config);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateHooks,
+            &admin,
+            0,
+            (Symbol::new(&env, "post_remove"), &hook),
+        );
         events::emit_hook_removed(&env, &hook, false);
         Ok(())
     }
@@ -4966,1870 +7935,4280 @@ impl VaultDAO {
         Ok(storage::get_config(&env)?.post_execution_hooks)
     }
 
-    fn call_hook(env: &Env, hook: &Address, proposal_id: u64, is_pre: bool) {
-        let _ = env.invoke_contract::<()>(
-            hook,
-            &Symbol::new(
-                env,
-                if is_pre {
-                    "pre_execute"
-                } else {
-                    "post_execute"
-                },
-            ),
-            (proposal_id,).into_val(env),
-        );
+    /// Register a typed hook (Admin only).
+    ///
+    /// Execution-stage hooks receive a [`HookContext`] and only run for
+    /// proposals matching their filter. A pre-execution hook can deny
+    /// execution with a reason code; its failure policy decides whether a
+    /// failing call blocks. Lifecycle-stage hooks receive a [`LifecycleEvent`]
+    /// when a proposal, stream, escrow or subscription changes.
+    pub fn register_execution_hook(
+        env: Env,
+        admin: Address,
+        hook: ExecutionHook,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        Self::require_council(
+            &env,
+            &admin,
+            "register_execution_hook",
+            (hook.clone(),).into_val(&env),
+        )?;
 
-        events::emit_hook_executed(env, hook, proposal_id, is_pre);
-    }
+        let filter = &hook.filter;
+        if filter.min_amount < 0
+            || filter.max_amount < 0
+            || (filter.max_amount > 0 && filter.max_amount < filter.min_amount)
+        {
+            return Err(VaultError::InvalidAmount);
+        }
+        // Lifecycle notifications are always fail-open
+        if hooks::is_lifecycle(&hook.stage) && hook.failure_policy == HookFailurePolicy::FailClosed
+        {
+            return Err(VaultError::InvalidAmount);
+        }
 
-    pub fn get_swap_result(env: Env, proposal_id: u64) -> Option<SwapResult> {
-        storage::get_swap_result(&env, proposal_id)
+        let mut hooks = storage::get_execution_hooks(&env);
+        for existing in hooks.iter() {
+            if existing.hook == hook.hook && existing.stage == hook.stage {
+                return Err(VaultError::SignerAlreadyExists);
+            }
+        }
+
+        hooks.push_back(hook.clone());
+        storage::set_execution_hooks(&env, &hooks);
+        storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateHooks,
+            &admin,
+            0,
+            (Symbol::new(&env, "typed_add"), hook.clone()),
+        );
+        if hooks::is_lifecycle(&hook.stage) {
+            events::emit_lifecycle_hook_registered(&env, &hook.hook, &hook.stage);
+        } else {
+            events::emit_hook_registered(&env, &hook.hook, hook.stage == HookStage::PreExecute);
+        }
+        Ok(())
     }
-    // ========================================================================
-    // Retry Helpers (private)
-    // ========================================================================
 
-    /// Attempt the actual transfer for a proposal. Separated from execute_proposal
-    /// so that retryable failures can be caught and handled.
-    fn try_execute_transfer(
-        env: &Env,
-        _executor: &Address,
-        proposal: &mut Proposal,
-        _current_ledger: u64,
+    /// Remove a typed hook registered for `stage` (Admin only).
+    pub fn remove_execution_hook(
+        env: Env,
+        admin: Address,
+        hook: Address,
+        stage: HookStage,
     ) -> Result<(), VaultError> {
-        // Evaluate execution conditions (if any) before balance check
-        if !proposal.conditions.is_empty() {
-            Self::evaluate_conditions(env, proposal)?;
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "remove_execution_hook",
+            (hook.clone(), stage.clone()).into_val(&env),
+        )?;
 
-        // Gas limit check
-        let fee_estimate = Self::calculate_execution_fee(env, proposal);
-        if proposal.gas_limit > 0 && fee_estimate.total_fee > proposal.gas_limit {
-            events::emit_gas_limit_exceeded(
-                env,
-                proposal.id,
-                fee_estimate.total_fee,
-                proposal.gas_limit,
-            );
-            return Err(VaultError::GasLimitExceeded);
+        let mut hooks = storage::get_execution_hooks(&env);
+        let mut found_idx: Option<u32> = None;
+        for i in 0..hooks.len() {
+            let existing = hooks.get(i).unwrap();
+            if existing.hook == hook && existing.stage == stage {
+                found_idx = Some(i);
+                break;
+            }
         }
 
-        // Calculate fee for this transaction
-        let fee_amount = Self::collect_and_distribute_fee(
+        let idx = found_idx.ok_or(VaultError::SignerNotFound)?;
+        hooks.remove(idx);
+        storage::set_execution_hooks(&env, &hooks);
+        storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateHooks,
+            &admin,
+            0,
+            (Symbol::new(&env, "typed_remove"), &hook, stage.clone()),
+        );
+        if hooks::is_lifecycle(&stage) {
+            events::emit_lifecycle_hook_removed(&env, &hook, &stage);
+        } else {
+            events::emit_hook_removed(&env, &hook, stage == HookStage::PreExecute);
+        }
+        Ok(())
+    }
+
+    /// Return registered typed hooks for all stages.
+    pub fn get_execution_hooks(env: Env) -> Vec<ExecutionHook> {
+        storage::get_execution_hooks(&env)
+    }
+
+    /// Return the denial that blocked the last execution attempt, if any.
+    ///
+    /// Cleared once the proposal passes its pre-execution hooks.
+    pub fn get_hook_denial(env: Env, proposal_id: u64) -> Option<HookDenial> {
+        storage::get_hook_denial(&env, proposal_id)
+    }
+
+    fn hook_context(proposal: &Proposal) -> HookContext {
+        HookContext {
+            proposal_id: proposal.id,
+            proposer: proposal.proposer.clone(),
+            recipient: proposal.recipient.clone(),
+            token: proposal.token.clone(),
+            amount: proposal.amount,
+            memo: proposal.memo.clone(),
+            tags: proposal.tags.clone(),
+        }
+    }
+
+    fn lifecycle_event(
+        env: &Env,
+        stage: HookStage,
+        subject_id: u64,
+        action: &str,
+        actor: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> LifecycleEvent {
+        LifecycleEvent {
+            stage,
+            subject_id,
+            action: Symbol::new(env, action),
+            actor: actor.clone(),
+            token: token.clone(),
+            amount,
+            tags: Vec::new(env),
+            ledger: env.ledger().sequence() as u64,
+        }
+    }
+
+    fn proposal_lifecycle_event(
+        env: &Env,
+        stage: HookStage,
+        action: &str,
+        proposal: &Proposal,
+        actor: &Address,
+    ) -> LifecycleEvent {
+        let mut event = Self::lifecycle_event(
             env,
-            &proposal.proposer,
+            stage,
+            proposal.id,
+            action,
+            actor,
             &proposal.token,
             proposal.amount,
-        )?;
+        );
+        event.tags = proposal.tags.clone();
+        event
+    }
 
-        // Check vault balance (account for insurance amount and fee)
-        let balance = token::balance(env, &proposal.token);
-        let total_required = proposal.amount + proposal.insurance_amount + fee_amount;
-        if balance < total_required {
-            return Err(VaultError::InsufficientBalance);
-        }
+    /// Notify typed hooks subscribed to `event.stage`.
+    ///
+    /// Notifications are always fail-open whatever the hook's failure
+    /// policy: a failing hook is reported by event and never blocks the
+    /// triggering call. Only pre-execution hooks can stop an action.
+    fn notify_lifecycle_hooks(env: &Env, event: LifecycleEvent) {
+        for entry in storage::get_execution_hooks(env).iter() {
+            if entry.stage != event.stage
+                || !hooks::matches_filter(&entry.filter, &event.token, event.amount, &event.tags)
+            {
+                continue;
+            }
 
-        // Execute transfer (deduct protocol fee from transfer amount)
-        let transfer_amount = proposal.amount.saturating_sub(fee_amount);
-        if token::try_transfer(env, &proposal.token, &proposal.recipient, transfer_amount).is_err()
-        {
-            return Err(VaultError::TransferFailed);
+            let client = hooks::ExecutionHookClient::new(env, &entry.hook);
+            match client.try_on_lifecycle(&event) {
+                Ok(Ok(())) => events::emit_lifecycle_hook_notified(env, &entry.hook, &event),
+                _ => events::emit_lifecycle_hook_failed(env, &entry.hook, &event),
+            }
         }
+    }
 
-        // Return insurance to proposer on success
-        if proposal.insurance_amount > 0 {
-            token::transfer(
-                env,
-                &proposal.token,
-                &proposal.proposer,
-                proposal.insurance_amount,
-            );
-            events::emit_insurance_returned(
-                env,
-                proposal.id,
-                &proposal.proposer,
-                proposal.insurance_amount,
-            );
+    fn matching_hook_count(env: &Env, ctx: &HookContext, stage: HookStage) -> u32 {
+        let mut count = 0;
+        for entry in storage::get_execution_hooks(env).iter() {
+            if entry.stage == stage
+                && hooks::matches_filter(&entry.filter, &ctx.token, ctx.amount, &ctx.tags)
+            {
+                count += 1;
+            }
         }
+        count
+    }
 
-        // Refund stake on successful execution
-        if proposal.stake_amount > 0 {
-            if let Some(mut stake_record) = storage::get_stake_record(env, proposal.id) {
-                if !stake_record.refunded && !stake_record.slashed {
-                    token::transfer(
-                        env,
-                        &proposal.token,
-                        &proposal.proposer,
-                        proposal.stake_amount,
-                    );
-
-                    let current_ledger = env.ledger().sequence() as u64;
-                    stake_record.refunded = true;
-                    stake_record.released_at = current_ledger;
-                    storage::set_stake_record(env, &stake_record);
+    /// Run matching typed pre-execution hooks; return the first denial.
+    ///
+    /// Hook events are emitted only when `emit` is set, so preflight can ask
+    /// for the decision silently.
+    fn run_pre_execution_hooks(env: &Env, ctx: &HookContext, emit: bool) -> Option<HookDenial> {
+        for entry in storage::get_execution_hooks(env).iter() {
+            if entry.stage != HookStage::PreExecute
+                || !hooks::matches_filter(&entry.filter, &ctx.token, ctx.amount, &ctx.tags)
+            {
+                continue;
+            }
 
-                    events::emit_stake_refunded(
-                        env,
-                        proposal.id,
-                        &proposal.proposer,
-                        proposal.stake_amount,
-                    );
+            let client = hooks::ExecutionHookClient::new(env, &entry.hook);
+            let (reason_code, hook_failed) = match client.try_before_execute(ctx) {
+                Ok(Ok(decision)) => {
+                    if emit {
+                        events::emit_hook_executed(env, &entry.hook, ctx.proposal_id, true);
+                    }
+                    if decision.allow {
+                        continue;
+                    }
+                    (decision.reason_code, false)
                 }
-            }
+                _ => {
+                    if entry.failure_policy == HookFailurePolicy::FailOpen {
+                        if emit {
+                            events::emit_hook_failed(env, &entry.hook, ctx.proposal_id, true);
+                        }
+                        continue;
+                    }
+                    (0, true)
+                }
+            };
+
+            return Some(HookDenial {
+                hook: entry.hook,
+                reason_code,
+                hook_failed,
+                denied_at: env.ledger().sequence() as u64,
+            });
         }
+        None
+    }
 
-        // Record gas used
-        proposal.gas_used = fee_estimate.total_fee;
+    /// Notify matching typed post-execution hooks.
+    ///
+    /// A failing `FailClosed` hook reverts the execution.
+    fn run_post_execution_hooks(env: &Env, ctx: &HookContext) -> Result<(), VaultError> {
+        for entry in storage::get_execution_hooks(env).iter() {
+            if entry.stage != HookStage::PostExecute
+                || !hooks::matches_filter(&entry.filter, &ctx.token, ctx.amount, &ctx.tags)
+            {
+                continue;
+            }
 
+            let client = hooks::ExecutionHookClient::new(env, &entry.hook);
+            match client.try_after_execute(ctx) {
+                Ok(Ok(())) => events::emit_hook_executed(env, &entry.hook, ctx.proposal_id, false),
+                _ if entry.failure_policy == HookFailurePolicy::FailOpen => {
+                    events::emit_hook_failed(env, &entry.hook, ctx.proposal_id, false);
+                }
+                _ => return Err(VaultError::ConditionsNotMet),
+            }
+        }
         Ok(())
     }
 
-    // ── Staking view functions ────────────────────────────────────────────────
+    fn call_hook(env: &Env, hook: &Address, proposal_id: u64, is_pre: bool) {
+        let _ = env.invoke_contract::<()>(
+            hook,
+            &Symbol::new(
+                env,
+                if is_pre {
+                    "pre_execute"
+                } else {
+                    "post_execute"
+                },
+            ),
+            (proposal_id,).into_val(env),
+        );
 
-    /// Get the current staking configuration.
-    ///
-    /// Returns the full [`StakingConfig`] so frontends and SDKs can read all
-    /// staking parameters (enabled flag, stake basis points, slash percentage,
-    /// reputation discounts, etc.) in a single call.
-    ///
-    /// This is a read-only view function — no state mutations, no authorization
-    /// required.
-    pub fn get_staking_config(env: Env) -> types::StakingConfig {
-        storage::extend_instance_ttl(&env);
-        storage::get_staking_config(&env)
+        events::emit_hook_executed(env, hook, proposal_id, is_pre);
     }
 
-    /// Get the stake record for a specific proposal.
-    ///
-    /// A stake record is created when a proposal is submitted and staking is
-    /// required for that amount.  It tracks whether the locked tokens have been
-    /// refunded (on success / proposer cancel) or slashed (on admin rejection).
-    ///
-    /// Returns `None` when:
-    /// * Staking was disabled at proposal creation time.
-    /// * The proposal amount was below `StakingConfig.min_amount`.
-    /// * The proposal was created via `batch_propose_transfers` (batch proposals
-    ///   never require individual stakes).
-    ///
-    /// # Arguments
-    /// * `proposal_id` — ID of the proposal whose stake record to retrieve.
-    pub fn get_stake_record(env: Env, proposal_id: u64) -> Option<types::StakeRecord> {
-        storage::extend_instance_ttl(&env);
-        storage::get_stake_record(&env, proposal_id)
+    pub fn get_swap_result(env: Env, proposal_id: u64) -> Option<SwapResult> {
+        storage::get_swap_result(&env, proposal_id)
     }
 
-    /// Get the current accumulated balance of the slashed-stake pool for a token.
-    ///
-    /// When an admin rejects a proposal, the slashed portion of the proposer's
-    /// stake flows into this pool.  Admins can drain it via [`withdraw_stake_pool`].
-    ///
-    /// # Arguments
-    /// * `token_addr` — Token contract address to query.
-    pub fn get_stake_pool_balance(env: Env, token_addr: Address) -> i128 {
-        storage::get_stake_pool(&env, &token_addr)
+    /// Get the tracked liquidity position for a pool (LP token).
+    pub fn get_lp_position(env: Env, lp_token: Address) -> Option<LpPosition> {
+        storage::get_lp_position(&env, &lp_token)
     }
 
-    fn calculate_execution_fee(env: &Env, proposal: &Proposal) -> ExecutionFeeEstimate {
-        let gas_cfg = storage::get_gas_config(env);
-        let mut operation_count: u32 = 1; // Core transfer step.
-        operation_count = operation_count.saturating_add(proposal.conditions.len());
-        if proposal.insurance_amount > 0 {
-            operation_count = operation_count.saturating_add(1);
-        }
-        if proposal.is_swap {
-            operation_count = operation_count.saturating_add(1);
+    /// Get all tracked liquidity positions, including fully exited ones.
+    pub fn get_lp_positions(env: Env) -> Vec<LpPosition> {
+        let mut positions = Vec::new(&env);
+        for lp_token in storage::get_lp_positions(&env).iter() {
+            if let Some(position) = storage::get_lp_position(&env, &lp_token) {
+                positions.push_back(position);
+            }
         }
+        positions
+    }
 
-        let resource_fee = gas_cfg
-            .condition_cost
-            .saturating_mul(operation_count as u64);
-        let total_fee = gas_cfg.base_cost.saturating_add(resource_fee);
+    /// Get the tracked position in a farm.
+    pub fn get_farm_position(env: Env, farm: Address) -> Option<FarmPosition> {
+        storage::get_farm_position(&env, &farm)
+    }
 
-        ExecutionFeeEstimate {
-            base_fee: gas_cfg.base_cost,
-            resource_fee,
-            total_fee,
-            operation_count,
+    /// Get all tracked farm positions.
+    pub fn get_farm_positions(env: Env) -> Vec<FarmPosition> {
+        let mut positions = Vec::new(&env);
+        for farm in storage::get_farm_positions(&env).iter() {
+            if let Some(position) = storage::get_farm_position(&env, &farm) {
+                positions.push_back(position);
+            }
         }
+        positions
     }
 
-    fn persist_execution_fee_estimate(env: &Env, proposal: &Proposal) -> ExecutionFeeEstimate {
-        let estimate = Self::calculate_execution_fee(env, proposal);
-        storage::set_execution_fee_estimate(env, proposal.id, &estimate);
-        events::emit_execution_fee_estimated(
-            env,
-            proposal.id,
-            estimate.base_fee,
-            estimate.resource_fee,
-            estimate.total_fee,
-        );
-        estimate
-    }
+    // ========================================================================
+    // DCA Orders (Issue: feature/dca-orders)
+    // ========================================================================
 
-    /// Create a new proposal template
-    ///
-    /// Templates allow pre-approved proposal configurations to be stored on-chain,
-    /// enabling quick creation of common proposals like monthly payroll.
-    ///
-    /// # Arguments
-    /// * `creator` - Address creating the template (must be Admin)
-    /// * `name` - Human-readable template name (must be unique)
-    /// * `description` - Template description
-    /// * `recipient` - Default recipient address
-    /// * `token` - Token contract address
-    /// * `amount` - Default amount
-    /// * `memo` - Default memo/description
-    /// * `min_amount` - Minimum allowed amount (0 = no minimum)
-    /// * `max_amount` - Maximum allowed amount (0 = no maximum)
+    /// Execute the next slice of an active DCA order.
     ///
-    /// # Returns
-    /// The unique ID of the newly created template
-    #[allow(clippy::too_many_arguments)]
-    pub fn create_template(
-        env: Env,
-        creator: Address,
-        name: Symbol,
-        description: Symbol,
-        recipient: Address,
-        token: Address,
-        amount: i128,
-        memo: Symbol,
-        min_amount: i128,
-        max_amount: i128,
-    ) -> Result<u64, VaultError> {
-        creator.require_auth();
-
-        // Check role - only Admin can create templates
-        let role = storage::get_role(&env, &creator);
-        if role != Role::Admin {
-            return Err(VaultError::InsufficientRole);
+    /// Can be called by anyone (keeper/bot) once `next_slice_ledger` is reached.
+    /// The final slice swaps whatever remains of the order total.
+    /// Returns `None` without swapping when the call trips the breaker of
+    /// either token, so the trip stays latched.
+    pub fn execute_dca_slice(env: Env, order_id: u64) -> Result<Option<SwapResult>, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
+        let mut order = storage::get_dca_order(&env, order_id)?;
+        if order.status != DcaStatus::Active {
+            return Err(VaultError::DexError);
         }
 
-        // Check if template name already exists
-        if storage::template_name_exists(&env, &name) {
-            return Err(VaultError::AlreadyInitialized); // Reusing error for duplicate name
+        let current_ledger = env.ledger().sequence() as u64;
+        if current_ledger < order.next_slice_ledger {
+            return Err(VaultError::TimelockNotExpired);
         }
 
-        // Validate parameters
-        if !Self::validate_template_params(env.clone(), amount, min_amount, max_amount) {
-            return Err(VaultError::TemplateValidationFailed);
+        if Self::circuit_breaker_tripped(&env, &order.token_in)?
+            || Self::circuit_breaker_tripped(&env, &order.token_out)?
+        {
+            return Ok(None);
         }
 
-        // Create template
-        let template_id = storage::increment_template_id(&env);
-        let current_ledger = env.ledger().sequence() as u64;
-
-        let template = ProposalTemplate {
-            id: template_id,
-            name: name.clone(),
-            description,
-            recipient,
-            token,
-            amount,
-            memo,
-            creator: creator.clone(),
-            version: 1,
-            is_active: true,
-            created_at: current_ledger,
-            updated_at: current_ledger,
-            min_amount,
-            max_amount,
+        let dex_config = storage::get_dex_config(&env).ok_or(VaultError::DexError)?;
+        let slice_amount = if order.slices_executed + 1 == order.slices {
+            order.remaining_amount
+        } else {
+            order.total_amount / order.slices as i128
         };
+        let result = Self::swap_exact_in(
+            &env,
+            &dex_config,
+            order_id,
+            &order.dex,
+            &order.token_in,
+            &order.token_out,
+            slice_amount,
+            order.min_amount_out_per_slice,
+        )?;
 
-        storage::set_template(&env, &template);
-        storage::set_template_name_mapping(&env, &name, template_id);
-        storage::extend_instance_ttl(&env);
+        order.slices_executed += 1;
+        order.amount_in_filled += result.amount_in;
+        order.amount_out_filled += result.amount_out;
+        order.remaining_amount = (order.total_amount - order.amount_in_filled).max(0);
+        order.average_price = order
+            .amount_out_filled
+            .checked_mul(10_000_000)
+            .ok_or(VaultError::DexError)?
+            / order.amount_in_filled.max(1);
+        order.next_slice_ledger = current_ledger + order.interval;
+        events::emit_dca_slice_executed(
+            &env,
+            order_id,
+            order.slices_executed,
+            result.amount_in,
+            result.amount_out,
+        );
 
-        Ok(template_id)
+        if order.slices_executed >= order.slices || order.remaining_amount == 0 {
+            order.status = DcaStatus::Completed;
+            storage::remove_active_dca_order(&env, order_id);
+            events::emit_dca_order_completed(
+                &env,
+                order_id,
+                order.amount_in_filled,
+                order.amount_out_filled,
+            );
+        }
+
+        storage::set_dca_order(&env, &order);
+        storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::ExecuteSwap,
+            &env.current_contract_address(),
+            order_id,
+            (Symbol::new(&env, "dca_slice"), result.clone()),
+        );
+        Ok(Some(result))
     }
 
-    /// Set template active status
-    ///
-    /// Allows admins to activate or deactivate templates.
+    /// Cancel an active DCA order. Unswapped token_in simply stays in the vault.
     ///
-    /// # Arguments
-    /// * `admin` - Address performing the action (must be Admin)
-    /// * `template_id` - ID of the template to modify
-    /// * `is_active` - New active status
-    pub fn set_template_status(
-        env: Env,
-        admin: Address,
-        template_id: u64,
-        is_active: bool,
-    ) -> Result<(), VaultError> {
+    /// Access: Admin role required, confirmed by the admin council once its
+    /// threshold is above 1 (see `set_admin_council`).
+    pub fn cancel_dca_order(env: Env, admin: Address, order_id: u64) -> Result<(), VaultError> {
         admin.require_auth();
-
-        // Check role - only Admin can modify templates
-        let role = storage::get_role(&env, &admin);
-        if role != Role::Admin {
+        if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::InsufficientRole);
         }
+        Self::require_council(&env, &admin, "cancel_dca_order", (order_id,).into_val(&env))?;
 
-        // Get and update template
-        let mut template = storage::get_template(&env, template_id)?;
-        template.is_active = is_active;
-        template.updated_at = env.ledger().sequence() as u64;
-        template.version += 1;
-
-        storage::set_template(&env, &template);
-        storage::extend_instance_ttl(&env);
+        let mut order = storage::get_dca_order(&env, order_id)?;
+        if order.status != DcaStatus::Active {
+            return Err(VaultError::DexError);
+        }
 
+        order.status = DcaStatus::Cancelled;
+        storage::set_dca_order(&env, &order);
+        storage::remove_active_dca_order(&env, order_id);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::CancelOrder,
+            &admin,
+            order_id,
+            (Symbol::new(&env, "dca"), order.remaining_amount),
+        );
+        events::emit_dca_order_cancelled(&env, order_id, &admin, order.remaining_amount);
         Ok(())
     }
 
-    /// Get a template by ID
-    ///
-    /// # Arguments
-    /// * `template_id` - ID of the template to retrieve
-    ///
-    /// # Returns
-    /// The template data
-    pub fn get_template(env: Env, template_id: u64) -> Result<ProposalTemplate, VaultError> {
-        storage::get_template(&env, template_id)
+    /// Get a DCA order by ID, including fill progress.
+    pub fn get_dca_order(env: Env, order_id: u64) -> Result<DcaOrder, VaultError> {
+        storage::get_dca_order(&env, order_id)
     }
 
-    /// Get template ID by name
-    ///
-    /// # Arguments
-    /// * `name` - Name of the template to look up
-    ///
-    /// # Returns
-    /// The template ID if found
-    pub fn get_template_id_by_name(env: Env, name: Symbol) -> Option<u64> {
-        storage::get_template_id_by_name(&env, &name)
+    /// IDs of DCA orders that still have slices to execute.
+    pub fn get_active_dca_orders(env: Env) -> Vec<u64> {
+        storage::get_active_dca_orders(&env)
     }
 
-    /// Create a proposal from a template
-    ///
-    /// Creates a new proposal using a pre-configured template with optional overrides.
-    ///
-    /// # Arguments
-    /// * `proposer` - Address creating the proposal
-    /// * `template_id` - ID of the template to use
-    /// * `overrides` - Optional overrides for template defaults
+    // ========================================================================
+    // Limit Orders (Issue: feature/limit-orders)
+    // ========================================================================
+
+    /// Propose a standing limit order: a `Swap` that keepers may execute once
+    /// `trigger` (`PriceAbove` / `PriceBelow`) holds, until `expires_at`.
     ///
-    /// # Returns
-    /// The unique ID of the newly created proposal
-    pub fn create_from_template(
+    /// The backing swap proposal goes through normal approval and expires at
+    /// `expires_at`. Its `min_amount_out` acts as the limit price floor.
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_limit_order(
         env: Env,
         proposer: Address,
-        template_id: u64,
-        overrides: TemplateOverrides,
+        swap_op: SwapProposal,
+        trigger: Condition,
+        expires_at: u64,
+        max_attempts: u32,
+        priority: Priority,
+        insurance_amount: i128,
     ) -> Result<u64, VaultError> {
         proposer.require_auth();
 
-        // Get and validate template
-        let template = storage::get_template(&env, template_id)?;
-
-        if !template.is_active {
-            return Err(VaultError::TemplateInactive);
-        }
-
-        // Check role
-        let role = storage::get_role(&env, &proposer);
-        if role != Role::Treasurer && role != Role::Admin {
-            return Err(VaultError::InsufficientRole);
+        if !matches!(swap_op, SwapProposal::Swap(..)) {
+            return Err(VaultError::DexError);
         }
-
-        // Apply overrides
-        let recipient = if overrides.override_recipient {
-            overrides.recipient.clone()
-        } else {
-            template.recipient.clone()
-        };
-        let amount = if overrides.override_amount {
-            overrides.amount
-        } else {
-            template.amount
-        };
-        let memo = if overrides.override_memo {
-            overrides.memo.clone()
-        } else {
-            template.memo.clone()
-        };
-        let priority = if overrides.override_priority {
-            overrides.priority
-        } else {
-            Priority::Normal
-        };
-
-        // Validate amount is within template bounds
-        if template.min_amount > 0 && amount < template.min_amount {
-            return Err(VaultError::TemplateValidationFailed);
+        if !matches!(
+            trigger,
+            Condition::PriceAbove(..) | Condition::PriceBelow(..)
+        ) {
+            return Err(VaultError::ConditionsNotMet);
         }
-        if template.max_amount > 0 && amount > template.max_amount {
-            return Err(VaultError::TemplateValidationFailed);
+        if max_attempts == 0 || expires_at <= env.ledger().sequence() as u64 {
+            return Err(VaultError::InvalidAmount);
         }
 
-        // Load config for validation
-        let config = storage::get_config(&env)?;
+        let order_id = Self::create_swap_proposal(
+            &env,
+            &proposer,
+            &swap_op,
+            priority,
+            Vec::from_array(&env, [trigger.clone()]),
+            ConditionLogic::And,
+            insurance_amount,
+            Some(expires_at),
+        )?;
 
-        // Velocity limit check
-        if !storage::check_and_update_velocity(&env, &proposer, &config.velocity_limit) {
-            return Err(VaultError::VelocityLimitExceeded);
+        storage::set_limit_order(
+            &env,
+            &LimitOrder {
+                id: order_id,
+                trigger,
+                expires_at,
+                max_attempts,
+                attempts: 0,
+                status: LimitOrderStatus::Open,
+                filled_at: 0,
+            },
+        );
+        storage::add_open_limit_order(&env, order_id);
+        events::emit_limit_order_created(&env, order_id, expires_at, max_attempts);
+        Ok(order_id)
+    }
+
+    /// Try to fill an open limit order.
+    ///
+    /// Can be called by anyone (keeper/bot). Fails with `ConditionsNotMet`
+    /// while the trigger does not hold. Once it holds, a fill that is rejected
+    /// by the DEX or the oracle bounds counts as an attempt instead of erroring.
+    /// An unavailable oracle price or router quote fails the call and does not
+    /// count. Returns the order status after the call.
+    pub fn execute_limit_order(env: Env, order_id: u64) -> Result<LimitOrderStatus, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
+        let mut order =
+            storage::get_limit_order(&env, order_id).ok_or(VaultError::ProposalNotFound)?;
+        if order.status != LimitOrderStatus::Open {
+            return Err(VaultError::DexError);
         }
 
-        // Validate amount
-        if amount <= 0 {
-            return Err(VaultError::InvalidAmount);
+        let mut proposal = storage::get_proposal(&env, order_id)?;
+        let current_ledger = env.ledger().sequence() as u64;
+        if current_ledger > order.expires_at {
+            if proposal.status == ProposalStatus::Pending
+                || proposal.status == ProposalStatus::Approved
+            {
+                proposal.status = ProposalStatus::Expired;
+                storage::set_proposal(&env, &proposal);
+                storage::metrics_on_expiry(&env);
+                events::emit_proposal_expired(&env, order_id, order.expires_at);
+            }
+            order.status = LimitOrderStatus::Expired;
+            storage::set_limit_order(&env, &order);
+            storage::remove_open_limit_order(&env, order_id);
+            storage::create_audit_entry(
+                &env,
+                AuditAction::ExecuteSwap,
+                &env.current_contract_address(),
+                order_id,
+                (
+                    Symbol::new(&env, "limit"),
+                    order.status.clone(),
+                    order.attempts,
+                ),
+            );
+            events::emit_limit_order_closed(&env, order_id, &order.status);
+            return Ok(order.status);
         }
 
-        // Check per-proposal spending limit
-        if amount > config.spending_limit {
-            return Err(VaultError::ExceedsProposalLimit);
+        if proposal.status != ProposalStatus::Approved {
+            return Err(VaultError::ProposalNotApproved);
         }
-
-        // Check daily aggregate limit
-        let today = storage::get_day_number(&env);
-        let spent_today = storage::get_daily_spent(&env, today);
-        if spent_today + amount > config.daily_limit {
-            return Err(VaultError::ExceedsDailyLimit);
+        if proposal.unlock_ledger > 0 && current_ledger < proposal.unlock_ledger {
+            return Err(VaultError::TimelockNotExpired);
         }
+        Self::evaluate_conditions(&env, &proposal).map_err(|_| VaultError::ConditionsNotMet)?;
 
-        // Check weekly aggregate limit
-        let week = storage::get_week_number(&env);
-        let spent_week = storage::get_weekly_spent(&env, week);
-        if spent_week + amount > config.weekly_limit {
-            return Err(VaultError::ExceedsWeeklyLimit);
+        let dex_config = storage::get_dex_config(&env).ok_or(VaultError::DexError)?;
+        let (dex, token_in, token_out, amount_in, min_amount_out) =
+            match storage::get_swap_proposal(&env, order_id) {
+                Some(SwapProposal::Swap(dex, token_in, token_out, amount_in, min_amount_out)) => {
+                    (dex, token_in, token_out, amount_in, min_amount_out)
+                }
+                _ => return Err(VaultError::DexError),
+            };
+        // A trip detected now leaves the order open; returning Ok keeps the latch
+        if Self::circuit_breaker_tripped(&env, &token_in)?
+            || Self::circuit_breaker_tripped(&env, &token_out)?
+        {
+            return Ok(order.status);
+        }
+        // Oracle and router outages say nothing about the market, so they fail
+        // the call instead of spending one of the order's attempts.
+        if Self::get_asset_price(&env, token_in.clone())? <= 0
+            || Self::get_asset_price(&env, token_out.clone())? <= 0
+        {
+            return Err(VaultError::DexError);
+        }
+        let path = Vec::from_array(&env, [token_in.clone(), token_out.clone()]);
+        if !matches!(
+            dex::RouterClient::new(&env, &dex).try_router_get_amounts_out(&amount_in, &path),
+            Ok(Ok(_))
+        ) {
+            return Err(VaultError::DexError);
         }
 
-        // Reserve spending
-        storage::add_daily_spent(&env, today, amount);
-        storage::add_weekly_spent(&env, week, amount);
-
+        let in_before = token::balance(&env, &token_in);
+        match Self::swap_exact_in(
+            &env,
+            &dex_config,
+            order_id,
+            &dex,
+            &token_in,
+            &token_out,
+            amount_in,
+            min_amount_out,
+        ) {
+            Ok(swap_result) => {
+                storage::set_swap_result(&env, order_id, &swap_result);
+                proposal.status = ProposalStatus::Executed;
+                storage::set_proposal(&env, &proposal);
+                order.status = LimitOrderStatus::Filled;
+                order.filled_at = current_ledger;
+
+                events::emit_proposal_executed(
+                    &env,
+                    order_id,
+                    &env.current_contract_address(),
+                    &env.current_contract_address(),
+                    &env.current_contract_address(),
+                    0,
+                    current_ledger,
+                );
+                Self::update_reputation_on_execution(&env, &proposal);
+                let execution_time = current_ledger.saturating_sub(proposal.created_at);
+                storage::metrics_on_execution(&env, proposal.gas_used, execution_time);
+            }
+            // A failure after funds moved cannot be recorded as an attempt
+            // without keeping the partial swap, so the whole call reverts.
+            Err(e) if token::balance(&env, &token_in) != in_before => return Err(e),
+            Err(_) => {
+                order.attempts += 1;
+                events::emit_limit_order_attempt_failed(
+                    &env,
+                    order_id,
+                    order.attempts,
+                    order.max_attempts,
+                );
+                if order.attempts >= order.max_attempts {
+                    order.status = LimitOrderStatus::Exhausted;
+                }
+            }
+        }
+
+        if order.status != LimitOrderStatus::Open {
+            storage::remove_open_limit_order(&env, order_id);
+            events::emit_limit_order_closed(&env, order_id, &order.status);
+        }
+        storage::set_limit_order(&env, &order);
+        storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::ExecuteSwap,
+            &env.current_contract_address(),
+            order_id,
+            (
+                Symbol::new(&env, "limit"),
+                order.status.clone(),
+                order.attempts,
+            ),
+        );
+        Ok(order.status)
+    }
+
+    /// Cancel an open limit order and its backing swap proposal.
+    ///
+    /// Access: Admin role required.
+    pub fn cancel_limit_order(env: Env, admin: Address, order_id: u64) -> Result<(), VaultError> {
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+        Self::require_council(
+            &env,
+            &admin,
+            "cancel_limit_order",
+            (order_id,).into_val(&env),
+        )?;
+
+        let mut order =
+            storage::get_limit_order(&env, order_id).ok_or(VaultError::ProposalNotFound)?;
+        if order.status != LimitOrderStatus::Open {
+            return Err(VaultError::DexError);
+        }
+
+        let mut proposal = storage::get_proposal(&env, order_id)?;
+        if proposal.status == ProposalStatus::Pending || proposal.status == ProposalStatus::Approved
+        {
+            proposal.status = ProposalStatus::Cancelled;
+            storage::set_proposal(&env, &proposal);
+            storage::remove_from_priority_queue(&env, proposal.priority.clone() as u32, order_id);
+        }
+
+        order.status = LimitOrderStatus::Cancelled;
+        storage::set_limit_order(&env, &order);
+        storage::remove_open_limit_order(&env, order_id);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::CancelOrder,
+            &admin,
+            order_id,
+            (Symbol::new(&env, "limit"), order.attempts),
+        );
+        events::emit_limit_order_closed(&env, order_id, &order.status);
+        Ok(())
+    }
+
+    /// Get a limit order by ID.
+    pub fn get_limit_order(env: Env, order_id: u64) -> Result<LimitOrder, VaultError> {
+        storage::get_limit_order(&env, order_id).ok_or(VaultError::ProposalNotFound)
+    }
+
+    /// IDs of limit orders that are still open.
+    pub fn get_open_limit_orders(env: Env) -> Vec<u64> {
+        storage::get_open_limit_orders(&env)
+    }
+    // ========================================================================
+    // Retry Helpers (private)
+    // ========================================================================
+
+    /// Attempt the actual transfer for a proposal. Separated from execute_proposal
+    /// so that retryable failures can be caught and handled.
+    fn try_execute_transfer(
+        env: &Env,
+        _executor: &Address,
+        proposal: &mut Proposal,
+        _current_ledger: u64,
+    ) -> Result<(), VaultError> {
+        // Evaluate execution conditions (if any) before balance check
+        if !proposal.conditions.is_empty() {
+            Self::evaluate_conditions(env, proposal)?;
+        }
+
+        // Gas limit check
+        let fee_estimate = Self::calculate_execution_fee(env, proposal);
+        if proposal.gas_limit > 0 && fee_estimate.total_fee > proposal.gas_limit {
+            events::emit_gas_limit_exceeded(
+                env,
+                proposal.id,
+                fee_estimate.total_fee,
+                proposal.gas_limit,
+            );
+            return Err(VaultError::GasLimitExceeded);
+        }
+
+        // Calculate fee for this transaction
+        let fee_amount = Self::collect_and_distribute_fee(
+            env,
+            &proposal.proposer,
+            &proposal.token,
+            proposal.amount,
+        )?;
+
+        // Check vault balance (account for insurance amount and fee)
+        let balance = token::balance(env, &proposal.token);
+        let total_required = proposal.amount + proposal.insurance_amount + fee_amount;
+        if balance < total_required {
+            return Err(VaultError::InsufficientBalance);
+        }
+
+        // Execute transfer (deduct protocol fee from transfer amount)
+        let transfer_amount = proposal.amount.saturating_sub(fee_amount);
+        if token::try_transfer(env, &proposal.token, &proposal.recipient, transfer_amount).is_err()
+        {
+            return Err(VaultError::TransferFailed);
+        }
+
+        // Return insurance to proposer on success
+        if proposal.insurance_amount > 0 {
+            token::transfer(
+                env,
+                &proposal.token,
+                &proposal.proposer,
+                proposal.insurance_amount,
+            );
+            events::emit_insurance_returned(
+                env,
+                proposal.id,
+                &proposal.proposer,
+                proposal.insurance_amount,
+            );
+        }
+
+        // Refund stake on successful execution
+        if proposal.stake_amount > 0 {
+            if let Some(mut stake_record) = storage::get_stake_record(env, proposal.id) {
+                if !stake_record.refunded && !stake_record.slashed {
+                    token::transfer(
+                        env,
+                        &proposal.token,
+                        &proposal.proposer,
+                        proposal.stake_amount,
+                    );
+
+                    let current_ledger = env.ledger().sequence() as u64;
+                    stake_record.refunded = true;
+                    stake_record.released_at = current_ledger;
+                    storage::set_stake_record(env, &stake_record);
+
+                    events::emit_stake_refunded(
+                        env,
+                        proposal.id,
+                        &proposal.proposer,
+                        proposal.stake_amount,
+                    );
+                }
+            }
+        }
+
+        // Record gas used
+        proposal.gas_used = fee_estimate.total_fee;
+
+        Ok(())
+    }
+
+    // ── Staking view functions ────────────────────────────────────────────────
+
+    /// Get the current staking configuration.
+    ///
+    /// Returns the full [`StakingConfig`] so frontends and SDKs can read all
+    /// staking parameters (enabled flag, stake basis points, slash percentage,
+    /// reputation discounts, etc.) in a single call.
+    ///
+    /// This is a read-only view function — no state mutations, no authorization
+    /// required.
+    pub fn get_staking_config(env: Env) -> types::StakingConfig {
+        storage::extend_instance_ttl(&env);
+        storage::get_staking_config(&env)
+    }
+
+    /// Get the stake record for a specific proposal.
+    ///
+    /// A stake record is created when a proposal is submitted and staking is
+    /// required for that amount.  It tracks whether the locked tokens have been
+    /// refunded (on success / proposer cancel) or slashed (on admin rejection).
+    ///
+    /// Returns `None` when:
+    /// * Staking was disabled at proposal creation time.
+    /// * The proposal amount was below `StakingConfig.min_amount`.
+    /// * The proposal was created via `batch_propose_transfers` (batch proposals
+    ///   never require individual stakes).
+    ///
+    /// # Arguments
+    /// * `proposal_id` — ID of the proposal whose stake record to retrieve.
+    pub fn get_stake_record(env: Env, proposal_id: u64) -> Option<types::StakeRecord> {
+        storage::extend_instance_ttl(&env);
+        storage::get_stake_record(&env, proposal_id)
+    }
+
+    /// Get the current accumulated balance of the slashed-stake pool for a token.
+    ///
+    /// When an admin rejects a proposal, the slashed portion of the proposer's
+    /// stake flows into this pool.  Admins can drain it via [`withdraw_stake_pool`].
+    ///
+    /// # Arguments
+    /// * `token_addr` — Token contract address to query.
+    pub fn get_stake_pool_balance(env: Env, token_addr: Address) -> i128 {
+        storage::get_stake_pool(&env, &token_addr)
+    }
+
+    fn calculate_execution_fee(env: &Env, proposal: &Proposal) -> ExecutionFeeEstimate {
+        let gas_cfg = storage::get_gas_config(env);
+        let mut operation_count: u32 = 1; // Core transfer step.
+        operation_count =
+            operation_count.saturating_add(Self::condition_count(&proposal.conditions));
+        if proposal.insurance_amount > 0 {
+            operation_count = operation_count.saturating_add(1);
+        }
+        if proposal.is_swap {
+            operation_count = operation_count.saturating_add(1);
+        }
+
+        let resource_fee = gas_cfg
+            .condition_cost
+            .saturating_mul(operation_count as u64);
+        let total_fee = gas_cfg.base_cost.saturating_add(resource_fee);
+
+        ExecutionFeeEstimate {
+            base_fee: gas_cfg.base_cost,
+            resource_fee,
+            total_fee,
+            operation_count,
+        }
+    }
+
+    fn persist_execution_fee_estimate(env: &Env, proposal: &Proposal) -> ExecutionFeeEstimate {
+        let estimate = Self::calculate_execution_fee(env, proposal);
+        storage::set_execution_fee_estimate(env, proposal.id, &estimate);
+        events::emit_execution_fee_estimated(
+            env,
+            proposal.id,
+            estimate.base_fee,
+            estimate.resource_fee,
+            estimate.total_fee,
+        );
+        estimate
+    }
+
+    /// Create a new proposal template
+    ///
+    /// Templates allow pre-approved proposal configurations to be stored on-chain,
+    /// enabling quick creation of common proposals like monthly payroll.
+    ///
+    /// # Arguments
+    /// * `creator` - Address creating the template (must be Admin)
+    /// * `name` - Human-readable template name (must be unique)
+    /// * `description` - Template description
+    /// * `recipient` - Default recipient address
+    /// * `token` - Token contract address
+    /// * `amount` - Default amount
+    /// * `memo` - Default memo/description
+    /// * `min_amount` - Minimum allowed amount (0 = no minimum)
+    /// * `max_amount` - Maximum allowed amount (0 = no maximum)
+    ///
+    /// # Returns
+    /// The unique ID of the newly created template
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_template(
+        env: Env,
+        creator: Address,
+        name: Symbol,
+        description: Symbol,
+        recipient: Address,
+        token: Address,
+        amount: i128,
+        memo: Symbol,
+        min_amount: i128,
+        max_amount: i128,
+    ) -> Result<u64, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        creator.require_auth();
+
+        // Check role - only Admin can create templates
+        let role = storage::get_role(&env, &creator);
+        if role != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+        Self::require_council(
+            &env,
+            &creator,
+            "create_template",
+            (
+                name.clone(),
+                description.clone(),
+                recipient.clone(),
+                token.clone(),
+                amount,
+                memo.clone(),
+                min_amount,
+                max_amount,
+            )
+                .into_val(&env),
+        )?;
+
+        // Check if template name already exists
+        if storage::template_name_exists(&env, &name) {
+            return Err(VaultError::AlreadyInitialized); // Reusing error for duplicate name
+        }
+
+        // Validate parameters
+        if !Self::validate_template_params(env.clone(), amount, min_amount, max_amount) {
+            return Err(VaultError::TemplateValidationFailed);
+        }
+
+        // Create template
+        let template_id = storage::increment_template_id(&env);
+        let current_ledger = env.ledger().sequence() as u64;
+
+        let template = ProposalTemplate {
+            id: template_id,
+            name: name.clone(),
+            description,
+            recipient,
+            token,
+            amount,
+            memo,
+            creator: creator.clone(),
+            version: 1,
+            is_active: true,
+            created_at: current_ledger,
+            updated_at: current_ledger,
+            min_amount,
+            max_amount,
+        };
+
+        storage::set_template(&env, &template);
+        storage::set_template_name_mapping(&env, &name, template_id);
+        storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateTemplate,
+            &creator,
+            template_id,
+            template,
+        );
+
+        Ok(template_id)
+    }
+
+    /// Set template active status
+    ///
+    /// Allows admins to activate or deactivate templates.
+    ///
+    /// # Arguments
+    /// * `admin` - Address performing the action (must be Admin)
+    /// * `template_id` - ID of the template to modify
+    /// * `is_active` - New active status
+    pub fn set_template_status(
+        env: Env,
+        admin: Address,
+        template_id: u64,
+        is_active: bool,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+
+        // Check role - only Admin can modify templates
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_template_status",
+            (template_id, is_active).into_val(&env),
+        )?;
+
+        // Get and update template
+        let mut template = storage::get_template(&env, template_id)?;
+        template.is_active = is_active;
+        template.updated_at = env.ledger().sequence() as u64;
+        template.version += 1;
+
+        storage::set_template(&env, &template);
+        storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateTemplate,
+            &admin,
+            template_id,
+            (is_active, template.version),
+        );
+
+        Ok(())
+    }
+
+    /// Get a template by ID
+    ///
+    /// # Arguments
+    /// * `template_id` - ID of the template to retrieve
+    ///
+    /// # Returns
+    /// The template data
+    pub fn get_template(env: Env, template_id: u64) -> Result<ProposalTemplate, VaultError> {
+        storage::get_template(&env, template_id)
+    }
+
+    /// Get template ID by name
+    ///
+    /// # Arguments
+    /// * `name` - Name of the template to look up
+    ///
+    /// # Returns
+    /// The template ID if found
+    pub fn get_template_id_by_name(env: Env, name: Symbol) -> Option<u64> {
+        storage::get_template_id_by_name(&env, &name)
+    }
+
+    /// Create a proposal from a template
+    ///
+    /// Creates a new proposal using a pre-configured template with optional overrides.
+    ///
+    /// # Arguments
+    /// * `proposer` - Address creating the proposal
+    /// * `template_id` - ID of the template to use
+    /// * `overrides` - Optional overrides for template defaults
+    ///
+    /// # Returns
+    /// The unique ID of the newly created proposal
+    pub fn create_from_template(
+        env: Env,
+        proposer: Address,
+        template_id: u64,
+        overrides: TemplateOverrides,
+    ) -> Result<u64, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Proposals)?;
+        proposer.require_auth();
+
+        // Get and validate template
+        let template = storage::get_template(&env, template_id)?;
+
+        if !template.is_active {
+            return Err(VaultError::TemplateInactive);
+        }
+
+        // Apply overrides
+        let recipient = if overrides.override_recipient {
+            overrides.recipient.clone()
+        } else {
+            template.recipient.clone()
+        };
+        let amount = if overrides.override_amount {
+            overrides.amount
+        } else {
+            template.amount
+        };
+        let request = PermissionRequest::transfer(&env, &template.token, amount, &recipient);
+        if !Self::check_permission(
+            &env,
+            &proposer,
+            &types::Permission::CreateProposal,
+            Some(&request),
+        ) {
+            return Err(VaultError::InsufficientRole);
+        }
+        let memo = if overrides.override_memo {
+            overrides.memo.clone()
+        } else {
+            template.memo.clone()
+        };
+        let priority = if overrides.override_priority {
+            overrides.priority
+        } else {
+            Priority::Normal
+        };
+
+        // Validate amount is within template bounds
+        if template.min_amount > 0 && amount < template.min_amount {
+            return Err(VaultError::TemplateValidationFailed);
+        }
+        if template.max_amount > 0 && amount > template.max_amount {
+            return Err(VaultError::TemplateValidationFailed);
+        }
+
+        // Load config for validation
+        let config = storage::get_config(&env)?;
+
+        // Velocity limit check
+        if !storage::check_and_update_velocity(&env, &proposer, &config.velocity_limit) {
+            return Err(VaultError::VelocityLimitExceeded);
+        }
+
+        // Validate amount
+        if amount <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        // Check per-proposal spending limit
+        if amount > config.spending_limit {
+            return Err(VaultError::ExceedsProposalLimit);
+        }
+
+        // Check daily aggregate limit
+        let today = storage::get_day_number(&env);
+        let spent_today = storage::get_daily_spent(&env, today);
+        if spent_today + amount > config.daily_limit {
+            return Err(VaultError::ExceedsDailyLimit);
+        }
+
+        // Check weekly aggregate limit
+        let week = storage::get_week_number(&env);
+        let spent_week = storage::get_weekly_spent(&env, week);
+        if spent_week + amount > config.weekly_limit {
+            return Err(VaultError::ExceedsWeeklyLimit);
+        }
+
+        // Reserve spending
+        storage::add_daily_spent(&env, today, amount);
+        storage::add_weekly_spent(&env, week, amount);
+
         // Create proposal
         let proposal_id = storage::increment_proposal_id(&env);
         let current_ledger = env.ledger().sequence() as u64;
 
-        // Calculate expiry
-        let expires_at = if config.default_voting_deadline > 0 {
-            current_ledger + config.default_voting_deadline
-        } else {
-            current_ledger + 100000 // Default ~6 days
-        };
+        // Calculate expiry
+        let expires_at = if config.default_voting_deadline > 0 {
+            current_ledger + config.default_voting_deadline
+        } else {
+            current_ledger + 100000 // Default ~6 days
+        };
+
+        // Calculate unlock ledger for timelock
+        let unlock_ledger = if amount >= config.timelock_threshold {
+            current_ledger + config.timelock_delay
+        } else {
+            0
+        };
+
+        let proposal = Proposal {
+            id: proposal_id,
+            proposer: proposer.clone(),
+            recipient,
+            token: template.token,
+            amount,
+            memo,
+            metadata: Map::new(&env),
+            tags: Vec::new(&env),
+            approvals: Vec::new(&env),
+            abstentions: Vec::new(&env),
+            attachments: Vec::new(&env),
+            status: ProposalStatus::Pending,
+            priority,
+            conditions: Vec::new(&env),
+            condition_logic: ConditionLogic::And,
+            created_at: current_ledger,
+            expires_at,
+            unlock_ledger,
+            execution_time: None,
+            insurance_amount: 0,
+            stake_amount: 0, // Template proposals don't require stake
+            gas_limit: 0,
+            gas_used: 0,
+            snapshot_ledger: current_ledger,
+            snapshot_signers: config.signers.clone(),
+            depends_on: Vec::new(&env),
+            is_swap: false,
+            voting_deadline: 0,
+        };
+
+        storage::set_proposal(&env, &proposal);
+        Self::persist_execution_fee_estimate(&env, &proposal);
+        storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::ProposeTransfer,
+            &proposer,
+            proposal_id,
+            (
+                &proposal.recipient,
+                &proposal.token,
+                proposal.amount,
+                template_id,
+            ),
+        );
+
+        events::emit_proposal_from_template(
+            &env,
+            proposal_id,
+            template_id,
+            &template.name,
+            &proposer,
+        );
+
+        Ok(proposal_id)
+    }
+
+    /// Validate template parameters
+    ///
+    /// Helper function to validate template parameters before creation/update.
+    ///
+    /// # Arguments
+    /// * `amount` - Default amount
+    /// * `min_amount` - Minimum allowed amount
+    /// * `max_amount` - Maximum allowed amount
+    ///
+    /// # Returns
+    /// true if parameters are valid
+    pub fn validate_template_params(
+        _env: Env,
+        amount: i128,
+        min_amount: i128,
+        max_amount: i128,
+    ) -> bool {
+        // Validate amount is positive
+        if amount <= 0 {
+            return false;
+        }
+
+        // Validate bounds relationship
+        if min_amount > 0 && max_amount > 0 && min_amount > max_amount {
+            return false;
+        }
+
+        // Validate default amount is within bounds
+        if min_amount > 0 && amount < min_amount {
+            return false;
+        }
+        if max_amount > 0 && amount > max_amount {
+            return false;
+        }
+
+        true
+    }
+
+    /// Check if an error is retryable (transient failure).
+    fn is_retryable_error(err: &VaultError) -> bool {
+        matches!(
+            err,
+            VaultError::InsufficientBalance | VaultError::ConditionsNotMet
+        )
+    }
+
+    /// Schedule a retry for a failed proposal execution with exponential backoff.
+    ///
+    /// Returns Ok(()) to signal that retry was scheduled (caller should also return Ok
+    /// to persist state), or Err(MaxRetriesExceeded) if all retries used up.
+    fn schedule_retry(
+        env: &Env,
+        proposal_id: u64,
+        retry_config: &RetryConfig,
+        current_ledger: u64,
+        err: &VaultError,
+    ) -> Result<(), VaultError> {
+        let mut retry_state = storage::get_retry_state(env, proposal_id).unwrap_or(RetryState {
+            retry_count: 0,
+            next_retry_ledger: 0,
+            last_retry_ledger: 0,
+        });
+
+        retry_state.retry_count += 1;
+
+        if retry_state.retry_count > retry_config.max_retries {
+            events::emit_retries_exhausted(env, proposal_id, retry_state.retry_count);
+            return Err(VaultError::RetryError);
+        }
+
+        // Exponential backoff: initial_backoff << (retry_count - 1), capped at 7 days (120,960 ledgers)
+        let max_backoff = 17_280 * 7; // 7 days in ledgers
+        let exponent = core::cmp::min(retry_state.retry_count - 1, 30); // Prevent overflow
+        let backoff = retry_config
+            .initial_backoff_ledgers
+            .checked_shl(exponent as u32)
+            .unwrap_or(max_backoff)
+            .min(max_backoff);
+
+        retry_state.next_retry_ledger = current_ledger + backoff;
+        retry_state.last_retry_ledger = current_ledger;
+
+        storage::set_retry_state(env, proposal_id, &retry_state);
+
+        // Map error to a u32 code for the event
+        let error_code: u32 = match err {
+            VaultError::InsufficientBalance => 70,
+            VaultError::ConditionsNotMet => 140,
+            _ => 0,
+        };
+
+        events::emit_retry_scheduled(
+            env,
+            proposal_id,
+            retry_state.retry_count,
+            retry_state.next_retry_ledger,
+            error_code,
+        );
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Escrow System (Issue: feature/escrow-system)
+    // ========================================================================
+
+    /// Create a new escrow agreement with milestone-based fund release
+    ///
+    /// # Arguments
+    /// * `funder` - Address funding the escrow
+    /// * `recipient` - Address receiving funds on completion
+    /// * `token` - Token contract address
+    /// * `amount` - Total escrow amount
+    /// * `milestones` - Milestones defining progressive release
+    /// * `duration_ledgers` - Duration until expiry (full refund after)
+    /// * `arbitrator` - Address for dispute resolution
+    pub fn create_escrow(
+        env: Env,
+        funder: Address,
+        recipient: Address,
+        token_addr: Address,
+        amount: i128,
+        milestones: Vec<Milestone>,
+        duration_ledgers: u64,
+        arbitrator: Address,
+    ) -> Result<u64, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Proposals)?;
+        funder.require_auth();
+
+        // Validate inputs
+        if amount <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        if milestones.is_empty() {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        // Validate milestone percentages sum to 100
+        let mut total_pct: u32 = 0;
+        for i in 0..milestones.len() {
+            if let Some(m) = milestones.get(i) {
+                if m.percentage == 0 || m.percentage > 100 {
+                    return Err(VaultError::InvalidAmount);
+                }
+                total_pct = total_pct.saturating_add(m.percentage);
+            }
+        }
+        if total_pct != 100 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        // Transfer tokens to vault (held in escrow)
+        token::transfer_to_vault(&env, &token_addr, &funder, amount);
+
+        // Create escrow record
+        let escrow_id = storage::increment_escrow_id(&env);
+        let current_ledger = env.ledger().sequence() as u64;
+
+        // Funds are locked on creation — status is immediately Active
+        let escrow = Escrow {
+            id: escrow_id,
+            funder: funder.clone(),
+            recipient: recipient.clone(),
+            token: token_addr.clone(),
+            total_amount: amount,
+            released_amount: 0,
+            milestones,
+            status: EscrowStatus::Active,
+            arbitrator,
+            dispute_reason: Symbol::new(&env, ""),
+            created_at: current_ledger,
+            expires_at: current_ledger + duration_ledgers,
+            finalized_at: 0,
+        };
+
+        storage::set_escrow(&env, &escrow);
+        storage::add_funder_escrow(&env, &funder, escrow_id);
+        storage::add_recipient_escrow(&env, &recipient, escrow_id);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::CreateEscrow,
+            &funder,
+            escrow_id,
+            (&recipient, &token_addr, amount, escrow.expires_at),
+        );
+
+        events::emit_escrow_created(
+            &env,
+            escrow_id,
+            &funder,
+            &recipient,
+            &token_addr,
+            amount,
+            duration_ledgers,
+        );
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::lifecycle_event(
+                &env,
+                HookStage::EscrowChanged,
+                escrow_id,
+                "created",
+                &funder,
+                &token_addr,
+                amount,
+            ),
+        );
+
+        Ok(escrow_id)
+    }
+
+    /// Mark a milestone as completed and verify conditions are met
+    pub fn complete_milestone(
+        env: Env,
+        completer: Address,
+        escrow_id: u64,
+        milestone_id: u64,
+    ) -> Result<(), VaultError> {
+        completer.require_auth();
+
+        let mut escrow = storage::get_escrow(&env, escrow_id)?;
+        let current_ledger = env.ledger().sequence() as u64;
+
+        // Validate escrow is active (not disputed, released, or refunded)
+        if escrow.status != EscrowStatus::Active {
+            return Err(VaultError::ProposalNotPending);
+        }
+
+        // Validate not expired
+        if current_ledger >= escrow.expires_at {
+            return Err(VaultError::ProposalExpired);
+        }
+
+        // Find and complete milestone
+        let mut found = false;
+        let mut updated_milestones = Vec::new(&env);
+
+        for i in 0..escrow.milestones.len() {
+            if let Some(m) = escrow.milestones.get(i) {
+                if m.id == milestone_id {
+                    if m.is_completed {
+                        return Err(VaultError::AlreadyApproved);
+                    }
+                    if current_ledger < m.release_ledger {
+                        return Err(VaultError::TimelockNotExpired);
+                    }
+
+                    let mut updated_m = m.clone();
+                    updated_m.is_completed = true;
+                    updated_m.completion_ledger = current_ledger;
+                    updated_milestones.push_back(updated_m);
+                    found = true;
+                } else {
+                    updated_milestones.push_back(m.clone());
+                }
+            }
+        }
+
+        if !found {
+            return Err(VaultError::ProposalNotFound);
+        }
+
+        escrow.milestones = updated_milestones;
+
+        // Check if all milestones completed
+        let mut all_complete = true;
+        for i in 0..escrow.milestones.len() {
+            if let Some(m) = escrow.milestones.get(i) {
+                if !m.is_completed {
+                    all_complete = false;
+                    break;
+                }
+            }
+        }
+
+        if all_complete {
+            escrow.status = EscrowStatus::MilestonesComplete;
+        } else {
+            escrow.status = EscrowStatus::Active;
+        }
+
+        storage::set_escrow(&env, &escrow);
+
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateEscrow,
+            &completer,
+            escrow_id,
+            (Symbol::new(&env, "milestone"), milestone_id),
+        );
+
+        events::emit_milestone_completed(&env, escrow_id, milestone_id, &completer);
+
+        Ok(())
+    }
+
+    /// Release escrowed funds to recipient after all milestones are completed.
+    /// Caller must be the funder, recipient, or admin.
+    pub fn release_escrow(env: Env, caller: Address, escrow_id: u64) -> Result<i128, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
+        caller.require_auth();
+
+        let mut escrow = storage::get_escrow(&env, escrow_id)?;
+        let current_ledger = env.ledger().sequence() as u64;
+
+        // Ensure caller is authorized
+        let role = storage::get_role(&env, &caller);
+        if caller != escrow.funder && caller != escrow.recipient && role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        // Cannot release a disputed escrow
+        if escrow.status == EscrowStatus::Disputed {
+            return Err(VaultError::ConditionsNotMet);
+        }
+
+        // Only release if all milestones complete or expired
+        let can_release = escrow.status == EscrowStatus::MilestonesComplete;
+        let is_expired = current_ledger >= escrow.expires_at;
+
+        if !can_release && !is_expired {
+            return Err(VaultError::ConditionsNotMet);
+        }
+
+        // Calculate amount to release
+        let amount_to_release = if is_expired {
+            // On expiry, return all unreleased to funder
+            escrow.total_amount - escrow.released_amount
+        } else {
+            // Release based on completed milestones
+            escrow.amount_to_release()
+        };
+
+        if amount_to_release <= 0 {
+            return Err(VaultError::ProposalAlreadyExecuted);
+        }
+
+        // Send to recipient if milestones complete, funder if expired
+        let recipient = if is_expired {
+            escrow.funder.clone()
+        } else {
+            escrow.recipient.clone()
+        };
+
+        token::transfer(&env, &escrow.token, &recipient, amount_to_release);
+
+        escrow.released_amount += amount_to_release;
+
+        // Update status
+        if escrow.released_amount >= escrow.total_amount {
+            escrow.status = if is_expired {
+                EscrowStatus::Refunded
+            } else {
+                EscrowStatus::Released
+            };
+            escrow.finalized_at = current_ledger;
+        }
+
+        storage::set_escrow(&env, &escrow);
+
+        storage::create_audit_entry(
+            &env,
+            AuditAction::ReleaseEscrow,
+            &caller,
+            escrow_id,
+            (&recipient, amount_to_release, is_expired),
+        );
+
+        events::emit_escrow_released(&env, escrow_id, &recipient, amount_to_release, is_expired);
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::lifecycle_event(
+                &env,
+                HookStage::EscrowChanged,
+                escrow_id,
+                "released",
+                &caller,
+                &escrow.token,
+                amount_to_release,
+            ),
+        );
+
+        Ok(amount_to_release)
+    }
+
+    /// Keep backward-compatible alias
+    pub fn release_escrow_funds(env: Env, escrow_id: u64) -> Result<i128, VaultError> {
+        let escrow = storage::get_escrow(&env, escrow_id)?;
+        let caller = escrow.recipient.clone();
+        Self::release_escrow(env, caller, escrow_id)
+    }
+
+    /// File a dispute on an escrow agreement
+    pub fn dispute_escrow(
+        env: Env,
+        disputer: Address,
+        escrow_id: u64,
+        reason: Symbol,
+    ) -> Result<(), VaultError> {
+        disputer.require_auth();
+
+        let mut escrow = storage::get_escrow(&env, escrow_id)?;
+
+        // Only funder or admin can dispute
+        let role = storage::get_role(&env, &disputer);
+        if disputer != escrow.funder && role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        // Can only dispute active escrows
+        if escrow.status != EscrowStatus::Active
+            && escrow.status != EscrowStatus::MilestonesComplete
+        {
+            return Err(VaultError::ProposalNotPending);
+        }
+
+        escrow.status = EscrowStatus::Disputed;
+        escrow.dispute_reason = reason.clone();
+
+        storage::set_escrow(&env, &escrow);
+
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateEscrow,
+            &disputer,
+            escrow_id,
+            (Symbol::new(&env, "dispute"), &reason),
+        );
+
+        events::emit_escrow_disputed(&env, escrow_id, &disputer, &reason);
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::lifecycle_event(
+                &env,
+                HookStage::EscrowChanged,
+                escrow_id,
+                "disputed",
+                &disputer,
+                &escrow.token,
+                escrow.total_amount - escrow.released_amount,
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Resolve an escrow dispute — admin only.
+    /// If `release_to_recipient` is true, funds go to recipient; otherwise refunded to funder.
+    pub fn resolve_escrow_dispute(
+        env: Env,
+        arbitrator: Address,
+        escrow_id: u64,
+        release_to_recipient: bool,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
+        arbitrator.require_auth();
+
+        // Admin-only: only the vault admin can resolve disputes
+        let role = storage::get_role(&env, &arbitrator);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        Self::require_council(
+            &env,
+            &arbitrator,
+            "resolve_escrow_dispute",
+            (escrow_id, release_to_recipient).into_val(&env),
+        )?;
+
+        let mut escrow = storage::get_escrow(&env, escrow_id)?;
+
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(VaultError::ProposalNotPending);
+        }
+
+        // Release all remaining funds based on arbitrator decision
+        let amount_to_release = escrow.total_amount - escrow.released_amount;
+        if amount_to_release > 0 {
+            let recipient = if release_to_recipient {
+                escrow.recipient.clone()
+            } else {
+                escrow.funder.clone()
+            };
+
+            token::transfer(&env, &escrow.token, &recipient, amount_to_release);
+            escrow.released_amount += amount_to_release;
+        }
+
+        escrow.status = if release_to_recipient {
+            EscrowStatus::Released
+        } else {
+            EscrowStatus::Refunded
+        };
+        escrow.finalized_at = env.ledger().sequence() as u64;
+
+        storage::set_escrow(&env, &escrow);
+
+        storage::create_audit_entry(
+            &env,
+            AuditAction::ReleaseEscrow,
+            &arbitrator,
+            escrow_id,
+            (release_to_recipient, amount_to_release),
+        );
+
+        events::emit_escrow_dispute_resolved(&env, escrow_id, &arbitrator, release_to_recipient);
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::lifecycle_event(
+                &env,
+                HookStage::EscrowChanged,
+                escrow_id,
+                "resolved",
+                &arbitrator,
+                &escrow.token,
+                escrow.total_amount,
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Query escrow details
+    pub fn get_escrow_info(env: Env, escrow_id: u64) -> Result<Escrow, VaultError> {
+        storage::get_escrow(&env, escrow_id)
+    }
+
+    /// Get all escrows for a funder
+    pub fn get_funder_escrows(env: Env, funder: Address) -> Vec<u64> {
+        storage::get_funder_escrows(&env, &funder)
+    }
+
+    /// Get all escrows for a recipient
+    pub fn get_recipient_escrows(env: Env, recipient: Address) -> Vec<u64> {
+        storage::get_recipient_escrows(&env, &recipient)
+    }
+
+    // ============================================================================
+    // Batch Transactions
+    // ============================================================================
+
+    /// Create a batch transaction with multiple operations
+    pub fn create_batch(
+        env: Env,
+        creator: Address,
+        operations: Vec<BatchOperation>,
+        memo: Symbol,
+    ) -> Result<u64, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Proposals)?;
+        creator.require_auth();
+
+        // Validate batch is not empty
+        if operations.is_empty() {
+            return Err(VaultError::BatchTooLarge);
+        }
+
+        // Enforce size limit (max 32 operations per batch)
+        const MAX_BATCH_OPS: u32 = 32;
+        if operations.len() > MAX_BATCH_OPS {
+            return Err(VaultError::BatchTooLarge);
+        }
+
+        // Validate each operation
+        for op in operations.iter() {
+            Self::validate_batch_operation(&env, &op)?;
+        }
+
+        let batch_id = storage::increment_batch_id(&env);
+        let _estimated_gas = Self::estimate_batch_gas(&env, &operations);
+
+        let batch = BatchTransaction {
+            id: batch_id,
+            creator: creator.clone(),
+            operations: operations.clone(),
+            status: BatchStatus::Pending,
+            created_at: env.ledger().timestamp(),
+            memo,
+        };
+
+        storage::set_batch(&env, &batch);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::CreateBatch,
+            &creator,
+            batch_id,
+            (operations.len(), &batch.memo),
+        );
+
+        Ok(batch_id)
+    }
+
+    /// Execute a batch transaction atomically
+    pub fn execute_batch(
+        env: Env,
+        executor: Address,
+        batch_id: u64,
+    ) -> Result<BatchExecutionResult, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
+        executor.require_auth();
+
+        let config = storage::get_config(&env)?;
+        let mut batch = storage::get_batch(&env, batch_id)?;
+
+        // Check authorization against every transfer in the batch
+        for op in batch.operations.iter() {
+            let request = PermissionRequest::transfer(&env, &op.token, op.amount, &op.recipient);
+            if !Self::check_permission(
+                &env,
+                &executor,
+                &types::Permission::ExecuteProposal,
+                Some(&request),
+            ) {
+                return Err(VaultError::InsufficientRole);
+            }
+        }
+
+        // Can only execute pending batches
+        if batch.status != BatchStatus::Pending {
+            return Err(VaultError::ProposalNotPending);
+        }
+
+        // Mark as executing
+        batch.status = BatchStatus::Executing;
+        storage::set_batch(&env, &batch);
+
+        let mut rollback_state: Vec<(Address, i128)> = Vec::new(&env);
+        let mut executed_count: u64 = 0;
+        let mut success = true;
+
+        // Execute operations sequentially
+        for op in batch.operations.iter() {
+            match Self::execute_batch_operation(&env, &op, &mut rollback_state, &config) {
+                Ok(_) => {
+                    executed_count += 1;
+                }
+                Err(err) => {
+                    success = false;
+                    let _error_code = match err {
+                        VaultError::ExceedsDailyLimit => Symbol::new(&env, "limit_exceeded"),
+                        VaultError::InsufficientRole => Symbol::new(&env, "insufficient_role"),
+                        VaultError::InvalidAmount => Symbol::new(&env, "invalid_amount"),
+                        VaultError::InsufficientBalance => {
+                            Symbol::new(&env, "insufficient_balance")
+                        }
+                        _ => Symbol::new(&env, "unknown_error"),
+                    };
+                    break;
+                }
+            }
+        }
+
+        // Perform rollback if execution failed
+        if !success {
+            Self::rollback_batch(&env, &rollback_state)?;
+            batch.status = BatchStatus::RolledBack;
+        } else {
+            batch.status = BatchStatus::Completed;
+        }
+
+        storage::set_batch(&env, &batch);
+
+        // Store execution result
+        let result = BatchExecutionResult {
+            batch_id,
+            success,
+            successful_ops: executed_count as u32,
+            failed_ops: if success {
+                0
+            } else {
+                batch.operations.len().saturating_sub(executed_count as u32)
+            },
+        };
+
+        storage::set_batch_result(&env, &result);
+
+        if !success {
+            storage::set_rollback_state(&env, batch_id, &rollback_state);
+        }
+
+        // Emit event for batch execution
+        let ops_len = batch.operations.len();
+        let failed_count = ops_len.saturating_sub(executed_count as u32);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::ExecuteBatch,
+            &executor,
+            batch_id,
+            (executed_count as u32, failed_count, success),
+        );
+
+        events::emit_batch_executed(&env, &executor, executed_count as u32, failed_count);
+
+        Ok(result)
+    }
+
+    /// Retrieve batch execution result
+    pub fn get_batch_result(env: Env, batch_id: u64) -> Result<BatchExecutionResult, VaultError> {
+        storage::get_batch_result(&env, batch_id)
+    }
 
-        // Calculate unlock ledger for timelock
-        let unlock_ledger = if amount >= config.timelock_threshold {
-            current_ledger + config.timelock_delay
-        } else {
-            0
-        };
+    /// Retrieve batch details
+    pub fn get_batch(env: Env, batch_id: u64) -> Result<BatchTransaction, VaultError> {
+        storage::get_batch(&env, batch_id)
+    }
 
-        let proposal = Proposal {
-            id: proposal_id,
-            proposer: proposer.clone(),
-            recipient,
-            token: template.token,
-            amount,
-            memo,
-            metadata: Map::new(&env),
-            tags: Vec::new(&env),
-            approvals: Vec::new(&env),
-            abstentions: Vec::new(&env),
-            attachments: Vec::new(&env),
-            status: ProposalStatus::Pending,
-            priority,
-            conditions: Vec::new(&env),
-            condition_logic: ConditionLogic::And,
-            created_at: current_ledger,
-            expires_at,
-            unlock_ledger,
-            execution_time: None,
-            insurance_amount: 0,
-            stake_amount: 0, // Template proposals don't require stake
-            gas_limit: 0,
-            gas_used: 0,
-            snapshot_ledger: current_ledger,
-            snapshot_signers: config.signers.clone(),
-            depends_on: Vec::new(&env),
-            is_swap: false,
-            voting_deadline: 0,
-        };
+    /// Reverse a transfer recorded in an execution snapshot.
+    ///
+    /// Only callable by an admin. Reads the snapshot stored under `proposal_id`,
+    /// transfers the recorded amount back from the recipient to the vault, then
+    /// clears the snapshot. Returns `SnapshotNotFound` when no snapshot exists.
+    pub fn rollback_execution(
+        env: Env,
+        admin: Address,
+        proposal_id: u64,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
+        admin.require_auth();
 
-        storage::set_proposal(&env, &proposal);
-        Self::persist_execution_fee_estimate(&env, &proposal);
-        storage::extend_instance_ttl(&env);
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+        Self::require_council(
+            &env,
+            &admin,
+            "rollback_execution",
+            (proposal_id,).into_val(&env),
+        )?;
 
-        events::emit_proposal_from_template(
+        let snapshot = storage::get_execution_snapshot(&env, proposal_id)
+            .ok_or(VaultError::ProposalNotFound)?;
+
+        let proposal = &snapshot.proposal;
+
+        // Reverse each transfer: send the recorded amount back to the proposer
+        // (the vault authorizes outgoing transfers from its own balance)
+        token::transfer(&env, &proposal.token, &proposal.proposer, proposal.amount);
+
+        storage::remove_execution_snapshot(&env, proposal_id);
+        storage::create_audit_entry(
             &env,
+            AuditAction::ExecuteBatch,
+            &admin,
             proposal_id,
-            template_id,
-            &template.name,
-            &proposer,
+            (
+                Symbol::new(&env, "rollback"),
+                &proposal.proposer,
+                proposal.amount,
+            ),
         );
 
-        Ok(proposal_id)
+        Ok(())
     }
 
-    /// Validate template parameters
+    /// Validate a single batch operation
+    fn validate_batch_operation(_env: &Env, op: &BatchOperation) -> Result<(), VaultError> {
+        // Amount must be positive
+        if op.amount <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        Ok(())
+    }
+
+    /// Execute a single batch operation
+    fn execute_batch_operation(
+        env: &Env,
+        op: &BatchOperation,
+        rollback_state: &mut Vec<(Address, i128)>,
+        config: &Config,
+    ) -> Result<(), VaultError> {
+        // Get current day for cumulative tracking
+        let today = env.ledger().timestamp() / 86400; // seconds to days
+
+        // Check spending limits
+        let daily_spent = storage::get_daily_spent(env, today);
+        let new_daily_total = daily_spent + op.amount;
+
+        if new_daily_total > config.daily_limit {
+            return Err(VaultError::ExceedsDailyLimit);
+        }
+
+        // Record rollback state
+        rollback_state.push_back((op.recipient.clone(), op.amount));
+
+        // Update spending limits
+        storage::add_daily_spent(env, today, op.amount);
+
+        Ok(())
+    }
+
+    /// Rollback batch operations in reverse order
+    fn rollback_batch(
+        _env: &Env,
+        _rollback_state: &Vec<(Address, i128)>,
+    ) -> Result<(), VaultError> {
+        // In production, this would reverse the transfers
+        // For now, we track the state for audit purposes
+        // Audit trail is maintained via event emission and result storage
+        Ok(())
+    }
+
+    /// Estimate gas cost for batch operations
+    fn estimate_batch_gas(_env: &Env, operations: &Vec<BatchOperation>) -> u64 {
+        // Base overhead: 100,000
+        // Per-operation cost: 50,000
+        const BASE_OVERHEAD: u64 = 100_000;
+        const PER_OP_COST: u64 = 50_000;
+
+        BASE_OVERHEAD + (operations.len() as u64 * PER_OP_COST)
+    }
+
+    // ========================================================================
+    // Time-Weighted Voting
+    // ========================================================================
+
+    /// Lock tokens to gain increased voting power
     ///
-    /// Helper function to validate template parameters before creation/update.
+    /// Locks tokens for a specified duration, granting voting power multipliers:
+    /// - < 30 days: 1.0x
+    /// - 30-90 days: 1.5x
+    /// - 90-180 days: 2.0x
+    /// - 180-365 days: 3.0x
+    /// - > 365 days: 4.0x
     ///
     /// # Arguments
-    /// * `amount` - Default amount
-    /// * `min_amount` - Minimum allowed amount
-    /// * `max_amount` - Maximum allowed amount
-    ///
-    /// # Returns
-    /// true if parameters are valid
-    pub fn validate_template_params(
-        _env: Env,
+    /// * `owner` - Address locking the tokens
+    /// * `token` - Token contract address
+    /// * `amount` - Amount of tokens to lock
+    /// * `duration` - Lock duration in ledgers
+    pub fn lock_tokens(
+        env: Env,
+        owner: Address,
+        token: Address,
         amount: i128,
-        min_amount: i128,
-        max_amount: i128,
-    ) -> bool {
-        // Validate amount is positive
-        if amount <= 0 {
-            return false;
+        duration: u64,
+    ) -> Result<(), VaultError> {
+        owner.require_auth();
+
+        let config = storage::get_time_weighted_config(&env);
+
+        if !config.enabled {
+            return Err(VaultError::Unauthorized);
         }
 
-        // Validate bounds relationship
-        if min_amount > 0 && max_amount > 0 && min_amount > max_amount {
-            return false;
+        if amount <= 0 {
+            return Err(VaultError::InvalidAmount);
         }
 
-        // Validate default amount is within bounds
-        if min_amount > 0 && amount < min_amount {
-            return false;
+        if duration < config.min_lock_duration || duration > config.max_lock_duration {
+            return Err(VaultError::InvalidAmount);
         }
-        if max_amount > 0 && amount > max_amount {
-            return false;
+
+        // Check if user already has an active lock
+        if let Some(existing_lock) = storage::get_token_lock(&env, &owner) {
+            if existing_lock.is_active {
+                return Err(VaultError::AlreadyApproved); // Reusing error for "already locked"
+            }
         }
 
-        true
-    }
+        // Transfer tokens to vault
+        token::transfer_to_vault(&env, &token, &owner, amount);
 
-    /// Check if an error is retryable (transient failure).
-    fn is_retryable_error(err: &VaultError) -> bool {
-        matches!(
-            err,
-            VaultError::InsufficientBalance | VaultError::ConditionsNotMet
-        )
+        let current_ledger = env.ledger().sequence() as u64;
+        let unlock_at = current_ledger + duration;
+        let power_multiplier_bps = types::TokenLock::calculate_multiplier(duration);
+
+        let lock = types::TokenLock {
+            owner: owner.clone(),
+            token: token.clone(),
+            amount,
+            locked_at: current_ledger,
+            duration,
+            unlock_at,
+            is_active: true,
+            power_multiplier_bps,
+        };
+
+        storage::set_token_lock(&env, &lock);
+        storage::set_total_locked(&env, &owner, amount);
+        storage::extend_instance_ttl(&env);
+
+        storage::create_audit_entry(
+            &env,
+            AuditAction::LockTokens,
+            &owner,
+            0,
+            (&token, amount, duration),
+        );
+
+        events::emit_tokens_locked(&env, &owner, amount, duration, power_multiplier_bps);
+
+        Ok(())
     }
 
-    /// Schedule a retry for a failed proposal execution with exponential backoff.
+    /// Extend an existing token lock duration
     ///
-    /// Returns Ok(()) to signal that retry was scheduled (caller should also return Ok
-    /// to persist state), or Err(MaxRetriesExceeded) if all retries used up.
-    fn schedule_retry(
-        env: &Env,
-        proposal_id: u64,
-        retry_config: &RetryConfig,
-        current_ledger: u64,
-        err: &VaultError,
+    /// Extends the lock duration, potentially increasing the voting power multiplier.
+    /// The new duration is added to the remaining time.
+    ///
+    /// # Arguments
+    /// * `owner` - Address that owns the lock
+    /// * `additional_duration` - Additional ledgers to add to the lock
+    pub fn extend_lock(
+        env: Env,
+        owner: Address,
+        additional_duration: u64,
     ) -> Result<(), VaultError> {
-        let mut retry_state = storage::get_retry_state(env, proposal_id).unwrap_or(RetryState {
-            retry_count: 0,
-            next_retry_ledger: 0,
-            last_retry_ledger: 0,
-        });
+        owner.require_auth();
 
-        retry_state.retry_count += 1;
+        let config = storage::get_time_weighted_config(&env);
 
-        if retry_state.retry_count > retry_config.max_retries {
-            events::emit_retries_exhausted(env, proposal_id, retry_state.retry_count);
-            return Err(VaultError::RetryError);
+        if !config.enabled {
+            return Err(VaultError::Unauthorized);
         }
 
-        // Exponential backoff: initial_backoff << (retry_count - 1), capped at 7 days (120,960 ledgers)
-        let max_backoff = 17_280 * 7; // 7 days in ledgers
-        let exponent = core::cmp::min(retry_state.retry_count - 1, 30); // Prevent overflow
-        let backoff = retry_config
-            .initial_backoff_ledgers
-            .checked_shl(exponent as u32)
-            .unwrap_or(max_backoff)
-            .min(max_backoff);
+        let mut lock = storage::get_token_lock(&env, &owner).ok_or(VaultError::ProposalNotFound)?;
 
-        retry_state.next_retry_ledger = current_ledger + backoff;
-        retry_state.last_retry_ledger = current_ledger;
+        if !lock.is_active {
+            return Err(VaultError::ProposalNotPending);
+        }
 
-        storage::set_retry_state(env, proposal_id, &retry_state);
+        let current_ledger = env.ledger().sequence() as u64;
 
-        // Map error to a u32 code for the event
-        let error_code: u32 = match err {
-            VaultError::InsufficientBalance => 70,
-            VaultError::ConditionsNotMet => 140,
-            _ => 0,
-        };
+        // Calculate new total duration from current time
+        let remaining = lock.unlock_at.saturating_sub(current_ledger);
+        let new_total_duration = remaining + additional_duration;
 
-        events::emit_retry_scheduled(
-            env,
-            proposal_id,
-            retry_state.retry_count,
-            retry_state.next_retry_ledger,
-            error_code,
+        if new_total_duration > config.max_lock_duration {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        // Update lock
+        lock.unlock_at = current_ledger + new_total_duration;
+        lock.duration = new_total_duration;
+        lock.power_multiplier_bps = types::TokenLock::calculate_multiplier(new_total_duration);
+
+        storage::set_token_lock(&env, &lock);
+        storage::extend_instance_ttl(&env);
+
+        storage::create_audit_entry(
+            &env,
+            AuditAction::LockTokens,
+            &owner,
+            0,
+            (
+                Symbol::new(&env, "extend"),
+                additional_duration,
+                lock.unlock_at,
+            ),
         );
 
+        events::emit_lock_extended(&env, &owner, new_total_duration, lock.power_multiplier_bps);
+
         Ok(())
     }
 
     // ========================================================================
-    // Escrow System (Issue: feature/escrow-system)
+    // Wallet Recovery (Issue: feature/wallet-recovery)
     // ========================================================================
 
-    /// Create a new escrow agreement with milestone-based fund release
-    ///
-    /// # Arguments
-    /// * `funder` - Address funding the escrow
-    /// * `recipient` - Address receiving funds on completion
-    /// * `token` - Token contract address
-    /// * `amount` - Total escrow amount
-    /// * `milestones` - Milestones defining progressive release
-    /// * `duration_ledgers` - Duration until expiry (full refund after)
-    /// * `arbitrator` - Address for dispute resolution
-    pub fn create_escrow(
+    /// Update recovery configuration
+    pub fn set_recovery_config(
         env: Env,
-        funder: Address,
-        recipient: Address,
-        token_addr: Address,
-        amount: i128,
-        milestones: Vec<Milestone>,
-        duration_ledgers: u64,
-        arbitrator: Address,
+        admin: Address,
+        config: RecoveryConfig,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_recovery_config",
+            (config.clone(),).into_val(&env),
+        )?;
+
+        let mut vault_config = storage::get_config(&env)?;
+        let previous = vault_config.recovery_config.clone();
+        vault_config.recovery_config = config.clone();
+        storage::set_config(&env, &vault_config);
+
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Recovery,
+            &admin,
+            0,
+            (Symbol::new(&env, "config"), previous, config),
+        );
+
+        events::emit_recovery_config_updated(&env, &admin);
+        Ok(())
+    }
+
+    /// Initiate a wallet recovery proposal
+    pub fn initiate_recovery(
+        env: Env,
+        caller: Address,
+        new_signers: Vec<Address>,
+        new_threshold: u32,
     ) -> Result<u64, VaultError> {
-        funder.require_auth();
+        caller.require_auth();
 
-        // Validate inputs
-        if amount <= 0 {
-            return Err(VaultError::InvalidAmount);
+        let config = storage::get_config(&env)?;
+        if !config.recovery_config.guardians.contains(&caller) {
+            return Err(VaultError::Unauthorized);
         }
 
-        if milestones.is_empty() {
-            return Err(VaultError::InvalidAmount);
+        // Validate new config
+        if new_signers.is_empty() {
+            return Err(VaultError::NoSigners);
         }
-
-        // Validate milestone percentages sum to 100
-        let mut total_pct: u32 = 0;
-        for i in 0..milestones.len() {
-            if let Some(m) = milestones.get(i) {
-                if m.percentage == 0 || m.percentage > 100 {
-                    return Err(VaultError::InvalidAmount);
-                }
-                total_pct = total_pct.saturating_add(m.percentage);
-            }
+        if new_threshold < 1 {
+            return Err(VaultError::ThresholdTooLow);
         }
-        if total_pct != 100 {
-            return Err(VaultError::InvalidAmount);
+        if new_threshold > new_signers.len() {
+            return Err(VaultError::ThresholdTooHigh);
         }
 
-        // Transfer tokens to vault (held in escrow)
-        token::transfer_to_vault(&env, &token_addr, &funder, amount);
-
-        // Create escrow record
-        let escrow_id = storage::increment_escrow_id(&env);
+        let id = storage::increment_recovery_id(&env);
         let current_ledger = env.ledger().sequence() as u64;
 
-        // Funds are locked on creation — status is immediately Active
-        let escrow = Escrow {
-            id: escrow_id,
-            funder: funder.clone(),
-            recipient: recipient.clone(),
-            token: token_addr.clone(),
-            total_amount: amount,
-            released_amount: 0,
-            milestones,
-            status: EscrowStatus::Active,
-            arbitrator,
-            dispute_reason: Symbol::new(&env, ""),
+        let proposal = RecoveryProposal {
+            id,
+            new_signers,
+            new_threshold,
+            approvals: Vec::new(&env),
+            status: RecoveryStatus::Pending,
             created_at: current_ledger,
-            expires_at: current_ledger + duration_ledgers,
-            finalized_at: 0,
+            execution_after: 0, // Set after approval threshold is met
         };
 
-        storage::set_escrow(&env, &escrow);
-        storage::add_funder_escrow(&env, &funder, escrow_id);
-        storage::add_recipient_escrow(&env, &recipient, escrow_id);
-
-        events::emit_escrow_created(
+        storage::set_recovery_proposal(&env, &proposal);
+        storage::create_audit_entry(
             &env,
-            escrow_id,
-            &funder,
-            &recipient,
-            &token_addr,
-            amount,
-            duration_ledgers,
+            AuditAction::Recovery,
+            &caller,
+            id,
+            (
+                Symbol::new(&env, "initiate"),
+                proposal.new_signers.clone(),
+                new_threshold,
+            ),
         );
 
-        Ok(escrow_id)
+        events::emit_recovery_proposed(&env, id, new_threshold);
+
+        Ok(id)
     }
 
-    /// Mark a milestone as completed and verify conditions are met
-    pub fn complete_milestone(
+    /// Approve a recovery proposal (guardians only)
+    pub fn approve_recovery(
         env: Env,
-        completer: Address,
-        escrow_id: u64,
-        milestone_id: u64,
+        guardian: Address,
+        proposal_id: u64,
     ) -> Result<(), VaultError> {
-        completer.require_auth();
+        guardian.require_auth();
 
-        let mut escrow = storage::get_escrow(&env, escrow_id)?;
-        let current_ledger = env.ledger().sequence() as u64;
+        let config = storage::get_config(&env)?;
+        if !config.recovery_config.guardians.contains(&guardian) {
+            return Err(VaultError::Unauthorized);
+        }
 
-        // Validate escrow is active (not disputed, released, or refunded)
-        if escrow.status != EscrowStatus::Active {
+        let mut proposal = storage::get_recovery_proposal(&env, proposal_id)?;
+        if proposal.status != RecoveryStatus::Pending {
             return Err(VaultError::ProposalNotPending);
         }
 
-        // Validate not expired
-        if current_ledger >= escrow.expires_at {
-            return Err(VaultError::ProposalExpired);
-        }
+        if proposal.approvals.contains(&guardian) {
+            return Err(VaultError::AlreadyApproved);
+        }
+
+        proposal.approvals.push_back(guardian.clone());
+
+        let threshold = config.recovery_config.threshold;
+        if proposal.approvals.len() >= threshold {
+            proposal.status = RecoveryStatus::Approved;
+            proposal.execution_after =
+                env.ledger().sequence() as u64 + config.recovery_config.delay;
+        }
+
+        storage::set_recovery_proposal(&env, &proposal);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Recovery,
+            &guardian,
+            proposal_id,
+            (Symbol::new(&env, "approve"), proposal.approvals.len()),
+        );
+
+        events::emit_recovery_approved(&env, proposal_id, &guardian);
+
+        Ok(())
+    }
+
+    /// Unlock tokens early with penalty
+    ///
+    /// Allows early unlock of tokens before the lock period expires.
+    /// A penalty is applied based on the configuration.
+    ///
+    /// # Arguments
+    /// * `owner` - Address that owns the lock
+    pub fn unlock_early(env: Env, owner: Address) -> Result<i128, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
+        owner.require_auth();
+
+        let config = storage::get_time_weighted_config(&env);
+
+        if !config.enabled {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut lock = storage::get_token_lock(&env, &owner).ok_or(VaultError::ProposalNotFound)?;
 
-        // Find and complete milestone
-        let mut found = false;
-        let mut updated_milestones = Vec::new(&env);
+        if !lock.is_active {
+            return Err(VaultError::ProposalNotPending);
+        }
 
-        for i in 0..escrow.milestones.len() {
-            if let Some(m) = escrow.milestones.get(i) {
-                if m.id == milestone_id {
-                    if m.is_completed {
-                        return Err(VaultError::AlreadyApproved);
-                    }
-                    if current_ledger < m.release_ledger {
-                        return Err(VaultError::TimelockNotExpired);
-                    }
+        let current_ledger = env.ledger().sequence() as u64;
 
-                    let mut updated_m = m.clone();
-                    updated_m.is_completed = true;
-                    updated_m.completion_ledger = current_ledger;
-                    updated_milestones.push_back(updated_m);
-                    found = true;
-                } else {
-                    updated_milestones.push_back(m.clone());
-                }
-            }
+        // Check if lock has naturally expired
+        if current_ledger >= lock.unlock_at {
+            return Self::unlock_tokens(env, owner);
         }
 
-        if !found {
-            return Err(VaultError::ProposalNotFound);
-        }
+        // Calculate penalty
+        let penalty_amount = (lock.amount * config.early_unlock_penalty_bps as i128) / 10_000;
+        let return_amount = lock.amount - penalty_amount;
 
-        escrow.milestones = updated_milestones;
+        // Transfer tokens back to owner (minus penalty)
+        token::transfer(&env, &lock.token, &owner, return_amount);
 
-        // Check if all milestones completed
-        let mut all_complete = true;
-        for i in 0..escrow.milestones.len() {
-            if let Some(m) = escrow.milestones.get(i) {
-                if !m.is_completed {
-                    all_complete = false;
-                    break;
-                }
-            }
+        // Penalty goes to insurance pool
+        if penalty_amount > 0 {
+            storage::add_to_insurance_pool(&env, &lock.token, penalty_amount);
         }
 
-        if all_complete {
-            escrow.status = EscrowStatus::MilestonesComplete;
-        } else {
-            escrow.status = EscrowStatus::Active;
-        }
+        // Deactivate lock
+        lock.is_active = false;
+        storage::set_token_lock(&env, &lock);
+        storage::set_total_locked(&env, &owner, 0);
+        storage::extend_instance_ttl(&env);
 
-        storage::set_escrow(&env, &escrow);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UnlockTokens,
+            &owner,
+            0,
+            (&lock.token, return_amount, penalty_amount),
+        );
 
-        events::emit_milestone_completed(&env, escrow_id, milestone_id, &completer);
+        events::emit_early_unlock(&env, &owner, return_amount, penalty_amount);
 
-        Ok(())
+        Ok(return_amount)
     }
 
-    /// Release escrowed funds to recipient after all milestones are completed.
-    /// Caller must be the funder, recipient, or admin.
-    pub fn release_escrow(env: Env, caller: Address, escrow_id: u64) -> Result<i128, VaultError> {
-        caller.require_auth();
+    /// Unlock tokens after lock period expires
+    ///
+    /// Returns all locked tokens to the owner without penalty.
+    ///
+    /// # Arguments
+    /// * `owner` - Address that owns the lock
+    pub fn unlock_tokens(env: Env, owner: Address) -> Result<i128, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
+        owner.require_auth();
 
-        let mut escrow = storage::get_escrow(&env, escrow_id)?;
-        let current_ledger = env.ledger().sequence() as u64;
+        let config = storage::get_time_weighted_config(&env);
 
-        // Ensure caller is authorized
-        let role = storage::get_role(&env, &caller);
-        if caller != escrow.funder && caller != escrow.recipient && role != Role::Admin {
+        if !config.enabled {
             return Err(VaultError::Unauthorized);
         }
 
-        // Cannot release a disputed escrow
-        if escrow.status == EscrowStatus::Disputed {
-            return Err(VaultError::ConditionsNotMet);
-        }
-
-        // Only release if all milestones complete or expired
-        let can_release = escrow.status == EscrowStatus::MilestonesComplete;
-        let is_expired = current_ledger >= escrow.expires_at;
+        let mut lock = storage::get_token_lock(&env, &owner).ok_or(VaultError::ProposalNotFound)?;
 
-        if !can_release && !is_expired {
-            return Err(VaultError::ConditionsNotMet);
+        if !lock.is_active {
+            return Err(VaultError::ProposalNotPending);
         }
 
-        // Calculate amount to release
-        let amount_to_release = if is_expired {
-            // On expiry, return all unreleased to funder
-            escrow.total_amount - escrow.released_amount
-        } else {
-            // Release based on completed milestones
-            escrow.amount_to_release()
-        };
+        let current_ledger = env.ledger().sequence() as u64;
 
-        if amount_to_release <= 0 {
-            return Err(VaultError::ProposalAlreadyExecuted);
+        // Check if lock period has expired
+        if current_ledger < lock.unlock_at {
+            return Err(VaultError::TimelockNotExpired);
         }
 
-        // Send to recipient if milestones complete, funder if expired
-        let recipient = if is_expired {
-            escrow.funder.clone()
-        } else {
-            escrow.recipient.clone()
-        };
+        let amount = lock.amount;
 
-        token::transfer(&env, &escrow.token, &recipient, amount_to_release);
+        // Transfer tokens back to owner
+        token::transfer(&env, &lock.token, &owner, amount);
 
-        escrow.released_amount += amount_to_release;
+        // Deactivate lock
+        lock.is_active = false;
+        storage::set_token_lock(&env, &lock);
+        storage::set_total_locked(&env, &owner, 0);
+        storage::extend_instance_ttl(&env);
 
-        // Update status
-        if escrow.released_amount >= escrow.total_amount {
-            escrow.status = if is_expired {
-                EscrowStatus::Refunded
-            } else {
-                EscrowStatus::Released
-            };
-            escrow.finalized_at = current_ledger;
-        }
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UnlockTokens,
+            &owner,
+            0,
+            (&lock.token, amount, 0i128),
+        );
 
-        storage::set_escrow(&env, &escrow);
+        events::emit_tokens_unlocked(&env, &owner, amount);
 
-        events::emit_escrow_released(&env, escrow_id, &recipient, amount_to_release, is_expired);
+        Ok(amount)
+    }
 
-        Ok(amount_to_release)
+    /// Get token lock information for an address
+    pub fn get_token_lock(env: Env, owner: Address) -> Option<types::TokenLock> {
+        storage::get_token_lock(&env, &owner)
     }
 
-    /// Keep backward-compatible alias
-    pub fn release_escrow_funds(env: Env, escrow_id: u64) -> Result<i128, VaultError> {
-        let escrow = storage::get_escrow(&env, escrow_id)?;
-        let caller = escrow.recipient.clone();
-        Self::release_escrow(env, caller, escrow_id)
+    /// Get voting power for an address
+    ///
+    /// Returns the current voting power including time-weighted multipliers
+    /// and decay if enabled.
+    pub fn get_voting_power(env: Env, owner: Address) -> i128 {
+        storage::calculate_voting_power(&env, &owner)
     }
 
-    /// File a dispute on an escrow agreement
-    pub fn dispute_escrow(
+    /// Configure time-weighted voting system
+    ///
+    /// Admin only function to enable/disable and configure time-weighted voting.
+    pub fn set_time_weighted_config(
         env: Env,
-        disputer: Address,
-        escrow_id: u64,
-        reason: Symbol,
+        admin: Address,
+        config: types::TimeWeightedConfig,
     ) -> Result<(), VaultError> {
-        disputer.require_auth();
-
-        let mut escrow = storage::get_escrow(&env, escrow_id)?;
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
 
-        // Only funder or admin can dispute
-        let role = storage::get_role(&env, &disputer);
-        if disputer != escrow.funder && role != Role::Admin {
-            return Err(VaultError::Unauthorized);
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::InsufficientRole);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_time_weighted_config",
+            (config.clone(),).into_val(&env),
+        )?;
 
-        // Can only dispute active escrows
-        if escrow.status != EscrowStatus::Active
-            && escrow.status != EscrowStatus::MilestonesComplete
-        {
-            return Err(VaultError::ProposalNotPending);
+        let previous = storage::get_time_weighted_config(&env);
+        storage::set_time_weighted_config(&env, &config);
+        storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdateVotingConfig,
+            &admin,
+            0,
+            (Symbol::new(&env, "time_weighted"), previous, config),
+        );
+
+        Ok(())
+    }
+
+    /// Get time-weighted voting configuration
+    pub fn get_time_weighted_config(env: Env) -> types::TimeWeightedConfig {
+        storage::get_time_weighted_config(&env)
+    }
+
+    // ========================================================================
+    // Recovery Proposals
+    // ========================================================================
+
+    /// Execute an approved recovery proposal
+    pub fn execute_recovery(env: Env, proposal_id: u64) -> Result<(), VaultError> {
+        let mut proposal = storage::get_recovery_proposal(&env, proposal_id)?;
+
+        if proposal.status != RecoveryStatus::Approved {
+            return Err(VaultError::ProposalNotApproved);
         }
 
-        escrow.status = EscrowStatus::Disputed;
-        escrow.dispute_reason = reason.clone();
+        let current_ledger = env.ledger().sequence() as u64;
+        if current_ledger < proposal.execution_after {
+            return Err(VaultError::TimelockNotExpired);
+        }
 
-        storage::set_escrow(&env, &escrow);
+        // Apply new configuration
+        let mut config = storage::get_config(&env)?;
+        let previous = (config.signers.clone(), config.threshold);
+        config.signers = proposal.new_signers.clone();
+        config.threshold = proposal.new_threshold;
+        // Reset quorum and other fields to safe defaults if they were invalid for new signers
+        if config.quorum > config.signers.len() {
+            config.quorum = config.signers.len();
+        }
 
-        events::emit_escrow_disputed(&env, escrow_id, &disputer, &reason);
+        storage::set_config(&env, &config);
 
-        Ok(())
-    }
+        proposal.status = RecoveryStatus::Executed;
+        storage::set_recovery_proposal(&env, &proposal);
 
-    /// Resolve an escrow dispute — admin only.
-    /// If `release_to_recipient` is true, funds go to recipient; otherwise refunded to funder.
-    pub fn resolve_escrow_dispute(
-        env: Env,
-        arbitrator: Address,
-        escrow_id: u64,
-        release_to_recipient: bool,
-    ) -> Result<(), VaultError> {
-        arbitrator.require_auth();
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Recovery,
+            &env.current_contract_address(),
+            proposal_id,
+            (
+                Symbol::new(&env, "execute"),
+                previous,
+                (config.signers.clone(), config.threshold),
+            ),
+        );
 
-        // Admin-only: only the vault admin can resolve disputes
-        let role = storage::get_role(&env, &arbitrator);
-        if role != Role::Admin {
-            return Err(VaultError::Unauthorized);
-        }
+        events::emit_recovery_executed(&env, proposal_id);
+        events::emit_config_updated(&env, &env.current_contract_address());
 
-        let mut escrow = storage::get_escrow(&env, escrow_id)?;
+        Ok(())
+    }
 
-        if escrow.status != EscrowStatus::Disputed {
-            return Err(VaultError::ProposalNotPending);
+    /// Cancel a recovery proposal (admins only)
+    pub fn cancel_recovery(env: Env, admin: Address, proposal_id: u64) -> Result<(), VaultError> {
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::InsufficientRole);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "cancel_recovery",
+            (proposal_id,).into_val(&env),
+        )?;
 
-        // Release all remaining funds based on arbitrator decision
-        let amount_to_release = escrow.total_amount - escrow.released_amount;
-        if amount_to_release > 0 {
-            let recipient = if release_to_recipient {
-                escrow.recipient.clone()
-            } else {
-                escrow.funder.clone()
-            };
-
-            token::transfer(&env, &escrow.token, &recipient, amount_to_release);
-            escrow.released_amount += amount_to_release;
+        let mut proposal = storage::get_recovery_proposal(&env, proposal_id)?;
+        if proposal.status != RecoveryStatus::Pending && proposal.status != RecoveryStatus::Approved
+        {
+            return Err(VaultError::ProposalNotPending);
         }
 
-        escrow.status = if release_to_recipient {
-            EscrowStatus::Released
-        } else {
-            EscrowStatus::Refunded
-        };
-        escrow.finalized_at = env.ledger().sequence() as u64;
+        proposal.status = RecoveryStatus::Cancelled;
+        storage::set_recovery_proposal(&env, &proposal);
 
-        storage::set_escrow(&env, &escrow);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Recovery,
+            &admin,
+            proposal_id,
+            Symbol::new(&env, "cancel"),
+        );
 
-        events::emit_escrow_dispute_resolved(&env, escrow_id, &arbitrator, release_to_recipient);
+        events::emit_recovery_cancelled(&env, proposal_id, &admin);
 
         Ok(())
     }
 
-    /// Query escrow details
-    pub fn get_escrow_info(env: Env, escrow_id: u64) -> Result<Escrow, VaultError> {
-        storage::get_escrow(&env, escrow_id)
-    }
-
-    /// Get all escrows for a funder
-    pub fn get_funder_escrows(env: Env, funder: Address) -> Vec<u64> {
-        storage::get_funder_escrows(&env, &funder)
+    /// Get recovery configuration
+    pub fn get_recovery_config(env: Env) -> Result<RecoveryConfig, VaultError> {
+        let config = storage::get_config(&env)?;
+        Ok(config.recovery_config)
     }
 
-    /// Get all escrows for a recipient
-    pub fn get_recipient_escrows(env: Env, recipient: Address) -> Vec<u64> {
-        storage::get_recipient_escrows(&env, &recipient)
+    /// Get recovery proposal details
+    pub fn get_recovery_proposal(env: Env, id: u64) -> Result<RecoveryProposal, VaultError> {
+        storage::get_recovery_proposal(&env, id)
     }
 
-    // ============================================================================
-    // Batch Transactions
-    // ============================================================================
+    // ========================================================================
+    // Contract Upgrades (Issue: feature/contract-upgrades)
+    // ========================================================================
 
-    /// Create a batch transaction with multiple operations
-    pub fn create_batch(
+    /// Propose switching the vault to an uploaded WASM (signers only).
+    ///
+    /// The upgrade needs `threshold` signer approvals and can only be executed
+    /// once the upgrade timelock (the longer of `UPGRADE_TIMELOCK` and the
+    /// vault's `timelock_delay`) has passed since approval. It expires if not
+    /// approved within `UPGRADE_EXPIRY` ledgers of being proposed, or not
+    /// executed within `UPGRADE_EXPIRY` ledgers of its timelock ending.
+    pub fn propose_upgrade(
         env: Env,
-        creator: Address,
-        operations: Vec<BatchOperation>,
-        memo: Symbol,
+        proposer: Address,
+        new_wasm_hash: BytesN<32>,
     ) -> Result<u64, VaultError> {
-        creator.require_auth();
-
-        // Validate batch is not empty
-        if operations.is_empty() {
-            return Err(VaultError::BatchTooLarge);
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        proposer.require_auth();
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&proposer) {
+            return Err(VaultError::NotASigner);
         }
 
-        // Enforce size limit (max 32 operations per batch)
-        const MAX_BATCH_OPS: u32 = 32;
-        if operations.len() > MAX_BATCH_OPS {
-            return Err(VaultError::BatchTooLarge);
-        }
+        let id = storage::increment_upgrade_id(&env);
+        let proposal = UpgradeProposal {
+            id,
+            proposer: proposer.clone(),
+            new_wasm_hash: new_wasm_hash.clone(),
+            approvals: Vec::new(&env),
+            status: UpgradeStatus::Pending,
+            created_at: env.ledger().sequence() as u64,
+            execution_after: 0,
+        };
 
-        // Validate each operation
-        for op in operations.iter() {
-            Self::validate_batch_operation(&env, &op)?;
+        storage::set_upgrade_proposal(&env, &proposal);
+        storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Upgrade,
+            &proposer,
+            id,
+            (Symbol::new(&env, "propose"), &new_wasm_hash),
+        );
+
+        events::emit_upgrade_proposed(&env, id, &proposer, &new_wasm_hash);
+        Ok(id)
+    }
+
+    /// Approve an upgrade proposal (signers only).
+    ///
+    /// Starts the upgrade timelock once `threshold` approvals are reached.
+    pub fn approve_upgrade(env: Env, signer: Address, proposal_id: u64) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        signer.require_auth();
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
         }
 
-        let batch_id = storage::increment_batch_id(&env);
-        let _estimated_gas = Self::estimate_batch_gas(&env, &operations);
+        let mut proposal = storage::get_upgrade_proposal(&env, proposal_id)?;
+        if proposal.status != UpgradeStatus::Pending {
+            return Err(VaultError::ProposalNotPending);
+        }
+        if Self::upgrade_expired(&env, &proposal) {
+            return Err(VaultError::ProposalExpired);
+        }
+        if proposal.approvals.contains(&signer) {
+            return Err(VaultError::AlreadyApproved);
+        }
 
-        let batch = BatchTransaction {
-            id: batch_id,
-            creator: creator.clone(),
-            operations: operations.clone(),
-            status: BatchStatus::Pending,
-            created_at: env.ledger().timestamp(),
-            memo,
-        };
+        proposal.approvals.push_back(signer.clone());
+        if Self::upgrade_approvals(&config, &proposal) >= config.threshold {
+            proposal.status = UpgradeStatus::Approved;
+            proposal.execution_after =
+                env.ledger().sequence() as u64 + UPGRADE_TIMELOCK.max(config.timelock_delay);
+        }
 
-        storage::set_batch(&env, &batch);
+        storage::set_upgrade_proposal(&env, &proposal);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Upgrade,
+            &signer,
+            proposal_id,
+            (Symbol::new(&env, "approve"), proposal.execution_after),
+        );
 
-        Ok(batch_id)
+        events::emit_upgrade_approved(&env, proposal_id, &signer, proposal.execution_after);
+        Ok(())
     }
 
-    /// Execute a batch transaction atomically
-    pub fn execute_batch(
+    /// Switch the vault to the approved WASM once the timelock has passed.
+    ///
+    /// Only approvals from current signers count, so the threshold is checked
+    /// again in case signers were removed since approving. The new code takes
+    /// effect after this invocation; call `migrate` next so stored data
+    /// matches the layout the new version expects.
+    pub fn execute_upgrade(
         env: Env,
         executor: Address,
-        batch_id: u64,
-    ) -> Result<BatchExecutionResult, VaultError> {
+        proposal_id: u64,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         executor.require_auth();
-
         let config = storage::get_config(&env)?;
-        let executor_role = storage::get_role(&env, &executor);
+        if !config.signers.contains(&executor) {
+            return Err(VaultError::NotASigner);
+        }
 
-        // Check authorization
-        if executor_role != Role::Admin && executor_role != Role::Treasurer {
-            return Err(VaultError::InsufficientRole);
+        let mut proposal = storage::get_upgrade_proposal(&env, proposal_id)?;
+        if proposal.status != UpgradeStatus::Approved
+            || Self::upgrade_approvals(&config, &proposal) < config.threshold
+        {
+            return Err(VaultError::ProposalNotApproved);
+        }
+        if (env.ledger().sequence() as u64) < proposal.execution_after {
+            return Err(VaultError::TimelockNotExpired);
+        }
+        if Self::upgrade_expired(&env, &proposal) {
+            return Err(VaultError::ProposalExpired);
         }
 
-        let mut batch = storage::get_batch(&env, batch_id)?;
+        proposal.status = UpgradeStatus::Executed;
+        storage::set_upgrade_proposal(&env, &proposal);
+        storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Upgrade,
+            &executor,
+            proposal_id,
+            (Symbol::new(&env, "execute"), &proposal.new_wasm_hash),
+        );
 
-        // Can only execute pending batches
-        if batch.status != BatchStatus::Pending {
+        events::emit_upgrade_executed(&env, proposal_id, &proposal.new_wasm_hash);
+
+        env.deployer()
+            .update_current_contract_wasm(proposal.new_wasm_hash);
+        Ok(())
+    }
+
+    /// Cancel a pending or approved upgrade.
+    ///
+    /// The proposer can withdraw its own upgrade while it is still pending.
+    /// Otherwise cancellation takes an Admin and goes through the admin
+    /// council.
+    pub fn cancel_upgrade(env: Env, caller: Address, proposal_id: u64) -> Result<(), VaultError> {
+        caller.require_auth();
+        let mut proposal = storage::get_upgrade_proposal(&env, proposal_id)?;
+        if proposal.status != UpgradeStatus::Pending && proposal.status != UpgradeStatus::Approved {
             return Err(VaultError::ProposalNotPending);
         }
+        if caller != proposal.proposer || proposal.status != UpgradeStatus::Pending {
+            if storage::get_role(&env, &caller) != Role::Admin {
+                return Err(VaultError::Unauthorized);
+            }
+            Self::require_council(
+                &env,
+                &caller,
+                "cancel_upgrade",
+                (proposal_id,).into_val(&env),
+            )?;
+        }
 
-        // Mark as executing
-        batch.status = BatchStatus::Executing;
-        storage::set_batch(&env, &batch);
+        proposal.status = UpgradeStatus::Cancelled;
+        storage::set_upgrade_proposal(&env, &proposal);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Upgrade,
+            &caller,
+            proposal_id,
+            Symbol::new(&env, "cancel"),
+        );
 
-        let mut rollback_state: Vec<(Address, i128)> = Vec::new(&env);
-        let mut executed_count: u64 = 0;
-        let mut success = true;
+        events::emit_upgrade_cancelled(&env, proposal_id, &caller);
+        Ok(())
+    }
 
-        // Execute operations sequentially
-        for op in batch.operations.iter() {
-            match Self::execute_batch_operation(&env, &op, &mut rollback_state, &config) {
-                Ok(_) => {
-                    executed_count += 1;
-                }
-                Err(err) => {
-                    success = false;
-                    let _error_code = match err {
-                        VaultError::ExceedsDailyLimit => Symbol::new(&env, "limit_exceeded"),
-                        VaultError::InsufficientRole => Symbol::new(&env, "insufficient_role"),
-                        VaultError::InvalidAmount => Symbol::new(&env, "invalid_amount"),
-                        VaultError::InsufficientBalance => {
-                            Symbol::new(&env, "insufficient_balance")
-                        }
-                        _ => Symbol::new(&env, "unknown_error"),
-                    };
-                    break;
-                }
-            }
-        }
+    /// Get an upgrade proposal by ID.
+    pub fn get_upgrade_proposal(env: Env, proposal_id: u64) -> Result<UpgradeProposal, VaultError> {
+        storage::get_upgrade_proposal(&env, proposal_id)
+    }
 
-        // Perform rollback if execution failed
-        if !success {
-            Self::rollback_batch(&env, &rollback_state)?;
-            batch.status = BatchStatus::RolledBack;
+    /// Approvals on an upgrade from addresses that are still signers.
+    fn upgrade_approvals(config: &Config, proposal: &UpgradeProposal) -> u32 {
+        proposal
+            .approvals
+            .iter()
+            .filter(|signer| config.signers.contains(signer))
+            .count() as u32
+    }
+
+    /// Whether an upgrade waited too long for approval or for execution.
+    fn upgrade_expired(env: &Env, proposal: &UpgradeProposal) -> bool {
+        let deadline = if proposal.status == UpgradeStatus::Approved {
+            proposal.execution_after
         } else {
-            batch.status = BatchStatus::Completed;
+            proposal.created_at
+        };
+        env.ledger().sequence() as u64 > deadline.saturating_add(UPGRADE_EXPIRY)
+    }
+
+    /// Data layout version the stored state has been migrated to.
+    pub fn get_contract_version(env: Env) -> u32 {
+        storage::get_contract_version(&env)
+    }
+
+    /// Bring stored data up to the layout this WASM expects (signers only).
+    ///
+    /// Runs each pending migration step once, in order, and records the new
+    /// version. Returns the version migrated to; fails with `AlreadyMigrated`
+    /// when there is nothing to do.
+    pub fn migrate(env: Env, caller: Address) -> Result<u32, VaultError> {
+        caller.require_auth();
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&caller) {
+            return Err(VaultError::NotASigner);
         }
 
-        storage::set_batch(&env, &batch);
+        let from_version = storage::get_contract_version(&env);
+        if from_version >= CONTRACT_VERSION {
+            return Err(VaultError::AlreadyMigrated);
+        }
+        for version in (from_version + 1)..=CONTRACT_VERSION {
+            Self::run_migration(&env, version)?;
+        }
 
-        // Store execution result
-        let result = BatchExecutionResult {
-            batch_id,
-            success,
-            successful_ops: executed_count as u32,
-            failed_ops: if success {
-                0
-            } else {
-                (batch.operations.len() as u32).saturating_sub(executed_count as u32)
-            },
-        };
+        storage::set_contract_version(&env, CONTRACT_VERSION);
+        storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Upgrade,
+            &caller,
+            0,
+            (Symbol::new(&env, "migrate"), from_version, CONTRACT_VERSION),
+        );
+
+        events::emit_contract_migrated(&env, from_version, CONTRACT_VERSION);
+        Ok(CONTRACT_VERSION)
+    }
+
+    /// Rewrite stored data from the layout of `version - 1` to `version`.
+    fn run_migration(env: &Env, version: u32) -> Result<(), VaultError> {
+        match version {
+            // Version 1 introduced versioning; existing data already matches it.
+            1 => Ok(()),
+            // Version 2 wraps records in versioned envelopes. The config is
+            // rewritten now; proposals are rewritten by `migrate_batch`.
+            2 => {
+                let rewritten = storage::migrate_config_entry(env)?;
+                let last_proposal_id = storage::get_next_proposal_id(env) - 1;
+                storage::set_migration_progress(
+                    env,
+                    &MigrationProgress {
+                        target_version: version,
+                        next_proposal_id: 1,
+                        last_proposal_id,
+                        next_audit_id: 1,
+                        last_audit_id: 0,
+                        migrated: rewritten as u64,
+                        legacy_audit_head: None,
+                        legacy_audit_hash: 0,
+                        complete: last_proposal_id == 0,
+                    },
+                );
+                Ok(())
+            }
+            // Version 3 replaces the 64-bit audit hashes with a SHA-256 chain.
+            // Every entry is re-linked from the start by `migrate_batch`, which
+            // first checks it against the old chain and its stored head.
+            3 => {
+                let mut progress = storage::get_migration_progress(env)
+                    .filter(|progress| !progress.complete)
+                    .unwrap_or(MigrationProgress {
+                        target_version: version,
+                        next_proposal_id: 1,
+                        last_proposal_id: 0,
+                        next_audit_id: 1,
+                        last_audit_id: 0,
+                        migrated: 0,
+                        legacy_audit_head: None,
+                        legacy_audit_hash: 0,
+                        complete: false,
+                    });
+                progress.target_version = version;
+                progress.next_audit_id = 1;
+                progress.legacy_audit_head = storage::get_legacy_audit_head(env);
+                progress.legacy_audit_hash = 0;
+                progress.last_audit_id = storage::get_next_audit_id(env) - 1;
+                progress.complete = false;
+                storage::set_migration_progress(env, &progress);
+                Ok(())
+            }
+            // Version 4 measures oracle staleness in seconds instead of ledgers.
+            // A config set by this WASM before `migrate` ran is already in
+            // seconds and is left alone.
+            4 => {
+                if storage::oracle_staleness_in_seconds(env) {
+                    return Ok(());
+                }
+                if let OptionalVaultOracleConfig::Some(mut oracle_cfg) =
+                    storage::get_oracle_config(env)
+                {
+                    oracle_cfg.max_staleness = oracle_cfg
+                        .max_staleness
+                        .saturating_mul(LEDGER_INTERVAL_SECONDS as u32);
+                    storage::set_oracle_config(env, &OptionalVaultOracleConfig::Some(oracle_cfg));
+                }
+                Ok(())
+            }
+            _ => Err(VaultError::AlreadyMigrated),
+        }
+    }
 
-        storage::set_batch_result(&env, &result);
+    /// Rewrite the next batch of old-format entries left by `migrate`.
+    ///
+    /// Can be called by anyone (keeper/bot) until the migration completes.
+    /// Inspects at most `limit` proposal IDs and audit entries in total (capped
+    /// at `MAX_MIGRATION_BATCH`; 0 uses the cap). Reads decode both layouts
+    /// meanwhile, so the vault stays usable while a migration is in progress.
+    /// Batches only re-encode existing data and add no audit entries; the
+    /// `migrate` call that started the migration is audited.
+    ///
+    /// Legacy audit entries are checked against the 64-bit chain they were
+    /// written with before being re-linked. A broken link, a hash mismatch or
+    /// a chain that does not end at the stored head fails with
+    /// `AuditChainBroken` and leaves the entries as they are.
+    pub fn migrate_batch(env: Env, limit: u32) -> Result<MigrationProgress, VaultError> {
+        let mut progress =
+            storage::get_migration_progress(&env).ok_or(VaultError::AlreadyMigrated)?;
+        if progress.complete {
+            return Err(VaultError::AlreadyMigrated);
+        }
 
-        if !success {
-            storage::set_rollback_state(&env, batch_id, &rollback_state);
+        let limit = if limit == 0 || limit > MAX_MIGRATION_BATCH {
+            MAX_MIGRATION_BATCH
+        } else {
+            limit
+        };
+        let mut processed = 0u32;
+        while processed < limit && progress.next_proposal_id <= progress.last_proposal_id {
+            if storage::migrate_proposal_entry(&env, progress.next_proposal_id)? {
+                progress.migrated += 1;
+            }
+            progress.next_proposal_id += 1;
+            processed += 1;
         }
 
-        // Emit event for batch execution
-        let ops_len = batch.operations.len();
-        let failed_count = ops_len.saturating_sub(executed_count as u32);
-        events::emit_batch_executed(&env, &executor, executed_count as u32, failed_count);
+        // Audit entries added while the chain is being re-linked are picked up too.
+        if progress.target_version >= 3 {
+            progress.last_audit_id = storage::get_next_audit_id(&env) - 1;
+        }
+        while processed < limit && progress.next_audit_id <= progress.last_audit_id {
+            if let Some(legacy) = storage::get_legacy_audit_entry(&env, progress.next_audit_id) {
+                if legacy.prev_hash != progress.legacy_audit_hash
+                    || storage::compute_legacy_audit_hash(&legacy) != legacy.hash
+                {
+                    return Err(VaultError::AuditChainBroken);
+                }
+                progress.legacy_audit_hash = legacy.hash;
+            }
+            storage::relink_audit_entry(&env, progress.next_audit_id)?;
+            progress.migrated += 1;
+            progress.next_audit_id += 1;
+            processed += 1;
+        }
+        progress.complete = progress.next_proposal_id > progress.last_proposal_id
+            && progress.next_audit_id > progress.last_audit_id;
+        if progress.complete {
+            if let Some(head) = progress.legacy_audit_head {
+                if head != progress.legacy_audit_hash {
+                    return Err(VaultError::AuditChainBroken);
+                }
+            }
+        }
 
-        Ok(result)
+        storage::set_migration_progress(&env, &progress);
+        storage::extend_instance_ttl(&env);
+        events::emit_migration_batch(&env, processed, progress.migrated, progress.complete);
+        Ok(progress)
     }
 
-    /// Retrieve batch execution result
-    pub fn get_batch_result(env: Env, batch_id: u64) -> Result<BatchExecutionResult, VaultError> {
-        storage::get_batch_result(&env, batch_id)
+    /// Progress of the most recent batched migration, if one was started.
+    pub fn get_migration_progress(env: Env) -> Option<MigrationProgress> {
+        storage::get_migration_progress(&env)
     }
 
-    /// Retrieve batch details
-    pub fn get_batch(env: Env, batch_id: u64) -> Result<BatchTransaction, VaultError> {
-        storage::get_batch(&env, batch_id)
-    }
+    // ========================================================================
+    // Advanced Permissions (Issue: feature/advanced-permissions)
+    // ========================================================================
 
-    /// Reverse a transfer recorded in an execution snapshot.
+    /// Maximum depth of a delegation chain to prevent unbounded traversal.
+    const MAX_DELEGATION_DEPTH: u32 = 3;
+
+    /// Grant a specific permission to an address.
     ///
-    /// Only callable by an admin. Reads the snapshot stored under `proposal_id`,
-    /// transfers the recorded amount back from the recipient to the vault, then
-    /// clears the snapshot. Returns `SnapshotNotFound` when no snapshot exists.
-    pub fn rollback_execution(
+    /// Only an Admin may call this. If the permission already exists it is
+    /// replaced (allowing expiry and scope updates). An optional expiry ledger
+    /// can be supplied; once that ledger is passed the grant is treated as
+    /// non-existent at check time. `scope` limits the grant to operations in
+    /// one token, up to an amount, to a recipient set or on proposals with
+    /// given tags; `PermissionScope::unrestricted` covers everything.
+    pub fn grant_permission(
         env: Env,
         admin: Address,
-        proposal_id: u64,
+        target: Address,
+        permission: types::Permission,
+        expires_at: Option<u64>,
+        scope: types::PermissionScope,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
-
+        if !storage::is_initialized(&env) {
+            return Err(VaultError::NotInitialized);
+        }
         if storage::get_role(&env, &admin) != Role::Admin {
-            return Err(VaultError::InsufficientRole);
+            return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "grant_permission",
+            (target.clone(), permission, expires_at, scope.clone()).into_val(&env),
+        )?;
+        Self::validate_permission_scope(&scope)?;
 
-        let snapshot = storage::get_execution_snapshot(&env, proposal_id)
-            .ok_or(VaultError::ProposalNotFound)?;
+        let mut grants = storage::get_permissions(&env, &target);
+        let mut replaced = false;
+        for i in 0..grants.len() {
+            if grants.get(i).unwrap().permission == permission {
+                grants.set(
+                    i,
+                    types::PermissionGrant {
+                        permission,
+                        granted_by: admin.clone(),
+                        granted_at: env.ledger().sequence() as u64,
+                        expires_at,
+                        scope: scope.clone(),
+                    },
+                );
+                replaced = true;
+                break;
+            }
+        }
+        if !replaced {
+            grants.push_back(types::PermissionGrant {
+                permission,
+                granted_by: admin.clone(),
+                granted_at: env.ledger().sequence() as u64,
+                expires_at,
+                scope: scope.clone(),
+            });
+        }
+        storage::set_permissions(&env, &target, grants);
+        storage::extend_instance_ttl(&env);
 
-        let proposal = &snapshot.proposal;
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdatePermissions,
+            &admin,
+            0,
+            (
+                Symbol::new(&env, "grant"),
+                &target,
+                permission as u32,
+                expires_at,
+                scope,
+            ),
+        );
 
-        // Reverse each transfer: send the recorded amount back to the proposer
-        // (the vault authorizes outgoing transfers from its own balance)
-        token::transfer(&env, &proposal.token, &proposal.proposer, proposal.amount);
+        events::emit_permission_granted(&env, &admin, &target, permission as u32);
+        Ok(())
+    }
 
-        storage::remove_execution_snapshot(&env, proposal_id);
+    /// Revoke a specific permission from an address.
+    ///
+    /// Only an Admin may call this. Returns [`VaultError::Unauthorized`]
+    /// if the address does not hold the specified permission.
+    pub fn revoke_permission(
+        env: Env,
+        admin: Address,
+        target: Address,
+        permission: types::Permission,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        if !storage::is_initialized(&env) {
+            return Err(VaultError::NotInitialized);
+        }
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        Self::require_council(
+            &env,
+            &admin,
+            "revoke_permission",
+            (target.clone(), permission).into_val(&env),
+        )?;
+
+        let grants = storage::get_permissions(&env, &target);
+        let mut updated = Vec::new(&env);
+        let mut found = false;
+        for p in grants.iter() {
+            if p.permission != permission {
+                updated.push_back(p);
+            } else {
+                found = true;
+            }
+        }
+        if !found {
+            return Err(VaultError::Unauthorized);
+        }
+        storage::set_permissions(&env, &target, updated);
+        storage::extend_instance_ttl(&env);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdatePermissions,
+            &admin,
+            0,
+            (Symbol::new(&env, "revoke"), &target, permission as u32),
+        );
+
+        events::emit_permission_revoked(&env, &admin, &target, permission as u32);
         Ok(())
     }
 
-    /// Validate a single batch operation
-    fn validate_batch_operation(_env: &Env, op: &BatchOperation) -> Result<(), VaultError> {
-        // Amount must be positive
-        if op.amount <= 0 {
-            return Err(VaultError::InvalidAmount);
+    /// Delegate a specific permission to another address temporarily.
+    ///
+    /// The delegator must hold the permission themselves (directly or via
+    /// role inheritance) and the delegation chain must not exceed
+    /// `MAX_DELEGATION_DEPTH`. The delegation expires at `expires_at` and may
+    /// be narrowed to `scope`.
+    pub fn delegate_permission(
+        env: Env,
+        delegator: Address,
+        delegatee: Address,
+        permission: types::Permission,
+        expires_at: u64,
+        scope: types::PermissionScope,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        delegator.require_auth();
+        if !storage::is_initialized(&env) {
+            return Err(VaultError::NotInitialized);
+        }
+
+        // Delegator must hold the permission without a cap or scope, which
+        // the delegation could not carry over.
+        let unrestricted = PermissionRequest::unrestricted(&env);
+        if !Self::check_permission(&env, &delegator, &permission, Some(&unrestricted)) {
+            return Err(VaultError::Unauthorized);
+        }
+        Self::validate_permission_scope(&scope)?;
+
+        // Guard against unbounded delegation chains.
+        let depth = Self::delegation_depth(&env, &delegator, &permission, 0);
+        if depth >= Self::MAX_DELEGATION_DEPTH {
+            return Err(VaultError::InsufficientRole);
         }
 
+        let delegation = types::DelegatedPermission {
+            permission,
+            delegator: delegator.clone(),
+            delegatee: delegatee.clone(),
+            granted_at: env.ledger().sequence() as u64,
+            expires_at,
+            scope: scope.clone(),
+        };
+        storage::set_delegated_permission(&env, &delegation);
+        storage::extend_instance_ttl(&env);
+
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdatePermissions,
+            &delegator,
+            0,
+            (
+                Symbol::new(&env, "delegate"),
+                &delegatee,
+                permission as u32,
+                expires_at,
+                scope,
+            ),
+        );
+
+        events::emit_permission_delegated(&env, &delegator, &delegatee, permission as u32);
         Ok(())
     }
 
-    /// Execute a single batch operation
-    fn execute_batch_operation(
-        env: &Env,
-        op: &BatchOperation,
-        rollback_state: &mut Vec<(Address, i128)>,
-        config: &Config,
+    /// Check if an address has a specific permission (returns bool for convenience).
+    ///
+    /// Scoped grants and capped custom roles count; operations still check
+    /// their own token, amount, recipients and tags against the scope.
+    pub fn has_permission(env: Env, addr: Address, permission: types::Permission) -> bool {
+        Self::check_permission(&env, &addr, &permission, None)
+    }
+
+    /// Entry-point version of the permission check that returns a Result.
+    ///
+    /// Returns `Ok(())` if the address holds a valid, non-expired permission
+    /// (directly or via delegation). Returns an error otherwise.
+    pub fn check_permission_entry(
+        env: Env,
+        addr: Address,
+        permission: types::Permission,
     ) -> Result<(), VaultError> {
-        // Get current day for cumulative tracking
-        let today = env.ledger().timestamp() / 86400; // seconds to days
+        if !storage::is_initialized(&env) {
+            return Err(VaultError::NotInitialized);
+        }
+        if Self::check_permission(&env, &addr, &permission, None) {
+            Ok(())
+        } else {
+            // Distinguish expired from simply absent.
+            let now = env.ledger().sequence() as u64;
+            let grants = storage::get_permissions(&env, &addr);
+            for g in grants.iter() {
+                if g.permission == permission && g.expires_at.is_some_and(|exp| now > exp) {
+                    return Err(VaultError::ProposalExpired);
+                }
+            }
+            Err(VaultError::Unauthorized)
+        }
+    }
 
-        // Check spending limits
-        let daily_spent = storage::get_daily_spent(env, today);
-        let new_daily_total = daily_spent + op.amount;
+    /// Internal permission check helper (bool, used by other contract functions).
+    ///
+    /// `request` describes what the operation touches; scoped grants and
+    /// delegations and capped custom roles only cover requests within their
+    /// limits. `None` asks whether the permission is held in any scope.
+    fn check_permission(
+        env: &Env,
+        addr: &Address,
+        permission: &types::Permission,
+        request: Option<&PermissionRequest>,
+    ) -> bool {
+        let current_ledger = env.ledger().sequence() as u64;
+
+        // Role-based inheritance.
+        let role = storage::get_role(env, addr);
+        if Self::role_has_permission(&role, permission) {
+            return true;
+        }
+
+        // Custom roles, each possibly capping the amount.
+        for name in storage::get_member_custom_roles(env, addr).iter() {
+            if let Ok(custom) = storage::get_custom_role(env, &name) {
+                let granted = custom.permissions.iter().any(|p| {
+                    p.permission == *permission
+                        && request.is_none_or(|r| r.within_cap(p.max_amount))
+                });
+                if granted {
+                    return true;
+                }
+            }
+        }
+
+        // Direct permission grants (expiry and scope enforced).
+        let permissions = storage::get_permissions(env, addr);
+        for p in permissions.iter() {
+            if p.permission == *permission {
+                if let Some(expires) = p.expires_at {
+                    if current_ledger >= expires {
+                        continue;
+                    }
+                }
+                if request.is_none_or(|r| r.within(&p.scope)) {
+                    return true;
+                }
+            }
+        }
 
-        if new_daily_total > config.daily_limit {
-            return Err(VaultError::ExceedsDailyLimit);
+        // Delegated permissions (expiry and scope enforced).
+        if let Ok(config) = storage::get_config(env) {
+            for signer in config.signers.iter() {
+                if let Some(delegation) =
+                    storage::get_delegated_permission(env, addr, &signer, *permission as u32)
+                {
+                    if current_ledger < delegation.expires_at
+                        && request.is_none_or(|r| r.within(&delegation.scope))
+                    {
+                        return true;
+                    }
+                }
+            }
         }
 
-        // Record rollback state
-        rollback_state.push_back((op.recipient.clone(), op.amount));
+        false
+    }
 
-        // Update spending limits
-        storage::add_daily_spent(env, today, op.amount);
+    /// Recursively count delegation hops above `addr` for a given permission.
+    fn delegation_depth(
+        env: &Env,
+        addr: &Address,
+        permission: &types::Permission,
+        depth: u32,
+    ) -> u32 {
+        if depth >= Self::MAX_DELEGATION_DEPTH {
+            return depth;
+        }
+        let config = match storage::get_config(env) {
+            Ok(c) => c,
+            Err(_) => return depth,
+        };
+        let now = env.ledger().sequence() as u64;
+        for signer in config.signers.iter() {
+            if let Some(dp) =
+                storage::get_delegated_permission(env, addr, &signer, *permission as u32)
+            {
+                if now <= dp.expires_at {
+                    return Self::delegation_depth(env, &signer, permission, depth + 1);
+                }
+            }
+        }
+        depth
+    }
 
-        Ok(())
+    /// Map role to inherited permissions.
+    fn role_has_permission(role: &Role, permission: &types::Permission) -> bool {
+        use types::Permission::*;
+        match role {
+            Role::Admin => true,
+            Role::Treasurer => matches!(
+                permission,
+                CreateProposal
+                    | ApproveProposal
+                    | ExecuteProposal
+                    | ViewMetrics
+                    | ManageRecurring
+                    | ManageEscrow
+                    | ManageSubscriptions
+            ),
+            Role::Member => matches!(permission, ViewMetrics),
+        }
     }
 
-    /// Rollback batch operations in reverse order
-    fn rollback_batch(
-        _env: &Env,
-        _rollback_state: &Vec<(Address, i128)>,
-    ) -> Result<(), VaultError> {
-        // In production, this would reverse the transfers
-        // For now, we track the state for audit purposes
-        // Audit trail is maintained via event emission and result storage
+    /// Reject scopes with a non-positive cap or oversized recipient/tag sets.
+    fn validate_permission_scope(scope: &types::PermissionScope) -> Result<(), VaultError> {
+        if scope.max_amount.is_some_and(|cap| cap <= 0)
+            || scope.recipients.len() > MAX_SCOPE_ENTRIES
+            || scope.tags.len() > MAX_SCOPE_ENTRIES
+        {
+            return Err(VaultError::InvalidPermissionScope);
+        }
         Ok(())
     }
 
-    /// Estimate gas cost for batch operations
-    fn estimate_batch_gas(_env: &Env, operations: &Vec<BatchOperation>) -> u64 {
-        // Base overhead: 100,000
-        // Per-operation cost: 50,000
-        const BASE_OVERHEAD: u64 = 100_000;
-        const PER_OP_COST: u64 = 50_000;
-
-        BASE_OVERHEAD + (operations.len() as u64 * PER_OP_COST)
+    /// Get all permissions for an address.
+    pub fn get_permissions(env: Env, addr: Address) -> Vec<types::PermissionGrant> {
+        storage::get_permissions(&env, &addr)
     }
 
     // ========================================================================
-    // Time-Weighted Voting
+    // Custom Roles (feature/custom-roles)
     // ========================================================================
 
-    /// Lock tokens to gain increased voting power
-    ///
-    /// Locks tokens for a specified duration, granting voting power multipliers:
-    /// - < 30 days: 1.0x
-    /// - 30-90 days: 1.5x
-    /// - 90-180 days: 2.0x
-    /// - 180-365 days: 3.0x
-    /// - > 365 days: 4.0x
+    /// Define a named role, or replace the permissions of an existing one.
     ///
-    /// # Arguments
-    /// * `owner` - Address locking the tokens
-    /// * `token` - Token contract address
-    /// * `amount` - Amount of tokens to lock
-    /// * `duration` - Lock duration in ledgers
-    pub fn lock_tokens(
+    /// Only an Admin may call this. Each permission may carry a per-operation
+    /// amount cap; holders get every permission of every role they hold, on
+    /// top of their fixed `Role` and direct grants.
+    pub fn define_custom_role(
         env: Env,
-        owner: Address,
-        token: Address,
-        amount: i128,
-        duration: u64,
+        admin: Address,
+        name: Symbol,
+        permissions: Vec<types::RolePermission>,
     ) -> Result<(), VaultError> {
-        owner.require_auth();
-
-        let config = storage::get_time_weighted_config(&env);
-
-        if !config.enabled {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "define_custom_role",
+            (name.clone(), permissions.clone()).into_val(&env),
+        )?;
 
-        if amount <= 0 {
-            return Err(VaultError::InvalidAmount);
+        if permissions.is_empty() {
+            return Err(VaultError::InvalidRolePermissions);
         }
-
-        if duration < config.min_lock_duration || duration > config.max_lock_duration {
-            return Err(VaultError::InvalidAmount);
+        for (i, entry) in permissions.iter().enumerate() {
+            if entry.max_amount.is_some_and(|cap| cap <= 0) {
+                return Err(VaultError::InvalidRolePermissions);
+            }
+            let duplicate = permissions
+                .iter()
+                .skip(i + 1)
+                .any(|other| other.permission == entry.permission);
+            if duplicate {
+                return Err(VaultError::InvalidRolePermissions);
+            }
         }
 
-        // Check if user already has an active lock
-        if let Some(existing_lock) = storage::get_token_lock(&env, &owner) {
-            if existing_lock.is_active {
-                return Err(VaultError::AlreadyApproved); // Reusing error for "already locked"
+        let previous = storage::get_custom_role(&env, &name).ok();
+        if previous.is_none() {
+            let mut names = storage::get_custom_role_names(&env);
+            if names.len() >= MAX_CUSTOM_ROLES {
+                return Err(VaultError::CustomRoleLimit);
             }
+            names.push_back(name.clone());
+            storage::set_custom_role_names(&env, &names);
         }
 
-        // Transfer tokens to vault
-        token::transfer_to_vault(&env, &token, &owner, amount);
-
-        let current_ledger = env.ledger().sequence() as u64;
-        let unlock_at = current_ledger + duration;
-        let power_multiplier_bps = types::TokenLock::calculate_multiplier(duration);
-
-        let lock = types::TokenLock {
-            owner: owner.clone(),
-            token: token.clone(),
-            amount,
-            locked_at: current_ledger,
-            duration,
-            unlock_at,
-            is_active: true,
-            power_multiplier_bps,
+        let role = types::CustomRole {
+            name: name.clone(),
+            permissions: permissions.clone(),
+            defined_by: admin.clone(),
+            defined_at: env.ledger().sequence() as u64,
         };
-
-        storage::set_token_lock(&env, &lock);
-        storage::set_total_locked(&env, &owner, amount);
+        storage::set_custom_role(&env, &role);
         storage::extend_instance_ttl(&env);
 
-        events::emit_tokens_locked(&env, &owner, amount, duration, power_multiplier_bps);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdatePermissions,
+            &admin,
+            0,
+            (
+                Symbol::new(&env, "role_define"),
+                &name,
+                previous.map(|role| role.permissions),
+                permissions.clone(),
+            ),
+        );
 
+        events::emit_custom_role_defined(&env, &name, &admin, permissions.len());
         Ok(())
     }
 
-    /// Extend an existing token lock duration
-    ///
-    /// Extends the lock duration, potentially increasing the voting power multiplier.
-    /// The new duration is added to the remaining time.
-    ///
-    /// # Arguments
-    /// * `owner` - Address that owns the lock
-    /// * `additional_duration` - Additional ledgers to add to the lock
-    pub fn extend_lock(
-        env: Env,
-        owner: Address,
-        additional_duration: u64,
-    ) -> Result<(), VaultError> {
-        owner.require_auth();
-
-        let config = storage::get_time_weighted_config(&env);
-
-        if !config.enabled {
+    /// Remove a custom role and take it away from every holder (Admin only).
+    pub fn remove_custom_role(env: Env, admin: Address, name: Symbol) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "remove_custom_role",
+            (name.clone(),).into_val(&env),
+        )?;
+        let role = storage::get_custom_role(&env, &name)?;
 
-        let mut lock = storage::get_token_lock(&env, &owner).ok_or(VaultError::ProposalNotFound)?;
-
-        if !lock.is_active {
-            return Err(VaultError::ProposalNotPending);
+        let members = storage::get_custom_role_members(&env, &name);
+        for member in members.iter() {
+            let mut held = storage::get_member_custom_roles(&env, &member);
+            if let Some(i) = held.first_index_of(&name) {
+                held.remove(i);
+            }
+            storage::set_member_custom_roles(&env, &member, &held);
         }
-
-        let current_ledger = env.ledger().sequence() as u64;
-
-        // Calculate new total duration from current time
-        let remaining = lock.unlock_at.saturating_sub(current_ledger);
-        let new_total_duration = remaining + additional_duration;
-
-        if new_total_duration > config.max_lock_duration {
-            return Err(VaultError::InvalidAmount);
+        let mut names = storage::get_custom_role_names(&env);
+        if let Some(i) = names.first_index_of(&name) {
+            names.remove(i);
         }
-
-        // Update lock
-        lock.unlock_at = current_ledger + new_total_duration;
-        lock.duration = new_total_duration;
-        lock.power_multiplier_bps = types::TokenLock::calculate_multiplier(new_total_duration);
-
-        storage::set_token_lock(&env, &lock);
+        storage::set_custom_role_names(&env, &names);
+        storage::remove_custom_role(&env, &name);
         storage::extend_instance_ttl(&env);
 
-        events::emit_lock_extended(&env, &owner, new_total_duration, lock.power_multiplier_bps);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdatePermissions,
+            &admin,
+            0,
+            (
+                Symbol::new(&env, "role_remove"),
+                &name,
+                role.permissions,
+                members,
+            ),
+        );
 
+        events::emit_custom_role_removed(&env, &name, &admin);
         Ok(())
     }
 
-    // ========================================================================
-    // Wallet Recovery (Issue: feature/wallet-recovery)
-    // ========================================================================
-
-    /// Update recovery configuration
-    pub fn set_recovery_config(
+    /// Give `member` a custom role (Admin only). An address may hold several.
+    pub fn assign_custom_role(
         env: Env,
         admin: Address,
-        config: RecoveryConfig,
+        member: Address,
+        name: Symbol,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
         if storage::get_role(&env, &admin) != Role::Admin {
-            return Err(VaultError::InsufficientRole);
-        }
-
-        let mut vault_config = storage::get_config(&env)?;
-        vault_config.recovery_config = config;
-        storage::set_config(&env, &vault_config);
-
-        events::emit_recovery_config_updated(&env, &admin);
-        Ok(())
-    }
-
-    /// Initiate a wallet recovery proposal
-    pub fn initiate_recovery(
-        env: Env,
-        caller: Address,
-        new_signers: Vec<Address>,
-        new_threshold: u32,
-    ) -> Result<u64, VaultError> {
-        caller.require_auth();
-
-        let config = storage::get_config(&env)?;
-        if !config.recovery_config.guardians.contains(&caller) {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "assign_custom_role",
+            (member.clone(), name.clone()).into_val(&env),
+        )?;
+        storage::get_custom_role(&env, &name)?;
 
-        // Validate new config
-        if new_signers.is_empty() {
-            return Err(VaultError::NoSigners);
-        }
-        if new_threshold < 1 {
-            return Err(VaultError::ThresholdTooLow);
-        }
-        if new_threshold > new_signers.len() {
-            return Err(VaultError::ThresholdTooHigh);
-        }
-
-        let id = storage::increment_recovery_id(&env);
-        let current_ledger = env.ledger().sequence() as u64;
-
-        let proposal = RecoveryProposal {
-            id,
-            new_signers,
-            new_threshold,
-            approvals: Vec::new(&env),
-            status: RecoveryStatus::Pending,
-            created_at: current_ledger,
-            execution_after: 0, // Set after approval threshold is met
-        };
-
-        storage::set_recovery_proposal(&env, &proposal);
-        events::emit_recovery_proposed(&env, id, new_threshold);
+        let mut held = storage::get_member_custom_roles(&env, &member);
+        if held.contains(&name) {
+            return Ok(());
+        }
+        let mut members = storage::get_custom_role_members(&env, &name);
+        if held.len() >= MAX_CUSTOM_ROLES_PER_ADDRESS || members.len() >= MAX_CUSTOM_ROLE_MEMBERS {
+            return Err(VaultError::CustomRoleLimit);
+        }
+        held.push_back(name.clone());
+        members.push_back(member.clone());
+        storage::set_member_custom_roles(&env, &member, &held);
+        storage::set_custom_role_members(&env, &name, &members);
+        storage::extend_instance_ttl(&env);
 
-        Ok(id)
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdatePermissions,
+            &admin,
+            0,
+            (Symbol::new(&env, "role_assign"), &name, &member),
+        );
+
+        events::emit_custom_role_assigned(&env, &name, &member);
+        Ok(())
     }
 
-    /// Approve a recovery proposal (guardians only)
-    pub fn approve_recovery(
+    /// Take a custom role away from `member` (Admin only).
+    ///
+    /// Returns [`VaultError::Unauthorized`] if the address does not hold it.
+    pub fn unassign_custom_role(
         env: Env,
-        guardian: Address,
-        proposal_id: u64,
+        admin: Address,
+        member: Address,
+        name: Symbol,
     ) -> Result<(), VaultError> {
-        guardian.require_auth();
-
-        let config = storage::get_config(&env)?;
-        if !config.recovery_config.guardians.contains(&guardian) {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "unassign_custom_role",
+            (member.clone(), name.clone()).into_val(&env),
+        )?;
 
-        let mut proposal = storage::get_recovery_proposal(&env, proposal_id)?;
-        if proposal.status != RecoveryStatus::Pending {
-            return Err(VaultError::ProposalNotPending);
-        }
-
-        if proposal.approvals.contains(&guardian) {
-            return Err(VaultError::AlreadyApproved);
-        }
-
-        proposal.approvals.push_back(guardian.clone());
-
-        let threshold = config.recovery_config.threshold;
-        if proposal.approvals.len() >= threshold {
-            proposal.status = RecoveryStatus::Approved;
-            proposal.execution_after =
-                env.ledger().sequence() as u64 + config.recovery_config.delay;
+        let mut held = storage::get_member_custom_roles(&env, &member);
+        let i = held.first_index_of(&name).ok_or(VaultError::Unauthorized)?;
+        held.remove(i);
+        let mut members = storage::get_custom_role_members(&env, &name);
+        if let Some(i) = members.first_index_of(&member) {
+            members.remove(i);
         }
+        storage::set_member_custom_roles(&env, &member, &held);
+        storage::set_custom_role_members(&env, &name, &members);
+        storage::extend_instance_ttl(&env);
 
-        storage::set_recovery_proposal(&env, &proposal);
-        events::emit_recovery_approved(&env, proposal_id, &guardian);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdatePermissions,
+            &admin,
+            0,
+            (Symbol::new(&env, "role_unassign"), &name, &member),
+        );
 
+        events::emit_custom_role_unassigned(&env, &name, &member);
         Ok(())
     }
 
-    /// Unlock tokens early with penalty
-    ///
-    /// Allows early unlock of tokens before the lock period expires.
-    /// A penalty is applied based on the configuration.
-    ///
-    /// # Arguments
-    /// * `owner` - Address that owns the lock
-    pub fn unlock_early(env: Env, owner: Address) -> Result<i128, VaultError> {
-        owner.require_auth();
+    /// Get a custom role by name.
+    pub fn get_custom_role(env: Env, name: Symbol) -> Result<types::CustomRole, VaultError> {
+        storage::get_custom_role(&env, &name)
+    }
 
-        let config = storage::get_time_weighted_config(&env);
+    /// Names of every defined custom role, in definition order.
+    pub fn list_custom_roles(env: Env) -> Vec<Symbol> {
+        storage::get_custom_role_names(&env)
+    }
 
-        if !config.enabled {
-            return Err(VaultError::Unauthorized);
-        }
+    /// Addresses holding a custom role.
+    pub fn get_custom_role_members(env: Env, name: Symbol) -> Vec<Address> {
+        storage::get_custom_role_members(&env, &name)
+    }
 
-        let mut lock = storage::get_token_lock(&env, &owner).ok_or(VaultError::ProposalNotFound)?;
+    /// Custom roles held by an address.
+    pub fn get_custom_roles_of(env: Env, addr: Address) -> Vec<Symbol> {
+        storage::get_member_custom_roles(&env, &addr)
+    }
 
-        if !lock.is_active {
-            return Err(VaultError::ProposalNotPending);
+    // ========================================================================
+    // Session Keys (feature/session-keys)
+    // ========================================================================
+
+    /// Register a restricted key that acts with `owner`'s permissions (signers only).
+    ///
+    /// The key may only call `allowed_entrypoints` (from
+    /// `SESSION_KEY_ENTRYPOINTS`), at most `max_uses` times, moving at most
+    /// `spend_cap` in total, until ledger `expires_at`. Registering a key the
+    /// owner already holds replaces it and resets its counters.
+    pub fn register_session_key(
+        env: Env,
+        owner: Address,
+        key: Address,
+        allowed_entrypoints: Vec<Symbol>,
+        spend_cap: i128,
+        expires_at: u64,
+        max_uses: u32,
+    ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        owner.require_auth();
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&owner) {
+            return Err(VaultError::NotASigner);
         }
 
         let current_ledger = env.ledger().sequence() as u64;
-
-        // Check if lock has naturally expired
-        if current_ledger >= lock.unlock_at {
-            return Self::unlock_tokens(env, owner);
+        if allowed_entrypoints.is_empty()
+            || spend_cap < 0
+            || max_uses == 0
+            || expires_at <= current_ledger
+            || key == owner
+            || config.signers.contains(&key)
+        {
+            return Err(VaultError::InvalidSessionKey);
+        }
+        for entrypoint in allowed_entrypoints.iter() {
+            let supported = SESSION_KEY_ENTRYPOINTS
+                .iter()
+                .any(|name| entrypoint == Symbol::new(&env, name));
+            if !supported {
+                return Err(VaultError::InvalidSessionKey);
+            }
         }
 
-        // Calculate penalty
-        let penalty_amount = (lock.amount * config.early_unlock_penalty_bps as i128) / 10_000;
-        let return_amount = lock.amount - penalty_amount;
-
-        // Transfer tokens back to owner (minus penalty)
-        token::transfer(&env, &lock.token, &owner, return_amount);
-
-        // Penalty goes to insurance pool
-        if penalty_amount > 0 {
-            storage::add_to_insurance_pool(&env, &lock.token, penalty_amount);
+        let mut keys = storage::get_owner_session_keys(&env, &owner);
+        match storage::get_session_key(&env, &key) {
+            Some(existing) if existing.owner != owner => {
+                return Err(VaultError::InvalidSessionKey);
+            }
+            Some(_) => {}
+            None => {
+                if keys.len() >= MAX_SESSION_KEYS_PER_OWNER {
+                    return Err(VaultError::InvalidSessionKey);
+                }
+                keys.push_back(key.clone());
+                storage::set_owner_session_keys(&env, &owner, &keys);
+            }
         }
 
-        // Deactivate lock
-        lock.is_active = false;
-        storage::set_token_lock(&env, &lock);
-        storage::set_total_locked(&env, &owner, 0);
+        storage::set_session_key(
+            &env,
+            &SessionKey {
+                key: key.clone(),
+                owner: owner.clone(),
+                allowed_entrypoints: allowed_entrypoints.clone(),
+                spend_cap,
+                spent: 0,
+                expires_at,
+                max_uses,
+                uses: 0,
+                created_at: current_ledger,
+            },
+        );
         storage::extend_instance_ttl(&env);
 
-        events::emit_early_unlock(&env, &owner, return_amount, penalty_amount);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdatePermissions,
+            &owner,
+            0,
+            (
+                Symbol::new(&env, "session_register"),
+                &key,
+                allowed_entrypoints,
+                spend_cap,
+                expires_at,
+                max_uses,
+            ),
+        );
 
-        Ok(return_amount)
+        events::emit_session_key_registered(&env, &owner, &key, expires_at);
+        Ok(())
     }
 
-    /// Unlock tokens after lock period expires
-    ///
-    /// Returns all locked tokens to the owner without penalty.
+    /// Revoke a session key immediately (its owner or an Admin).
     ///
-    /// # Arguments
-    /// * `owner` - Address that owns the lock
-    pub fn unlock_tokens(env: Env, owner: Address) -> Result<i128, VaultError> {
-        owner.require_auth();
-
-        let config = storage::get_time_weighted_config(&env);
-
-        if !config.enabled {
+    /// Not blocked by pauses, so a leaked key can always be shut off.
+    pub fn revoke_session_key(env: Env, caller: Address, key: Address) -> Result<(), VaultError> {
+        caller.require_auth();
+        let session = storage::get_session_key(&env, &key).ok_or(VaultError::SessionKeyNotFound)?;
+        if caller != session.owner && storage::get_role(&env, &caller) != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
 
-        let mut lock = storage::get_token_lock(&env, &owner).ok_or(VaultError::ProposalNotFound)?;
-
-        if !lock.is_active {
-            return Err(VaultError::ProposalNotPending);
-        }
-
-        let current_ledger = env.ledger().sequence() as u64;
-
-        // Check if lock period has expired
-        if current_ledger < lock.unlock_at {
-            return Err(VaultError::TimelockNotExpired);
+        storage::remove_session_key(&env, &key);
+        let mut keys = storage::get_owner_session_keys(&env, &session.owner);
+        if let Some(i) = keys.first_index_of(&key) {
+            keys.remove(i);
         }
-
-        let amount = lock.amount;
-
-        // Transfer tokens back to owner
-        token::transfer(&env, &lock.token, &owner, amount);
-
-        // Deactivate lock
-        lock.is_active = false;
-        storage::set_token_lock(&env, &lock);
-        storage::set_total_locked(&env, &owner, 0);
+        storage::set_owner_session_keys(&env, &session.owner, &keys);
         storage::extend_instance_ttl(&env);
 
-        events::emit_tokens_unlocked(&env, &owner, amount);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::UpdatePermissions,
+            &caller,
+            0,
+            (
+                Symbol::new(&env, "session_revoke"),
+                &key,
+                &session.owner,
+                session.uses,
+                session.spent,
+            ),
+        );
 
-        Ok(amount)
+        events::emit_session_key_revoked(&env, &key, &caller);
+        Ok(())
     }
 
-    /// Get token lock information for an address
-    pub fn get_token_lock(env: Env, owner: Address) -> Option<types::TokenLock> {
-        storage::get_token_lock(&env, &owner)
+    /// Get a session key, including its use count and amount spent.
+    pub fn get_session_key(env: Env, key: Address) -> Result<SessionKey, VaultError> {
+        storage::get_session_key(&env, &key).ok_or(VaultError::SessionKeyNotFound)
     }
 
-    /// Get voting power for an address
-    ///
-    /// Returns the current voting power including time-weighted multipliers
-    /// and decay if enabled.
-    pub fn get_voting_power(env: Env, owner: Address) -> i128 {
-        storage::calculate_voting_power(&env, &owner)
+    /// Session keys registered by a signer.
+    pub fn get_session_keys(env: Env, owner: Address) -> Vec<Address> {
+        storage::get_owner_session_keys(&env, &owner)
     }
 
-    /// Configure time-weighted voting system
+    /// Resolve whose permissions `caller` uses at `entrypoint`.
     ///
-    /// Admin only function to enable/disable and configure time-weighted voting.
-    pub fn set_time_weighted_config(
-        env: Env,
-        admin: Address,
-        config: types::TimeWeightedConfig,
-    ) -> Result<(), VaultError> {
-        admin.require_auth();
-
-        let role = storage::get_role(&env, &admin);
-        if role != Role::Admin {
-            return Err(VaultError::InsufficientRole);
+    /// A session key acts for its owner once its allow-list, expiry and use
+    /// count check out and the owner is still a signer; the use is counted.
+    /// Any other address acts for itself.
+    fn session_authority(
+        env: &Env,
+        caller: &Address,
+        entrypoint: &str,
+    ) -> Result<Address, VaultError> {
+        let Some(mut session) = storage::get_session_key(env, caller) else {
+            return Ok(caller.clone());
+        };
+        if !session
+            .allowed_entrypoints
+            .contains(Symbol::new(env, entrypoint))
+        {
+            return Err(VaultError::SessionKeyNotAllowed);
         }
+        if env.ledger().sequence() as u64 > session.expires_at {
+            return Err(VaultError::SessionKeyExpired);
+        }
+        if session.uses >= session.max_uses {
+            return Err(VaultError::SessionKeyExhausted);
+        }
+        if !storage::get_config(env)?.signers.contains(&session.owner) {
+            return Err(VaultError::Unauthorized);
+        }
+        session.uses += 1;
+        storage::set_session_key(env, &session);
+        Ok(session.owner)
+    }
 
-        storage::set_time_weighted_config(&env, &config);
-        storage::extend_instance_ttl(&env);
-
+    /// Count `amount` against `caller`'s spend cap if it is a session key.
+    fn charge_session_key(env: &Env, caller: &Address, amount: i128) -> Result<(), VaultError> {
+        if let Some(mut session) = storage::get_session_key(env, caller) {
+            let spent = session.spent.saturating_add(amount);
+            if spent > session.spend_cap {
+                return Err(VaultError::SessionKeyExhausted);
+            }
+            session.spent = spent;
+            storage::set_session_key(env, &session);
+        }
         Ok(())
     }
 
-    /// Get time-weighted voting configuration
-    pub fn get_time_weighted_config(env: Env) -> types::TimeWeightedConfig {
-        storage::get_time_weighted_config(&env)
+    /// Give back the use `session_authority` took from `caller` if it is a
+    /// session key.
+    fn release_session_use(env: &Env, caller: &Address) {
+        if let Some(mut session) = storage::get_session_key(env, caller) {
+            session.uses = session.uses.saturating_sub(1);
+            storage::set_session_key(env, &session);
+        }
     }
 
     // ========================================================================
-    // Recovery Proposals
+    // Config Timelock (feature/config-timelock)
     // ========================================================================
 
-    /// Execute an approved recovery proposal
-    pub fn execute_recovery(env: Env, proposal_id: u64) -> Result<(), VaultError> {
-        let mut proposal = storage::get_recovery_proposal(&env, proposal_id)?;
+    /// Set how many ledgers configuration changes wait before taking effect
+    /// (Admin only). With a delay, `update_limits`, `set_list_mode`,
+    /// `set_fee_structure`, `update_oracle_config` / `set_oracle_config` and
+    /// this function queue a change instead of applying it; 0 applies
+    /// immediately. Lowering the delay waits out the current one.
+    pub fn set_config_delay(env: Env, admin: Address, delay: u64) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        Self::require_council(&env, &admin, "set_config_delay", (delay,).into_val(&env))?;
+        if delay > MAX_CONFIG_DELAY_LEDGERS {
+            return Err(VaultError::InvalidAmount);
+        }
 
-        if proposal.status != RecoveryStatus::Approved {
-            return Err(VaultError::ProposalNotApproved);
+        if Self::defer_config_change(&env, &admin, "set_config_delay", (delay,).into_val(&env))? {
+            return Ok(());
         }
+        Self::apply_config_delay(&env, &admin, delay)
+    }
 
-        let current_ledger = env.ledger().sequence() as u64;
-        if current_ledger < proposal.execution_after {
+    /// Current config timelock delay in ledgers.
+    pub fn get_config_delay(env: Env) -> u64 {
+        storage::get_config_delay(&env)
+    }
+
+    /// Apply a queued configuration change once its delay has passed.
+    ///
+    /// Anyone may apply; the change takes effect on behalf of the admin that
+    /// queued it, who must still hold the Admin role.
+    pub fn apply_config_change(env: Env, caller: Address, id: u64) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        caller.require_auth();
+
+        let mut change = storage::get_config_change(&env, id)?;
+        if change.status != ConfigChangeStatus::Pending {
+            return Err(VaultError::ConfigChangeNotPending);
+        }
+        if (env.ledger().sequence() as u64) < change.eta {
             return Err(VaultError::TimelockNotExpired);
         }
-
-        // Apply new configuration
-        let mut config = storage::get_config(&env)?;
-        config.signers = proposal.new_signers.clone();
-        config.threshold = proposal.new_threshold;
-        // Reset quorum and other fields to safe defaults if they were invalid for new signers
-        if config.quorum > config.signers.len() {
-            config.quorum = config.signers.len();
+        if storage::get_role(&env, &change.proposer) != Role::Admin {
+            return Err(VaultError::Unauthorized);
         }
 
-        storage::set_config(&env, &config);
+        change.status = ConfigChangeStatus::Applied;
+        storage::set_config_change(&env, &change);
+        Self::remove_pending_config_change(&env, id);
+        Self::dispatch_config_change(&env, &change)?;
+        storage::extend_instance_ttl(&env);
 
-        proposal.status = RecoveryStatus::Executed;
-        storage::set_recovery_proposal(&env, &proposal);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::ConfigTimelock,
+            &caller,
+            id,
+            (Symbol::new(&env, "apply"), change.action),
+        );
 
-        events::emit_recovery_executed(&env, proposal_id);
-        events::emit_config_updated(&env, &env.current_contract_address());
+        events::emit_config_change_applied(&env, id, &caller);
+        Ok(())
+    }
+
+    /// Cancel a pending configuration change (signers and pause guardians).
+    ///
+    /// Not blocked by an emergency pause.
+    pub fn cancel_config_change(env: Env, caller: Address, id: u64) -> Result<(), VaultError> {
+        caller.require_auth();
+        let config = storage::get_config(&env)?;
+        let is_guardian = storage::get_pause_config(&env)
+            .map(|pause| pause.guardians.contains(&caller))
+            .unwrap_or(false);
+        if !config.signers.contains(&caller) && !is_guardian {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut change = storage::get_config_change(&env, id)?;
+        if change.status != ConfigChangeStatus::Pending {
+            return Err(VaultError::ConfigChangeNotPending);
+        }
+        change.status = ConfigChangeStatus::Cancelled;
+        storage::set_config_change(&env, &change);
+        Self::remove_pending_config_change(&env, id);
+        storage::extend_instance_ttl(&env);
+
+        storage::create_audit_entry(
+            &env,
+            AuditAction::ConfigTimelock,
+            &caller,
+            id,
+            (Symbol::new(&env, "cancel"), change.action),
+        );
 
+        events::emit_config_change_cancelled(&env, id, &caller);
         Ok(())
     }
 
-    /// Cancel a recovery proposal (admins only)
-    pub fn cancel_recovery(env: Env, admin: Address, proposal_id: u64) -> Result<(), VaultError> {
-        admin.require_auth();
-        if storage::get_role(&env, &admin) != Role::Admin {
-            return Err(VaultError::InsufficientRole);
+    /// Get a queued configuration change by ID.
+    pub fn get_config_change(env: Env, id: u64) -> Result<ConfigChange, VaultError> {
+        storage::get_config_change(&env, id)
+    }
+
+    /// Configuration changes still waiting to be applied or cancelled, oldest
+    /// first.
+    pub fn get_pending_config_changes(env: Env) -> Vec<ConfigChange> {
+        let mut changes = Vec::new(&env);
+        for id in storage::get_pending_config_changes(&env).iter() {
+            if let Ok(change) = storage::get_config_change(&env, id) {
+                changes.push_back(change);
+            }
         }
+        changes
+    }
 
-        let mut proposal = storage::get_recovery_proposal(&env, proposal_id)?;
-        if proposal.status != RecoveryStatus::Pending && proposal.status != RecoveryStatus::Approved
-        {
-            return Err(VaultError::ProposalNotPending);
+    /// Store the config timelock delay; also used by the config timelock.
+    fn apply_config_delay(env: &Env, admin: &Address, delay: u64) -> Result<(), VaultError> {
+        let previous = storage::get_config_delay(env);
+        storage::set_config_delay(env, delay);
+        storage::extend_instance_ttl(env);
+        storage::create_audit_entry(
+            env,
+            AuditAction::ConfigTimelock,
+            admin,
+            0,
+            (Symbol::new(env, "delay"), previous, delay),
+        );
+        events::emit_config_updated(env, admin);
+        Ok(())
+    }
+
+    /// Queue `action` behind the config timelock if a delay is set. Returns
+    /// whether the change was queued; if not, the caller applies it now.
+    fn defer_config_change(
+        env: &Env,
+        admin: &Address,
+        action: &str,
+        args: Vec<Val>,
+    ) -> Result<bool, VaultError> {
+        let delay = storage::get_config_delay(env);
+        if delay == 0 {
+            return Ok(false);
+        }
+        let mut pending = storage::get_pending_config_changes(env);
+        if pending.len() >= MAX_PENDING_CONFIG_CHANGES {
+            return Err(VaultError::ConfigChangeLimit);
         }
 
-        proposal.status = RecoveryStatus::Cancelled;
-        storage::set_recovery_proposal(&env, &proposal);
+        let id = storage::increment_config_change_id(env);
+        let current_ledger = env.ledger().sequence() as u64;
+        let change = ConfigChange {
+            id,
+            action: Symbol::new(env, action),
+            args,
+            proposer: admin.clone(),
+            status: ConfigChangeStatus::Pending,
+            queued_at: current_ledger,
+            eta: current_ledger + delay,
+        };
+        storage::set_config_change(env, &change);
+        pending.push_back(id);
+        storage::set_pending_config_changes(env, &pending);
+        storage::extend_instance_ttl(env);
 
-        events::emit_recovery_cancelled(&env, proposal_id, &admin);
+        storage::create_audit_entry(
+            env,
+            AuditAction::ConfigTimelock,
+            admin,
+            id,
+            (Symbol::new(env, "queue"), change.action.clone(), change.eta),
+        );
+        events::emit_config_change_queued(env, id, &change.action, admin, change.eta);
+        Ok(true)
+    }
 
-        Ok(())
+    fn remove_pending_config_change(env: &Env, id: u64) {
+        let mut pending = storage::get_pending_config_changes(env);
+        if let Some(index) = pending.first_index_of(id) {
+            pending.remove(index);
+            storage::set_pending_config_changes(env, &pending);
+        }
     }
 
-    /// Get recovery configuration
-    pub fn get_recovery_config(env: Env) -> Result<RecoveryConfig, VaultError> {
-        let config = storage::get_config(&env)?;
-        Ok(config.recovery_config)
+    /// Run the apply step of the entrypoint that queued `change`.
+    fn dispatch_config_change(env: &Env, change: &ConfigChange) -> Result<(), VaultError> {
+        let admin = &change.proposer;
+        let args = &change.args;
+        let action = &change.action;
+        if *action == Symbol::new(env, "update_limits") {
+            Self::apply_limits(
+                env,
+                admin,
+                Self::config_arg(env, args, 0)?,
+                Self::config_arg(env, args, 1)?,
+                Self::config_arg(env, args, 2)?,
+            )
+        } else if *action == Symbol::new(env, "set_list_mode") {
+            Self::apply_list_mode(env, admin, Self::config_arg(env, args, 0)?)
+        } else if *action == Symbol::new(env, "set_fee_structure") {
+            Self::apply_fee_structure(env, admin, Self::config_arg(env, args, 0)?)
+        } else if *action == Symbol::new(env, "update_oracle_config") {
+            Self::apply_oracle_config(env, admin, Self::config_arg(env, args, 0)?)
+        } else if *action == Symbol::new(env, "set_config_delay") {
+            Self::apply_config_delay(env, admin, Self::config_arg(env, args, 0)?)
+        } else {
+            Err(VaultError::UnknownSchema)
+        }
     }
 
-    /// Get recovery proposal details
-    pub fn get_recovery_proposal(env: Env, id: u64) -> Result<RecoveryProposal, VaultError> {
-        storage::get_recovery_proposal(&env, id)
+    fn config_arg<T: TryFromVal<Env, Val>>(
+        env: &Env,
+        args: &Vec<Val>,
+        index: u32,
+    ) -> Result<T, VaultError> {
+        args.get(index)
+            .and_then(|arg| T::try_from_val(env, &arg).ok())
+            .ok_or(VaultError::UnknownSchema)
     }
 
     // ========================================================================
-    // Advanced Permissions (Issue: feature/advanced-permissions)
+    // Admin Council (feature/admin-council)
     // ========================================================================
 
-    /// Maximum depth of a delegation chain to prevent unbounded traversal.
-    const MAX_DELEGATION_DEPTH: u32 = 3;
-
-    /// Grant a specific permission to an address.
-    ///
-    /// Only an Admin may call this. If the permission already exists it is
-    /// replaced (allowing expiry updates). An optional expiry ledger can be
-    /// supplied; once that ledger is passed the grant is treated as
-    /// non-existent at check time.
-    pub fn grant_permission(
+    /// Set how many admins must confirm a council action, and which council
+    /// actions any single admin may still take (Admin only; itself a council
+    /// action once the threshold is above 1).
+    pub fn set_admin_council(
         env: Env,
         admin: Address,
-        target: Address,
-        permission: types::Permission,
-        expires_at: Option<u64>,
+        threshold: u32,
+        single_admin_actions: Vec<Symbol>,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
-        if !storage::is_initialized(&env) {
-            return Err(VaultError::NotInitialized);
-        }
         if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_admin_council",
+            (threshold, single_admin_actions.clone()).into_val(&env),
+        )?;
 
-        let mut grants = storage::get_permissions(&env, &target);
-        let mut replaced = false;
-        for i in 0..grants.len() {
-            if grants.get(i).unwrap().permission == permission {
-                grants.set(
-                    i,
-                    types::PermissionGrant {
-                        permission,
-                        granted_by: admin.clone(),
-                        granted_at: env.ledger().sequence() as u64,
-                        expires_at,
-                    },
-                );
-                replaced = true;
-                break;
-            }
+        if threshold == 0 || threshold > storage::count_admins(&env) {
+            return Err(VaultError::InvalidAdminCouncil);
         }
-        if !replaced {
-            grants.push_back(types::PermissionGrant {
-                permission,
-                granted_by: admin.clone(),
-                granted_at: env.ledger().sequence() as u64,
-                expires_at,
-            });
+        for action in single_admin_actions.iter() {
+            if !Self::is_council_action(&env, &action)
+                || action == Symbol::new(&env, "set_admin_council")
+            {
+                return Err(VaultError::InvalidAdminCouncil);
+            }
         }
-        storage::set_permissions(&env, &target, grants);
+
+        let previous = storage::get_admin_council(&env);
+        storage::set_admin_council(
+            &env,
+            &AdminCouncil {
+                threshold,
+                single_admin_actions: single_admin_actions.clone(),
+            },
+        );
         storage::extend_instance_ttl(&env);
 
-        events::emit_permission_granted(&env, &admin, &target, permission as u32);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::AdminCouncil,
+            &admin,
+            0,
+            (
+                Symbol::new(&env, "council"),
+                previous.threshold,
+                threshold,
+                single_admin_actions,
+            ),
+        );
+
+        events::emit_admin_council_updated(&env, &admin, threshold);
         Ok(())
     }
 
-    /// Revoke a specific permission from an address.
+    /// Queue a council action for confirmation by other admins (Admin only).
     ///
-    /// Only an Admin may call this. Returns [`VaultError::Unauthorized`]
-    /// if the address does not hold the specified permission.
-    pub fn revoke_permission(
+    /// `args` are the exact arguments the action will be called with,
+    /// excluding the admin; they are stored on the action so confirmers can
+    /// check what they approve. The queuing admin counts as the first
+    /// confirmation. Once confirmations reach the council threshold, any
+    /// admin makes the call itself.
+    pub fn queue_admin_action(
         env: Env,
         admin: Address,
-        target: Address,
-        permission: types::Permission,
-    ) -> Result<(), VaultError> {
+        action: Symbol,
+        args: Vec<Val>,
+    ) -> Result<u64, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
-        if !storage::is_initialized(&env) {
-            return Err(VaultError::NotInitialized);
-        }
         if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        if !Self::is_council_action(&env, &action) {
+            return Err(VaultError::InvalidAdminCouncil);
+        }
 
-        let grants = storage::get_permissions(&env, &target);
-        let mut updated = Vec::new(&env);
-        let mut found = false;
-        for p in grants.iter() {
-            if p.permission != permission {
-                updated.push_back(p);
-            } else {
-                found = true;
+        let call_hash = storage::admin_call_hash(&env, &action, &args);
+        let current_ledger = env.ledger().sequence() as u64;
+        if let Some(existing) = storage::get_pending_admin_action_id(&env, &call_hash) {
+            if storage::get_admin_action(&env, existing)?.expires_at >= current_ledger {
+                return Err(VaultError::AlreadyApproved);
             }
         }
-        if !found {
-            return Err(VaultError::Unauthorized);
-        }
-        storage::set_permissions(&env, &target, updated);
+
+        let id = storage::increment_admin_action_id(&env);
+        let queued = AdminAction {
+            id,
+            action: action.clone(),
+            args,
+            call_hash: call_hash.clone(),
+            proposer: admin.clone(),
+            confirmations: Vec::from_array(&env, [admin.clone()]),
+            status: AdminActionStatus::Pending,
+            created_at: current_ledger,
+            expires_at: current_ledger + ADMIN_ACTION_EXPIRY_LEDGERS,
+        };
+        storage::set_admin_action(&env, &queued);
+        storage::set_pending_admin_action_id(&env, &call_hash, Some(id));
         storage::extend_instance_ttl(&env);
 
-        events::emit_permission_revoked(&env, &admin, &target, permission as u32);
-        Ok(())
-    }
+        storage::create_audit_entry(
+            &env,
+            AuditAction::AdminCouncil,
+            &admin,
+            id,
+            (Symbol::new(&env, "queue"), &action, call_hash),
+        );
 
-    /// Delegate a specific permission to another address temporarily.
-    ///
-    /// The delegator must hold the permission themselves (directly or via
-    /// role inheritance) and the delegation chain must not exceed
-    /// `MAX_DELEGATION_DEPTH`. The delegation expires at `expires_at`.
-    pub fn delegate_permission(
-        env: Env,
-        delegator: Address,
-        delegatee: Address,
-        permission: types::Permission,
-        expires_at: u64,
-    ) -> Result<(), VaultError> {
-        delegator.require_auth();
-        if !storage::is_initialized(&env) {
-            return Err(VaultError::NotInitialized);
-        }
+        events::emit_admin_action_queued(&env, id, &action, &admin);
+        Ok(id)
+    }
 
-        // Delegator must hold the permission.
-        if !Self::check_permission(&env, &delegator, &permission) {
+    /// Confirm a queued council action (Admin only). Returns the number of
+    /// confirmations so far.
+    pub fn confirm_admin_action(env: Env, admin: Address, id: u64) -> Result<u32, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
 
-        // Guard against unbounded delegation chains.
-        let depth = Self::delegation_depth(&env, &delegator, &permission, 0);
-        if depth >= Self::MAX_DELEGATION_DEPTH {
-            return Err(VaultError::InsufficientRole);
+        let mut queued = storage::get_admin_action(&env, id)?;
+        if queued.status != AdminActionStatus::Pending
+            || env.ledger().sequence() as u64 > queued.expires_at
+        {
+            return Err(VaultError::AdminActionExpired);
         }
-
-        let delegation = types::DelegatedPermission {
-            permission,
-            delegator: delegator.clone(),
-            delegatee: delegatee.clone(),
-            granted_at: env.ledger().sequence() as u64,
-            expires_at,
-        };
-        storage::set_delegated_permission(&env, &delegation);
+        if queued.confirmations.contains(&admin) {
+            return Err(VaultError::AlreadyApproved);
+        }
+        queued.confirmations.push_back(admin.clone());
+        storage::set_admin_action(&env, &queued);
         storage::extend_instance_ttl(&env);
 
-        events::emit_permission_delegated(&env, &delegator, &delegatee, permission as u32);
-        Ok(())
-    }
+        storage::create_audit_entry(
+            &env,
+            AuditAction::AdminCouncil,
+            &admin,
+            id,
+            (Symbol::new(&env, "confirm"), &queued.action),
+        );
 
-    /// Check if an address has a specific permission (returns bool for convenience).
-    pub fn has_permission(env: Env, addr: Address, permission: types::Permission) -> bool {
-        Self::check_permission(&env, &addr, &permission)
+        let confirmations = queued.confirmations.len();
+        events::emit_admin_action_confirmed(&env, id, &admin, confirmations);
+        Ok(confirmations)
     }
 
-    /// Entry-point version of the permission check that returns a Result.
-    ///
-    /// Returns `Ok(())` if the address holds a valid, non-expired permission
-    /// (directly or via delegation). Returns an error otherwise.
-    pub fn check_permission_entry(
-        env: Env,
-        addr: Address,
-        permission: types::Permission,
-    ) -> Result<(), VaultError> {
-        if !storage::is_initialized(&env) {
-            return Err(VaultError::NotInitialized);
+    /// Cancel a pending council action (any Admin).
+    pub fn cancel_admin_action(env: Env, admin: Address, id: u64) -> Result<(), VaultError> {
+        admin.require_auth();
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::Unauthorized);
         }
-        if Self::check_permission(&env, &addr, &permission) {
-            Ok(())
-        } else {
-            // Distinguish expired from simply absent.
-            let now = env.ledger().sequence() as u64;
-            let grants = storage::get_permissions(&env, &addr);
-            for g in grants.iter() {
-                if g.permission == permission && g.expires_at.is_some_and(|exp| now > exp) {
-                    return Err(VaultError::ProposalExpired);
-                }
-            }
-            Err(VaultError::Unauthorized)
+
+        let mut queued = storage::get_admin_action(&env, id)?;
+        if queued.status != AdminActionStatus::Pending {
+            return Err(VaultError::AdminActionExpired);
         }
-    }
+        queued.status = AdminActionStatus::Cancelled;
+        storage::set_admin_action(&env, &queued);
+        storage::set_pending_admin_action_id(&env, &queued.call_hash, None);
+        storage::extend_instance_ttl(&env);
 
-    /// Internal permission check helper (bool, used by other contract functions).
-    fn check_permission(env: &Env, addr: &Address, permission: &types::Permission) -> bool {
-        let current_ledger = env.ledger().sequence() as u64;
+        storage::create_audit_entry(
+            &env,
+            AuditAction::AdminCouncil,
+            &admin,
+            id,
+            (Symbol::new(&env, "cancel"), &queued.action),
+        );
 
-        // Role-based inheritance.
-        let role = storage::get_role(env, addr);
-        if Self::role_has_permission(&role, permission) {
-            return true;
-        }
+        events::emit_admin_action_cancelled(&env, id, &admin);
+        Ok(())
+    }
 
-        // Direct permission grants (expiry enforced).
-        let permissions = storage::get_permissions(env, addr);
-        for p in permissions.iter() {
-            if p.permission == *permission {
-                if let Some(expires) = p.expires_at {
-                    if current_ledger >= expires {
-                        continue;
-                    }
-                }
-                return true;
-            }
-        }
+    /// Current admin council; a threshold of 1 means every admin acts alone.
+    pub fn get_admin_council(env: Env) -> AdminCouncil {
+        storage::get_admin_council(&env)
+    }
 
-        // Delegated permissions (expiry enforced).
-        if let Ok(config) = storage::get_config(env) {
-            for signer in config.signers.iter() {
-                if let Some(delegation) =
-                    storage::get_delegated_permission(env, addr, &signer, *permission as u32)
-                {
-                    if current_ledger < delegation.expires_at {
-                        return true;
-                    }
-                }
-            }
-        }
+    /// Get a queued council action by ID.
+    pub fn get_admin_action(env: Env, id: u64) -> Result<AdminAction, VaultError> {
+        storage::get_admin_action(&env, id)
+    }
 
-        false
+    /// Hash identifying a council call: `action` with `args`, the entrypoint's
+    /// arguments after the admin, in order.
+    pub fn admin_action_hash(env: Env, action: Symbol, args: Vec<Val>) -> BytesN<32> {
+        storage::admin_call_hash(&env, &action, &args)
     }
 
-    /// Recursively count delegation hops above `addr` for a given permission.
-    fn delegation_depth(
+    fn is_council_action(env: &Env, action: &Symbol) -> bool {
+        COUNCIL_ACTIONS
+            .iter()
+            .any(|name| *action == Symbol::new(env, name))
+    }
+
+    /// Let a council action through: immediately when the council threshold
+    /// is 1 or the action is exempt, otherwise only if a queued action for
+    /// exactly this call has enough confirmations from current admins. The
+    /// queued action is consumed, so each confirmation authorizes one call.
+    fn require_council(
         env: &Env,
-        addr: &Address,
-        permission: &types::Permission,
-        depth: u32,
-    ) -> u32 {
-        if depth >= Self::MAX_DELEGATION_DEPTH {
-            return depth;
+        admin: &Address,
+        action: &str,
+        args: Vec<Val>,
+    ) -> Result<(), VaultError> {
+        if !COUNCIL_ACTIONS.contains(&action) {
+            return Err(VaultError::InvalidAdminCouncil);
         }
-        let config = match storage::get_config(env) {
-            Ok(c) => c,
-            Err(_) => return depth,
-        };
-        let now = env.ledger().sequence() as u64;
-        for signer in config.signers.iter() {
-            if let Some(dp) =
-                storage::get_delegated_permission(env, addr, &signer, *permission as u32)
-            {
-                if now <= dp.expires_at {
-                    return Self::delegation_depth(env, &signer, permission, depth + 1);
-                }
-            }
+        let council = storage::get_admin_council(env);
+        let action = Symbol::new(env, action);
+        if council.threshold <= 1 || council.single_admin_actions.contains(&action) {
+            return Ok(());
         }
-        depth
-    }
 
-    /// Map role to inherited permissions.
-    fn role_has_permission(role: &Role, permission: &types::Permission) -> bool {
-        use types::Permission::*;
-        match role {
-            Role::Admin => true,
-            Role::Treasurer => matches!(
-                permission,
-                CreateProposal
-                    | ApproveProposal
-                    | ExecuteProposal
-                    | ViewMetrics
-                    | ManageRecurring
-                    | ManageEscrow
-                    | ManageSubscriptions
-            ),
-            Role::Member => matches!(permission, ViewMetrics),
+        let call_hash = storage::admin_call_hash(env, &action, &args);
+        let id = storage::get_pending_admin_action_id(env, &call_hash)
+            .ok_or(VaultError::AdminActionNotConfirmed)?;
+        let mut queued = storage::get_admin_action(env, id)?;
+        if queued.action != action || queued.args != args {
+            return Err(VaultError::AdminActionNotConfirmed);
+        }
+        if env.ledger().sequence() as u64 > queued.expires_at {
+            return Err(VaultError::AdminActionExpired);
+        }
+        let confirmations = queued
+            .confirmations
+            .iter()
+            .filter(|addr| storage::get_role(env, addr) == Role::Admin)
+            .count() as u32;
+        if confirmations < council.threshold {
+            return Err(VaultError::AdminActionNotConfirmed);
         }
-    }
 
-    /// Get all permissions for an address.
-    pub fn get_permissions(env: Env, addr: Address) -> Vec<types::PermissionGrant> {
-        storage::get_permissions(&env, &addr)
+        queued.status = AdminActionStatus::Executed;
+        storage::set_admin_action(env, &queued);
+        storage::set_pending_admin_action_id(env, &call_hash, None);
+        storage::create_audit_entry(
+            env,
+            AuditAction::AdminCouncil,
+            admin,
+            id,
+            (Symbol::new(env, "execute"), action),
+        );
+        events::emit_admin_action_executed(env, id, admin);
+        Ok(())
     }
 
     // ========================================================================
@@ -6905,7 +12284,8 @@ impl VaultDAO {
     ///
     /// # Arguments
     /// * `env` - Contract environment
-    /// * `caller` - Address executing the proposal
+    /// * `caller` - Address executing the proposal; must hold `ExecuteProposal`
+    ///   covering it, or be a session key whose owner does
     /// * `proposal_id` - ID of the proposal to execute
     ///
     /// # Returns
@@ -6915,6 +12295,7 @@ impl VaultDAO {
         caller: Address,
         proposal_id: u64,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
         caller.require_auth();
 
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
@@ -6931,6 +12312,19 @@ impl VaultDAO {
             return Err(VaultError::SchedulingError);
         }
 
+        // Caller, or the signer behind its session key, must hold
+        // ExecuteProposal covering this proposal
+        let authority = Self::session_authority(&env, &caller, "execute_scheduled_proposal")?;
+        let request = PermissionRequest::proposal(&env, &proposal);
+        if !Self::check_permission(
+            &env,
+            &authority,
+            &types::Permission::ExecuteProposal,
+            Some(&request),
+        ) {
+            return Err(VaultError::InsufficientRole);
+        }
+
         // Verify sufficient approvals
         let config = storage::get_config(&env)?;
         if proposal.approvals.len() < config.threshold {
@@ -6943,9 +12337,18 @@ impl VaultDAO {
 
         match token_client.try_transfer(&vault_address, &proposal.recipient, &proposal.amount) {
             Ok(_) => {
+                Self::charge_session_key(&env, &caller, proposal.amount)?;
+
                 // Execution successful - transition to Executed
                 proposal.status = ProposalStatus::Executed;
                 storage::set_proposal(&env, &proposal);
+                storage::create_audit_entry(
+                    &env,
+                    AuditAction::ExecuteProposal,
+                    &caller,
+                    proposal_id,
+                    (&proposal.recipient, &proposal.token, proposal.amount),
+                );
 
                 // Return insurance if any
                 if proposal.insurance_amount > 0 {
@@ -7023,6 +12426,14 @@ impl VaultDAO {
         storage::set_proposal(&env, &proposal);
 
         let current_ledger = env.ledger().sequence() as u64;
+        storage::create_audit_entry(
+            &env,
+            AuditAction::RejectProposal,
+            &caller,
+            proposal_id,
+            (&proposal.proposer, proposal.amount),
+        );
+
         events::emit_scheduled_proposal_cancelled(&env, proposal_id, current_ledger);
 
         Ok(())
@@ -7148,11 +12559,17 @@ impl VaultDAO {
         total_amount: i128,
         milestones: Vec<FundingMilestone>,
     ) -> Result<u64, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Proposals)?;
         proposer.require_auth();
 
-        // Role check: Treasurer or Admin
-        let role = storage::get_role(&env, &proposer);
-        if role != Role::Treasurer && role != Role::Admin {
+        // Permission check: Treasurer, Admin, or a grant/custom role covering the total
+        let request = PermissionRequest::transfer(&env, &token, total_amount, &recipient);
+        if !Self::check_permission(
+            &env,
+            &proposer,
+            &types::Permission::CreateProposal,
+            Some(&request),
+        ) {
             return Err(VaultError::InsufficientRole);
         }
 
@@ -7217,6 +12634,18 @@ impl VaultDAO {
 
         storage::set_funding_round(&env, &round);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::FundingRound,
+            &proposer,
+            round_id,
+            (
+                Symbol::new(&env, "create"),
+                &recipient,
+                &token,
+                total_amount,
+            ),
+        );
 
         events::emit_funding_round_created(
             &env,
@@ -7245,6 +12674,12 @@ impl VaultDAO {
         if role != Role::Admin {
             return Err(VaultError::InsufficientRole);
         }
+        Self::require_council(
+            &env,
+            &approver,
+            "approve_funding_round",
+            (round_id,).into_val(&env),
+        )?;
 
         let mut round = storage::get_funding_round(&env, round_id)?;
 
@@ -7257,6 +12692,14 @@ impl VaultDAO {
         round.approved_at = env.ledger().timestamp();
 
         storage::set_funding_round(&env, &round);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::FundingRound,
+            &approver,
+            round_id,
+            Symbol::new(&env, "approve"),
+        );
+
         events::emit_funding_round_approved(&env, round_id, &approver);
 
         Ok(())
@@ -7302,6 +12745,14 @@ impl VaultDAO {
         round.milestones.set(milestone_index, updated);
         storage::set_funding_round(&env, &round);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::FundingRound,
+            &submitter,
+            round_id,
+            (Symbol::new(&env, "submit"), milestone_index),
+        );
+
         events::emit_milestone_submitted(&env, round_id, milestone_index, &submitter);
 
         Ok(())
@@ -7321,12 +12772,19 @@ impl VaultDAO {
         round_id: u64,
         milestone_index: u32,
     ) -> Result<i128, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
         verifier.require_auth();
 
         let role = storage::get_role(&env, &verifier);
         if role != Role::Admin {
             return Err(VaultError::InsufficientRole);
         }
+        Self::require_council(
+            &env,
+            &verifier,
+            "verify_milestone",
+            (round_id, milestone_index).into_val(&env),
+        )?;
 
         let mut round = storage::get_funding_round(&env, round_id)?;
 
@@ -7369,6 +12827,18 @@ impl VaultDAO {
         }
 
         storage::set_funding_round(&env, &round);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::FundingRound,
+            &verifier,
+            round_id,
+            (
+                Symbol::new(&env, "verify"),
+                milestone_index,
+                &round.recipient,
+                amount,
+            ),
+        );
 
         Ok(amount)
     }
@@ -7390,6 +12860,12 @@ impl VaultDAO {
         if role != Role::Admin {
             return Err(VaultError::InsufficientRole);
         }
+        Self::require_council(
+            &env,
+            &canceller,
+            "cancel_funding_round",
+            (round_id,).into_val(&env),
+        )?;
 
         let mut round = storage::get_funding_round(&env, round_id)?;
 
@@ -7404,6 +12880,14 @@ impl VaultDAO {
         round.finalized_at = env.ledger().timestamp();
 
         storage::set_funding_round(&env, &round);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::FundingRound,
+            &canceller,
+            round_id,
+            (Symbol::new(&env, "cancel"), round.released_amount),
+        );
+
         events::emit_funding_round_cancelled(&env, round_id, &canceller);
 
         Ok(())
@@ -7425,6 +12909,7 @@ impl VaultDAO {
         signer: Address,
         config: FundingRoundConfig,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         signer.require_auth();
 
         let vault_config = storage::get_config(&env)?;
@@ -7432,7 +12917,16 @@ impl VaultDAO {
             return Err(VaultError::NotASigner);
         }
 
+        let previous = storage::get_funding_round_config(&env);
         storage::set_funding_round_config(&env, &config);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::FundingRound,
+            &signer,
+            0,
+            (Symbol::new(&env, "config"), previous, config),
+        );
+
         Ok(())
     }
 
@@ -7451,13 +12945,29 @@ impl VaultDAO {
         admin: Address,
         config: CrossVaultConfig,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Config)?;
         admin.require_auth();
         let vault_config = storage::get_config(&env)?;
         if storage::get_role(&env, &admin) != Role::Admin && !vault_config.signers.contains(&admin)
         {
             return Err(VaultError::Unauthorized);
         }
+        Self::require_council(
+            &env,
+            &admin,
+            "set_cross_vault_config",
+            (config.clone(),).into_val(&env),
+        )?;
+        let previous = storage::get_cross_vault_config(&env);
         storage::set_cross_vault_config(&env, &config);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::CrossVault,
+            &admin,
+            0,
+            (Symbol::new(&env, "config"), previous, config),
+        );
+
         events::emit_cross_vault_config_set(&env, &admin);
         Ok(())
     }
@@ -7479,27 +12989,44 @@ impl VaultDAO {
         condition_logic: ConditionLogic,
         insurance_amount: i128,
     ) -> Result<u64, VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Proposals)?;
         proposer.require_auth();
 
         let config = storage::get_config(&env)?;
-        let role = storage::get_role(&env, &proposer);
-        if role != Role::Treasurer && role != Role::Admin {
-            return Err(VaultError::InsufficientRole);
-        }
-
         if actions.is_empty() {
             return Err(VaultError::InvalidAmount);
         }
 
         // Validate each action amount and that the target vault is non-zero
         let mut total_amount: i128 = 0;
+        let mut request = PermissionRequest {
+            token: Some(actions.get(0).unwrap().token),
+            amount: 0,
+            recipients: Vec::new(&env),
+            tags: Vec::new(&env),
+        };
         for i in 0..actions.len() {
             let action = actions.get(i).unwrap();
             if action.amount <= 0 {
                 return Err(VaultError::InvalidAmount);
             }
             total_amount = total_amount.saturating_add(action.amount);
+            // Mixed tokens only match grants that are not token-scoped
+            if request.token.as_ref() != Some(&action.token) {
+                request.token = None;
+            }
+            request.recipients.push_back(action.recipient);
+        }
+        request.amount = total_amount;
+        if !Self::check_permission(
+            &env,
+            &proposer,
+            &types::Permission::CreateProposal,
+            Some(&request),
+        ) {
+            return Err(VaultError::InsufficientRole);
         }
+        Self::validate_conditions(&conditions, 0)?;
 
         // Use the first action's token/recipient as the base proposal fields
         let first = actions.get(0).unwrap();
@@ -7562,6 +13089,18 @@ impl VaultDAO {
         storage::set_cross_vault_proposal(&env, proposal_id, &cv);
         storage::extend_instance_ttl(&env);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::CrossVault,
+            &proposer,
+            proposal_id,
+            (
+                Symbol::new(&env, "propose"),
+                cv.actions.clone(),
+                total_amount,
+            ),
+        );
+
         events::emit_cross_vault_proposed(&env, proposal_id, &proposer, action_count);
 
         Ok(proposal_id)
@@ -7575,6 +13114,7 @@ impl VaultDAO {
         executor: Address,
         proposal_id: u64,
     ) -> Result<(), VaultError> {
+        Self::ensure_not_paused(&env, PauseCategory::Outflows)?;
         executor.require_auth();
 
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
@@ -7641,6 +13181,18 @@ impl VaultDAO {
         storage::set_cross_vault_proposal(&env, proposal_id, &cv);
         storage::set_proposal(&env, &proposal);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::CrossVault,
+            &executor,
+            proposal_id,
+            (
+                Symbol::new(&env, "execute"),
+                cv.execution_results.clone(),
+                success_count,
+            ),
+        );
+
         events::emit_cross_vault_executed(&env, proposal_id, &executor, success_count);
 
         Ok(())
@@ -7712,6 +13264,19 @@ impl VaultDAO {
         storage::add_proposal_dispute(&env, proposal_id, dispute_id);
         storage::extend_instance_ttl(&env);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Dispute,
+            &disputer,
+            dispute_id,
+            (
+                Symbol::new(&env, "raise"),
+                proposal_id,
+                escrow_id,
+                &dispute.reason,
+            ),
+        );
+
         events::emit_dispute_raised(&env, dispute_id, proposal_id, &disputer);
 
         Ok(dispute_id)
@@ -7748,6 +13313,14 @@ impl VaultDAO {
 
         storage::set_dispute(&env, &dispute);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Dispute,
+            &admin,
+            dispute_id,
+            (Symbol::new(&env, "resolve"), resolution_code),
+        );
+
         events::emit_dispute_resolved(&env, dispute_id, &admin, resolution_code);
 
         Ok(())
@@ -7818,6 +13391,19 @@ impl VaultDAO {
         storage::set_subscription(&env, &sub);
         storage::add_to_subscriber_index(&env, &sub.subscriber, id);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Subscription,
+            &sub.subscriber,
+            id,
+            (
+                Symbol::new(&env, "create"),
+                &sub.service_provider,
+                &sub.token,
+                amount_per_period,
+                interval_ledgers,
+            ),
+        );
 
         events::emit_subscription_created(
             &env,
@@ -7826,6 +13412,18 @@ impl VaultDAO {
             tier as u32,
             amount_per_period,
         );
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::lifecycle_event(
+                &env,
+                HookStage::SubscriptionChanged,
+                id,
+                "created",
+                &sub.subscriber,
+                &sub.token,
+                amount_per_period,
+            ),
+        );
 
         Ok(id)
     }
@@ -7879,7 +13477,27 @@ impl VaultDAO {
         storage::set_subscription(&env, &sub);
         storage::extend_instance_ttl(&env);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Subscription,
+            &caller,
+            subscription_id,
+            (Symbol::new(&env, "renew"), payment_number, amount),
+        );
+
         events::emit_subscription_renewed(&env, subscription_id, payment_number, amount);
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::lifecycle_event(
+                &env,
+                HookStage::SubscriptionChanged,
+                subscription_id,
+                "renewed",
+                &caller,
+                &sub.token,
+                amount,
+            ),
+        );
 
         Ok(())
     }
@@ -7909,7 +13527,27 @@ impl VaultDAO {
         storage::set_subscription(&env, &sub);
         storage::extend_instance_ttl(&env);
 
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Subscription,
+            &caller,
+            subscription_id,
+            Symbol::new(&env, "cancel"),
+        );
+
         events::emit_subscription_cancelled(&env, subscription_id, &caller);
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::lifecycle_event(
+                &env,
+                HookStage::SubscriptionChanged,
+                subscription_id,
+                "cancelled",
+                &caller,
+                &sub.token,
+                sub.amount_per_period,
+            ),
+        );
 
         Ok(())
     }
@@ -7940,11 +13578,23 @@ impl VaultDAO {
         }
 
         let old_tier = sub.tier.clone();
+        let old_amount = sub.amount_per_period;
         sub.tier = new_tier.clone();
         sub.amount_per_period = new_amount_per_period;
 
         storage::set_subscription(&env, &sub);
         storage::extend_instance_ttl(&env);
+        storage::create_audit_entry(
+            &env,
+            AuditAction::Subscription,
+            &subscriber,
+            subscription_id,
+            (
+                Symbol::new(&env, "upgrade"),
+                (old_tier.clone() as u32, old_amount),
+                (new_tier.clone() as u32, new_amount_per_period),
+            ),
+        );
 
         events::emit_subscription_upgraded(
             &env,
@@ -7953,6 +13603,18 @@ impl VaultDAO {
             new_tier as u32,
             new_amount_per_period,
         );
+        Self::notify_lifecycle_hooks(
+            &env,
+            Self::lifecycle_event(
+                &env,
+                HookStage::SubscriptionChanged,
+                subscription_id,
+                "upgraded",
+                &subscriber,
+                &sub.token,
+                new_amount_per_period,
+            ),
+        );
 
         Ok(())
     }
@@ -7969,4 +13631,284 @@ impl VaultDAO {
     ) -> Vec<u64> {
         storage::get_subscriber_index(&env, &subscriber)
     }
+
+    // ========================================================================
+    // Treasury Runway (Issue: feature/treasury-runway)
+    // ========================================================================
+
+    /// Project scheduled outflows per token over the next `horizon_ledgers`.
+    ///
+    /// Outflows are gathered from active recurring payments, active and paused
+    /// streams and the unreleased milestones of approved funding rounds.
+    /// Overdue recurring payments and unreleased funding round tranches are
+    /// treated as due immediately. Subscriptions are left out: each renewal
+    /// is pulled from the subscriber before it is paid, so it nets to zero.
+    /// Only the most recent `MAX_RUNWAY_SCAN` IDs of each schedule type are
+    /// read; `truncated` is set when older IDs exist and were skipped.
+    ///
+    /// # Arguments
+    /// * `horizon_ledgers` - Number of ledgers to project forward (must be > 0).
+    /// * `bucket_ledgers`  - Width of each time bucket in ledgers (must be > 0).
+    ///
+    /// # Returns
+    /// One `TokenRunway` per token with a scheduled outflow inside the horizon,
+    /// and whether the scan was truncated.
+    ///
+    /// # Errors
+    /// Returns [`VaultError::InvalidAmount`] if either argument is zero.
+    /// Returns [`VaultError::BatchTooLarge`] if the horizon needs more than
+    /// `MAX_RUNWAY_BUCKETS` buckets.
+    pub fn get_treasury_runway(
+        env: Env,
+        horizon_ledgers: u64,
+        bucket_ledgers: u64,
+    ) -> Result<types::TreasuryRunway, VaultError> {
+        if horizon_ledgers == 0 || bucket_ledgers == 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        if horizon_ledgers.div_ceil(bucket_ledgers) > MAX_RUNWAY_BUCKETS {
+            return Err(VaultError::BatchTooLarge);
+        }
+
+        let schedule = ScheduledOutflows::load(&env);
+        let now = env.ledger().sequence() as u64;
+        let last_ledger = now + horizon_ledgers - 1;
+
+        let mut runways: Vec<types::TokenRunway> = Vec::new(&env);
+        for token in schedule.tokens(&env).iter() {
+            let total_outflow = schedule.outflow_until(&env, &token, last_ledger);
+            if total_outflow <= 0 {
+                continue;
+            }
+
+            let mut buckets: Vec<types::RunwayBucket> = Vec::new(&env);
+            let mut cumulative: i128 = 0;
+            let mut start_ledger = now;
+            while start_ledger <= last_ledger {
+                let end_ledger = (start_ledger + bucket_ledgers - 1).min(last_ledger);
+                let until_end = schedule.outflow_until(&env, &token, end_ledger);
+                buckets.push_back(types::RunwayBucket {
+                    start_ledger,
+                    end_ledger,
+                    outflow: until_end - cumulative,
+                });
+                cumulative = until_end;
+                start_ledger = end_ledger + 1;
+            }
+
+            let balance = token::balance(&env, &token);
+            let depletion_ledger = if total_outflow >= balance {
+                // Cumulative outflow is monotonic in the ledger, so binary
+                // search for the first ledger at which it covers the balance.
+                let (mut lo, mut hi) = (now, last_ledger);
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    if schedule.outflow_until(&env, &token, mid) >= balance {
+                        hi = mid;
+                    } else {
+                        lo = mid + 1;
+                    }
+                }
+                Some(lo)
+            } else {
+                None
+            };
+
+            runways.push_back(types::TokenRunway {
+                token,
+                balance,
+                total_outflow,
+                buckets,
+                depletion_ledger,
+            });
+        }
+
+        Ok(types::TreasuryRunway {
+            tokens: runways,
+            truncated: schedule.truncated,
+        })
+    }
+}
+
+/// What an operation touches, checked by `check_permission` against scoped
+/// grants and delegations and capped custom-role permissions.
+struct PermissionRequest {
+    token: Option<Address>,
+    amount: i128,
+    recipients: Vec<Address>,
+    tags: Vec<Symbol>,
+}
+
+impl PermissionRequest {
+    /// A single transfer of `amount` of `token` to `recipient`.
+    fn transfer(env: &Env, token: &Address, amount: i128, recipient: &Address) -> Self {
+        PermissionRequest {
+            token: Some(token.clone()),
+            amount,
+            recipients: Vec::from_array(env, [recipient.clone()]),
+            tags: Vec::new(env),
+        }
+    }
+
+    /// Executing `proposal`, whose tags count towards tag-scoped grants.
+    fn proposal(env: &Env, proposal: &Proposal) -> Self {
+        let mut request =
+            Self::transfer(env, &proposal.token, proposal.amount, &proposal.recipient);
+        request.tags = proposal.tags.clone();
+        request
+    }
+
+    /// Matches only holders with no cap or scope at all.
+    fn unrestricted(env: &Env) -> Self {
+        PermissionRequest {
+            token: None,
+            amount: i128::MAX,
+            recipients: Vec::new(env),
+            tags: Vec::new(env),
+        }
+    }
+
+    fn within_cap(&self, cap: Option<i128>) -> bool {
+        cap.is_none_or(|cap| self.amount <= cap)
+    }
+
+    /// Whether `scope` covers this request. An operation without recipients
+    /// or tags is not covered by a scope that restricts them.
+    fn within(&self, scope: &types::PermissionScope) -> bool {
+        let token_ok = scope.token.is_none() || scope.token == self.token;
+        let recipients_ok = scope.recipients.is_empty()
+            || (!self.recipients.is_empty()
+                && self
+                    .recipients
+                    .iter()
+                    .all(|r| scope.recipients.contains(&r)));
+        let tags_ok = scope.tags.is_empty() || self.tags.iter().any(|t| scope.tags.contains(&t));
+        token_ok && self.within_cap(scope.max_amount) && recipients_ok && tags_ok
+    }
+}
+
+/// In-memory snapshot of every schedule that can move funds out of the vault,
+/// loaded once per runway projection.
+struct ScheduledOutflows {
+    recurring: Vec<RecurringPayment>,
+    streams: Vec<StreamingPayment>,
+    funding_rounds: Vec<FundingRound>,
+    /// IDs older than the scan window exist for some schedule type
+    truncated: bool,
+}
+
+impl ScheduledOutflows {
+    fn load(env: &Env) -> Self {
+        let next_ids = [
+            storage::get_next_recurring_id(env),
+            storage::get_next_stream_id(env),
+            storage::get_next_funding_round_id(env),
+        ];
+        let truncated = next_ids
+            .iter()
+            .any(|next_id| Self::scan_ids(*next_id).start > 1);
+
+        let mut recurring = Vec::new(env);
+        for id in Self::scan_ids(next_ids[0]) {
+            if let Ok(payment) = storage::get_recurring_payment(env, id) {
+                if payment.is_active {
+                    recurring.push_back(payment);
+                }
+            }
+        }
+
+        let mut streams = Vec::new(env);
+        for id in Self::scan_ids(next_ids[1]) {
+            if let Ok(stream) = storage::get_streaming_payment(env, id) {
+                if stream.status == StreamStatus::Active || stream.status == StreamStatus::Paused {
+                    streams.push_back(stream);
+                }
+            }
+        }
+
+        let mut funding_rounds = Vec::new(env);
+        for id in Self::scan_ids(next_ids[2]) {
+            if let Ok(round) = storage::get_funding_round(env, id) {
+                // Pending rounds may never be approved, so nothing is due yet
+                if round.status == FundingRoundStatus::Approved
+                    || round.status == FundingRoundStatus::Active
+                {
+                    funding_rounds.push_back(round);
+                }
+            }
+        }
+
+        ScheduledOutflows {
+            recurring,
+            streams,
+            funding_rounds,
+            truncated,
+        }
+    }
+
+    /// The most recent `MAX_RUNWAY_SCAN` IDs below `next_id`.
+    fn scan_ids(next_id: u64) -> core::ops::Range<u64> {
+        next_id.saturating_sub(MAX_RUNWAY_SCAN).max(1)..next_id
+    }
+
+    /// Distinct tokens referenced by any loaded schedule, in discovery order.
+    fn tokens(&self, env: &Env) -> Vec<Address> {
+        let mut tokens: Vec<Address> = Vec::new(env);
+        let mut add = |token: Address| {
+            if !tokens.contains(&token) {
+                tokens.push_back(token);
+            }
+        };
+        for payment in self.recurring.iter() {
+            add(payment.token);
+        }
+        for stream in self.streams.iter() {
+            add(stream.token_addr);
+        }
+        for round in self.funding_rounds.iter() {
+            add(round.token);
+        }
+        tokens
+    }
+
+    /// Cumulative outflow of `token` falling due from now up to and including `ledger`.
+    fn outflow_until(&self, env: &Env, token: &Address, ledger: u64) -> i128 {
+        let mut total: i128 = 0;
+
+        for payment in self.recurring.iter() {
+            if payment.token == *token && ledger >= payment.next_payment_ledger {
+                let count = (ledger - payment.next_payment_ledger) / payment.interval + 1;
+                total = total.saturating_add(payment.amount.saturating_mul(count as i128));
+            }
+        }
+
+        // Streams accrue per second; map the ledger onto an approximate timestamp.
+        let now_ledger = env.ledger().sequence() as u64;
+        let at_timestamp =
+            env.ledger().timestamp() + ledger.saturating_sub(now_ledger) * LEDGER_INTERVAL_SECONDS;
+        for stream in self.streams.iter() {
+            if stream.token_addr != *token {
+                continue;
+            }
+            let mut active_seconds = stream.accumulated_seconds;
+            if stream.status == StreamStatus::Active {
+                active_seconds += at_timestamp
+                    .min(stream.end_timestamp)
+                    .saturating_sub(stream.last_update_timestamp);
+            }
+            let earned = stream
+                .rate
+                .saturating_mul(active_seconds as i128)
+                .min(stream.total_amount);
+            total = total.saturating_add((earned - stream.claimed_amount).max(0));
+        }
+
+        for round in self.funding_rounds.iter() {
+            if round.token == *token {
+                total = total.saturating_add(round.total_amount - round.released_amount);
+            }
+        }
+
+        total
+    }
 }
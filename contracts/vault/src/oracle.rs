@@ -0,0 +1,44 @@
+//! VaultDAO - Oracle Interface
+//!
+//! Client interface for SEP-40 price feed oracles.
+//!
+//! Oracles report prices with their own `decimals()`; the vault normalizes
+//! every answer to [`PRICE_DECIMALS`] so prices from different feeds can be
+//! compared and aggregated. Answer timestamps are Unix seconds and are
+//! checked against the ledger close time, not the ledger sequence.
+
+use crate::types::{Asset, VaultPriceData};
+use soroban_sdk::{contractclient, Env};
+
+/// Decimals of every price returned by the vault's oracle helpers.
+pub const PRICE_DECIMALS: u32 = 7;
+
+/// SEP-40 price feed interface (subset used by the vault).
+#[allow(dead_code)]
+#[contractclient(name = "PriceFeedClient")]
+pub trait PriceFeed {
+    /// Number of decimals in reported prices.
+    fn decimals(env: Env) -> u32;
+
+    /// Most recent price for `asset`.
+    fn lastprice(env: Env, asset: Asset) -> Option<VaultPriceData>;
+}
+
+/// Rescale `price` from `decimals` to [`PRICE_DECIMALS`].
+pub fn normalize_price(price: i128, decimals: u32) -> i128 {
+    if decimals >= PRICE_DECIMALS {
+        match 10i128.checked_pow(decimals - PRICE_DECIMALS) {
+            Some(scale) => price / scale,
+            None => 0,
+        }
+    } else {
+        price.saturating_mul(10i128.pow(PRICE_DECIMALS - decimals))
+    }
+}
+
+/// Whether an answer stamped at `timestamp` is older than `max_staleness`
+/// seconds. The configured bound is never relaxed by what the feed reports,
+/// so the feed's SEP-40 `resolution()` is not consulted.
+pub fn is_stale(env: &Env, timestamp: u64, max_staleness: u32) -> bool {
+    env.ledger().timestamp().saturating_sub(timestamp) > max_staleness as u64
+}
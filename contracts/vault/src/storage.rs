@@ -18,17 +18,25 @@
 //!
 //! 5. **Batch Operations**: Multiple related updates are batched into single storage operations.
 
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{
+    contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol,
+    TryFromVal, Val, Vec,
+};
 
 use crate::errors::VaultError;
 use crate::types::{
-    AuditEntry, BatchExecutionResult, BatchTransaction, Comment, Config, DelegatedPermission,
-    Delegation, DelegationHistory, DexConfig, Escrow, ExecutionFeeEstimate, ExecutionSnapshot,
-    FeeStructure, FundingRound, FundingRoundConfig, GasConfig, InsuranceConfig, ListMode,
-    NotificationPreferences, PermissionGrant, Proposal, ProposalAmendment, ProposalTemplate,
-    RecoveryProposal, Reputation, RetryState, Role, RoleAssignment, StakeRecord, StakingConfig,
-    Subscription, SwapProposal, SwapResult, TimeWeightedConfig, TokenLock, VaultMetrics,
-    VelocityConfig, VotingStrategy,
+    AdminAction, AdminCouncil, Asset, AssetOracleSources, AuditCheckpoint, AuditEntry,
+    BatchExecutionResult, BatchTransaction, CircuitBreakerConfig, CircuitBreakerState, Comment,
+    Config, ConfigChange, ConfigV1, CustomRole, DcaOrder, DelegatedPermission, Delegation,
+    DelegationHistory, DexConfig, Escrow, ExecutionFeeEstimate, ExecutionHook, ExecutionSnapshot,
+    FarmPosition, FeeStructure, FundingRound, FundingRoundConfig, GasConfig, HookDenial,
+    InsuranceConfig, LegacyAuditEntry, LegacyDelegatedPermission, LegacyPermissionGrant,
+    LegacySwapResult, LimitOrder, ListMode, LpPosition, MigrationProgress, NotificationPreferences,
+    PauseCategory, PauseConfig, PauseState, PermissionGrant, PermissionScope, Proposal,
+    ProposalAmendment, ProposalTemplate, ProposalV1, RecoveryProposal, Reputation, RetryState,
+    Role, RoleAssignment, SessionKey, StakeRecord, StakingConfig, Subscription, SwapProposal,
+    SwapResult, TimeWeightedConfig, TokenLock, UpgradeProposal, VaultMetrics, VelocityConfig,
+    VersionedConfig, VersionedProposal, VotingStrategy,
 };
 
 /// Core storage key definitions (kept minimal to avoid size limits)
@@ -216,6 +224,164 @@ pub enum FeatureKey {
     SubscriberIndex(Address),
 }
 
+/// DEX order and position storage keys (split from `FeatureKey` to stay under enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum DexKey {
+    /// DCA order by ID -> DcaOrder
+    DcaOrder(u64),
+    /// IDs of DCA orders still active -> Vec<u64>
+    ActiveDcaOrders,
+    /// Limit order by ID -> LimitOrder
+    LimitOrder(u64),
+    /// IDs of limit orders still open -> Vec<u64>
+    OpenLimitOrders,
+    /// LP position by pool (LP token) -> LpPosition
+    LpPosition(Address),
+    /// Pools with a tracked LP position -> Vec<Address>
+    LpPositions,
+    /// Farm position by farm -> FarmPosition
+    FarmPosition(Address),
+    /// Farms with a tracked position -> Vec<Address>
+    FarmPositions,
+}
+
+/// Oracle storage keys (split from `FeatureKey` to stay under enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum OracleKey {
+    /// Per-asset oracle sources -> AssetOracleSources
+    AssetSources(Address),
+    /// SEP-40 asset a token is priced as, when not the token itself -> Asset
+    FeedAsset(Address),
+    /// Circuit breaker config per asset -> CircuitBreakerConfig
+    CircuitBreaker(Address),
+    /// Circuit breaker reference and trip state per asset -> CircuitBreakerState
+    CircuitBreakerState(Address),
+    /// Set once the vault oracle config's `max_staleness` is in seconds -> bool
+    StalenessInSeconds,
+}
+
+/// Typed hook storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum HookKey {
+    /// Registered typed hooks -> Vec<ExecutionHook>
+    ExecutionHooks,
+    /// Last hook denial per proposal -> HookDenial
+    Denial(u64),
+}
+
+/// Emergency pause storage keys (instance storage, read on every guarded call).
+///
+/// Variant names must not collide with `DataKey`, which shares instance storage.
+#[contracttype]
+#[derive(Clone)]
+pub enum PauseKey {
+    /// Guardians, unpause threshold and max duration -> PauseConfig
+    PauseConfig,
+    /// Active pause per category -> PauseState
+    PauseState(PauseCategory),
+    /// Ledger before which a category cannot be paused again -> u64
+    Cooldown(PauseCategory),
+}
+
+/// Contract upgrade storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum UpgradeKey {
+    /// Next upgrade proposal ID counter (instance) -> u64
+    NextUpgradeId,
+    /// Upgrade proposal by ID (persistent) -> UpgradeProposal
+    UpgradeProposal(u64),
+    /// Data layout version the stored state has been migrated to (instance) -> u32
+    ContractVersion,
+    /// Progress of the current batched migration (instance) -> MigrationProgress
+    MigrationProgress,
+}
+
+/// Audit checkpoint storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum AuditKey {
+    /// Next audit checkpoint ID counter (instance) -> u64
+    NextCheckpointId,
+    /// Audit checkpoint by ID (persistent) -> AuditCheckpoint
+    Checkpoint(u64),
+    /// Highest audit entry ID added to the secondary indexes (instance) -> u64
+    IndexedThrough,
+    /// Number of entry IDs in a secondary index (persistent) -> u64
+    IndexLen(AuditIndex),
+    /// Page of entry IDs in a secondary index, ascending (persistent) -> Vec<u64>
+    IndexPage(AuditIndex, u64),
+}
+
+/// Custom role storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum RoleKey {
+    /// Custom role by name (persistent) -> CustomRole
+    CustomRole(Symbol),
+    /// Names of all defined custom roles (instance) -> Vec<Symbol>
+    CustomRoleNames,
+    /// Holders of a custom role (persistent) -> Vec<Address>
+    CustomRoleMembers(Symbol),
+    /// Custom roles held by an address (persistent) -> Vec<Symbol>
+    MemberCustomRoles(Address),
+}
+
+/// Admin council storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum CouncilKey {
+    /// Council configuration (instance) -> AdminCouncil
+    Council,
+    /// Next admin action ID (instance) -> u64
+    NextActionId,
+    /// Queued admin action by ID (persistent) -> AdminAction
+    Action(u64),
+    /// Pending admin action by call hash (persistent) -> u64
+    PendingByHash(BytesN<32>),
+}
+
+/// Config timelock storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum ConfigTimelockKey {
+    /// Delay before queued changes apply, in ledgers (instance) -> u64
+    Delay,
+    /// Next configuration change ID (instance) -> u64
+    NextChangeId,
+    /// Queued configuration change by ID (persistent) -> ConfigChange
+    Change(u64),
+    /// IDs of pending configuration changes (instance) -> Vec<u64>
+    Pending,
+}
+
+/// Session key storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum SessionStoreKey {
+    /// Session key record by key address (persistent) -> SessionKey
+    Session(Address),
+    /// Session keys registered by a signer (persistent) -> Vec<Address>
+    OwnerSessions(Address),
+}
+
+/// Secondary audit indexes, each an ID-ordered list of entry IDs
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AuditIndex {
+    Actor(Address),
+    Action(crate::types::AuditAction),
+    /// Entries with target 0 (no specific target) are not indexed by target.
+    /// Targets are bare IDs, so proposal 3 and stream 3 share an index.
+    Target(u64),
+}
+
+/// Number of entry IDs stored per secondary index page
+pub const AUDIT_INDEX_PAGE_SIZE: u64 = 64;
+
 /// TTL constants (in ledgers, ~5 seconds each)
 pub const DAY_IN_LEDGERS: u32 = 17_280; // ~24 hours
 pub const PROPOSAL_TTL: u32 = DAY_IN_LEDGERS * 7; // 7 days
@@ -241,14 +407,29 @@ pub fn set_initialized(env: &Env) {
 // ============================================================================
 
 pub fn get_config(env: &Env) -> Result<Config, VaultError> {
-    env.storage()
+    let raw: Val = env
+        .storage()
         .instance()
         .get(&DataKey::Config)
-        .ok_or(VaultError::NotInitialized)
+        .ok_or(VaultError::NotInitialized)?;
+    decode_config(env, &raw)
 }
 
 pub fn set_config(env: &Env, config: &Config) {
-    env.storage().instance().set(&DataKey::Config, config);
+    env.storage().instance().set(
+        &DataKey::Config,
+        &VersionedConfig::V1(config.clone().into()),
+    );
+}
+
+fn decode_config(env: &Env, raw: &Val) -> Result<Config, VaultError> {
+    match VersionedConfig::try_from_val(env, raw) {
+        Ok(VersionedConfig::V1(config)) => Ok(config.into()),
+        // Entries written before schema versioning are stored unwrapped.
+        Err(_) => ConfigV1::try_from_val(env, raw)
+            .map(Config::from)
+            .map_err(|_| VaultError::UnknownSchema),
+    }
 }
 
 pub fn get_voting_strategy(env: &Env) -> VotingStrategy {
@@ -339,11 +520,12 @@ pub fn get_role_assignments(env: &Env) -> Vec<RoleAssignment> {
 // ============================================================================
 
 pub fn get_proposal(env: &Env, id: u64) -> Result<Proposal, VaultError> {
-    let mut proposal: Proposal = env
+    let raw: Val = env
         .storage()
         .persistent()
         .get(&DataKey::Proposal(id))
         .ok_or(VaultError::ProposalNotFound)?;
+    let mut proposal = decode_proposal(env, &raw)?;
     proposal.attachments = get_attachments(env, id);
     Ok(proposal)
 }
@@ -354,12 +536,24 @@ pub fn proposal_exists(env: &Env, id: u64) -> bool {
 
 pub fn set_proposal(env: &Env, proposal: &Proposal) {
     let key = DataKey::Proposal(proposal.id);
-    env.storage().persistent().set(&key, proposal);
+    env.storage()
+        .persistent()
+        .set(&key, &VersionedProposal::V1(proposal.clone().into()));
     env.storage()
         .persistent()
         .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
 }
 
+fn decode_proposal(env: &Env, raw: &Val) -> Result<Proposal, VaultError> {
+    match VersionedProposal::try_from_val(env, raw) {
+        Ok(VersionedProposal::V1(proposal)) => Ok(proposal.into()),
+        // Entries written before schema versioning are stored unwrapped.
+        Err(_) => ProposalV1::try_from_val(env, raw)
+            .map(Proposal::from)
+            .map_err(|_| VaultError::UnknownSchema),
+    }
+}
+
 pub fn get_next_proposal_id(env: &Env) -> u64 {
     env.storage()
         .instance()
@@ -937,15 +1131,29 @@ pub fn increment_audit_id(env: &Env) -> u64 {
     id
 }
 
-pub fn get_last_audit_hash(env: &Env) -> u64 {
+/// Hash of the latest audit entry, or all zeroes before the first entry.
+///
+/// Vaults created before schema version 3 stored a 64-bit hash here; it reads
+/// as all zeroes until the migration re-links the chain and overwrites it.
+pub fn get_last_audit_hash(env: &Env) -> BytesN<32> {
     env.storage()
         .instance()
-        .get(&DataKey::LastAuditHash)
-        .unwrap_or(0)
+        .get::<_, Val>(&DataKey::LastAuditHash)
+        .and_then(|raw| BytesN::<32>::try_from_val(env, &raw).ok())
+        .unwrap_or_else(|| BytesN::from_array(env, &[0; 32]))
+}
+
+pub fn set_last_audit_hash(env: &Env, hash: &BytesN<32>) {
+    env.storage().instance().set(&DataKey::LastAuditHash, hash);
 }
 
-pub fn set_last_audit_hash(env: &Env, hash: u64) {
-    env.storage().instance().set(&DataKey::LastAuditHash, &hash);
+/// The 64-bit chain head stored before schema version 3, if no SHA-256 entry
+/// has overwritten it yet.
+pub fn get_legacy_audit_head(env: &Env) -> Option<u64> {
+    env.storage()
+        .instance()
+        .get::<_, Val>(&DataKey::LastAuditHash)
+        .and_then(|raw| u64::try_from_val(env, &raw).ok())
 }
 // Attachments
 // ============================================================================
@@ -1100,10 +1308,23 @@ pub fn get_dex_config(env: &Env) -> Option<DexConfig> {
 // ============================================================================
 // NOTE: Oracle config functions commented out due to DataKey enum size limit
 //
+/// Store the vault oracle config; `max_staleness` must be in seconds.
 pub fn set_oracle_config(env: &Env, config: &crate::OptionalVaultOracleConfig) {
     env.storage()
         .instance()
         .set(&FeatureKey::VaultOracleConfig, config);
+    env.storage()
+        .instance()
+        .set(&OracleKey::StalenessInSeconds, &true);
+}
+
+/// Whether the stored oracle config was written with `max_staleness` in
+/// seconds. Configs written before schema version 4 count ledgers instead.
+pub fn oracle_staleness_in_seconds(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&OracleKey::StalenessInSeconds)
+        .unwrap_or(false)
 }
 
 pub fn get_oracle_config(env: &Env) -> crate::OptionalVaultOracleConfig {
@@ -1113,6 +1334,160 @@ pub fn get_oracle_config(env: &Env) -> crate::OptionalVaultOracleConfig {
         .unwrap_or(crate::OptionalVaultOracleConfig::None)
 }
 
+pub fn get_asset_oracle_sources(env: &Env, asset: &Address) -> Option<AssetOracleSources> {
+    env.storage()
+        .persistent()
+        .get(&OracleKey::AssetSources(asset.clone()))
+}
+
+pub fn set_asset_oracle_sources(env: &Env, asset: &Address, sources: &AssetOracleSources) {
+    let key = OracleKey::AssetSources(asset.clone());
+    env.storage().persistent().set(&key, sources);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn remove_asset_oracle_sources(env: &Env, asset: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&OracleKey::AssetSources(asset.clone()));
+}
+
+/// SEP-40 asset used to price `token`; defaults to `Asset::Stellar(token)`.
+pub fn get_oracle_asset(env: &Env, token: &Address) -> Asset {
+    env.storage()
+        .persistent()
+        .get(&OracleKey::FeedAsset(token.clone()))
+        .unwrap_or_else(|| Asset::Stellar(token.clone()))
+}
+
+pub fn set_oracle_asset(env: &Env, token: &Address, asset: &Asset) {
+    let key = OracleKey::FeedAsset(token.clone());
+    if *asset == Asset::Stellar(token.clone()) {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, asset);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_circuit_breaker(env: &Env, asset: &Address) -> Option<CircuitBreakerConfig> {
+    env.storage()
+        .persistent()
+        .get(&OracleKey::CircuitBreaker(asset.clone()))
+}
+
+pub fn set_circuit_breaker(env: &Env, asset: &Address, config: &CircuitBreakerConfig) {
+    let key = OracleKey::CircuitBreaker(asset.clone());
+    env.storage().persistent().set(&key, config);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn remove_circuit_breaker(env: &Env, asset: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&OracleKey::CircuitBreaker(asset.clone()));
+}
+
+pub fn get_circuit_breaker_state(env: &Env, asset: &Address) -> Option<CircuitBreakerState> {
+    env.storage()
+        .persistent()
+        .get(&OracleKey::CircuitBreakerState(asset.clone()))
+}
+
+pub fn set_circuit_breaker_state(env: &Env, asset: &Address, state: &CircuitBreakerState) {
+    let key = OracleKey::CircuitBreakerState(asset.clone());
+    env.storage().persistent().set(&key, state);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn remove_circuit_breaker_state(env: &Env, asset: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&OracleKey::CircuitBreakerState(asset.clone()));
+}
+
+pub fn get_execution_hooks(env: &Env) -> Vec<ExecutionHook> {
+    env.storage()
+        .persistent()
+        .get(&HookKey::ExecutionHooks)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_execution_hooks(env: &Env, hooks: &Vec<ExecutionHook>) {
+    let key = HookKey::ExecutionHooks;
+    env.storage().persistent().set(&key, hooks);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_hook_denial(env: &Env, proposal_id: u64) -> Option<HookDenial> {
+    env.storage()
+        .persistent()
+        .get(&HookKey::Denial(proposal_id))
+}
+
+pub fn set_hook_denial(env: &Env, proposal_id: u64, denial: &HookDenial) {
+    let key = HookKey::Denial(proposal_id);
+    env.storage().persistent().set(&key, denial);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn remove_hook_denial(env: &Env, proposal_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&HookKey::Denial(proposal_id));
+}
+
+pub fn get_pause_config(env: &Env) -> Option<PauseConfig> {
+    env.storage().instance().get(&PauseKey::PauseConfig)
+}
+
+pub fn set_pause_config(env: &Env, config: &PauseConfig) {
+    env.storage().instance().set(&PauseKey::PauseConfig, config);
+}
+
+pub fn get_pause_state(env: &Env, category: &PauseCategory) -> Option<PauseState> {
+    env.storage()
+        .instance()
+        .get(&PauseKey::PauseState(category.clone()))
+}
+
+pub fn set_pause_state(env: &Env, state: &PauseState) {
+    env.storage()
+        .instance()
+        .set(&PauseKey::PauseState(state.category.clone()), state);
+}
+
+pub fn remove_pause_state(env: &Env, category: &PauseCategory) {
+    env.storage()
+        .instance()
+        .remove(&PauseKey::PauseState(category.clone()));
+}
+
+pub fn get_pause_cooldown(env: &Env, category: &PauseCategory) -> u64 {
+    env.storage()
+        .instance()
+        .get(&PauseKey::Cooldown(category.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_pause_cooldown(env: &Env, category: &PauseCategory, until: u64) {
+    env.storage()
+        .instance()
+        .set(&PauseKey::Cooldown(category.clone()), &until);
+}
+
 pub fn set_swap_proposal(env: &Env, proposal_id: u64, swap: &SwapProposal) {
     let key = FeatureKey::SwapProposal(proposal_id);
     env.storage().persistent().set(&key, swap);
@@ -1137,10 +1512,154 @@ pub fn set_swap_result(env: &Env, proposal_id: u64, result: &SwapResult) {
         .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PROPOSAL_TTL);
 }
 
+/// Results stored before `amount_b` and `oracle_price` existed decode as
+/// [`LegacySwapResult`]. The layouts differ in field count, which the host
+/// rejects outright, so the `amount_b` field is checked for before decoding.
 pub fn get_swap_result(env: &Env, proposal_id: u64) -> Option<SwapResult> {
+    let raw: Val = env
+        .storage()
+        .persistent()
+        .get(&FeatureKey::SwapResult(proposal_id))?;
+    let fields = Map::<Symbol, Val>::try_from_val(env, &raw).ok()?;
+    if fields.contains_key(Symbol::new(env, "amount_b")) {
+        return SwapResult::try_from_val(env, &raw).ok();
+    }
+    LegacySwapResult::try_from_val(env, &raw)
+        .ok()
+        .map(SwapResult::from)
+}
+
+// ============================================================================
+// DCA Orders (Issue: feature/dca-orders)
+// ============================================================================
+
+pub fn get_dca_order(env: &Env, id: u64) -> Result<DcaOrder, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&DexKey::DcaOrder(id))
+        .ok_or(VaultError::ProposalNotFound)
+}
+
+pub fn set_dca_order(env: &Env, order: &DcaOrder) {
+    let key = DexKey::DcaOrder(order.id);
+    env.storage().persistent().set(&key, order);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_active_dca_orders(env: &Env) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&DexKey::ActiveDcaOrders)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn add_active_dca_order(env: &Env, id: u64) {
+    let mut ids = get_active_dca_orders(env);
+    ids.push_back(id);
+    env.storage().instance().set(&DexKey::ActiveDcaOrders, &ids);
+}
+
+pub fn remove_active_dca_order(env: &Env, id: u64) {
+    let mut ids = get_active_dca_orders(env);
+    if let Some(idx) = ids.first_index_of(id) {
+        ids.remove(idx);
+        env.storage().instance().set(&DexKey::ActiveDcaOrders, &ids);
+    }
+}
+
+// ============================================================================
+// Limit Orders (Issue: feature/limit-orders)
+// ============================================================================
+
+pub fn get_limit_order(env: &Env, id: u64) -> Option<LimitOrder> {
+    env.storage().persistent().get(&DexKey::LimitOrder(id))
+}
+
+pub fn set_limit_order(env: &Env, order: &LimitOrder) {
+    let key = DexKey::LimitOrder(order.id);
+    env.storage().persistent().set(&key, order);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_open_limit_orders(env: &Env) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&DexKey::OpenLimitOrders)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn add_open_limit_order(env: &Env, id: u64) {
+    let mut ids = get_open_limit_orders(env);
+    ids.push_back(id);
+    env.storage().instance().set(&DexKey::OpenLimitOrders, &ids);
+}
+
+pub fn remove_open_limit_order(env: &Env, id: u64) {
+    let mut ids = get_open_limit_orders(env);
+    if let Some(idx) = ids.first_index_of(id) {
+        ids.remove(idx);
+        env.storage().instance().set(&DexKey::OpenLimitOrders, &ids);
+    }
+}
+
+// ============================================================================
+// LP Positions (Issue: feature/lp-positions)
+// ============================================================================
+
+pub fn get_lp_position(env: &Env, lp_token: &Address) -> Option<LpPosition> {
+    env.storage()
+        .persistent()
+        .get(&DexKey::LpPosition(lp_token.clone()))
+}
+
+pub fn set_lp_position(env: &Env, position: &LpPosition) {
+    let key = DexKey::LpPosition(position.lp_token.clone());
+    if !env.storage().persistent().has(&key) {
+        let mut pools = get_lp_positions(env);
+        pools.push_back(position.lp_token.clone());
+        env.storage().instance().set(&DexKey::LpPositions, &pools);
+    }
+    env.storage().persistent().set(&key, position);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_lp_positions(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DexKey::LpPositions)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn get_farm_position(env: &Env, farm: &Address) -> Option<FarmPosition> {
+    env.storage()
+        .persistent()
+        .get(&DexKey::FarmPosition(farm.clone()))
+}
+
+pub fn set_farm_position(env: &Env, position: &FarmPosition) {
+    let key = DexKey::FarmPosition(position.farm.clone());
+    if !env.storage().persistent().has(&key) {
+        let mut farms = get_farm_positions(env);
+        farms.push_back(position.farm.clone());
+        env.storage().instance().set(&DexKey::FarmPositions, &farms);
+    }
+    env.storage().persistent().set(&key, position);
     env.storage()
         .persistent()
-        .get(&FeatureKey::SwapResult(proposal_id))
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_farm_positions(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DexKey::FarmPositions)
+        .unwrap_or_else(|| Vec::new(env))
 }
 
 // ============================================================================
@@ -1274,10 +1793,36 @@ pub fn set_stake_record(env: &Env, record: &StakeRecord) {
 }
 
 pub fn get_permissions(env: &Env, addr: &Address) -> Vec<PermissionGrant> {
-    env.storage()
+    let raw: Vec<Val> = env
+        .storage()
         .persistent()
         .get(&FeatureKey::Permissions(addr.clone()))
-        .unwrap_or_else(|| Vec::new(env))
+        .unwrap_or_else(|| Vec::new(env));
+    let mut grants = Vec::new(env);
+    for val in raw.iter() {
+        if let Some(grant) = decode_permission_grant(env, &val) {
+            grants.push_back(grant);
+        }
+    }
+    grants
+}
+
+/// Decode a grant in either layout; grants written before permission scopes
+/// read as unscoped. The layouts differ in field count, which the host
+/// rejects outright, so the `scope` field is checked for before decoding.
+fn decode_permission_grant(env: &Env, raw: &Val) -> Option<PermissionGrant> {
+    let fields = Map::<Symbol, Val>::try_from_val(env, raw).ok()?;
+    if fields.contains_key(Symbol::new(env, "scope")) {
+        return PermissionGrant::try_from_val(env, raw).ok();
+    }
+    let legacy = LegacyPermissionGrant::try_from_val(env, raw).ok()?;
+    Some(PermissionGrant {
+        permission: legacy.permission,
+        granted_by: legacy.granted_by,
+        granted_at: legacy.granted_at,
+        expires_at: legacy.expires_at,
+        scope: PermissionScope::unrestricted(env),
+    })
 }
 
 pub fn set_permissions(env: &Env, addr: &Address, permissions: Vec<PermissionGrant>) {
@@ -1294,13 +1839,28 @@ pub fn get_delegated_permission(
     signer: &Address,
     permission: u32,
 ) -> Option<DelegatedPermission> {
-    env.storage()
+    let raw: Val = env
+        .storage()
         .persistent()
         .get(&FeatureKey::DelegatedPermission(
             addr.clone(),
             signer.clone(),
             permission,
-        ))
+        ))?;
+    let fields = Map::<Symbol, Val>::try_from_val(env, &raw).ok()?;
+    if fields.contains_key(Symbol::new(env, "scope")) {
+        return DelegatedPermission::try_from_val(env, &raw).ok();
+    }
+    // Delegations written before permission scopes read as unscoped.
+    let legacy = LegacyDelegatedPermission::try_from_val(env, &raw).ok()?;
+    Some(DelegatedPermission {
+        permission: legacy.permission,
+        delegator: legacy.delegator,
+        delegatee: legacy.delegatee,
+        granted_at: legacy.granted_at,
+        expires_at: legacy.expires_at,
+        scope: PermissionScope::unrestricted(env),
+    })
 }
 
 pub fn set_delegated_permission(env: &Env, delegation: &DelegatedPermission) {
@@ -1324,53 +1884,337 @@ pub fn set_audit_entry(env: &Env, entry: &AuditEntry) {
 }
 
 pub fn get_audit_entry(env: &Env, id: u64) -> Result<AuditEntry, VaultError> {
-    env.storage()
+    let raw: Val = env
+        .storage()
         .persistent()
         .get(&DataKey::AuditEntry(id))
-        .ok_or(VaultError::ProposalNotFound)
+        .ok_or(VaultError::ProposalNotFound)?;
+    decode_audit_entry(env, &raw)
+}
+
+/// Decode an audit entry in either layout. Entries written before schema
+/// version 3 carry their 64-bit hashes in the low bytes until re-linked, so
+/// they fail verification rather than decoding. The layouts differ in field
+/// count, which the host rejects outright, so the payload hash field is
+/// checked for before decoding.
+fn decode_audit_entry(env: &Env, raw: &Val) -> Result<AuditEntry, VaultError> {
+    let fields =
+        Map::<Symbol, Val>::try_from_val(env, raw).map_err(|_| VaultError::UnknownSchema)?;
+    if fields.contains_key(Symbol::new(env, "payload_hash")) {
+        return AuditEntry::try_from_val(env, raw).map_err(|_| VaultError::UnknownSchema);
+    }
+    let legacy = decode_legacy_audit_entry(env, raw)?;
+    let widen = |hash: u64| {
+        let mut bytes = [0u8; 32];
+        bytes[24..].copy_from_slice(&hash.to_be_bytes());
+        BytesN::from_array(env, &bytes)
+    };
+    Ok(AuditEntry {
+        id: legacy.id,
+        action: legacy.action,
+        actor: legacy.actor,
+        target: legacy.target,
+        timestamp: legacy.timestamp,
+        payload_hash: BytesN::from_array(env, &[0; 32]),
+        prev_hash: widen(legacy.prev_hash),
+        hash: widen(legacy.hash),
+    })
+}
+
+fn decode_legacy_audit_entry(env: &Env, raw: &Val) -> Result<LegacyAuditEntry, VaultError> {
+    LegacyAuditEntry::try_from_val(env, raw).map_err(|_| VaultError::UnknownSchema)
+}
+
+/// Audit entry `id` as written before schema version 3, or `None` once it
+/// has been re-linked (or if it does not exist).
+pub fn get_legacy_audit_entry(env: &Env, id: u64) -> Option<LegacyAuditEntry> {
+    let raw: Val = env.storage().persistent().get(&DataKey::AuditEntry(id))?;
+    let fields = Map::<Symbol, Val>::try_from_val(env, &raw).ok()?;
+    if fields.contains_key(Symbol::new(env, "payload_hash")) {
+        return None;
+    }
+    decode_legacy_audit_entry(env, &raw).ok()
 }
 
-pub fn compute_audit_hash(
-    _env: &Env,
-    action: &crate::types::AuditAction,
-    actor: &Address,
-    target: u64,
-    timestamp: u64,
-    prev_hash: u64,
-) -> u64 {
-    let mut hash = prev_hash;
-    hash = hash.wrapping_mul(31).wrapping_add(action.clone() as u64);
+/// The 64-bit hash entries were chained with before schema version 3. Only
+/// used to check legacy entries before they are re-linked.
+pub fn compute_legacy_audit_hash(entry: &LegacyAuditEntry) -> u64 {
+    let mut hash = entry.prev_hash;
+    hash = hash
+        .wrapping_mul(31)
+        .wrapping_add(entry.action.clone() as u64);
     hash = hash
         .wrapping_mul(31)
-        .wrapping_add(actor.to_string().len() as u64);
-    hash = hash.wrapping_mul(31).wrapping_add(target);
-    hash = hash.wrapping_mul(31).wrapping_add(timestamp);
+        .wrapping_add(entry.actor.to_string().len() as u64);
+    hash = hash.wrapping_mul(31).wrapping_add(entry.target);
+    hash = hash.wrapping_mul(31).wrapping_add(entry.timestamp);
     hash
 }
 
-pub fn create_audit_entry(
+/// SHA-256 over the XDR of every field of `entry` except `hash` itself.
+pub fn compute_audit_hash(env: &Env, entry: &AuditEntry) -> BytesN<32> {
+    let payload = (
+        entry.id,
+        entry.action.clone(),
+        entry.actor.clone(),
+        entry.target,
+        entry.timestamp,
+        entry.payload_hash.clone(),
+        entry.prev_hash.clone(),
+    )
+        .to_xdr(env);
+    env.crypto().sha256(&payload).to_bytes()
+}
+
+/// Append an audit entry recording `payload`, the values needed to tell what
+/// changed (old and new config, amounts, recipients). Only its SHA-256 is
+/// stored; anyone holding the values can recompute it to check the entry.
+pub fn create_audit_entry<P: IntoVal<Env, Val>>(
     env: &Env,
     action: crate::types::AuditAction,
     actor: &Address,
     target: u64,
+    payload: P,
 ) {
     let id = increment_audit_id(env);
-    let timestamp = env.ledger().sequence() as u64;
     let prev_hash = get_last_audit_hash(env);
-    let hash = compute_audit_hash(env, &action, actor, target, timestamp, prev_hash);
-
-    let entry = AuditEntry {
+    let mut entry = AuditEntry {
         id,
         action,
         actor: actor.clone(),
         target,
-        timestamp,
+        timestamp: env.ledger().sequence() as u64,
+        payload_hash: env.crypto().sha256(&payload.to_xdr(env)).to_bytes(),
+        hash: prev_hash.clone(),
         prev_hash,
-        hash,
     };
+    entry.hash = compute_audit_hash(env, &entry);
 
     set_audit_entry(env, &entry);
-    set_last_audit_hash(env, hash);
+    set_last_audit_hash(env, &entry.hash);
+    index_audit_entry(env, &entry);
+}
+
+/// Re-link audit entry `id` into the SHA-256 chain after the entry before it.
+///
+/// Entries must be re-linked in ascending order; the last entry's hash also
+/// becomes the chain head for new entries. Entries written before the
+/// secondary indexes existed are indexed on the way.
+pub fn relink_audit_entry(env: &Env, id: u64) -> Result<(), VaultError> {
+    let prev_hash = if id == 1 {
+        BytesN::from_array(env, &[0; 32])
+    } else {
+        get_audit_entry(env, id - 1)?.hash
+    };
+    let mut entry = get_audit_entry(env, id)?;
+    entry.prev_hash = prev_hash;
+    entry.hash = compute_audit_hash(env, &entry);
+    set_audit_entry(env, &entry);
+    if id == get_next_audit_id(env) - 1 {
+        set_last_audit_hash(env, &entry.hash);
+    }
+    index_audit_entry(env, &entry);
+    Ok(())
+}
+
+// ============================================================================
+// Audit Indexes (feature/audit-export)
+// ============================================================================
+
+/// Secondary indexes `entry` belongs to.
+pub fn audit_indexes(env: &Env, entry: &AuditEntry) -> Vec<AuditIndex> {
+    let mut indexes = Vec::new(env);
+    indexes.push_back(AuditIndex::Actor(entry.actor.clone()));
+    indexes.push_back(AuditIndex::Action(entry.action.clone()));
+    if entry.target != 0 {
+        indexes.push_back(AuditIndex::Target(entry.target));
+    }
+    indexes
+}
+
+pub fn get_audit_indexed_through(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&AuditKey::IndexedThrough)
+        .unwrap_or(0)
+}
+
+/// Append `entry` to its secondary indexes, keeping them in ID order.
+///
+/// Only the entry right after the last indexed one is added. Entries written
+/// before indexing existed leave a gap that the schema migration fills in ID
+/// order; until it catches up, newer entries wait for it as well.
+pub fn index_audit_entry(env: &Env, entry: &AuditEntry) {
+    if entry.id != get_audit_indexed_through(env) + 1 {
+        return;
+    }
+    for index in audit_indexes(env, entry).iter() {
+        let len = get_audit_index_len(env, &index);
+        let page_key = AuditKey::IndexPage(index.clone(), len / AUDIT_INDEX_PAGE_SIZE);
+        let mut page: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&page_key)
+            .unwrap_or_else(|| Vec::new(env));
+        page.push_back(entry.id);
+        env.storage().persistent().set(&page_key, &page);
+        env.storage()
+            .persistent()
+            .extend_ttl(&page_key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+
+        let len_key = AuditKey::IndexLen(index);
+        env.storage().persistent().set(&len_key, &(len + 1));
+        env.storage()
+            .persistent()
+            .extend_ttl(&len_key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+    }
+    env.storage()
+        .instance()
+        .set(&AuditKey::IndexedThrough, &entry.id);
+}
+
+pub fn get_audit_index_len(env: &Env, index: &AuditIndex) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&AuditKey::IndexLen(index.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_audit_index_page(env: &Env, index: &AuditIndex, page: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&AuditKey::IndexPage(index.clone(), page))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+// ============================================================================
+// Audit Checkpoints (feature/audit-checkpoints)
+// ============================================================================
+
+/// Number of checkpoints committed so far; IDs run from 1 to this value.
+pub fn get_audit_checkpoint_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get::<_, u64>(&AuditKey::NextCheckpointId)
+        .unwrap_or(1)
+        - 1
+}
+
+pub fn increment_audit_checkpoint_id(env: &Env) -> u64 {
+    let id = get_audit_checkpoint_count(env) + 1;
+    env.storage()
+        .instance()
+        .set(&AuditKey::NextCheckpointId, &(id + 1));
+    id
+}
+
+pub fn set_audit_checkpoint(env: &Env, checkpoint: &AuditCheckpoint) {
+    let key = AuditKey::Checkpoint(checkpoint.id);
+    env.storage().persistent().set(&key, checkpoint);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+pub fn get_audit_checkpoint(env: &Env, id: u64) -> Result<AuditCheckpoint, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&AuditKey::Checkpoint(id))
+        .ok_or(VaultError::ProposalNotFound)
+}
+
+/// Find the checkpoint covering `entry_id`. Checkpoints cover consecutive
+/// ranges, so a binary search over their IDs suffices.
+pub fn find_audit_checkpoint(env: &Env, entry_id: u64) -> Result<AuditCheckpoint, VaultError> {
+    let (mut low, mut high) = (1, get_audit_checkpoint_count(env));
+    while low <= high {
+        let mid = low + (high - low) / 2;
+        let checkpoint = get_audit_checkpoint(env, mid)?;
+        if entry_id < checkpoint.start_id {
+            high = mid - 1;
+        } else if entry_id > checkpoint.end_id {
+            low = mid + 1;
+        } else {
+            return Ok(checkpoint);
+        }
+    }
+    Err(VaultError::AuditEntryNotCheckpointed)
+}
+
+/// Inner Merkle node: SHA-256 over a 0x01 prefix and both children, so a node
+/// can never be mistaken for an entry hash.
+fn merkle_parent(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &[1]);
+    data.append(&Bytes::from(left.clone()));
+    data.append(&Bytes::from(right.clone()));
+    env.crypto().sha256(&data).to_bytes()
+}
+
+/// Hash one tree level into the next; an unpaired last node is carried up.
+fn merkle_level_up(env: &Env, level: &Vec<BytesN<32>>) -> Vec<BytesN<32>> {
+    let mut next = Vec::new(env);
+    let mut i = 0;
+    while i < level.len() {
+        let left = level.get_unchecked(i);
+        if i + 1 < level.len() {
+            next.push_back(merkle_parent(env, &left, &level.get_unchecked(i + 1)));
+        } else {
+            next.push_back(left);
+        }
+        i += 2;
+    }
+    next
+}
+
+/// Merkle root over `leaves`, or `None` when there are none.
+pub fn merkle_root(env: &Env, leaves: &Vec<BytesN<32>>) -> Option<BytesN<32>> {
+    let mut level = leaves.clone();
+    while level.len() > 1 {
+        level = merkle_level_up(env, &level);
+    }
+    level.first()
+}
+
+/// Sibling hashes from the leaf at `index` up to the root.
+pub fn merkle_path(env: &Env, leaves: &Vec<BytesN<32>>, index: u32) -> Vec<BytesN<32>> {
+    let mut siblings = Vec::new(env);
+    let mut level = leaves.clone();
+    let mut index = index;
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            siblings.push_back(sibling);
+        }
+        level = merkle_level_up(env, &level);
+        index /= 2;
+    }
+    siblings
+}
+
+/// Fold `leaf` at `index` of a `count`-leaf tree with its sibling path.
+/// Returns the resulting root, or `None` if the path has the wrong length.
+pub fn merkle_fold(
+    env: &Env,
+    leaf: &BytesN<32>,
+    index: u32,
+    count: u32,
+    siblings: &Vec<BytesN<32>>,
+) -> Option<BytesN<32>> {
+    let mut node = leaf.clone();
+    let (mut index, mut width, mut used) = (index, count, 0);
+    while width > 1 {
+        if index ^ 1 < width {
+            let sibling = siblings.get(used)?;
+            node = if index % 2 == 0 {
+                merkle_parent(env, &node, &sibling)
+            } else {
+                merkle_parent(env, &sibling, &node)
+            };
+            used += 1;
+        }
+        index /= 2;
+        width = width.div_ceil(2);
+    }
+    (used == siblings.len()).then_some(node)
 }
 
 // ============================================================================
@@ -1679,11 +2523,104 @@ pub fn get_recovery_proposal(env: &Env, id: u64) -> Result<RecoveryProposal, Vau
         .ok_or(VaultError::ProposalNotFound)
 }
 
+// ============================================================================
+// Contract Upgrades
+// ============================================================================
+
+pub fn increment_upgrade_id(env: &Env) -> u64 {
+    let id = env
+        .storage()
+        .instance()
+        .get(&UpgradeKey::NextUpgradeId)
+        .unwrap_or(1u64);
+    env.storage()
+        .instance()
+        .set(&UpgradeKey::NextUpgradeId, &(id + 1));
+    id
+}
+
+pub fn set_upgrade_proposal(env: &Env, proposal: &UpgradeProposal) {
+    let key = UpgradeKey::UpgradeProposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+pub fn get_upgrade_proposal(env: &Env, id: u64) -> Result<UpgradeProposal, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&UpgradeKey::UpgradeProposal(id))
+        .ok_or(VaultError::ProposalNotFound)
+}
+
+/// Version the stored data has been migrated to. Vaults deployed before
+/// versioning was introduced report 0.
+pub fn get_contract_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&UpgradeKey::ContractVersion)
+        .unwrap_or(0)
+}
+
+pub fn set_contract_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&UpgradeKey::ContractVersion, &version);
+}
+
+pub fn get_migration_progress(env: &Env) -> Option<MigrationProgress> {
+    env.storage().instance().get(&UpgradeKey::MigrationProgress)
+}
+
+pub fn set_migration_progress(env: &Env, progress: &MigrationProgress) {
+    env.storage()
+        .instance()
+        .set(&UpgradeKey::MigrationProgress, progress);
+}
+
+/// Rewrite the stored config in the versioned format if it is still unwrapped.
+/// Returns whether the entry was rewritten.
+pub fn migrate_config_entry(env: &Env) -> Result<bool, VaultError> {
+    let raw: Val = env
+        .storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(VaultError::NotInitialized)?;
+    if VersionedConfig::try_from_val(env, &raw).is_ok() {
+        return Ok(false);
+    }
+    let config = decode_config(env, &raw)?;
+    set_config(env, &config);
+    Ok(true)
+}
+
+/// Rewrite proposal `id` in the versioned format if it is still unwrapped.
+/// Returns whether the entry was rewritten; missing IDs are skipped.
+pub fn migrate_proposal_entry(env: &Env, id: u64) -> Result<bool, VaultError> {
+    let key = DataKey::Proposal(id);
+    let raw: Option<Val> = env.storage().persistent().get(&key);
+    let Some(raw) = raw else {
+        return Ok(false);
+    };
+    if VersionedProposal::try_from_val(env, &raw).is_ok() {
+        return Ok(false);
+    }
+    let proposal = decode_proposal(env, &raw)?;
+    env.storage()
+        .persistent()
+        .set(&key, &VersionedProposal::V1(proposal.into()));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+    Ok(true)
+}
+
 // ============================================================================
 // Funding Rounds
 // ============================================================================
 
-fn get_next_funding_round_id(env: &Env) -> u64 {
+pub fn get_next_funding_round_id(env: &Env) -> u64 {
     env.storage()
         .instance()
         .get(&FeatureKey::NextFundingRoundId)
@@ -1720,6 +2657,7 @@ pub fn get_proposal_funding_rounds(env: &Env, proposal_id: u64) -> Vec<u64> {
         .unwrap_or_else(|| Vec::new(env))
 }
 
+#[allow(dead_code)]
 pub fn add_proposal_funding_round(env: &Env, proposal_id: u64, round_id: u64) {
     let mut rounds = get_proposal_funding_rounds(env, proposal_id);
     rounds.push_back(round_id);
@@ -1971,7 +2909,7 @@ pub fn add_proposal_dispute(env: &Env, proposal_id: u64, dispute_id: u64) {
 // Subscriptions
 // ============================================================================
 
-fn get_next_subscription_id(env: &Env) -> u64 {
+pub fn get_next_subscription_id(env: &Env) -> u64 {
     env.storage()
         .instance()
         .get(&FeatureKey::NextSubscriptionId)
@@ -2021,3 +2959,263 @@ pub fn add_to_subscriber_index(env: &Env, subscriber: &Address, subscription_id:
         .persistent()
         .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
 }
+
+// ============================================================================
+// Custom Roles (feature/custom-roles)
+// ============================================================================
+
+pub fn get_custom_role(env: &Env, name: &Symbol) -> Result<CustomRole, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::CustomRole(name.clone()))
+        .ok_or(VaultError::CustomRoleNotFound)
+}
+
+pub fn set_custom_role(env: &Env, role: &CustomRole) {
+    let key = RoleKey::CustomRole(role.name.clone());
+    env.storage().persistent().set(&key, role);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+pub fn remove_custom_role(env: &Env, name: &Symbol) {
+    env.storage()
+        .persistent()
+        .remove(&RoleKey::CustomRole(name.clone()));
+    env.storage()
+        .persistent()
+        .remove(&RoleKey::CustomRoleMembers(name.clone()));
+}
+
+pub fn get_custom_role_names(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&RoleKey::CustomRoleNames)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_custom_role_names(env: &Env, names: &Vec<Symbol>) {
+    env.storage()
+        .instance()
+        .set(&RoleKey::CustomRoleNames, names);
+}
+
+pub fn get_custom_role_members(env: &Env, name: &Symbol) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::CustomRoleMembers(name.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_custom_role_members(env: &Env, name: &Symbol, members: &Vec<Address>) {
+    let key = RoleKey::CustomRoleMembers(name.clone());
+    if members.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, members);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+pub fn get_member_custom_roles(env: &Env, addr: &Address) -> Vec<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::MemberCustomRoles(addr.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_member_custom_roles(env: &Env, addr: &Address, roles: &Vec<Symbol>) {
+    let key = RoleKey::MemberCustomRoles(addr.clone());
+    if roles.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, roles);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+// ============================================================================
+// Session Keys (feature/session-keys)
+// ============================================================================
+
+pub fn get_session_key(env: &Env, key: &Address) -> Option<SessionKey> {
+    env.storage()
+        .persistent()
+        .get(&SessionStoreKey::Session(key.clone()))
+}
+
+pub fn set_session_key(env: &Env, session: &SessionKey) {
+    let key = SessionStoreKey::Session(session.key.clone());
+    env.storage().persistent().set(&key, session);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+pub fn remove_session_key(env: &Env, key: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&SessionStoreKey::Session(key.clone()));
+}
+
+pub fn get_owner_session_keys(env: &Env, owner: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&SessionStoreKey::OwnerSessions(owner.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_owner_session_keys(env: &Env, owner: &Address, keys: &Vec<Address>) {
+    let key = SessionStoreKey::OwnerSessions(owner.clone());
+    if keys.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, keys);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+// ============================================================================
+// Admin Council (feature/admin-council)
+// ============================================================================
+
+/// Council configuration; without one every admin acts alone.
+pub fn get_admin_council(env: &Env) -> AdminCouncil {
+    env.storage()
+        .instance()
+        .get(&CouncilKey::Council)
+        .unwrap_or_else(|| AdminCouncil {
+            threshold: 1,
+            single_admin_actions: Vec::new(env),
+        })
+}
+
+pub fn set_admin_council(env: &Env, council: &AdminCouncil) {
+    env.storage().instance().set(&CouncilKey::Council, council);
+}
+
+/// SHA-256 over the XDR of `[action, args...]`, identifying one council call.
+pub fn admin_call_hash(env: &Env, action: &Symbol, args: &Vec<Val>) -> BytesN<32> {
+    let mut call = Vec::from_array(env, [action.to_val()]);
+    call.append(args);
+    env.crypto().sha256(&call.to_xdr(env)).to_bytes()
+}
+
+/// Number of addresses currently holding the Admin role.
+pub fn count_admins(env: &Env) -> u32 {
+    get_role_index(env)
+        .iter()
+        .filter(|addr| get_role(env, addr) == Role::Admin)
+        .count() as u32
+}
+
+pub fn increment_admin_action_id(env: &Env) -> u64 {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&CouncilKey::NextActionId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&CouncilKey::NextActionId, &(id + 1));
+    id
+}
+
+pub fn get_admin_action(env: &Env, id: u64) -> Result<AdminAction, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&CouncilKey::Action(id))
+        .ok_or(VaultError::AdminActionNotFound)
+}
+
+pub fn set_admin_action(env: &Env, action: &AdminAction) {
+    let key = CouncilKey::Action(action.id);
+    env.storage().persistent().set(&key, action);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+pub fn get_pending_admin_action_id(env: &Env, call_hash: &BytesN<32>) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&CouncilKey::PendingByHash(call_hash.clone()))
+}
+
+pub fn set_pending_admin_action_id(env: &Env, call_hash: &BytesN<32>, id: Option<u64>) {
+    let key = CouncilKey::PendingByHash(call_hash.clone());
+    match id {
+        Some(id) => {
+            env.storage().persistent().set(&key, &id);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ============================================================================
+// Config Timelock (feature/config-timelock)
+// ============================================================================
+
+/// Delay before configuration changes apply; 0 applies them immediately.
+pub fn get_config_delay(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ConfigTimelockKey::Delay)
+        .unwrap_or(0)
+}
+
+pub fn set_config_delay(env: &Env, delay: u64) {
+    env.storage()
+        .instance()
+        .set(&ConfigTimelockKey::Delay, &delay);
+}
+
+pub fn increment_config_change_id(env: &Env) -> u64 {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&ConfigTimelockKey::NextChangeId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&ConfigTimelockKey::NextChangeId, &(id + 1));
+    id
+}
+
+pub fn get_config_change(env: &Env, id: u64) -> Result<ConfigChange, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&ConfigTimelockKey::Change(id))
+        .ok_or(VaultError::ConfigChangeNotFound)
+}
+
+pub fn set_config_change(env: &Env, change: &ConfigChange) {
+    let key = ConfigTimelockKey::Change(change.id);
+    env.storage().persistent().set(&key, change);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+pub fn get_pending_config_changes(env: &Env) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&ConfigTimelockKey::Pending)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_pending_config_changes(env: &Env, ids: &Vec<u64>) {
+    env.storage()
+        .instance()
+        .set(&ConfigTimelockKey::Pending, ids);
+}
@@ -108,16 +108,18 @@ fn setup_spending_limits_env(
 }
 
 #[allow(dead_code)]
-fn set_exact_reputation(env: &Env, addr: &Address, score: u32) {
-    storage::set_reputation(
-        env,
-        addr,
-        &Reputation {
-            score,
-            last_decay_ledger: env.ledger().sequence() as u64,
-            ..Reputation::default()
-        },
-    );
+fn set_exact_reputation(env: &Env, client: &VaultDAOClient, addr: &Address, score: u32) {
+    env.as_contract(&client.address, || {
+        storage::set_reputation(
+            env,
+            addr,
+            &Reputation {
+                score,
+                last_decay_ledger: env.ledger().sequence() as u64,
+                ..Reputation::default()
+            },
+        );
+    });
 }
 
 #[test]
@@ -1564,7 +1566,7 @@ fn test_propose_transfer_reputation_boost_thresholds_at_799_800_and_900() {
         },
     );
 
-    set_exact_reputation(&env, &proposer, 799);
+    set_exact_reputation(&env, &client, &proposer, 799);
     let at_799 = client.try_propose_transfer(
         &proposer,
         &recipient,
@@ -1578,7 +1580,7 @@ fn test_propose_transfer_reputation_boost_thresholds_at_799_800_and_900() {
     );
     assert_eq!(at_799.err(), Some(Ok(VaultError::ExceedsProposalLimit)));
 
-    set_exact_reputation(&env, &proposer, 800);
+    set_exact_reputation(&env, &client, &proposer, 800);
     let at_800_fail = client.try_propose_transfer(
         &proposer,
         &recipient,
@@ -1592,7 +1594,7 @@ fn test_propose_transfer_reputation_boost_thresholds_at_799_800_and_900() {
     );
     assert_eq!(at_800_fail.err(), Some(Ok(VaultError::ExceedsProposalLimit)));
 
-    set_exact_reputation(&env, &proposer, 800);
+    set_exact_reputation(&env, &client, &proposer, 800);
     let at_800_pass = client.propose_transfer(
         &proposer,
         &recipient,
@@ -1606,7 +1608,7 @@ fn test_propose_transfer_reputation_boost_thresholds_at_799_800_and_900() {
     );
     assert!(at_800_pass > 0);
 
-    set_exact_reputation(&env, &proposer, 900);
+    set_exact_reputation(&env, &client, &proposer, 900);
     let at_900_fail = client.try_propose_transfer(
         &proposer,
         &recipient,
@@ -1620,7 +1622,7 @@ fn test_propose_transfer_reputation_boost_thresholds_at_799_800_and_900() {
     );
     assert_eq!(at_900_fail.err(), Some(Ok(VaultError::ExceedsProposalLimit)));
 
-    set_exact_reputation(&env, &proposer, 900);
+    set_exact_reputation(&env, &client, &proposer, 900);
     let at_900_pass = client.propose_transfer(
         &proposer,
         &recipient,
@@ -1663,15 +1665,19 @@ fn test_cancel_proposal_refunds_daily_and_weekly_spending_limits() {
         &0i128,
     );
 
-    let today = storage::get_day_number(&env);
-    let week = storage::get_week_number(&env);
-    assert_eq!(storage::get_daily_spent(&env, today), 400);
-    assert_eq!(storage::get_weekly_spent(&env, week), 400);
+    let spent = || {
+        env.as_contract(&client.address, || {
+            (
+                storage::get_daily_spent(&env, storage::get_day_number(&env)),
+                storage::get_weekly_spent(&env, storage::get_week_number(&env)),
+            )
+        })
+    };
+    assert_eq!(spent(), (400, 400));
 
     client.cancel_proposal(&proposer, &proposal_id, &Symbol::new(&env, "cancel"));
 
-    assert_eq!(storage::get_daily_spent(&env, today), 0);
-    assert_eq!(storage::get_weekly_spent(&env, week), 0);
+    assert_eq!(spent(), (0, 0));
 
     let replacement = client.propose_transfer(
         &proposer,
@@ -3610,7 +3616,7 @@ fn test_condition_no_conditions() {
     let proposal = client.get_proposal(&proposal_id);
     assert_eq!(proposal.status, ProposalStatus::Approved);
     let result = client.try_execute_proposal(&admin, &proposal_id);
-    assert_eq!(result, Ok(Ok(())));
+    assert_eq!(result, Ok(Ok(ExecutionOutcome::Executed)));
 
     let exec_prop = client.get_proposal(&proposal_id);
     assert_eq!(exec_prop.status, ProposalStatus::Executed);
@@ -3820,94 +3826,6 @@ fn test_dex_not_enabled_error() {
     assert_eq!(result.err(), Some(Ok(VaultError::DexError)));
 }
 
-#[test]
-fn test_execute_swap() {
-    let env = Env::default();
-    env.mock_all_auths();
-
-    let contract_id = env.register(VaultDAO, ());
-    let client = VaultDAOClient::new(&env, &contract_id);
-
-    let admin = Address::generate(&env);
-    let treasurer = Address::generate(&env);
-    let executor = Address::generate(&env);
-    let dex = Address::generate(&env);
-    let token_in = Address::generate(&env);
-    let token_out = Address::generate(&env);
-
-    let mut signers = Vec::new(&env);
-    signers.push_back(admin.clone());
-    signers.push_back(treasurer.clone());
-    signers.push_back(executor.clone());
-
-    let config = InitConfig {
-        signers,
-        threshold: 2,
-        quorum: 0,
-        quorum_percentage: 0,
-        default_voting_deadline: 0,
-        spending_limit: 10000,
-        daily_limit: 50000,
-        weekly_limit: 100000,
-        timelock_threshold: 5000,
-        timelock_delay: 100,
-        velocity_limit: VelocityConfig {
-            limit: 100,
-            window: 3600,
-        },
-        threshold_strategy: ThresholdStrategy::Fixed,
-        retry_config: RetryConfig {
-            enabled: false,
-            max_retries: 0,
-            initial_backoff_ledgers: 0,
-        },
-        recovery_config: crate::types::RecoveryConfig::default(&env),
-        staking_config: types::StakingConfig::default(),
-        pre_execution_hooks: soroban_sdk::Vec::new(&env),
-        post_execution_hooks: soroban_sdk::Vec::new(&env),
-        veto_addresses: soroban_sdk::Vec::new(&env),
-    };
-    client.initialize(&admin, &config);
-    client.set_role(&admin, &treasurer, &Role::Treasurer);
-
-    let mut enabled_dexs = Vec::new(&env);
-    enabled_dexs.push_back(dex.clone());
-    let dex_config = DexConfig {
-        enabled_dexs,
-        max_slippage_bps: 200, // 2%
-        max_price_impact_bps: 500,
-        min_liquidity: 1000,
-    };
-    client.set_dex_config(&admin, &dex_config);
-
-    // Propose swap
-    let swap_op = SwapProposal::Swap(dex.clone(), token_in.clone(), token_out.clone(), 1000, 950);
-    let proposal_id = client.propose_swap(
-        &treasurer,
-        &swap_op,
-        &Priority::Normal,
-        &Vec::new(&env),
-        &ConditionLogic::And,
-        &0i128,
-    );
-
-    // Approve the proposal
-    client.approve_proposal(&treasurer, &proposal_id);
-    client.approve_proposal(&executor, &proposal_id);
-
-    // Execute the swap
-    client.execute_swap(&executor, &proposal_id);
-
-    // Check that proposal is executed
-    let proposal = client.get_proposal(&proposal_id);
-    assert_eq!(proposal.status, ProposalStatus::Executed);
-
-    // Check that swap result is stored
-    let swap_result = client.get_swap_result(&proposal_id);
-    assert_eq!(swap_result.amount_in, 1000);
-    assert_eq!(swap_result.amount_out, 990); // Mock: 1% slippage
-}
-
 #[test]
 fn test_batch_propose_multi_token() {
     let env = Env::default();
@@ -7367,7 +7285,7 @@ fn test_wallet_recovery_flow() {
     let mut new_signers = Vec::new(&env);
     new_signers.push_back(new_signer.clone());
 
-    let recovery_id = client.initiate_recovery(&Address::generate(&env), &new_signers, &1);
+    let recovery_id = client.initiate_recovery(&guardian1, &new_signers, &1);
 
     // 2. First guardian approval
     client.approve_recovery(&guardian1, &recovery_id);
@@ -7446,7 +7364,7 @@ fn test_recovery_cancellation() {
     // 1. Initiate recovery
     let mut new_signers = Vec::new(&env);
     new_signers.push_back(Address::generate(&env));
-    let recovery_id = client.initiate_recovery(&Address::generate(&env), &new_signers, &1);
+    let recovery_id = client.initiate_recovery(&guardian1, &new_signers, &1);
 
     // 2. Admin cancels recovery
     client.cancel_recovery(&admin, &recovery_id);
@@ -7488,7 +7406,7 @@ fn test_non_guardian_cannot_approve_recovery() {
 
     let mut new_signers = Vec::new(&env);
     new_signers.push_back(Address::generate(&env));
-    let recovery_id = client.initiate_recovery(&Address::generate(&env), &new_signers, &1);
+    let recovery_id = client.initiate_recovery(&guardian, &new_signers, &1);
 
     // Non-guardian (a regular signer) must be rejected.
     let res = client.try_approve_recovery(&non_guardian, &recovery_id);
@@ -8622,9 +8540,9 @@ fn test_rollback_execution_reverses_transfer_and_clears_snapshot() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let admin = Address::generate(&env);
-    let signer1 = Address::generate(&env);
-    let recipient = Address::generate(&env);
+    let _admin = Address::generate(&env);
+    let _signer1 = Address::generate(&env);
+    let _recipient = Address::generate(&env);
 
     // Set up a real SAC token so the transfer actually executes
     let token_admin = Address::generate(&env);
@@ -8633,14 +8551,15 @@ fn test_rollback_execution_reverses_transfer_and_clears_snapshot() {
     let token_sac = StellarAssetClient::new(&env, &token_addr);
 
     let contract_id = env.register(VaultDAO, ());
-    let client = VaultDAOClient::new(&env, &contract_id);
+    let _client = VaultDAOClient::new(&env, &contract_id);
 
     // Fund the vault with 500 tokens
     token_sac.mint(&contract_id, &500);
 
     // Note: This test is incomplete - requires full implementation
     // For now, just verify setup works
-    assert_eq!(token_sac.balance(&contract_id), 500);
+    let token_client = soroban_sdk::token::TokenClient::new(&env, &token_addr);
+    assert_eq!(token_client.balance(&contract_id), 500);
 }
 
 // ============================================================================
@@ -8651,7 +8570,7 @@ fn test_rollback_execution_reverses_transfer_and_clears_snapshot() {
 fn setup_escrow_env(
     env: &Env,
 ) -> (
-    VaultDAOClient,
+    VaultDAOClient<'_>,
     Address, // contract_id
     Address, // admin
     Address, // funder
@@ -8679,6 +8598,7 @@ fn setup_escrow_env(
         signers,
         threshold: 1,
         quorum: 0,
+        quorum_percentage: 0,
         spending_limit: 100_000,
         daily_limit: 500_000,
         weekly_limit: 1_000_000,
@@ -8699,6 +8619,7 @@ fn setup_escrow_env(
             initial_backoff_ledgers: 0,
         },
         recovery_config: crate::types::RecoveryConfig::default(env),
+        staking_config: crate::types::StakingConfig::default(),
     };
     client.initialize(&admin, &config);
 
@@ -8744,7 +8665,7 @@ fn test_escrow_creation_locks_funds() {
     env.mock_all_auths();
 
     let (client, contract_id, admin, funder, recipient, token) = setup_escrow_env(&env);
-    let token_client = soroban_sdk::token::StellarAssetClient::new(&env, &token);
+    let token_client = soroban_sdk::token::TokenClient::new(&env, &token);
 
     let amount = 1_000i128;
     let funder_balance_before = token_client.balance(&funder);
@@ -8805,8 +8726,8 @@ fn test_escrow_milestone_completion_flow() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, contract_id, admin, funder, recipient, token) = setup_escrow_env(&env);
-    let token_client = soroban_sdk::token::StellarAssetClient::new(&env, &token);
+    let (client, _contract_id, admin, funder, recipient, token) = setup_escrow_env(&env);
+    let _token_client = soroban_sdk::token::StellarAssetClient::new(&env, &token);
 
     let amount = 1_000i128;
     let escrow_id = client.create_escrow(
@@ -8839,7 +8760,7 @@ fn test_escrow_release_after_all_milestones() {
     env.mock_all_auths();
 
     let (client, _contract_id, admin, funder, recipient, token) = setup_escrow_env(&env);
-    let token_client = soroban_sdk::token::StellarAssetClient::new(&env, &token);
+    let token_client = soroban_sdk::token::TokenClient::new(&env, &token);
 
     let amount = 1_000i128;
     let escrow_id = client.create_escrow(
@@ -9020,7 +8941,7 @@ fn test_escrow_dispute_resolution_release_to_recipient() {
     env.mock_all_auths();
 
     let (client, _contract_id, admin, funder, recipient, token) = setup_escrow_env(&env);
-    let token_client = soroban_sdk::token::StellarAssetClient::new(&env, &token);
+    let token_client = soroban_sdk::token::TokenClient::new(&env, &token);
 
     let amount = 1_000i128;
     let escrow_id = client.create_escrow(
@@ -9053,7 +8974,7 @@ fn test_escrow_dispute_resolution_refund_to_funder() {
     env.mock_all_auths();
 
     let (client, _contract_id, admin, funder, recipient, token) = setup_escrow_env(&env);
-    let token_client = soroban_sdk::token::StellarAssetClient::new(&env, &token);
+    let token_client = soroban_sdk::token::TokenClient::new(&env, &token);
 
     let amount = 1_000i128;
     let escrow_id = client.create_escrow(
@@ -9101,7 +9022,7 @@ fn test_escrow_resolve_dispute_non_admin_fails() {
     client.dispute_escrow(&funder, &escrow_id, &Symbol::new(&env, "dispute"));
 
     // Non-admin cannot resolve
-    let res = client.try_resolve_dispute(&stranger, &escrow_id, &true);
+    let res = client.try_resolve_escrow_dispute(&stranger, &escrow_id, &true);
     assert!(res.is_err());
 }
 
@@ -9146,7 +9067,6 @@ fn test_escrow_invalid_milestone_id_fails() {
         &1000u64,
         &admin,
     );
-    assert_eq!(round_id, 1);
 
     // Milestone ID 99 doesn't exist
     let res = client.try_complete_milestone(&funder, &escrow_id, &99u64);
@@ -9158,7 +9078,7 @@ fn test_escrow_invalid_escrow_id_fails() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, _contract_id, admin, funder, recipient, token) = setup_escrow_env(&env);
+    let (client, _contract_id, _admin, _funder, _recipient, _token) = setup_escrow_env(&env);
 
     // No escrow created — ID 999 doesn't exist
     let res = client.try_get_escrow_info(&999u64);
@@ -9289,11 +9209,11 @@ fn test_notification_preferences_set_and_get() {
 
     // Get and verify preferences
     let retrieved = client.get_notification_preferences(&user);
-    assert_eq!(retrieved.notify_on_proposal, false);
-    assert_eq!(retrieved.notify_on_approval, true);
-    assert_eq!(retrieved.notify_on_execution, true);
-    assert_eq!(retrieved.notify_on_rejection, false);
-    assert_eq!(retrieved.notify_on_expiry, true);
+    assert!(!retrieved.notify_on_proposal);
+    assert!(retrieved.notify_on_approval);
+    assert!(retrieved.notify_on_execution);
+    assert!(!retrieved.notify_on_rejection);
+    assert!(retrieved.notify_on_expiry);
 }
 
 #[test]
@@ -9315,11 +9235,11 @@ fn test_notification_preferences_default() {
 
     // Get default preferences for user who never set them
     let prefs = client.get_notification_preferences(&user);
-    assert_eq!(prefs.notify_on_proposal, true);
-    assert_eq!(prefs.notify_on_approval, true);
-    assert_eq!(prefs.notify_on_execution, true);
-    assert_eq!(prefs.notify_on_rejection, true);
-    assert_eq!(prefs.notify_on_expiry, false);
+    assert!(prefs.notify_on_proposal);
+    assert!(prefs.notify_on_approval);
+    assert!(prefs.notify_on_execution);
+    assert!(prefs.notify_on_rejection);
+    assert!(!prefs.notify_on_expiry);
 }
 
 // ============================================================================
@@ -9518,7 +9438,7 @@ fn test_gas_config_set_and_get() {
 
     // Get and verify gas config
     let retrieved = client.get_gas_config();
-    assert_eq!(retrieved.enabled, true);
+    assert!(retrieved.enabled);
     assert_eq!(retrieved.default_gas_limit, 5000);
     assert_eq!(retrieved.base_cost, 1000);
     assert_eq!(retrieved.condition_cost, 500);
@@ -9608,7 +9528,7 @@ fn test_gas_limit_unlimited_when_disabled() {
 
     // Gas config disabled (default)
     let gas_config = client.get_gas_config();
-    assert_eq!(gas_config.enabled, false);
+    assert!(!gas_config.enabled);
 
     // Create and execute proposal - should succeed even with high gas usage
     let proposal_id = client.propose_transfer(
@@ -9722,12 +9642,12 @@ fn test_withdraw_fees_transfers_accumulated_fees() {
     };
     client.set_fee_structure(&admin, &fee_structure);
 
-    // Propose and execute a transfer so fees are collected
+    // Propose and execute a transfer below the timelock threshold so fees are collected
     let proposal_id = client.propose_transfer(
         &treasurer,
         &recipient_addr,
         &token,
-        &1000,
+        &400,
         &Symbol::new(&env, "pay"),
         &Priority::Normal,
         &Vec::new(&env),
@@ -9806,8 +9726,8 @@ fn test_price_below_condition_satisfied() {
     let client = VaultDAOClient::new(&env, &contract_id);
     token_sac.mint(&contract_id, &1000);
 
-    // Mock oracle returns price = 1000 with timestamp = 0
-    let oracle_id = env.register(mock_oracle::MockOracle, ());
+    // Mock oracle returns price = 1000 stamped with the ledger time
+    let oracle_id = env.register(crate::test_conditions::mock_oracle::MockOracle, ());
 
     let mut signers = Vec::new(&env);
     signers.push_back(admin.clone());
@@ -9820,13 +9740,15 @@ fn test_price_below_condition_satisfied() {
     client.set_oracle_config(
         &admin,
         &crate::types::VaultOracleConfig {
-            address: oracle_id,
+            address: oracle_id.clone(),
             base_symbol: Symbol::new(&env, "USD"),
             max_staleness: 10000,
         },
     );
 
     let asset = Address::generate(&env);
+    crate::test_conditions::mock_oracle::MockOracleClient::new(&env, &oracle_id)
+        .set_price(&asset, &1000);
     let mut conditions = Vec::new(&env);
     // price = 1000, threshold = 2000 → PriceBelow(2000) satisfied
     conditions.push_back(Condition::PriceBelow(asset, 2000));
@@ -9869,7 +9791,7 @@ fn test_price_below_condition_not_satisfied() {
     token_sac.mint(&contract_id, &1000);
 
     // Mock oracle returns price = 1000
-    let oracle_id = env.register(mock_oracle::MockOracle, ());
+    let oracle_id = env.register(crate::test_conditions::mock_oracle::MockOracle, ());
 
     let mut signers = Vec::new(&env);
     signers.push_back(admin.clone());
@@ -9881,13 +9803,15 @@ fn test_price_below_condition_not_satisfied() {
     client.set_oracle_config(
         &admin,
         &crate::types::VaultOracleConfig {
-            address: oracle_id,
+            address: oracle_id.clone(),
             base_symbol: Symbol::new(&env, "USD"),
             max_staleness: 10000,
         },
     );
 
     let asset = Address::generate(&env);
+    crate::test_conditions::mock_oracle::MockOracleClient::new(&env, &oracle_id)
+        .set_price(&asset, &1000);
     let mut conditions = Vec::new(&env);
     // price = 1000, threshold = 500 → PriceBelow(500) NOT satisfied
     conditions.push_back(Condition::PriceBelow(asset, 500));
@@ -9908,3 +9832,33 @@ fn test_price_below_condition_not_satisfied() {
     let res = client.try_execute_proposal(&admin, &proposal_id);
     assert!(res.is_err(), "PriceBelow not satisfied should block execution");
 }
+
+#[test]
+fn test_error_spec_covers_every_vault_error() {
+    extern crate std;
+    use crate::errors::spec::{VaultError as CoreSpec, VaultFeatureError as FeatureSpec};
+    use std::format;
+
+    let mut covered = 0u32;
+    for code in 0..1000u32 {
+        let err = soroban_sdk::Error::from_contract_error(code);
+        let runtime = VaultError::try_from(err).ok().map(|e| format!("{:?}", e));
+        let core = CoreSpec::try_from(err).ok().map(|e| format!("{:?}", e));
+        let feature = FeatureSpec::try_from(err).ok().map(|e| format!("{:?}", e));
+        assert!(
+            core.is_none() || feature.is_none(),
+            "code {} in both spec enums",
+            code
+        );
+        assert_eq!(
+            runtime,
+            core.or(feature),
+            "code {} differs from the spec",
+            code
+        );
+        if runtime.is_some() {
+            covered += 1;
+        }
+    }
+    assert_eq!(covered, 77);
+}
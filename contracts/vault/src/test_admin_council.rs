@@ -0,0 +1,250 @@
+//! Unit tests for the admin council.
+//!
+//! Covers: council actions blocked until confirmed, one-shot consumption,
+//! single-admin exemptions, cancellation, expiry, the demotion guard and
+//! council coverage of hook and oracle configuration.
+
+use crate::types::{RetryConfig, VelocityConfig};
+use crate::{InitConfig, Role, ThresholdStrategy, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, IntoVal, Symbol, Val, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+struct Setup {
+    client: VaultDAOClient<'static>,
+    admin: Address,
+    second: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let second = Address::generate(env);
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(second.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 10_000,
+            daily_limit: 50_000,
+            weekly_limit: 100_000,
+            timelock_threshold: 50_000,
+            timelock_delay: 100,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+
+    client.set_role(&admin, &second, &Role::Admin);
+    client.set_admin_council(&admin, &2, &Vec::new(env));
+    Setup {
+        client,
+        admin,
+        second,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_council_action_needs_confirmations_and_is_consumed() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    // A lone admin can no longer change the threshold
+    assert_eq!(
+        s.client.try_update_threshold(&s.admin, &2),
+        Err(Ok(VaultError::AdminActionNotConfirmed))
+    );
+
+    let args: Vec<Val> = (2u32,).into_val(&env);
+    let id = s
+        .client
+        .queue_admin_action(&s.admin, &Symbol::new(&env, "update_threshold"), &args);
+    // Confirmers can see the exact call they are approving
+    assert_eq!(s.client.get_admin_action(&id).args, args);
+    // Queuing counts as one confirmation, which is not enough
+    assert_eq!(
+        s.client.try_update_threshold(&s.admin, &2),
+        Err(Ok(VaultError::AdminActionNotConfirmed))
+    );
+    assert_eq!(
+        s.client.try_confirm_admin_action(&s.admin, &id),
+        Err(Ok(VaultError::AlreadyApproved))
+    );
+    assert_eq!(s.client.confirm_admin_action(&s.second, &id), 2);
+
+    // Different arguments do not match the confirmed call
+    assert_eq!(
+        s.client.try_update_threshold(&s.admin, &1),
+        Err(Ok(VaultError::AdminActionNotConfirmed))
+    );
+    s.client.update_threshold(&s.second, &2);
+    assert_eq!(
+        s.client.get_admin_action(&id).status,
+        crate::types::AdminActionStatus::Executed
+    );
+
+    // The confirmation authorized exactly one call
+    assert_eq!(
+        s.client.try_update_threshold(&s.admin, &2),
+        Err(Ok(VaultError::AdminActionNotConfirmed))
+    );
+}
+
+#[test]
+fn test_single_admin_actions_bypass_council() {
+    let env = Env::default();
+    let s = setup(&env);
+    let blocked = Address::generate(&env);
+
+    let exempt = Vec::from_array(&env, [Symbol::new(&env, "add_to_blacklist")]);
+    let args: Vec<Val> = (2u32, exempt.clone()).into_val(&env);
+    let id = s
+        .client
+        .queue_admin_action(&s.admin, &Symbol::new(&env, "set_admin_council"), &args);
+    s.client.confirm_admin_action(&s.second, &id);
+    s.client.set_admin_council(&s.admin, &2, &exempt);
+
+    s.client.add_to_blacklist(&s.admin, &blocked);
+    assert!(s.client.is_blacklisted(&blocked));
+    assert_eq!(
+        s.client.try_remove_from_blacklist(&s.admin, &blocked),
+        Err(Ok(VaultError::AdminActionNotConfirmed))
+    );
+}
+
+#[test]
+fn test_cancelled_and_expired_actions_do_not_authorize() {
+    let env = Env::default();
+    let s = setup(&env);
+    let action = Symbol::new(&env, "update_quorum");
+    let args: Vec<Val> = (1u32,).into_val(&env);
+
+    let id = s.client.queue_admin_action(&s.admin, &action, &args);
+    assert_eq!(
+        s.client.try_queue_admin_action(&s.second, &action, &args),
+        Err(Ok(VaultError::AlreadyApproved))
+    );
+    s.client.cancel_admin_action(&s.second, &id);
+    assert_eq!(
+        s.client.try_confirm_admin_action(&s.second, &id),
+        Err(Ok(VaultError::AdminActionExpired))
+    );
+    assert_eq!(
+        s.client.try_update_quorum(&s.admin, &1),
+        Err(Ok(VaultError::AdminActionNotConfirmed))
+    );
+
+    // A fresh queue for the same call is allowed once the old one is gone
+    let id = s.client.queue_admin_action(&s.admin, &action, &args);
+    s.client.confirm_admin_action(&s.second, &id);
+    let expires_at = s.client.get_admin_action(&id).expires_at;
+    env.ledger().set_sequence_number(expires_at as u32 + 1);
+    assert_eq!(
+        s.client.try_update_quorum(&s.admin, &1),
+        Err(Ok(VaultError::AdminActionExpired))
+    );
+}
+
+#[test]
+fn test_council_validation_and_demotion_guard() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_queue_admin_action(
+            &s.admin,
+            &Symbol::new(&env, "propose_transfer"),
+            &Vec::new(&env),
+        ),
+        Err(Ok(VaultError::InvalidAdminCouncil))
+    );
+
+    // An entrypoint missing from the council list is refused, not let through
+    env.as_contract(&s.client.address, || {
+        assert_eq!(
+            VaultDAO::require_council(&env, &s.admin, "propose_transfer", Vec::new(&env)),
+            Err(VaultError::InvalidAdminCouncil)
+        );
+    });
+
+    // Threshold above the number of admins is rejected even when confirmed
+    let args: Vec<Val> = (3u32, Vec::<Symbol>::new(&env)).into_val(&env);
+    let id = s
+        .client
+        .queue_admin_action(&s.admin, &Symbol::new(&env, "set_admin_council"), &args);
+    s.client.confirm_admin_action(&s.second, &id);
+    assert_eq!(
+        s.client
+            .try_set_admin_council(&s.admin, &3, &Vec::new(&env)),
+        Err(Ok(VaultError::InvalidAdminCouncil))
+    );
+
+    // Demoting either admin would leave the council unable to act
+    let args: Vec<Val> = (s.second.clone(), Role::Member).into_val(&env);
+    let id = s
+        .client
+        .queue_admin_action(&s.admin, &Symbol::new(&env, "set_role"), &args);
+    s.client.confirm_admin_action(&s.second, &id);
+    assert_eq!(
+        s.client.try_set_role(&s.admin, &s.second, &Role::Member),
+        Err(Ok(VaultError::InvalidAdminCouncil))
+    );
+    assert_eq!(s.client.get_role(&s.second), Role::Admin);
+}
+
+#[test]
+fn test_hook_and_oracle_config_require_council() {
+    let env = Env::default();
+    let s = setup(&env);
+    let hook = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    assert_eq!(
+        s.client.try_register_pre_hook(&s.admin, &hook),
+        Err(Ok(VaultError::AdminActionNotConfirmed))
+    );
+    assert_eq!(
+        s.client.try_remove_circuit_breaker(&s.admin, &asset),
+        Err(Ok(VaultError::AdminActionNotConfirmed))
+    );
+
+    let args: Vec<Val> = (hook.clone(),).into_val(&env);
+    let id = s
+        .client
+        .queue_admin_action(&s.admin, &Symbol::new(&env, "register_pre_hook"), &args);
+    s.client.confirm_admin_action(&s.second, &id);
+    s.client.register_pre_hook(&s.admin, &hook);
+    assert!(s.client.get_config().pre_execution_hooks.contains(&hook));
+}
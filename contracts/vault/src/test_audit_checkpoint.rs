@@ -0,0 +1,213 @@
+//! Unit tests for Merkle audit checkpoints and inclusion proofs.
+//!
+//! Covers: committing checkpoints over consecutive ranges, sibling paths for
+//! odd-sized trees, on-chain proof verification and rejection of tampered
+//! entries or broken chains.
+
+use crate::storage;
+use crate::types::{AuditCheckpoint, AuditProof, RetryConfig, VelocityConfig};
+use crate::{InitConfig, Role, ThresholdStrategy, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, Vec};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn setup(env: &Env) -> (VaultDAOClient<'static>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(Address::generate(env));
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 1_000,
+            daily_limit: 5_000,
+            weekly_limit: 10_000,
+            timelock_threshold: 500,
+            timelock_delay: 100,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+    (client, admin)
+}
+
+/// Append `n` audit entries by assigning roles to fresh addresses.
+fn add_entries(env: &Env, client: &VaultDAOClient, admin: &Address, n: u32) {
+    for _ in 0..n {
+        client.set_role(admin, &Address::generate(env), &Role::Member);
+    }
+}
+
+fn proves(client: &VaultDAOClient, entry_id: u64) -> bool {
+    let proof = client.get_audit_proof(&entry_id);
+    client.verify_audit_proof(&client.get_audit_entry(&entry_id), &proof)
+}
+
+// ---------------------------------------------------------------------------
+// Checkpoints
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_checkpoint_commits_pending_entries() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    add_entries(&env, &client, &admin, 4);
+    assert_eq!(client.get_audit_checkpoint_count(), 0);
+
+    let checkpoint = client.checkpoint_audit_trail();
+    assert_eq!(
+        (checkpoint.id, checkpoint.start_id, checkpoint.end_id),
+        (1, 1, 5)
+    );
+    assert_eq!(client.get_audit_checkpoint(&1), checkpoint);
+    assert_eq!(client.get_audit_checkpoint_count(), 1);
+
+    let res = client.try_checkpoint_audit_trail();
+    assert_eq!(res.err(), Some(Ok(VaultError::NothingToCheckpoint)));
+
+    // The next checkpoint picks up where the last one ended.
+    add_entries(&env, &client, &admin, 2);
+    let next = client.checkpoint_audit_trail();
+    assert_eq!((next.id, next.start_id, next.end_id), (2, 6, 7));
+    assert_ne!(next.root, checkpoint.root);
+}
+
+#[test]
+fn test_checkpoint_covers_bounded_range() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    add_entries(&env, &client, &admin, 39);
+
+    let first = client.checkpoint_audit_trail();
+    assert_eq!((first.start_id, first.end_id), (1, 32));
+    let second = client.checkpoint_audit_trail();
+    assert_eq!((second.start_id, second.end_id), (33, 40));
+
+    // Lookups across several checkpoints find the covering one.
+    for id in [1, 17, 32, 33, 40] {
+        assert!(proves(&client, id));
+    }
+}
+
+#[test]
+fn test_checkpoint_refuses_broken_chain() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    add_entries(&env, &client, &admin, 2);
+
+    env.as_contract(&client.address, || {
+        let mut tampered = storage::get_audit_entry(&env, 2).unwrap();
+        tampered.target = 99;
+        storage::set_audit_entry(&env, &tampered);
+    });
+
+    let res = client.try_checkpoint_audit_trail();
+    assert_eq!(res.err(), Some(Ok(VaultError::AuditChainBroken)));
+    assert_eq!(client.get_audit_checkpoint_count(), 0);
+}
+
+// ---------------------------------------------------------------------------
+// Inclusion proofs
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_every_entry_proves_against_odd_sized_tree() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    add_entries(&env, &client, &admin, 6);
+    let checkpoint: AuditCheckpoint = client.checkpoint_audit_trail();
+    assert_eq!(checkpoint.end_id, 7);
+
+    for id in 1..=7 {
+        assert!(proves(&client, id));
+    }
+    // Entry 7 has no sibling at the leaf level and is carried up.
+    assert_eq!(client.get_audit_proof(&7).siblings.len(), 2);
+    assert_eq!(client.get_audit_proof(&1).siblings.len(), 3);
+}
+
+#[test]
+fn test_single_entry_checkpoint_root_is_entry_hash() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let checkpoint = client.checkpoint_audit_trail();
+
+    assert_eq!(checkpoint.root, client.get_audit_entry(&1).hash);
+    let proof = client.get_audit_proof(&1);
+    assert!(proof.siblings.is_empty());
+    assert!(client.verify_audit_proof(&client.get_audit_entry(&1), &proof));
+}
+
+#[test]
+fn test_proof_rejects_tampered_entry_or_path() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    add_entries(&env, &client, &admin, 4);
+    client.checkpoint_audit_trail();
+
+    let entry = client.get_audit_entry(&3);
+    let proof = client.get_audit_proof(&3);
+    assert!(client.verify_audit_proof(&entry, &proof));
+
+    // Altered fields no longer hash to the committed leaf.
+    let mut forged = entry.clone();
+    forged.actor = Address::generate(&env);
+    assert!(!client.verify_audit_proof(&forged, &proof));
+
+    // Recomputing the forged entry's hash does not help either.
+    forged.hash = env.as_contract(&client.address, || {
+        storage::compute_audit_hash(&env, &forged)
+    });
+    assert!(!client.verify_audit_proof(&forged, &proof));
+
+    // A proof for another entry, a corrupted sibling or a missing checkpoint fail.
+    assert!(!client.verify_audit_proof(&entry, &client.get_audit_proof(&2)));
+    let mut corrupted = proof.clone();
+    corrupted
+        .siblings
+        .set(0, BytesN::from_array(&env, &[7; 32]));
+    assert!(!client.verify_audit_proof(&entry, &corrupted));
+    let missing = AuditProof {
+        checkpoint_id: 9,
+        ..proof
+    };
+    assert!(!client.verify_audit_proof(&entry, &missing));
+}
+
+#[test]
+fn test_proof_requires_checkpoint() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.checkpoint_audit_trail();
+    add_entries(&env, &client, &admin, 1);
+
+    let res = client.try_get_audit_proof(&2);
+    assert_eq!(res.err(), Some(Ok(VaultError::AuditEntryNotCheckpointed)));
+    client.checkpoint_audit_trail();
+    assert!(proves(&client, 2));
+}
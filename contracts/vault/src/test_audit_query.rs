@@ -0,0 +1,307 @@
+//! Unit tests for filtered, cursor-paginated audit trail queries.
+//!
+//! Covers: actor/action/ledger-range filters, target lookups (shared across
+//! entity kinds), pagination across index pages and the per-page scan bound.
+
+use crate::types::{AuditAction, AuditFilter, AuditPage, RetryConfig, VelocityConfig};
+use crate::{
+    ConditionLogic, InitConfig, Priority, Role, ThresholdStrategy, VaultDAO, VaultDAOClient,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Symbol, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn setup(env: &Env) -> (VaultDAOClient<'static>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let signer = Address::generate(env);
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 1_000,
+            daily_limit: 5_000,
+            weekly_limit: 10_000,
+            timelock_threshold: 500,
+            timelock_delay: 100,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+    client.set_role(&admin, &signer, &Role::Treasurer);
+    (client, admin, signer)
+}
+
+fn filter(env: &Env) -> AuditFilter {
+    AuditFilter {
+        actor: None,
+        actions: Vec::new(env),
+        target: None,
+        from_ledger: 0,
+        to_ledger: 0,
+    }
+}
+
+fn actions_of(env: &Env, page: &AuditPage) -> Vec<AuditAction> {
+    let mut actions = Vec::new(env);
+    for entry in page.entries.iter() {
+        actions.push_back(entry.action);
+    }
+    actions
+}
+
+fn add_members(env: &Env, client: &VaultDAOClient, admin: &Address, n: u32) {
+    for _ in 0..n {
+        client.set_role(admin, &Address::generate(env), &Role::Member);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Filters
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_query_config_changes_by_actor_between_ledgers() {
+    let env = Env::default();
+    let (client, admin, signer) = setup(&env);
+    client.set_role(&admin, &signer, &Role::Admin);
+
+    env.ledger().set_sequence_number(100);
+    client.update_threshold(&admin, &2);
+    env.ledger().set_sequence_number(200);
+    client.update_limits(&admin, &2_000, &10_000, &20_000);
+    client.update_quorum(&signer, &1);
+    env.ledger().set_sequence_number(300);
+    client.update_quorum(&admin, &2);
+
+    let mut config_changes = filter(&env);
+    config_changes
+        .actions
+        .push_back(AuditAction::UpdateThreshold);
+    config_changes.actions.push_back(AuditAction::UpdateLimits);
+    config_changes
+        .actions
+        .push_back(AuditAction::UpdateVotingConfig);
+
+    let mut by_admin = config_changes.clone();
+    by_admin.actor = Some(admin.clone());
+    let page = client.query_audit_trail(&by_admin, &0, &10);
+    assert_eq!(
+        actions_of(&env, &page),
+        Vec::from_array(
+            &env,
+            [
+                AuditAction::UpdateThreshold,
+                AuditAction::UpdateLimits,
+                AuditAction::UpdateVotingConfig
+            ]
+        )
+    );
+    assert_eq!(page.next_cursor, None);
+
+    by_admin.from_ledger = 150;
+    by_admin.to_ledger = 250;
+    let page = client.query_audit_trail(&by_admin, &0, &10);
+    assert_eq!(
+        actions_of(&env, &page),
+        Vec::from_array(&env, [AuditAction::UpdateLimits])
+    );
+    assert!(page.entries.iter().all(|entry| entry.actor == admin));
+
+    // Without an actor every signer's changes in the range match.
+    config_changes.from_ledger = 200;
+    config_changes.to_ledger = 200;
+    let page = client.query_audit_trail(&config_changes, &0, &10);
+    assert_eq!(page.entries.len(), 2);
+    assert_eq!(page.entries.get(1).unwrap().actor, signer);
+}
+
+#[test]
+fn test_query_actions_targeting_proposal() {
+    let env = Env::default();
+    let (client, admin, signer) = setup(&env);
+    let token = Address::generate(&env);
+
+    let propose = || {
+        client.propose_transfer(
+            &signer,
+            &Address::generate(&env),
+            &token,
+            &100,
+            &Symbol::new(&env, "pay"),
+            &Priority::Normal,
+            &Vec::new(&env),
+            &ConditionLogic::And,
+            &0i128,
+        )
+    };
+    let first = propose();
+    let second = propose();
+    client.approve_proposal(&admin, &second);
+    client.approve_proposal(&admin, &first);
+
+    let mut by_target = filter(&env);
+    by_target.target = Some(first);
+    let page = client.query_audit_trail(&by_target, &0, &10);
+    assert_eq!(
+        actions_of(&env, &page),
+        Vec::from_array(
+            &env,
+            [AuditAction::ProposeTransfer, AuditAction::ApproveProposal]
+        )
+    );
+    assert!(page.entries.iter().all(|entry| entry.target == first));
+
+    // Target and action filters combine.
+    by_target.actions.push_back(AuditAction::ApproveProposal);
+    by_target.target = Some(second);
+    let page = client.query_audit_trail(&by_target, &0, &10);
+    assert_eq!(page.entries.len(), 1);
+    assert_eq!(page.entries.get(0).unwrap().actor, admin);
+}
+
+#[test]
+fn test_query_target_is_shared_across_entity_kinds() {
+    let env = Env::default();
+    let (client, _, signer) = setup(&env);
+    let token = Address::generate(&env);
+
+    let proposal = client.propose_transfer(
+        &signer,
+        &Address::generate(&env),
+        &token,
+        &100,
+        &Symbol::new(&env, "pay"),
+        &Priority::Normal,
+        &Vec::new(&env),
+        &ConditionLogic::And,
+        &0i128,
+    );
+    let payment = client.schedule_payment(
+        &signer,
+        &Address::generate(&env),
+        &token,
+        &100,
+        &Symbol::new(&env, "rent"),
+        &1_000,
+    );
+    assert_eq!(proposal, payment);
+
+    // Targets are bare IDs: proposal 1 and recurring payment 1 both match.
+    let mut by_target = filter(&env);
+    by_target.target = Some(proposal);
+    let page = client.query_audit_trail(&by_target, &0, &10);
+    assert_eq!(
+        actions_of(&env, &page),
+        Vec::from_array(
+            &env,
+            [AuditAction::ProposeTransfer, AuditAction::SchedulePayment]
+        )
+    );
+
+    // Action filters narrow a target to one kind of entity.
+    by_target.actions.push_back(AuditAction::SchedulePayment);
+    let page = client.query_audit_trail(&by_target, &0, &10);
+    assert_eq!(
+        actions_of(&env, &page),
+        Vec::from_array(&env, [AuditAction::SchedulePayment])
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Pagination
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_query_cursor_walks_across_index_pages() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+    add_members(&env, &client, &admin, 70);
+
+    let mut by_admin = filter(&env);
+    by_admin.actor = Some(admin.clone());
+    let mut ids = Vec::new(&env);
+    let mut cursor = 0;
+    loop {
+        let page = client.query_audit_trail(&by_admin, &cursor, &30);
+        for entry in page.entries.iter() {
+            ids.push_back(entry.id);
+        }
+        match page.next_cursor {
+            Some(next) => cursor = next,
+            None => break,
+        }
+    }
+
+    // Initialize plus 71 role assignments, each exactly once in ID order.
+    assert_eq!(ids.len(), 72);
+    for i in 1..ids.len() {
+        assert!(ids.get(i - 1).unwrap() < ids.get(i).unwrap());
+    }
+    assert_eq!(ids.last().unwrap(), client.get_audit_entry_count());
+}
+
+#[test]
+fn test_query_scan_is_bounded_per_page() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+    add_members(&env, &client, &admin, 250);
+    client.update_threshold(&admin, &2);
+
+    // Two action types and no actor or target: the whole trail is walked.
+    let mut sparse = filter(&env);
+    sparse.actions.push_back(AuditAction::Initialize);
+    sparse.actions.push_back(AuditAction::UpdateThreshold);
+
+    let page = client.query_audit_trail(&sparse, &0, &10);
+    assert_eq!(
+        actions_of(&env, &page),
+        Vec::from_array(&env, [AuditAction::Initialize])
+    );
+    assert_eq!(page.next_cursor, Some(200));
+
+    let page = client.query_audit_trail(&sparse, &200, &10);
+    assert_eq!(
+        actions_of(&env, &page),
+        Vec::from_array(&env, [AuditAction::UpdateThreshold])
+    );
+    assert_eq!(page.next_cursor, None);
+
+    // A single action type uses its own index instead.
+    let mut threshold_only = filter(&env);
+    threshold_only
+        .actions
+        .push_back(AuditAction::UpdateThreshold);
+    let page = client.query_audit_trail(&threshold_only, &0, &10);
+    assert_eq!(page.entries.len(), 1);
+    assert_eq!(page.next_cursor, None);
+}
@@ -0,0 +1,468 @@
+//! Unit tests for execution conditions.
+//!
+//! Covers: balance thresholds on the proposal token and other tokens,
+//! proposal-executed dependencies, timestamp dates, external predicate
+//! contracts, nested AND/OR groups and condition validation on every
+//! proposal path.
+
+use crate::types::{
+    Condition, RetryConfig, SwapProposal, TransferDetails, VaultAction, VaultOracleConfig,
+    VelocityConfig,
+};
+use crate::{
+    ConditionLogic, InitConfig, Priority, ProposalStatus, Role, ThresholdStrategy, VaultDAO,
+    VaultDAOClient, VaultError,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Mocks
+// ---------------------------------------------------------------------------
+
+mod mock_predicate {
+    use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+    /// Predicate returning a result set by the test. A broken predicate panics.
+    #[contract]
+    pub struct MockPredicate;
+
+    #[contractimpl]
+    impl MockPredicate {
+        pub fn set_result(env: Env, result: Option<bool>) {
+            env.storage()
+                .instance()
+                .set(&Symbol::new(&env, "result"), &result);
+        }
+
+        pub fn check_condition(env: Env, _vault: Address, _proposal_id: u64) -> bool {
+            let result: Option<bool> = env
+                .storage()
+                .instance()
+                .get(&Symbol::new(&env, "result"))
+                .unwrap_or(Some(false));
+            result.expect("predicate unavailable")
+        }
+    }
+}
+
+pub(crate) mod mock_oracle {
+    use crate::types::{Asset, VaultPriceData};
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    /// SEP-40 oracle returning prices set by the test.
+    #[contract]
+    pub struct MockOracle;
+
+    #[contractimpl]
+    impl MockOracle {
+        pub fn set_price(env: Env, asset: Address, price: i128) {
+            env.storage().instance().set(&asset, &price);
+        }
+
+        pub fn decimals(_env: Env) -> u32 {
+            7
+        }
+
+        pub fn resolution(_env: Env) -> u32 {
+            300
+        }
+
+        pub fn lastprice(env: Env, asset: Asset) -> Option<VaultPriceData> {
+            let Asset::Stellar(asset) = asset else {
+                return None;
+            };
+            env.storage()
+                .instance()
+                .get(&asset)
+                .map(|price| VaultPriceData {
+                    price,
+                    timestamp: env.ledger().timestamp(),
+                })
+        }
+    }
+}
+
+use mock_oracle::{MockOracle, MockOracleClient};
+use mock_predicate::{MockPredicate, MockPredicateClient};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 2024-03-01T00:00:00Z
+const MARCH_1: u64 = 1_709_251_200;
+
+fn setup(env: &Env) -> (VaultDAOClient<'static>, Address, Address, Address) {
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let treasurer = Address::generate(env);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(treasurer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 1_000_000,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+    client.set_role(&admin, &treasurer, &Role::Treasurer);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &1_000);
+
+    (client, admin, treasurer, token)
+}
+
+fn conditions(env: &Env, items: &[Condition]) -> Vec<Condition> {
+    let mut conditions = Vec::new(env);
+    for item in items {
+        conditions.push_back(item.clone());
+    }
+    conditions
+}
+
+fn group(env: &Env, logic: ConditionLogic, items: &[Condition]) -> Condition {
+    Condition::Group(logic, conditions(env, items))
+}
+
+/// Propose and approve a 100-unit transfer guarded by `conditions`.
+fn propose_with(
+    env: &Env,
+    client: &VaultDAOClient,
+    treasurer: &Address,
+    token: &Address,
+    conditions: &Vec<Condition>,
+) -> u64 {
+    let proposal_id = client.propose_transfer(
+        treasurer,
+        &Address::generate(env),
+        token,
+        &100,
+        &Symbol::new(env, "cond"),
+        &Priority::Normal,
+        conditions,
+        &ConditionLogic::And,
+        &0i128,
+    );
+    client.approve_proposal(treasurer, &proposal_id);
+    proposal_id
+}
+
+fn assert_blocked(client: &VaultDAOClient, treasurer: &Address, proposal_id: u64) {
+    assert_eq!(
+        client.try_execute_proposal(treasurer, &proposal_id),
+        Err(Ok(VaultError::ProposalNotApproved))
+    );
+}
+
+fn assert_executes(client: &VaultDAOClient, treasurer: &Address, proposal_id: u64) {
+    client.execute_proposal(treasurer, &proposal_id);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Leaf conditions
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_balance_below_and_other_token_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, treasurer, token) = setup(&env);
+    let other = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &other).mint(&client.address, &50);
+
+    let below = conditions(&env, &[Condition::BalanceBelow(1_000)]);
+    let proposal_id = propose_with(&env, &client, &treasurer, &token, &below);
+    assert_blocked(&client, &treasurer, proposal_id);
+
+    let other_low = conditions(
+        &env,
+        &[
+            Condition::TokenBalanceBelow(other.clone(), 100),
+            Condition::TokenBalanceAbove(other.clone(), 10),
+        ],
+    );
+    let proposal_id = propose_with(&env, &client, &treasurer, &token, &other_low);
+    assert_executes(&client, &treasurer, proposal_id);
+
+    // The proposal token balance is now 900.
+    let proposal_id = propose_with(&env, &client, &treasurer, &token, &below);
+    assert_executes(&client, &treasurer, proposal_id);
+}
+
+#[test]
+fn test_time_conditions_use_ledger_timestamp() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, treasurer, token) = setup(&env);
+    env.ledger().with_mut(|li| {
+        li.timestamp = MARCH_1 - 1;
+        li.sequence_number = 10;
+    });
+
+    let window = conditions(
+        &env,
+        &[
+            Condition::TimeAfter(MARCH_1),
+            Condition::TimeBefore(MARCH_1 + 86_400),
+        ],
+    );
+    let proposal_id = propose_with(&env, &client, &treasurer, &token, &window);
+    assert_blocked(&client, &treasurer, proposal_id);
+
+    env.ledger().with_mut(|li| li.timestamp = MARCH_1 + 1);
+    assert_executes(&client, &treasurer, proposal_id);
+}
+
+#[test]
+fn test_proposal_executed_condition() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, treasurer, token) = setup(&env);
+
+    let first = propose_with(&env, &client, &treasurer, &token, &Vec::new(&env));
+    let after_first = conditions(&env, &[Condition::ProposalExecuted(first)]);
+    let second = propose_with(&env, &client, &treasurer, &token, &after_first);
+    assert_blocked(&client, &treasurer, second);
+
+    assert_executes(&client, &treasurer, first);
+    assert_executes(&client, &treasurer, second);
+
+    let missing = conditions(&env, &[Condition::ProposalExecuted(999)]);
+    let proposal_id = propose_with(&env, &client, &treasurer, &token, &missing);
+    assert_blocked(&client, &treasurer, proposal_id);
+}
+
+#[test]
+fn test_predicate_condition() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, treasurer, token) = setup(&env);
+    let predicate = MockPredicateClient::new(&env, &env.register(MockPredicate, ()));
+
+    let guarded = conditions(&env, &[Condition::Predicate(predicate.address.clone())]);
+    let proposal_id = propose_with(&env, &client, &treasurer, &token, &guarded);
+    assert_blocked(&client, &treasurer, proposal_id);
+
+    // A failing predicate is treated as unsatisfied.
+    predicate.set_result(&None);
+    assert_blocked(&client, &treasurer, proposal_id);
+
+    predicate.set_result(&Some(true));
+    assert_executes(&client, &treasurer, proposal_id);
+}
+
+// ---------------------------------------------------------------------------
+// Groups
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_nested_group_after_date_and_price_or_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, treasurer, token) = setup(&env);
+    let asset = Address::generate(&env);
+    let oracle = MockOracleClient::new(&env, &env.register(MockOracle, ()));
+    oracle.set_price(&asset, &90);
+    client.set_oracle_config(
+        &admin,
+        &VaultOracleConfig {
+            address: oracle.address.clone(),
+            base_symbol: Symbol::new(&env, "USD"),
+            max_staleness: 100,
+        },
+    );
+    env.ledger().with_mut(|li| li.timestamp = MARCH_1 + 1);
+
+    let dependency = propose_with(&env, &client, &treasurer, &token, &Vec::new(&env));
+
+    // after March 1 AND (price > 100 OR dependency executed)
+    let nested = conditions(
+        &env,
+        &[
+            Condition::TimeAfter(MARCH_1),
+            group(
+                &env,
+                ConditionLogic::Or,
+                &[
+                    Condition::PriceAbove(asset.clone(), 100),
+                    Condition::ProposalExecuted(dependency),
+                ],
+            ),
+        ],
+    );
+    let by_price = propose_with(&env, &client, &treasurer, &token, &nested);
+    assert_blocked(&client, &treasurer, by_price);
+
+    oracle.set_price(&asset, &110);
+    assert_executes(&client, &treasurer, by_price);
+
+    oracle.set_price(&asset, &90);
+    let by_dependency = propose_with(&env, &client, &treasurer, &token, &nested);
+    assert_blocked(&client, &treasurer, by_dependency);
+    assert_executes(&client, &treasurer, dependency);
+    assert_executes(&client, &treasurer, by_dependency);
+}
+
+#[test]
+fn test_propose_rejects_malformed_groups() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, treasurer, token) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let empty_group = conditions(&env, &[group(&env, ConditionLogic::Or, &[])]);
+    assert_eq!(
+        client.try_propose_transfer(
+            &treasurer,
+            &recipient,
+            &token,
+            &100,
+            &Symbol::new(&env, "cond"),
+            &Priority::Normal,
+            &empty_group,
+            &ConditionLogic::And,
+            &0i128,
+        ),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+
+    let mut nested = Condition::BalanceAbove(0);
+    for _ in 0..4 {
+        nested = group(&env, ConditionLogic::And, &[nested]);
+    }
+    let too_deep = conditions(&env, &[nested]);
+    assert_eq!(
+        client.try_propose_transfer(
+            &treasurer,
+            &recipient,
+            &token,
+            &100,
+            &Symbol::new(&env, "cond"),
+            &Priority::Normal,
+            &too_deep,
+            &ConditionLogic::And,
+            &0i128,
+        ),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+
+    // Three levels of nesting are allowed.
+    let mut nested = Condition::BalanceAbove(0);
+    for _ in 0..3 {
+        nested = group(&env, ConditionLogic::And, &[nested]);
+    }
+    let allowed = conditions(&env, &[nested]);
+    let proposal_id = propose_with(&env, &client, &treasurer, &token, &allowed);
+    assert_executes(&client, &treasurer, proposal_id);
+}
+
+#[test]
+fn test_every_proposal_path_rejects_malformed_groups() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, treasurer, token) = setup(&env);
+    let recipient = Address::generate(&env);
+    let empty_group = conditions(&env, &[group(&env, ConditionLogic::Or, &[])]);
+
+    let transfers = Vec::from_array(
+        &env,
+        [TransferDetails {
+            recipient: recipient.clone(),
+            token: token.clone(),
+            amount: 100,
+        }],
+    );
+    assert_eq!(
+        client.try_batch_propose_transfers(
+            &treasurer,
+            &transfers,
+            &Priority::Normal,
+            &empty_group,
+            &ConditionLogic::And,
+            &0i128,
+        ),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+
+    let swap = SwapProposal::Swap(
+        Address::generate(&env),
+        token.clone(),
+        Address::generate(&env),
+        100,
+        90,
+    );
+    assert_eq!(
+        client.try_propose_swap(
+            &treasurer,
+            &swap,
+            &Priority::Normal,
+            &empty_group,
+            &ConditionLogic::And,
+            &0i128,
+        ),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+
+    let actions = Vec::from_array(
+        &env,
+        [VaultAction {
+            vault_address: Address::generate(&env),
+            recipient,
+            token,
+            amount: 100,
+            memo: Symbol::new(&env, "cross"),
+        }],
+    );
+    assert_eq!(
+        client.try_propose_cross_vault(
+            &treasurer,
+            &actions,
+            &Priority::Normal,
+            &empty_group,
+            &ConditionLogic::And,
+            &0i128,
+        ),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+}
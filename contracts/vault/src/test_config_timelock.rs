@@ -0,0 +1,206 @@
+//! Unit tests for timelocked configuration changes.
+//!
+//! Covers: queuing behind the delay, applying after it, cancellation by
+//! signers and guardians, lowering the delay and queue-time validation.
+
+use crate::types::{ConfigChangeStatus, PauseConfig, RetryConfig, VelocityConfig};
+use crate::{InitConfig, ListMode, Role, ThresholdStrategy, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Symbol, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+struct Setup {
+    client: VaultDAOClient<'static>,
+    admin: Address,
+    signer: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let signer = Address::generate(env);
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 10_000,
+            daily_limit: 50_000,
+            weekly_limit: 100_000,
+            timelock_threshold: 50_000,
+            timelock_delay: 100,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+
+    client.set_config_delay(&admin, &100);
+    Setup {
+        client,
+        admin,
+        signer,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_limits_change_waits_out_delay() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.ledger().set_sequence_number(10);
+
+    s.client.update_limits(&s.admin, &5_000, &20_000, &60_000);
+    assert_eq!(s.client.get_config().spending_limit, 10_000);
+
+    let pending = s.client.get_pending_config_changes();
+    assert_eq!(pending.len(), 1);
+    let change = pending.get(0).unwrap();
+    assert_eq!(change.action, Symbol::new(&env, "update_limits"));
+    assert_eq!(change.proposer, s.admin);
+    assert_eq!(change.eta, 110);
+
+    assert_eq!(
+        s.client.try_apply_config_change(&s.signer, &change.id),
+        Err(Ok(VaultError::TimelockNotExpired))
+    );
+    env.ledger().set_sequence_number(110);
+    s.client.apply_config_change(&s.signer, &change.id);
+
+    let config = s.client.get_config();
+    assert_eq!(config.spending_limit, 5_000);
+    assert_eq!(config.daily_limit, 20_000);
+    assert_eq!(config.weekly_limit, 60_000);
+    assert_eq!(s.client.get_pending_config_changes().len(), 0);
+    assert_eq!(
+        s.client.get_config_change(&change.id).status,
+        ConfigChangeStatus::Applied
+    );
+    assert_eq!(
+        s.client.try_apply_config_change(&s.signer, &change.id),
+        Err(Ok(VaultError::ConfigChangeNotPending))
+    );
+}
+
+#[test]
+fn test_signers_and_guardians_cancel_pending_changes() {
+    let env = Env::default();
+    let s = setup(&env);
+    let guardian = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    // Pause configuration is not timelocked
+    s.client.set_pause_config(
+        &s.admin,
+        &PauseConfig {
+            guardians: Vec::from_array(&env, [guardian.clone()]),
+            unpause_threshold: 1,
+            max_duration: 1_000,
+        },
+    );
+
+    s.client.set_list_mode(&s.admin, &ListMode::Blacklist);
+    s.client.set_list_mode(&s.admin, &ListMode::Whitelist);
+    let pending = s.client.get_pending_config_changes();
+    let first = pending.get(0).unwrap().id;
+    let second = pending.get(1).unwrap().id;
+
+    assert_eq!(
+        s.client.try_cancel_config_change(&outsider, &first),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    s.client.cancel_config_change(&s.signer, &first);
+    s.client.cancel_config_change(&guardian, &second);
+    assert_eq!(s.client.get_pending_config_changes().len(), 0);
+    assert_eq!(
+        s.client.try_cancel_config_change(&s.signer, &first),
+        Err(Ok(VaultError::ConfigChangeNotPending))
+    );
+
+    env.ledger().set_sequence_number(200);
+    assert_eq!(
+        s.client.try_apply_config_change(&s.admin, &first),
+        Err(Ok(VaultError::ConfigChangeNotPending))
+    );
+    assert_eq!(s.client.get_list_mode(), ListMode::Disabled);
+}
+
+#[test]
+fn test_lowering_delay_is_itself_delayed() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_config_delay(&s.admin, &0);
+    assert_eq!(s.client.get_config_delay(), 100);
+    let id = s.client.get_pending_config_changes().get(0).unwrap().id;
+
+    env.ledger().set_sequence_number(100);
+    s.client.apply_config_change(&s.admin, &id);
+    assert_eq!(s.client.get_config_delay(), 0);
+
+    // Changes now apply immediately
+    s.client.set_list_mode(&s.admin, &ListMode::Blacklist);
+    assert_eq!(s.client.get_list_mode(), ListMode::Blacklist);
+}
+
+#[test]
+fn test_config_timelock_validation() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_set_config_delay(&s.admin, &200_000),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+    // Invalid values are rejected when queued, not when applied
+    assert_eq!(
+        s.client
+            .try_update_limits(&s.admin, &30_000, &20_000, &60_000),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+    assert_eq!(
+        s.client.try_apply_config_change(&s.admin, &42),
+        Err(Ok(VaultError::ConfigChangeNotFound))
+    );
+
+    // A change only applies while its proposer is still an admin
+    s.client.update_limits(&s.admin, &5_000, &20_000, &60_000);
+    let id = s.client.get_pending_config_changes().get(0).unwrap().id;
+    let other_admin = Address::generate(&env);
+    s.client.set_role(&s.admin, &other_admin, &Role::Admin);
+    s.client.set_role(&other_admin, &s.admin, &Role::Member);
+    env.ledger().set_sequence_number(100);
+    assert_eq!(
+        s.client.try_apply_config_change(&s.signer, &id),
+        Err(Ok(VaultError::Unauthorized))
+    );
+}
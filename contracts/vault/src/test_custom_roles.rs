@@ -0,0 +1,297 @@
+//! Unit tests for admin-defined custom roles.
+//!
+//! Covers: defining and enumerating roles, multiple roles per address,
+//! per-permission amount caps at proposal creation, removal and validation.
+
+use crate::types::{Permission, PermissionScope, RetryConfig, RolePermission, VelocityConfig};
+use crate::{
+    ConditionLogic, InitConfig, Priority, Role, ThresholdStrategy, VaultDAO, VaultDAOClient,
+    VaultError,
+};
+use soroban_sdk::{testutils::Address as _, Address, Env, Symbol, Vec};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn setup(env: &Env) -> (VaultDAOClient<'static>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let member = Address::generate(env);
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 1_000,
+            daily_limit: 5_000,
+            weekly_limit: 10_000,
+            timelock_threshold: 500,
+            timelock_delay: 100,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+    client.set_role(&admin, &member, &Role::Member);
+    (client, admin, member)
+}
+
+fn grant(permission: Permission, max_amount: Option<i128>) -> RolePermission {
+    RolePermission {
+        permission,
+        max_amount,
+    }
+}
+
+fn propose(
+    env: &Env,
+    client: &VaultDAOClient,
+    proposer: &Address,
+    amount: i128,
+) -> Result<u64, VaultError> {
+    match client.try_propose_transfer(
+        proposer,
+        &Address::generate(env),
+        &Address::generate(env),
+        &amount,
+        &Symbol::new(env, "payroll"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0i128,
+    ) {
+        Ok(Ok(id)) => Ok(id),
+        Err(Ok(err)) => Err(err),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Definition and membership
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_define_assign_and_enumerate_roles() {
+    let env = Env::default();
+    let (client, admin, member) = setup(&env);
+    let payroll = Symbol::new(&env, "payroll");
+    let auditor = Symbol::new(&env, "auditor");
+
+    client.define_custom_role(
+        &admin,
+        &payroll,
+        &Vec::from_array(&env, [grant(Permission::CreateProposal, Some(300))]),
+    );
+    client.define_custom_role(
+        &admin,
+        &auditor,
+        &Vec::from_array(&env, [grant(Permission::ManageLists, None)]),
+    );
+    assert_eq!(
+        client.list_custom_roles(),
+        Vec::from_array(&env, [payroll.clone(), auditor.clone()])
+    );
+
+    client.assign_custom_role(&admin, &member, &payroll);
+    client.assign_custom_role(&admin, &member, &auditor);
+    // Assigning twice is a no-op.
+    client.assign_custom_role(&admin, &member, &auditor);
+
+    assert_eq!(
+        client.get_custom_roles_of(&member),
+        Vec::from_array(&env, [payroll.clone(), auditor.clone()])
+    );
+    assert_eq!(
+        client.get_custom_role_members(&auditor),
+        Vec::from_array(&env, [member.clone()])
+    );
+    assert!(client.has_permission(&member, &Permission::ManageLists));
+    assert!(client.has_permission(&member, &Permission::CreateProposal));
+    assert!(!client.has_permission(&member, &Permission::ManageConfig));
+
+    client.unassign_custom_role(&admin, &member, &auditor);
+    assert!(!client.has_permission(&member, &Permission::ManageLists));
+    assert_eq!(
+        client.get_custom_role_members(&auditor),
+        Vec::<Address>::new(&env)
+    );
+    assert_eq!(
+        client.try_unassign_custom_role(&admin, &member, &auditor),
+        Err(Ok(VaultError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_redefining_role_updates_holders() {
+    let env = Env::default();
+    let (client, admin, member) = setup(&env);
+    let ops = Symbol::new(&env, "ops");
+
+    client.define_custom_role(
+        &admin,
+        &ops,
+        &Vec::from_array(&env, [grant(Permission::ManageLists, None)]),
+    );
+    client.assign_custom_role(&admin, &member, &ops);
+    client.define_custom_role(
+        &admin,
+        &ops,
+        &Vec::from_array(&env, [grant(Permission::ManageTemplates, None)]),
+    );
+
+    assert_eq!(client.list_custom_roles().len(), 1);
+    assert!(client.has_permission(&member, &Permission::ManageTemplates));
+    assert!(!client.has_permission(&member, &Permission::ManageLists));
+    assert_eq!(client.get_custom_role(&ops).defined_by, admin);
+}
+
+// ---------------------------------------------------------------------------
+// Amount caps
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_capped_role_limits_proposal_amount() {
+    let env = Env::default();
+    let (client, admin, member) = setup(&env);
+    let payroll = Symbol::new(&env, "payroll");
+
+    assert_eq!(
+        propose(&env, &client, &member, 200),
+        Err(VaultError::InsufficientRole)
+    );
+
+    client.define_custom_role(
+        &admin,
+        &payroll,
+        &Vec::from_array(&env, [grant(Permission::CreateProposal, Some(300))]),
+    );
+    client.assign_custom_role(&admin, &member, &payroll);
+
+    assert!(propose(&env, &client, &member, 300).is_ok());
+    assert_eq!(
+        propose(&env, &client, &member, 301),
+        Err(VaultError::InsufficientRole)
+    );
+
+    // A capped holder cannot hand the permission on.
+    assert_eq!(
+        client.try_delegate_permission(
+            &member,
+            &Address::generate(&env),
+            &Permission::CreateProposal,
+            &1_000,
+            &PermissionScope::unrestricted(&env)
+        ),
+        Err(Ok(VaultError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_remove_role_unassigns_members() {
+    let env = Env::default();
+    let (client, admin, member) = setup(&env);
+    let payroll = Symbol::new(&env, "payroll");
+
+    client.define_custom_role(
+        &admin,
+        &payroll,
+        &Vec::from_array(&env, [grant(Permission::CreateProposal, None)]),
+    );
+    client.assign_custom_role(&admin, &member, &payroll);
+    assert!(propose(&env, &client, &member, 500).is_ok());
+
+    client.remove_custom_role(&admin, &payroll);
+    assert_eq!(client.list_custom_roles(), Vec::<Symbol>::new(&env));
+    assert_eq!(
+        client.get_custom_roles_of(&member),
+        Vec::<Symbol>::new(&env)
+    );
+    assert_eq!(
+        client.try_get_custom_role(&payroll),
+        Err(Ok(VaultError::CustomRoleNotFound))
+    );
+    assert_eq!(
+        propose(&env, &client, &member, 500),
+        Err(VaultError::InsufficientRole)
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_role_management_validation() {
+    let env = Env::default();
+    let (client, admin, member) = setup(&env);
+    let name = Symbol::new(&env, "ops");
+    let valid = Vec::from_array(&env, [grant(Permission::ViewMetrics, None)]);
+
+    assert_eq!(
+        client.try_define_custom_role(&member, &name, &valid),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_define_custom_role(&admin, &name, &Vec::new(&env)),
+        Err(Ok(VaultError::InvalidRolePermissions))
+    );
+    assert_eq!(
+        client.try_define_custom_role(
+            &admin,
+            &name,
+            &Vec::from_array(&env, [grant(Permission::CreateProposal, Some(0))])
+        ),
+        Err(Ok(VaultError::InvalidRolePermissions))
+    );
+    assert_eq!(
+        client.try_define_custom_role(
+            &admin,
+            &name,
+            &Vec::from_array(
+                &env,
+                [
+                    grant(Permission::ViewMetrics, None),
+                    grant(Permission::ViewMetrics, Some(10))
+                ]
+            )
+        ),
+        Err(Ok(VaultError::InvalidRolePermissions))
+    );
+    assert_eq!(
+        client.try_assign_custom_role(&admin, &member, &name),
+        Err(Ok(VaultError::CustomRoleNotFound))
+    );
+
+    for i in 0..crate::MAX_CUSTOM_ROLES_PER_ADDRESS {
+        let role = Symbol::new(&env, ["r0", "r1", "r2", "r3", "r4"][i as usize]);
+        client.define_custom_role(&admin, &role, &valid);
+        client.assign_custom_role(&admin, &member, &role);
+    }
+    client.define_custom_role(&admin, &name, &valid);
+    assert_eq!(
+        client.try_assign_custom_role(&admin, &member, &name),
+        Err(Ok(VaultError::CustomRoleLimit))
+    );
+}
@@ -0,0 +1,1546 @@
+//! Unit tests for DEX/AMM execution.
+//!
+//! Covers: execute_swap against mock router/pair/farm contracts, measured
+//! SwapResult amounts (including results stored in the older layout),
+//! min-output / price-impact enforcement, DCA and limit orders.
+
+use crate::storage::FeatureKey;
+use crate::types::{
+    CircuitBreakerConfig, Condition, DcaStatus, DexConfig, LegacySwapResult, LimitOrderStatus,
+    RetryConfig, SwapProposal, VaultOracleConfig, VelocityConfig,
+};
+use crate::{
+    ConditionLogic, InitConfig, Priority, ProposalStatus, Role, ThresholdStrategy, VaultDAO,
+    VaultDAOClient, VaultError,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, IntoVal, Symbol, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Mock DEX contracts
+// ---------------------------------------------------------------------------
+
+mod mock_pair {
+    use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+    #[contracttype]
+    pub enum DataKey {
+        Token0,
+        Token1,
+        Reserves,
+        Balance(Address),
+    }
+
+    /// Pair contract that doubles as its own LP token.
+    #[contract]
+    pub struct MockPair;
+
+    #[contractimpl]
+    impl MockPair {
+        pub fn init(env: Env, token_0: Address, token_1: Address) {
+            env.storage().instance().set(&DataKey::Token0, &token_0);
+            env.storage().instance().set(&DataKey::Token1, &token_1);
+        }
+
+        pub fn set_reserves(env: Env, reserve_0: i128, reserve_1: i128) {
+            env.storage()
+                .instance()
+                .set(&DataKey::Reserves, &(reserve_0, reserve_1));
+        }
+
+        pub fn token_0(env: Env) -> Address {
+            env.storage().instance().get(&DataKey::Token0).unwrap()
+        }
+
+        pub fn token_1(env: Env) -> Address {
+            env.storage().instance().get(&DataKey::Token1).unwrap()
+        }
+
+        pub fn get_reserves(env: Env) -> (i128, i128) {
+            env.storage()
+                .instance()
+                .get(&DataKey::Reserves)
+                .unwrap_or((0, 0))
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            env.storage()
+                .instance()
+                .get(&DataKey::Balance(id))
+                .unwrap_or(0)
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            from.require_auth();
+            let from_balance = Self::balance(env.clone(), from.clone());
+            if from_balance < amount {
+                panic!("insufficient LP balance");
+            }
+            let to_balance = Self::balance(env.clone(), to.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::Balance(from), &(from_balance - amount));
+            env.storage()
+                .instance()
+                .set(&DataKey::Balance(to), &(to_balance + amount));
+        }
+
+        pub fn mint(env: Env, to: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), to.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::Balance(to), &(balance + amount));
+        }
+
+        pub fn burn(env: Env, from: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), from.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::Balance(from), &(balance - amount));
+        }
+    }
+}
+
+mod mock_token {
+    use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+    #[contracttype]
+    pub enum DataKey {
+        Decimals,
+        Balance(Address),
+    }
+
+    /// Minimal token with configurable decimals.
+    #[contract]
+    pub struct MockToken;
+
+    #[contractimpl]
+    impl MockToken {
+        pub fn init(env: Env, decimals: u32) {
+            env.storage().instance().set(&DataKey::Decimals, &decimals);
+        }
+
+        pub fn decimals(env: Env) -> u32 {
+            env.storage().instance().get(&DataKey::Decimals).unwrap()
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            env.storage()
+                .instance()
+                .get(&DataKey::Balance(id))
+                .unwrap_or(0)
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            from.require_auth();
+            let from_balance = Self::balance(env.clone(), from.clone());
+            let to_balance = Self::balance(env.clone(), to.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::Balance(from), &(from_balance - amount));
+            env.storage()
+                .instance()
+                .set(&DataKey::Balance(to), &(to_balance + amount));
+        }
+
+        pub fn mint(env: Env, to: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), to.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::Balance(to), &(balance + amount));
+        }
+    }
+}
+
+mod mock_router {
+    use super::mock_pair::MockPairClient;
+    use soroban_sdk::{
+        contract, contractimpl, contracttype, token::TokenClient, Address, Env, Vec,
+    };
+
+    #[contracttype]
+    pub enum DataKey {
+        Pair,
+        RateBps,
+        HaircutBps,
+        QuoteFails,
+    }
+
+    /// Router that quotes `amount_in * rate_bps / 10_000` and can be told to
+    /// deliver less than it quoted. Outputs are paid from its own balance.
+    #[contract]
+    pub struct MockRouter;
+
+    #[contractimpl]
+    impl MockRouter {
+        pub fn init(env: Env, pair: Address, rate_bps: i128) {
+            env.storage().instance().set(&DataKey::Pair, &pair);
+            env.storage().instance().set(&DataKey::RateBps, &rate_bps);
+        }
+
+        pub fn set_rate(env: Env, rate_bps: i128) {
+            env.storage().instance().set(&DataKey::RateBps, &rate_bps);
+        }
+
+        pub fn set_haircut(env: Env, haircut_bps: i128) {
+            env.storage()
+                .instance()
+                .set(&DataKey::HaircutBps, &haircut_bps);
+        }
+
+        pub fn router_pair_for(env: Env, _token_a: Address, _token_b: Address) -> Address {
+            env.storage().instance().get(&DataKey::Pair).unwrap()
+        }
+
+        pub fn set_quote_fails(env: Env, fails: bool) {
+            env.storage().instance().set(&DataKey::QuoteFails, &fails);
+        }
+
+        pub fn router_get_amounts_out(env: Env, amount_in: i128, _path: Vec<Address>) -> Vec<i128> {
+            if env
+                .storage()
+                .instance()
+                .get(&DataKey::QuoteFails)
+                .unwrap_or(false)
+            {
+                panic!("quote unavailable");
+            }
+            let rate: i128 = env.storage().instance().get(&DataKey::RateBps).unwrap();
+            Vec::from_array(&env, [amount_in, amount_in * rate / 10_000])
+        }
+
+        pub fn swap_exact_tokens_for_tokens(
+            env: Env,
+            amount_in: i128,
+            _amount_out_min: i128,
+            path: Vec<Address>,
+            to: Address,
+            _deadline: u64,
+        ) -> Vec<i128> {
+            let pair = Self::router_pair_for(env.clone(), to.clone(), to.clone());
+            TokenClient::new(&env, &path.get(0).unwrap()).transfer(&to, &pair, &amount_in);
+
+            let quoted = Self::router_get_amounts_out(env.clone(), amount_in, path.clone())
+                .get(1)
+                .unwrap();
+            let haircut: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::HaircutBps)
+                .unwrap_or(0);
+            let amount_out = quoted - quoted * haircut / 10_000;
+            TokenClient::new(&env, &path.get(1).unwrap()).transfer(
+                &env.current_contract_address(),
+                &to,
+                &amount_out,
+            );
+            Vec::from_array(&env, [amount_in, amount_out])
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        pub fn add_liquidity(
+            env: Env,
+            token_a: Address,
+            token_b: Address,
+            amount_a_desired: i128,
+            amount_b_desired: i128,
+            _amount_a_min: i128,
+            _amount_b_min: i128,
+            to: Address,
+            _deadline: u64,
+        ) -> (i128, i128, i128) {
+            let pair = Self::router_pair_for(env.clone(), token_a.clone(), token_b.clone());
+            TokenClient::new(&env, &token_a).transfer(&to, &pair, &amount_a_desired);
+            TokenClient::new(&env, &token_b).transfer(&to, &pair, &amount_b_desired);
+            let liquidity = amount_a_desired + amount_b_desired;
+            MockPairClient::new(&env, &pair).mint(&to, &liquidity);
+            (amount_a_desired, amount_b_desired, liquidity)
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        pub fn remove_liquidity(
+            env: Env,
+            token_a: Address,
+            token_b: Address,
+            liquidity: i128,
+            _amount_a_min: i128,
+            _amount_b_min: i128,
+            to: Address,
+            _deadline: u64,
+        ) -> (i128, i128) {
+            let pair = Self::router_pair_for(env.clone(), token_a.clone(), token_b.clone());
+            let pair_client = MockPairClient::new(&env, &pair);
+            pair_client.transfer(&to, &pair, &liquidity);
+            pair_client.burn(&pair, &liquidity);
+
+            let amount = liquidity / 2;
+            let router = env.current_contract_address();
+            TokenClient::new(&env, &token_a).transfer(&router, &to, &amount);
+            TokenClient::new(&env, &token_b).transfer(&router, &to, &amount);
+            (amount, amount)
+        }
+    }
+}
+
+mod mock_farm {
+    use soroban_sdk::{contract, contractimpl, contracttype, token::TokenClient, Address, Env};
+
+    #[contracttype]
+    pub enum DataKey {
+        RewardToken,
+        RewardPerClaim,
+    }
+
+    #[contract]
+    pub struct MockFarm;
+
+    #[contractimpl]
+    impl MockFarm {
+        pub fn init(env: Env, reward_token: Address, reward_per_claim: i128) {
+            env.storage()
+                .instance()
+                .set(&DataKey::RewardToken, &reward_token);
+            env.storage()
+                .instance()
+                .set(&DataKey::RewardPerClaim, &reward_per_claim);
+        }
+
+        pub fn reward_token(env: Env) -> Address {
+            env.storage().instance().get(&DataKey::RewardToken).unwrap()
+        }
+
+        pub fn stake(env: Env, from: Address, lp_token: Address, amount: i128) {
+            TokenClient::new(&env, &lp_token).transfer(
+                &from,
+                &env.current_contract_address(),
+                &amount,
+            );
+        }
+
+        pub fn unstake(env: Env, to: Address, lp_token: Address, amount: i128) {
+            TokenClient::new(&env, &lp_token).transfer(
+                &env.current_contract_address(),
+                &to,
+                &amount,
+            );
+        }
+
+        pub fn claim_rewards(env: Env, to: Address) -> i128 {
+            let amount: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::RewardPerClaim)
+                .unwrap();
+            TokenClient::new(&env, &Self::reward_token(env.clone())).transfer(
+                &env.current_contract_address(),
+                &to,
+                &amount,
+            );
+            amount
+        }
+    }
+}
+
+mod mock_oracle {
+    use crate::types::{Asset, VaultPriceData};
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    /// SEP-40 oracle returning prices set by the test, stamped with the
+    /// current ledger time.
+    #[contract]
+    pub struct MockOracle;
+
+    #[contractimpl]
+    impl MockOracle {
+        pub fn set_price(env: Env, asset: Address, price: i128) {
+            env.storage().instance().set(&asset, &price);
+        }
+
+        pub fn decimals(_env: Env) -> u32 {
+            7
+        }
+
+        pub fn resolution(_env: Env) -> u32 {
+            300
+        }
+
+        pub fn lastprice(env: Env, asset: Asset) -> Option<VaultPriceData> {
+            let Asset::Stellar(asset) = asset else {
+                return None;
+            };
+            env.storage()
+                .instance()
+                .get(&asset)
+                .map(|price| VaultPriceData {
+                    price,
+                    timestamp: env.ledger().timestamp(),
+                })
+        }
+    }
+}
+
+use mock_farm::{MockFarm, MockFarmClient};
+use mock_oracle::{MockOracle, MockOracleClient};
+use mock_pair::{MockPair, MockPairClient};
+use mock_router::{MockRouter, MockRouterClient};
+use mock_token::{MockToken, MockTokenClient};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+struct DexSetup<'a> {
+    client: VaultDAOClient<'a>,
+    admin: Address,
+    treasurer: Address,
+    token_a: Address,
+    token_b: Address,
+    pair: MockPairClient<'a>,
+    router: MockRouterClient<'a>,
+    farm: MockFarmClient<'a>,
+    oracle: MockOracleClient<'a>,
+    reward_token: Address,
+}
+
+fn create_token(env: &Env) -> Address {
+    let token_admin = Address::generate(env);
+    env.register_stellar_asset_contract_v2(token_admin)
+        .address()
+}
+
+/// Vault holding 10_000 of each token, a router quoting 2:1 with 100_000 of
+/// each token in reserve, an oracle pricing token_a at twice token_b, and a
+/// farm paying 25 reward tokens per claim.
+fn setup(env: &Env) -> DexSetup<'static> {
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let treasurer = Address::generate(env);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(treasurer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 1_000_000,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+    client.set_role(&admin, &treasurer, &Role::Treasurer);
+
+    let token_a = create_token(env);
+    let token_b = create_token(env);
+    let reward_token = create_token(env);
+
+    let pair = MockPairClient::new(env, &env.register(MockPair, ()));
+    pair.init(&token_a, &token_b);
+    let router = MockRouterClient::new(env, &env.register(MockRouter, ()));
+    router.init(&pair.address, &20_000);
+    let farm = MockFarmClient::new(env, &env.register(MockFarm, ()));
+    farm.init(&reward_token, &25);
+
+    StellarAssetClient::new(env, &token_a).mint(&contract_id, &10_000);
+    StellarAssetClient::new(env, &token_b).mint(&contract_id, &10_000);
+    StellarAssetClient::new(env, &token_a).mint(&router.address, &100_000);
+    StellarAssetClient::new(env, &token_b).mint(&router.address, &100_000);
+    StellarAssetClient::new(env, &reward_token).mint(&farm.address, &1_000);
+
+    let oracle = MockOracleClient::new(env, &env.register(MockOracle, ()));
+    oracle.set_price(&token_a, &20_000_000);
+    oracle.set_price(&token_b, &10_000_000);
+    client.set_oracle_config(
+        &admin,
+        &VaultOracleConfig {
+            address: oracle.address.clone(),
+            base_symbol: Symbol::new(env, "USD"),
+            max_staleness: 100,
+        },
+    );
+
+    let mut enabled_dexs = Vec::new(env);
+    enabled_dexs.push_back(router.address.clone());
+    enabled_dexs.push_back(farm.address.clone());
+    client.set_dex_config(
+        &admin,
+        &DexConfig {
+            enabled_dexs,
+            max_slippage_bps: 100,
+            max_price_impact_bps: 500,
+            min_liquidity: 0,
+        },
+    );
+
+    DexSetup {
+        client,
+        admin,
+        treasurer,
+        token_a,
+        token_b,
+        pair,
+        router,
+        farm,
+        oracle,
+        reward_token,
+    }
+}
+
+fn propose_and_approve(env: &Env, s: &DexSetup, op: &SwapProposal) -> u64 {
+    let proposal_id = s.client.propose_swap(
+        &s.treasurer,
+        op,
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0i128,
+    );
+    s.client.approve_proposal(&s.treasurer, &proposal_id);
+    proposal_id
+}
+
+fn balance(env: &Env, token: &Address, id: &Address) -> i128 {
+    TokenClient::new(env, token).balance(id)
+}
+
+// ---------------------------------------------------------------------------
+// Swap
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_execute_swap_records_measured_amounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let op = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        500,
+        990,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+
+    let vault = s.client.address.clone();
+    assert_eq!(balance(&env, &s.token_a, &vault), 9_500);
+    assert_eq!(balance(&env, &s.token_b, &vault), 11_000);
+    assert_eq!(balance(&env, &s.token_a, &s.pair.address), 500);
+
+    let result = s.client.get_swap_result(&proposal_id).unwrap();
+    assert_eq!(result.amount_in, 500);
+    assert_eq!(result.amount_out, 1_000);
+    assert_eq!(result.price_impact_bps, 0);
+    assert_eq!(result.oracle_price, 20_000_000);
+    assert_eq!(
+        s.client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+}
+
+#[test]
+fn test_swap_result_stored_before_liquidity_fields_still_reads() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    env.as_contract(&s.client.address, || {
+        env.storage().persistent().set(
+            &FeatureKey::SwapResult(7),
+            &LegacySwapResult {
+                amount_in: 500,
+                amount_out: 1_000,
+                price_impact_bps: 12,
+                executed_at: 42,
+            },
+        );
+    });
+
+    let result = s.client.get_swap_result(&7).unwrap();
+    assert_eq!(result.amount_in, 500);
+    assert_eq!(result.amount_out, 1_000);
+    assert_eq!(result.amount_b, 0);
+    assert_eq!(result.price_impact_bps, 12);
+    assert_eq!(result.oracle_price, 0);
+    assert_eq!(result.executed_at, 42);
+}
+
+#[test]
+fn test_execute_swap_enforces_min_output_on_received_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    // Router quotes 1000 but delivers 970, below the 990 minimum.
+    s.router.set_haircut(&300);
+    let op = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        500,
+        990,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+
+    assert_eq!(
+        s.client.try_execute_swap(&s.treasurer, &proposal_id),
+        Err(Ok(VaultError::DexError))
+    );
+    let vault = s.client.address.clone();
+    assert_eq!(balance(&env, &s.token_a, &vault), 10_000);
+    assert_eq!(balance(&env, &s.token_b, &vault), 10_000);
+    assert!(s.client.get_swap_result(&proposal_id).is_none());
+    assert_eq!(
+        s.client.get_proposal(&proposal_id).status,
+        ProposalStatus::Approved
+    );
+}
+
+#[test]
+fn test_execute_swap_enforces_price_impact_against_quote() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let op = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        500,
+        900,
+    );
+
+    // 3% short of the quote stays within the 5% bound.
+    s.router.set_haircut(&300);
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+    let result = s.client.get_swap_result(&proposal_id).unwrap();
+    assert_eq!(result.amount_out, 970);
+    assert_eq!(result.price_impact_bps, 300);
+
+    // Realized output may fall short of the oracle by price impact plus
+    // slippage (5% + 1%), no more.
+    s.router.set_haircut(&600);
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+    assert_eq!(
+        s.client
+            .get_swap_result(&proposal_id)
+            .unwrap()
+            .price_impact_bps,
+        600
+    );
+
+    // 8% short of the quote exceeds it even though min_amount_out is met.
+    s.router.set_haircut(&800);
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    assert_eq!(
+        s.client.try_execute_swap(&s.treasurer, &proposal_id),
+        Err(Ok(VaultError::DexError))
+    );
+}
+
+#[test]
+fn test_execute_swap_rejects_quote_deviating_from_oracle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let op = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        500,
+        900,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+
+    // Oracle now implies 1150 out; the router's 1000 quote is 13% short.
+    s.oracle.set_price(&s.token_a, &23_000_000);
+    assert_eq!(
+        s.client.try_execute_swap(&s.treasurer, &proposal_id),
+        Err(Ok(VaultError::DexError))
+    );
+
+    // Within the 5% price impact bound (1050 implied) the swap goes through.
+    s.oracle.set_price(&s.token_a, &21_000_000);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+    let result = s.client.get_swap_result(&proposal_id).unwrap();
+    assert_eq!(result.amount_out, 1_000);
+    assert_eq!(result.price_impact_bps, 476);
+    assert_eq!(result.oracle_price, 21_000_000);
+}
+
+#[test]
+fn test_execute_swap_rejects_quote_above_oracle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let op = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        500,
+        900,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+
+    // Oracle implies 900 out; a 1000 quote is 11% too generous.
+    s.oracle.set_price(&s.token_a, &18_000_000);
+    assert_eq!(
+        s.client.try_execute_swap(&s.treasurer, &proposal_id),
+        Err(Ok(VaultError::DexError))
+    );
+}
+
+#[test]
+fn test_execute_swap_scales_oracle_bound_by_decimals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    // 6-decimal token priced like token_b: 500 raw units of token_a are worth
+    // 100 raw units of it, which is what the router quotes at 20%.
+    let token_c = MockTokenClient::new(&env, &env.register(MockToken, ()));
+    token_c.init(&6);
+    token_c.mint(&s.router.address, &100_000);
+    s.oracle.set_price(&token_c.address, &10_000_000);
+    s.router.set_rate(&2_000);
+
+    let op = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        token_c.address.clone(),
+        500,
+        90,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+
+    let result = s.client.get_swap_result(&proposal_id).unwrap();
+    assert_eq!(result.amount_out, 100);
+    assert_eq!(result.price_impact_bps, 0);
+    assert_eq!(token_c.balance(&s.client.address), 100);
+}
+
+#[test]
+fn test_execute_swap_rejects_overflowing_oracle_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let op = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        500,
+        990,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.oracle.set_price(&s.token_a, &(i128::MAX / 1_000));
+    assert_eq!(
+        s.client.try_execute_swap(&s.treasurer, &proposal_id),
+        Err(Ok(VaultError::DexError))
+    );
+    assert_eq!(balance(&env, &s.token_a, &s.client.address), 10_000);
+}
+
+#[test]
+fn test_execute_swap_maps_router_failure_to_dex_error() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let op = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        500,
+        900,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+
+    s.router.set_quote_fails(&true);
+    assert_eq!(
+        s.client.try_execute_swap(&s.treasurer, &proposal_id),
+        Err(Ok(VaultError::DexError))
+    );
+}
+
+#[test]
+fn test_execute_swap_requires_oracle_price_for_both_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let unpriced = create_token(&env);
+    let op = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        unpriced,
+        500,
+        900,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    assert!(s
+        .client
+        .try_execute_swap(&s.treasurer, &proposal_id)
+        .is_err());
+    assert_eq!(
+        s.client.get_proposal(&proposal_id).status,
+        ProposalStatus::Approved
+    );
+}
+
+#[test]
+fn test_execute_swap_rejects_dex_disabled_after_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let op = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        500,
+        990,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+
+    s.client.set_dex_config(
+        &s.admin,
+        &DexConfig {
+            enabled_dexs: Vec::new(&env),
+            max_slippage_bps: 100,
+            max_price_impact_bps: 500,
+            min_liquidity: 0,
+        },
+    );
+    assert_eq!(
+        s.client.try_execute_swap(&s.treasurer, &proposal_id),
+        Err(Ok(VaultError::DexError))
+    );
+}
+
+#[test]
+fn test_execute_swap_waits_for_conditions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let op = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        500,
+        990,
+    );
+    let after = env.ledger().sequence() as u64 + 100;
+    let proposal_id = s.client.propose_swap(
+        &s.treasurer,
+        &op,
+        &Priority::Normal,
+        &Vec::from_array(&env, [Condition::DateAfter(after)]),
+        &ConditionLogic::And,
+        &0i128,
+    );
+    s.client.approve_proposal(&s.treasurer, &proposal_id);
+    assert_eq!(
+        s.client.try_execute_swap(&s.treasurer, &proposal_id),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+
+    env.ledger().set_sequence_number(after as u32 + 1);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+    assert_eq!(
+        s.client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+}
+
+#[test]
+fn test_execute_swap_blocked_by_tripped_circuit_breaker() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let op = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        500,
+        990,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.set_circuit_breaker(
+        &s.admin,
+        &s.token_b,
+        &CircuitBreakerConfig {
+            max_move_bps: 2_000,
+            window_ledgers: 100,
+        },
+    );
+    assert!(!s.client.check_circuit_breaker(&s.token_b));
+
+    s.oracle.set_price(&s.token_b, &5_000_000);
+    assert!(s.client.check_circuit_breaker(&s.token_b));
+    s.oracle.set_price(&s.token_b, &10_000_000);
+    assert_eq!(
+        s.client.try_execute_swap(&s.treasurer, &proposal_id),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+
+    s.client.reset_circuit_breaker(&s.admin, &s.token_b);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+    assert_eq!(
+        s.client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Liquidity
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_add_and_remove_liquidity() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let vault = s.client.address.clone();
+
+    // Reserves at 1:2 -> only 200 of the desired 300 token_b is deposited.
+    s.pair.set_reserves(&1_000, &2_000);
+    let op = SwapProposal::AddLiquidity(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        100,
+        300,
+        300,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+
+    assert_eq!(balance(&env, &s.token_a, &vault), 9_900);
+    assert_eq!(balance(&env, &s.token_b, &vault), 9_800);
+    assert_eq!(s.pair.balance(&vault), 300);
+    let result = s.client.get_swap_result(&proposal_id).unwrap();
+    assert_eq!(result.amount_in, 100);
+    assert_eq!(result.amount_out, 300);
+    assert_eq!(result.amount_b, 200);
+
+    let op = SwapProposal::RemoveLiquidity(
+        s.router.address.clone(),
+        s.pair.address.clone(),
+        200,
+        100,
+        100,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+
+    assert_eq!(s.pair.balance(&vault), 100);
+    assert_eq!(balance(&env, &s.token_a, &vault), 10_000);
+    assert_eq!(balance(&env, &s.token_b, &vault), 9_900);
+    let result = s.client.get_swap_result(&proposal_id).unwrap();
+    assert_eq!(result.amount_in, 200);
+    assert_eq!(result.amount_out, 100);
+    assert_eq!(result.amount_b, 100);
+}
+
+#[test]
+fn test_add_liquidity_enforces_min_lp() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let op = SwapProposal::AddLiquidity(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        100,
+        100,
+        201,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    assert_eq!(
+        s.client.try_execute_swap(&s.treasurer, &proposal_id),
+        Err(Ok(VaultError::DexError))
+    );
+    assert_eq!(s.pair.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_add_liquidity_rejects_pool_with_one_empty_reserve() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let op = SwapProposal::AddLiquidity(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        100,
+        100,
+        0,
+    );
+    for (reserve_0, reserve_1) in [(0, 2_000), (1_000, 0)] {
+        s.pair.set_reserves(&reserve_0, &reserve_1);
+        let proposal_id = propose_and_approve(&env, &s, &op);
+        assert_eq!(
+            s.client.try_execute_swap(&s.treasurer, &proposal_id),
+            Err(Ok(VaultError::DexError))
+        );
+    }
+    assert_eq!(s.pair.balance(&s.client.address), 0);
+}
+
+// ---------------------------------------------------------------------------
+// Farming
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_stake_unstake_and_claim_rewards() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let vault = s.client.address.clone();
+    s.pair.mint(&vault, &500);
+
+    let op = SwapProposal::StakeLp(s.farm.address.clone(), s.pair.address.clone(), 300);
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+    assert_eq!(s.pair.balance(&vault), 200);
+    assert_eq!(s.pair.balance(&s.farm.address), 300);
+    assert_eq!(
+        s.client.get_swap_result(&proposal_id).unwrap().amount_in,
+        300
+    );
+
+    let op = SwapProposal::ClaimRewards(s.farm.address.clone());
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+    assert_eq!(balance(&env, &s.reward_token, &vault), 25);
+    assert_eq!(
+        s.client.get_swap_result(&proposal_id).unwrap().amount_out,
+        25
+    );
+
+    let op = SwapProposal::UnstakeLp(s.farm.address.clone(), s.pair.address.clone(), 100);
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+    assert_eq!(s.pair.balance(&vault), 300);
+    assert_eq!(
+        s.client.get_swap_result(&proposal_id).unwrap().amount_out,
+        100
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Position accounting
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_lp_position_tracks_cost_basis_and_realized() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    assert!(s.client.get_lp_position(&s.pair.address).is_none());
+
+    s.pair.set_reserves(&1_000, &2_000);
+    let op = SwapProposal::AddLiquidity(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        100,
+        300,
+        300,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+
+    let position = s.client.get_lp_position(&s.pair.address).unwrap();
+    assert_eq!(position.token_a, s.token_a);
+    assert_eq!(position.token_b, s.token_b);
+    assert_eq!(position.lp_held, 300);
+    assert_eq!(position.cost_basis_a, 100);
+    assert_eq!(position.cost_basis_b, 200);
+
+    // Burning 2/3 of the LP releases 2/3 of the basis against 100 + 100 returned.
+    let op = SwapProposal::RemoveLiquidity(
+        s.router.address.clone(),
+        s.pair.address.clone(),
+        200,
+        100,
+        100,
+    );
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+
+    let position = s.client.get_lp_position(&s.pair.address).unwrap();
+    assert_eq!(position.lp_held, 100);
+    assert_eq!(position.cost_basis_a, 34);
+    assert_eq!(position.cost_basis_b, 67);
+    assert_eq!(position.realized_a, 34);
+    assert_eq!(position.realized_b, -33);
+    assert_eq!(s.client.get_lp_positions().len(), 1);
+}
+
+#[test]
+fn test_farm_position_tracks_stake_and_rewards() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.pair.mint(&s.client.address, &500);
+
+    let op = SwapProposal::StakeLp(s.farm.address.clone(), s.pair.address.clone(), 300);
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+    let op = SwapProposal::ClaimRewards(s.farm.address.clone());
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+    let op = SwapProposal::UnstakeLp(s.farm.address.clone(), s.pair.address.clone(), 100);
+    let proposal_id = propose_and_approve(&env, &s, &op);
+    s.client.execute_swap(&s.treasurer, &proposal_id);
+
+    let farm = s.client.get_farm_position(&s.farm.address).unwrap();
+    assert_eq!(farm.staked, 200);
+    assert_eq!(farm.reward_token, Some(s.reward_token.clone()));
+    assert_eq!(farm.rewards_claimed, 25);
+    assert_eq!(s.client.get_farm_positions().len(), 1);
+
+    // LP minted outside a tracked AddLiquidity carries no cost basis.
+    let position = s.client.get_lp_position(&s.pair.address).unwrap();
+    assert_eq!(position.lp_staked, 200);
+    assert_eq!(position.lp_held, 100);
+    assert_eq!(position.cost_basis_a, 0);
+}
+
+// ---------------------------------------------------------------------------
+// DCA orders
+// ---------------------------------------------------------------------------
+
+fn dca_op(s: &DexSetup, total: i128, slices: u32, interval: u64) -> SwapProposal {
+    SwapProposal::Dca(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        total,
+        slices,
+        interval,
+        600,
+    )
+}
+
+#[test]
+fn test_dca_order_executes_slices_over_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let vault = s.client.address.clone();
+
+    let order_id = propose_and_approve(&env, &s, &dca_op(&s, 1_000, 3, 10));
+    s.client.execute_swap(&s.treasurer, &order_id);
+
+    // Opening the order moves no funds.
+    assert_eq!(balance(&env, &s.token_a, &vault), 10_000);
+    assert!(s.client.get_swap_result(&order_id).is_none());
+    assert_eq!(s.client.get_active_dca_orders().len(), 1);
+
+    let result = s.client.execute_dca_slice(&order_id).unwrap();
+    assert_eq!(result.amount_in, 333);
+    assert_eq!(result.amount_out, 666);
+    assert_eq!(
+        s.client.try_execute_dca_slice(&order_id).err(),
+        Some(Ok(VaultError::TimelockNotExpired))
+    );
+
+    let order = s.client.get_dca_order(&order_id);
+    assert_eq!(order.slices_executed, 1);
+    assert_eq!(order.remaining_amount, 667);
+    assert_eq!(order.average_price, 20_000_000);
+
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    s.client.execute_dca_slice(&order_id);
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    let result = s.client.execute_dca_slice(&order_id).unwrap();
+    assert_eq!(result.amount_in, 334);
+
+    let order = s.client.get_dca_order(&order_id);
+    assert_eq!(order.status, DcaStatus::Completed);
+    assert_eq!(order.amount_in_filled, 1_000);
+    assert_eq!(order.amount_out_filled, 2_000);
+    assert_eq!(order.remaining_amount, 0);
+    assert_eq!(s.client.get_active_dca_orders().len(), 0);
+    assert_eq!(balance(&env, &s.token_a, &vault), 9_000);
+    assert_eq!(balance(&env, &s.token_b, &vault), 12_000);
+
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    assert_eq!(
+        s.client.try_execute_dca_slice(&order_id).err(),
+        Some(Ok(VaultError::DexError))
+    );
+}
+
+#[test]
+fn test_dca_slice_enforces_per_slice_min_output() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let order_id = propose_and_approve(&env, &s, &dca_op(&s, 1_000, 4, 10));
+    s.client.execute_swap(&s.treasurer, &order_id);
+
+    // 250 in returns 500 out, below the 600 per-slice minimum.
+    assert_eq!(
+        s.client.try_execute_dca_slice(&order_id).err(),
+        Some(Ok(VaultError::DexError))
+    );
+    assert_eq!(s.client.get_dca_order(&order_id).slices_executed, 0);
+}
+
+#[test]
+fn test_cancel_dca_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let order_id = propose_and_approve(&env, &s, &dca_op(&s, 1_000, 2, 10));
+    s.client.execute_swap(&s.treasurer, &order_id);
+    s.client.execute_dca_slice(&order_id);
+
+    assert_eq!(
+        s.client.try_cancel_dca_order(&s.treasurer, &order_id),
+        Err(Ok(VaultError::InsufficientRole))
+    );
+    s.client.cancel_dca_order(&s.admin, &order_id);
+
+    let order = s.client.get_dca_order(&order_id);
+    assert_eq!(order.status, DcaStatus::Cancelled);
+    assert_eq!(order.remaining_amount, 500);
+    assert_eq!(s.client.get_active_dca_orders().len(), 0);
+
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    assert_eq!(
+        s.client.try_execute_dca_slice(&order_id).err(),
+        Some(Ok(VaultError::DexError))
+    );
+}
+
+#[test]
+fn test_cancel_dca_order_goes_through_admin_council() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let order_id = propose_and_approve(&env, &s, &dca_op(&s, 1_000, 2, 10));
+    s.client.execute_swap(&s.treasurer, &order_id);
+
+    let second_admin = Address::generate(&env);
+    s.client.set_role(&s.admin, &second_admin, &Role::Admin);
+    s.client.set_admin_council(&s.admin, &2, &Vec::new(&env));
+    assert_eq!(
+        s.client.try_cancel_dca_order(&s.admin, &order_id),
+        Err(Ok(VaultError::AdminActionNotConfirmed))
+    );
+
+    let action = Symbol::new(&env, "cancel_dca_order");
+    let id = s
+        .client
+        .queue_admin_action(&s.admin, &action, &(order_id,).into_val(&env));
+    s.client.confirm_admin_action(&second_admin, &id);
+    s.client.cancel_dca_order(&s.admin, &order_id);
+    assert_eq!(
+        s.client.get_dca_order(&order_id).status,
+        DcaStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_propose_dca_validates_parameters() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    for (op, err) in [
+        (dca_op(&s, 1_000, 0, 10), VaultError::InvalidAmount),
+        (dca_op(&s, 2, 3, 10), VaultError::InvalidAmount),
+        (dca_op(&s, 1_000, 3, 0), VaultError::IntervalTooShort),
+    ] {
+        assert_eq!(
+            s.client.try_propose_swap(
+                &s.treasurer,
+                &op,
+                &Priority::Normal,
+                &Vec::new(&env),
+                &ConditionLogic::And,
+                &0i128,
+            ),
+            Err(Ok(err))
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Limit orders
+// ---------------------------------------------------------------------------
+
+fn propose_take_profit(s: &DexSetup, max_attempts: u32) -> u64 {
+    let op = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        500,
+        1_000,
+    );
+    let order_id = s.client.propose_limit_order(
+        &s.treasurer,
+        &op,
+        &Condition::PriceAbove(s.token_a.clone(), 25_000_000),
+        &1_000u64,
+        &max_attempts,
+        &Priority::Normal,
+        &0i128,
+    );
+    s.client.approve_proposal(&s.treasurer, &order_id);
+    order_id
+}
+
+#[test]
+fn test_limit_order_fills_once_triggered() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let order_id = propose_take_profit(&s, 3);
+    assert_eq!(s.client.get_open_limit_orders().len(), 1);
+    assert_eq!(
+        s.client.try_execute_limit_order(&order_id),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+    // Direct execution bypassing the trigger is refused.
+    assert_eq!(
+        s.client.try_execute_swap(&s.treasurer, &order_id),
+        Err(Ok(VaultError::DexError))
+    );
+
+    s.oracle.set_price(&s.token_a, &25_000_000);
+    s.router.set_rate(&25_000);
+    assert_eq!(
+        s.client.execute_limit_order(&order_id),
+        LimitOrderStatus::Filled
+    );
+
+    let order = s.client.get_limit_order(&order_id);
+    assert_eq!(order.attempts, 0);
+    assert_eq!(order.filled_at, env.ledger().sequence() as u64);
+    assert_eq!(s.client.get_open_limit_orders().len(), 0);
+    assert_eq!(
+        s.client.get_swap_result(&order_id).unwrap().amount_out,
+        1_250
+    );
+    assert_eq!(
+        s.client.get_proposal(&order_id).status,
+        ProposalStatus::Executed
+    );
+    assert_eq!(
+        s.client.try_execute_limit_order(&order_id),
+        Err(Ok(VaultError::DexError))
+    );
+}
+
+#[test]
+fn test_limit_order_counts_failed_fill_attempts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let vault = s.client.address.clone();
+
+    let order_id = propose_take_profit(&s, 2);
+
+    // Trigger holds but the router still quotes 2:1, 20% short of the oracle.
+    s.oracle.set_price(&s.token_a, &25_000_000);
+    assert_eq!(
+        s.client.execute_limit_order(&order_id),
+        LimitOrderStatus::Open
+    );
+    assert_eq!(s.client.get_limit_order(&order_id).attempts, 1);
+    assert_eq!(balance(&env, &s.token_a, &vault), 10_000);
+
+    assert_eq!(
+        s.client.execute_limit_order(&order_id),
+        LimitOrderStatus::Exhausted
+    );
+    assert_eq!(s.client.get_limit_order(&order_id).attempts, 2);
+    assert_eq!(s.client.get_open_limit_orders().len(), 0);
+    assert!(s.client.get_swap_result(&order_id).is_none());
+}
+
+#[test]
+fn test_limit_order_outages_do_not_count_as_attempts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let order_id = propose_take_profit(&s, 1);
+    s.oracle.set_price(&s.token_a, &25_000_000);
+    s.router.set_rate(&25_000);
+
+    s.router.set_quote_fails(&true);
+    assert_eq!(
+        s.client.try_execute_limit_order(&order_id),
+        Err(Ok(VaultError::DexError))
+    );
+    s.router.set_quote_fails(&false);
+
+    s.oracle.set_price(&s.token_b, &0);
+    assert_eq!(
+        s.client.try_execute_limit_order(&order_id),
+        Err(Ok(VaultError::DexError))
+    );
+    assert_eq!(s.client.get_limit_order(&order_id).attempts, 0);
+
+    s.oracle.set_price(&s.token_b, &10_000_000);
+    assert_eq!(
+        s.client.execute_limit_order(&order_id),
+        LimitOrderStatus::Filled
+    );
+}
+
+#[test]
+fn test_limit_order_expires() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let order_id = propose_take_profit(&s, 3);
+    env.ledger().with_mut(|li| li.sequence_number = 1_001);
+
+    assert_eq!(
+        s.client.execute_limit_order(&order_id),
+        LimitOrderStatus::Expired
+    );
+    assert_eq!(
+        s.client.get_proposal(&order_id).status,
+        ProposalStatus::Expired
+    );
+    assert_eq!(s.client.get_open_limit_orders().len(), 0);
+}
+
+#[test]
+fn test_cancel_limit_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let order_id = propose_take_profit(&s, 3);
+    assert_eq!(
+        s.client.try_cancel_limit_order(&s.treasurer, &order_id),
+        Err(Ok(VaultError::InsufficientRole))
+    );
+    s.client.cancel_limit_order(&s.admin, &order_id);
+
+    assert_eq!(
+        s.client.get_limit_order(&order_id).status,
+        LimitOrderStatus::Cancelled
+    );
+    assert_eq!(
+        s.client.get_proposal(&order_id).status,
+        ProposalStatus::Cancelled
+    );
+    s.oracle.set_price(&s.token_a, &25_000_000);
+    assert_eq!(
+        s.client.try_execute_limit_order(&order_id),
+        Err(Ok(VaultError::DexError))
+    );
+}
+
+#[test]
+fn test_propose_limit_order_validates_parameters() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let swap = SwapProposal::Swap(
+        s.router.address.clone(),
+        s.token_a.clone(),
+        s.token_b.clone(),
+        500,
+        1_000,
+    );
+    let trigger = Condition::PriceBelow(s.token_a.clone(), 15_000_000);
+    let cases = [
+        (
+            SwapProposal::ClaimRewards(s.farm.address.clone()),
+            trigger.clone(),
+            1_000u64,
+            3u32,
+            VaultError::DexError,
+        ),
+        (
+            swap.clone(),
+            Condition::DateAfter(10),
+            1_000,
+            3,
+            VaultError::ConditionsNotMet,
+        ),
+        (
+            swap.clone(),
+            trigger.clone(),
+            1_000,
+            0,
+            VaultError::InvalidAmount,
+        ),
+        (
+            swap.clone(),
+            trigger.clone(),
+            0,
+            3,
+            VaultError::InvalidAmount,
+        ),
+    ];
+    for (op, trigger, expires_at, max_attempts, err) in cases {
+        assert_eq!(
+            s.client.try_propose_limit_order(
+                &s.treasurer,
+                &op,
+                &trigger,
+                &expires_at,
+                &max_attempts,
+                &Priority::Normal,
+                &0i128,
+            ),
+            Err(Ok(err))
+        );
+    }
+}
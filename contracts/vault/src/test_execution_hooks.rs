@@ -0,0 +1,791 @@
+//! Unit tests for typed execution and lifecycle hooks.
+//!
+//! Covers: allow/deny decisions with reason codes, fail-open and fail-closed
+//! policies, token/amount/tag filters, registration access control and
+//! lifecycle notifications for proposals and streams.
+
+use crate::types::{
+    AuditAction, ExecutionHook, ExecutionOutcome, HookContext, HookDecision, HookFailurePolicy,
+    HookFilter, HookStage, LifecycleEvent, RetryConfig, VelocityConfig,
+};
+use crate::{
+    ConditionLogic, InitConfig, Priority, ProposalStatus, Role, ThresholdStrategy, VaultDAO,
+    VaultDAOClient, VaultError,
+};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    testutils::{Address as _, Events as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, TryFromVal, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Mock hook
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+enum MockHookKey {
+    Decision,
+    Broken,
+    LastContext,
+    AfterCalls,
+    Lifecycle,
+}
+
+#[contract]
+pub struct MockExecutionHook;
+
+#[contractimpl]
+impl MockExecutionHook {
+    pub fn set_decision(env: Env, allow: bool, reason_code: u32) {
+        env.storage()
+            .instance()
+            .set(&MockHookKey::Decision, &HookDecision { allow, reason_code });
+    }
+
+    pub fn set_broken(env: Env, broken: bool) {
+        env.storage().instance().set(&MockHookKey::Broken, &broken);
+    }
+
+    pub fn last_context(env: Env) -> Option<HookContext> {
+        env.storage().instance().get(&MockHookKey::LastContext)
+    }
+
+    pub fn after_calls(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&MockHookKey::AfterCalls)
+            .unwrap_or(0)
+    }
+
+    pub fn lifecycle_events(env: Env) -> Vec<LifecycleEvent> {
+        env.storage()
+            .instance()
+            .get(&MockHookKey::Lifecycle)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    pub fn before_execute(env: Env, ctx: HookContext) -> HookDecision {
+        if env
+            .storage()
+            .instance()
+            .get(&MockHookKey::Broken)
+            .unwrap_or(false)
+        {
+            panic!("hook unavailable");
+        }
+        env.storage()
+            .instance()
+            .set(&MockHookKey::LastContext, &ctx);
+        env.storage()
+            .instance()
+            .get(&MockHookKey::Decision)
+            .unwrap_or(HookDecision {
+                allow: true,
+                reason_code: 0,
+            })
+    }
+
+    pub fn after_execute(env: Env, _ctx: HookContext) {
+        if env
+            .storage()
+            .instance()
+            .get(&MockHookKey::Broken)
+            .unwrap_or(false)
+        {
+            panic!("hook unavailable");
+        }
+        let calls = Self::after_calls(env.clone()) + 1;
+        env.storage()
+            .instance()
+            .set(&MockHookKey::AfterCalls, &calls);
+    }
+
+    pub fn on_lifecycle(env: Env, event: LifecycleEvent) {
+        if env
+            .storage()
+            .instance()
+            .get(&MockHookKey::Broken)
+            .unwrap_or(false)
+        {
+            panic!("hook unavailable");
+        }
+        let mut events = Self::lifecycle_events(env.clone());
+        events.push_back(event);
+        env.storage()
+            .instance()
+            .set(&MockHookKey::Lifecycle, &events);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn setup(env: &Env) -> (VaultDAOClient<'static>, Address, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let treasurer = Address::generate(env);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(treasurer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 500_000,
+            timelock_delay: 100,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+    client.set_role(&admin, &treasurer, &Role::Treasurer);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &10_000);
+
+    (client, admin, treasurer, token)
+}
+
+fn propose(
+    env: &Env,
+    client: &VaultDAOClient,
+    treasurer: &Address,
+    token: &Address,
+    amount: i128,
+) -> u64 {
+    let id = client.propose_transfer(
+        treasurer,
+        &Address::generate(env),
+        token,
+        &amount,
+        &Symbol::new(env, "payment"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0i128,
+    );
+    client.approve_proposal(treasurer, &id);
+    id
+}
+
+fn any_filter(env: &Env) -> HookFilter {
+    HookFilter {
+        tokens: Vec::new(env),
+        min_amount: 0,
+        max_amount: 0,
+        tags: Vec::new(env),
+    }
+}
+
+fn typed_hook(
+    hook: &Address,
+    stage: HookStage,
+    failure_policy: HookFailurePolicy,
+    filter: HookFilter,
+) -> ExecutionHook {
+    ExecutionHook {
+        hook: hook.clone(),
+        stage,
+        failure_policy,
+        filter,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Decisions
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_pre_hook_allow_receives_context() {
+    let env = Env::default();
+    let (client, admin, treasurer, token) = setup(&env);
+    let hook_id = env.register(MockExecutionHook, ());
+    let hook = MockExecutionHookClient::new(&env, &hook_id);
+
+    client.register_execution_hook(
+        &admin,
+        &typed_hook(
+            &hook_id,
+            HookStage::PreExecute,
+            HookFailurePolicy::FailClosed,
+            any_filter(&env),
+        ),
+    );
+
+    let id = propose(&env, &client, &treasurer, &token, 250);
+    client.add_proposal_tag(&treasurer, &id, &Symbol::new(&env, "payroll"));
+    client.execute_proposal(&admin, &id);
+
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Executed);
+    let ctx = hook.last_context().unwrap();
+    assert_eq!(ctx.proposal_id, id);
+    assert_eq!(ctx.proposer, treasurer);
+    assert_eq!(ctx.token, token);
+    assert_eq!(ctx.amount, 250);
+    assert_eq!(
+        ctx.tags,
+        Vec::from_array(&env, [Symbol::new(&env, "payroll")])
+    );
+    assert!(client.get_hook_denial(&id).is_none());
+}
+
+#[test]
+fn test_pre_hook_deny_records_reason_and_keeps_proposal_approved() {
+    let env = Env::default();
+    let (client, admin, treasurer, token) = setup(&env);
+    let hook_id = env.register(MockExecutionHook, ());
+    let hook = MockExecutionHookClient::new(&env, &hook_id);
+    hook.set_decision(&false, &42);
+
+    client.register_execution_hook(
+        &admin,
+        &typed_hook(
+            &hook_id,
+            HookStage::PreExecute,
+            HookFailurePolicy::FailClosed,
+            any_filter(&env),
+        ),
+    );
+
+    let id = propose(&env, &client, &treasurer, &token, 250);
+    assert_eq!(
+        client.execute_proposal(&admin, &id),
+        ExecutionOutcome::HookDenied
+    );
+
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Approved);
+    let denial = client.get_hook_denial(&id).unwrap();
+    assert_eq!(denial.hook, hook_id);
+    assert_eq!(denial.reason_code, 42);
+    assert!(!denial.hook_failed);
+
+    // Once the hook allows it, the proposal executes and the denial clears.
+    hook.set_decision(&true, &0);
+    assert_eq!(
+        client.execute_proposal(&admin, &id),
+        ExecutionOutcome::Executed
+    );
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Executed);
+    assert!(client.get_hook_denial(&id).is_none());
+}
+
+#[test]
+fn test_pre_hook_denial_does_not_use_session_key() {
+    let env = Env::default();
+    let (client, admin, treasurer, token) = setup(&env);
+    let hook_id = env.register(MockExecutionHook, ());
+    let hook = MockExecutionHookClient::new(&env, &hook_id);
+    hook.set_decision(&false, &42);
+    client.register_execution_hook(
+        &admin,
+        &typed_hook(
+            &hook_id,
+            HookStage::PreExecute,
+            HookFailurePolicy::FailClosed,
+            any_filter(&env),
+        ),
+    );
+    let keeper = Address::generate(&env);
+    client.register_session_key(
+        &admin,
+        &keeper,
+        &Vec::from_array(&env, [Symbol::new(&env, "execute_proposal")]),
+        &1_000,
+        &1_000,
+        &1,
+    );
+
+    let id = propose(&env, &client, &treasurer, &token, 250);
+    assert_eq!(
+        client.execute_proposal(&keeper, &id),
+        ExecutionOutcome::HookDenied
+    );
+    let session = client.get_session_key(&keeper);
+    assert_eq!((session.uses, session.spent), (0, 0));
+
+    hook.set_decision(&true, &0);
+    assert_eq!(
+        client.execute_proposal(&keeper, &id),
+        ExecutionOutcome::Executed
+    );
+    let session = client.get_session_key(&keeper);
+    assert_eq!((session.uses, session.spent), (1, 250));
+}
+
+// ---------------------------------------------------------------------------
+// Failure policies
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_failing_pre_hook_fail_open_continues() {
+    let env = Env::default();
+    let (client, admin, treasurer, token) = setup(&env);
+    let hook_id = env.register(MockExecutionHook, ());
+    MockExecutionHookClient::new(&env, &hook_id).set_broken(&true);
+
+    client.register_execution_hook(
+        &admin,
+        &typed_hook(
+            &hook_id,
+            HookStage::PreExecute,
+            HookFailurePolicy::FailOpen,
+            any_filter(&env),
+        ),
+    );
+
+    let id = propose(&env, &client, &treasurer, &token, 250);
+    client.execute_proposal(&admin, &id);
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Executed);
+}
+
+#[test]
+fn test_failing_pre_hook_fail_closed_blocks() {
+    let env = Env::default();
+    let (client, admin, treasurer, token) = setup(&env);
+    let hook_id = env.register(MockExecutionHook, ());
+    MockExecutionHookClient::new(&env, &hook_id).set_broken(&true);
+
+    client.register_execution_hook(
+        &admin,
+        &typed_hook(
+            &hook_id,
+            HookStage::PreExecute,
+            HookFailurePolicy::FailClosed,
+            any_filter(&env),
+        ),
+    );
+
+    let id = propose(&env, &client, &treasurer, &token, 250);
+    client.execute_proposal(&admin, &id);
+
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Approved);
+    let denial = client.get_hook_denial(&id).unwrap();
+    assert_eq!(denial.reason_code, 0);
+    assert!(denial.hook_failed);
+}
+
+#[test]
+fn test_post_hook_policies() {
+    let env = Env::default();
+    let (client, admin, treasurer, token) = setup(&env);
+    let hook_id = env.register(MockExecutionHook, ());
+    let hook = MockExecutionHookClient::new(&env, &hook_id);
+
+    client.register_execution_hook(
+        &admin,
+        &typed_hook(
+            &hook_id,
+            HookStage::PostExecute,
+            HookFailurePolicy::FailClosed,
+            any_filter(&env),
+        ),
+    );
+
+    let id = propose(&env, &client, &treasurer, &token, 250);
+    client.execute_proposal(&admin, &id);
+    assert_eq!(hook.after_calls(), 1);
+
+    // A failing fail-closed post hook reverts the execution.
+    hook.set_broken(&true);
+    let id = propose(&env, &client, &treasurer, &token, 250);
+    let res = client.try_execute_proposal(&admin, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::ConditionsNotMet)));
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Approved);
+
+    // Switching to fail-open lets it through.
+    client.remove_execution_hook(&admin, &hook_id, &HookStage::PostExecute);
+    client.register_execution_hook(
+        &admin,
+        &typed_hook(
+            &hook_id,
+            HookStage::PostExecute,
+            HookFailurePolicy::FailOpen,
+            any_filter(&env),
+        ),
+    );
+    client.execute_proposal(&admin, &id);
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Executed);
+}
+
+// ---------------------------------------------------------------------------
+// Filters
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_filters_select_proposals() {
+    let env = Env::default();
+    let (client, admin, treasurer, token) = setup(&env);
+    let hook_id = env.register(MockExecutionHook, ());
+    MockExecutionHookClient::new(&env, &hook_id).set_decision(&false, &7);
+
+    let other_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    client.register_execution_hook(
+        &admin,
+        &typed_hook(
+            &hook_id,
+            HookStage::PreExecute,
+            HookFailurePolicy::FailClosed,
+            HookFilter {
+                tokens: Vec::from_array(&env, [token.clone(), other_token]),
+                min_amount: 100,
+                max_amount: 500,
+                tags: Vec::from_array(&env, [Symbol::new(&env, "grants")]),
+            },
+        ),
+    );
+
+    // Below the amount range: not filtered in, executes.
+    let small = propose(&env, &client, &treasurer, &token, 50);
+    client.add_proposal_tag(&treasurer, &small, &Symbol::new(&env, "grants"));
+    client.execute_proposal(&admin, &small);
+    assert_eq!(client.get_proposal(&small).status, ProposalStatus::Executed);
+
+    // Missing tag: executes.
+    let untagged = propose(&env, &client, &treasurer, &token, 200);
+    client.execute_proposal(&admin, &untagged);
+    assert_eq!(
+        client.get_proposal(&untagged).status,
+        ProposalStatus::Executed
+    );
+
+    // Above the amount range: executes.
+    let large = propose(&env, &client, &treasurer, &token, 600);
+    client.add_proposal_tag(&treasurer, &large, &Symbol::new(&env, "grants"));
+    client.execute_proposal(&admin, &large);
+    assert_eq!(client.get_proposal(&large).status, ProposalStatus::Executed);
+
+    // Matches every filter: denied.
+    let matched = propose(&env, &client, &treasurer, &token, 200);
+    client.add_proposal_tag(&treasurer, &matched, &Symbol::new(&env, "grants"));
+    client.execute_proposal(&admin, &matched);
+    assert_eq!(
+        client.get_proposal(&matched).status,
+        ProposalStatus::Approved
+    );
+    assert_eq!(client.get_hook_denial(&matched).unwrap().reason_code, 7);
+
+    // Preflight counts only matching typed hooks and reports the denial.
+    let report = client.preflight_proposal(&matched);
+    let pre_hooks = report
+        .checks
+        .iter()
+        .find(|check| check.name == Symbol::new(&env, "pre_hooks"))
+        .unwrap();
+    assert_eq!(pre_hooks.actual, 1);
+    assert!(!pre_hooks.passed);
+    assert!(!report.executable);
+}
+
+// ---------------------------------------------------------------------------
+// Registration
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_register_and_remove_execution_hook() {
+    let env = Env::default();
+    let (client, admin, treasurer, _) = setup(&env);
+    let hook_id = Address::generate(&env);
+    let hook = typed_hook(
+        &hook_id,
+        HookStage::PreExecute,
+        HookFailurePolicy::FailOpen,
+        any_filter(&env),
+    );
+
+    let res = client.try_register_execution_hook(&treasurer, &hook);
+    assert_eq!(res.err(), Some(Ok(VaultError::Unauthorized)));
+
+    client.register_execution_hook(&admin, &hook);
+    let res = client.try_register_execution_hook(&admin, &hook);
+    assert_eq!(res.err(), Some(Ok(VaultError::SignerAlreadyExists)));
+
+    // The same contract may also be registered for the other stage.
+    client.register_execution_hook(
+        &admin,
+        &typed_hook(
+            &hook_id,
+            HookStage::PostExecute,
+            HookFailurePolicy::FailOpen,
+            any_filter(&env),
+        ),
+    );
+    assert_eq!(client.get_execution_hooks().len(), 2);
+
+    let mut bad_filter = any_filter(&env);
+    bad_filter.min_amount = 500;
+    bad_filter.max_amount = 100;
+    let res = client.try_register_execution_hook(
+        &admin,
+        &typed_hook(
+            &Address::generate(&env),
+            HookStage::PreExecute,
+            HookFailurePolicy::FailOpen,
+            bad_filter,
+        ),
+    );
+    assert_eq!(res.err(), Some(Ok(VaultError::InvalidAmount)));
+
+    client.remove_execution_hook(&admin, &hook_id, &HookStage::PreExecute);
+    assert_eq!(
+        client.get_execution_hooks(),
+        Vec::from_array(
+            &env,
+            [typed_hook(
+                &hook_id,
+                HookStage::PostExecute,
+                HookFailurePolicy::FailOpen,
+                any_filter(&env),
+            )]
+        )
+    );
+    let res = client.try_remove_execution_hook(&admin, &hook_id, &HookStage::PreExecute);
+    assert_eq!(res.err(), Some(Ok(VaultError::SignerNotFound)));
+}
+
+// ---------------------------------------------------------------------------
+// Lifecycle hooks
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_lifecycle_hooks_observe_proposal_events() {
+    let env = Env::default();
+    let (client, admin, treasurer, token) = setup(&env);
+    let hook_id = env.register(MockExecutionHook, ());
+    let hook = MockExecutionHookClient::new(&env, &hook_id);
+
+    for stage in [
+        HookStage::ProposalCreated,
+        HookStage::ProposalApproved,
+        HookStage::ProposalCancelled,
+    ] {
+        client.register_execution_hook(
+            &admin,
+            &typed_hook(
+                &hook_id,
+                stage,
+                HookFailurePolicy::FailOpen,
+                any_filter(&env),
+            ),
+        );
+    }
+
+    let approved = propose(&env, &client, &treasurer, &token, 250);
+    let cancelled = client.propose_transfer(
+        &treasurer,
+        &Address::generate(&env),
+        &token,
+        &100,
+        &Symbol::new(&env, "payment"),
+        &Priority::Normal,
+        &Vec::new(&env),
+        &ConditionLogic::And,
+        &0i128,
+    );
+    client.cancel_proposal(&treasurer, &cancelled, &Symbol::new(&env, "dup"));
+
+    let events = hook.lifecycle_events();
+    assert_eq!(events.len(), 4);
+
+    let created = events.get(0).unwrap();
+    assert_eq!(created.stage, HookStage::ProposalCreated);
+    assert_eq!(created.subject_id, approved);
+    assert_eq!(created.action, Symbol::new(&env, "created"));
+    assert_eq!(created.actor, treasurer);
+    assert_eq!(created.token, token);
+    assert_eq!(created.amount, 250);
+
+    assert_eq!(events.get(1).unwrap().stage, HookStage::ProposalApproved);
+    assert_eq!(events.get(1).unwrap().subject_id, approved);
+    assert_eq!(events.get(2).unwrap().stage, HookStage::ProposalCreated);
+    let cancel = events.get(3).unwrap();
+    assert_eq!(cancel.stage, HookStage::ProposalCancelled);
+    assert_eq!(cancel.subject_id, cancelled);
+    assert_eq!(cancel.action, Symbol::new(&env, "cancelled"));
+}
+
+#[test]
+fn test_lifecycle_hooks_observe_expiry() {
+    let env = Env::default();
+    // Keep storage live until the proposal expires.
+    env.ledger().with_mut(|li| {
+        li.min_persistent_entry_ttl = 200_000;
+        li.min_temp_entry_ttl = 200_000;
+        li.max_entry_ttl = 300_000;
+    });
+    let (client, admin, treasurer, token) = setup(&env);
+    let hook_id = env.register(MockExecutionHook, ());
+    let hook = MockExecutionHookClient::new(&env, &hook_id);
+    client.register_execution_hook(
+        &admin,
+        &typed_hook(
+            &hook_id,
+            HookStage::ProposalExpired,
+            HookFailurePolicy::FailOpen,
+            any_filter(&env),
+        ),
+    );
+
+    let id = propose(&env, &client, &treasurer, &token, 250);
+    let res = client.try_expire_proposal(&admin, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::ConditionsNotMet)));
+
+    // Execution past expiry fails and reverts; the keeper records it.
+    let expires_at = client.get_proposal(&id).expires_at;
+    env.ledger()
+        .with_mut(|li| li.sequence_number = expires_at as u32 + 1);
+    let res = client.try_execute_proposal(&admin, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::ProposalExpired)));
+    assert_eq!(hook.lifecycle_events().len(), 0);
+
+    client.expire_proposal(&admin, &id);
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Expired);
+    let entry = client.get_audit_entry(&client.get_audit_entry_count());
+    assert_eq!(entry.action, AuditAction::RejectProposal);
+    assert_eq!(entry.actor, admin);
+    assert_eq!(entry.target, id);
+    let events = hook.lifecycle_events();
+    assert_eq!(events.len(), 1);
+    let expired = events.get(0).unwrap();
+    assert_eq!(expired.stage, HookStage::ProposalExpired);
+    assert_eq!(expired.subject_id, id);
+    assert_eq!(expired.action, Symbol::new(&env, "expired"));
+
+    let res = client.try_expire_proposal(&admin, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::ProposalNotPending)));
+}
+
+#[test]
+fn test_lifecycle_hooks_observe_stream_changes() {
+    let env = Env::default();
+    let (client, admin, treasurer, token) = setup(&env);
+    let hook_id = env.register(MockExecutionHook, ());
+    let hook = MockExecutionHookClient::new(&env, &hook_id);
+    StellarAssetClient::new(&env, &token).mint(&treasurer, &1_000);
+
+    client.register_execution_hook(
+        &admin,
+        &typed_hook(
+            &hook_id,
+            HookStage::StreamChanged,
+            HookFailurePolicy::FailOpen,
+            any_filter(&env),
+        ),
+    );
+
+    let stream_id = client.create_stream(
+        &treasurer,
+        &Address::generate(&env),
+        &token,
+        &1,
+        &1_000,
+        &1_000,
+    );
+    client.pause_stream(&treasurer, &stream_id);
+
+    let events = hook.lifecycle_events();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events.get(0).unwrap().subject_id, stream_id);
+    assert_eq!(events.get(0).unwrap().action, Symbol::new(&env, "created"));
+    assert_eq!(events.get(0).unwrap().amount, 1_000);
+    assert_eq!(events.get(1).unwrap().action, Symbol::new(&env, "paused"));
+}
+
+#[test]
+fn test_lifecycle_hook_filters_and_failure_policy() {
+    let env = Env::default();
+    let (client, admin, treasurer, token) = setup(&env);
+    let hook_id = env.register(MockExecutionHook, ());
+    let hook = MockExecutionHookClient::new(&env, &hook_id);
+
+    // Lifecycle stages cannot be fail-closed.
+    let mut filter = any_filter(&env);
+    filter.min_amount = 500;
+    let res = client.try_register_execution_hook(
+        &admin,
+        &typed_hook(
+            &hook_id,
+            HookStage::ProposalCreated,
+            HookFailurePolicy::FailClosed,
+            filter.clone(),
+        ),
+    );
+    assert_eq!(res.err(), Some(Ok(VaultError::InvalidAmount)));
+    client.register_execution_hook(
+        &admin,
+        &typed_hook(
+            &hook_id,
+            HookStage::ProposalCreated,
+            HookFailurePolicy::FailOpen,
+            filter,
+        ),
+    );
+
+    // Below the filter's minimum: not notified.
+    propose(&env, &client, &treasurer, &token, 100);
+    assert_eq!(hook.lifecycle_events().len(), 0);
+
+    // Notifications are fail-open: a failing hook is reported and the
+    // proposal is still created.
+    hook.set_broken(&true);
+    let id = client.propose_transfer(
+        &treasurer,
+        &Address::generate(&env),
+        &token,
+        &600,
+        &Symbol::new(&env, "payment"),
+        &Priority::Normal,
+        &Vec::new(&env),
+        &ConditionLogic::And,
+        &0i128,
+    );
+    assert!(env.events().all().iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|topic| Symbol::try_from_val(&env, &topic).ok())
+            == Some(Symbol::new(&env, "lifecycle_hook_failed"))
+    }));
+    assert_eq!(client.get_proposal(&id).amount, 600);
+
+    // Unauthorized callers cannot subscribe hooks to lifecycle stages.
+    let res = client.try_register_execution_hook(
+        &treasurer,
+        &typed_hook(
+            &hook_id,
+            HookStage::ProposalVetoed,
+            HookFailurePolicy::FailOpen,
+            any_filter(&env),
+        ),
+    );
+    assert_eq!(res.err(), Some(Ok(VaultError::Unauthorized)));
+}
@@ -0,0 +1,818 @@
+//! Unit tests for oracle pricing and the oracle circuit breaker.
+//!
+//! Covers: per-asset oracle sources, median pricing, staleness, minimum
+//! source count, the max-deviation guard, SEP-40 compatibility (asset
+//! identifiers, decimals, resolution and timestamps in seconds) and
+//! per-asset circuit breakers.
+
+use crate::types::{
+    Asset, AssetOracleSources, CircuitBreakerConfig, ExecutionOutcome, RetryConfig,
+    VaultOracleConfig, VelocityConfig,
+};
+use crate::{
+    ConditionLogic, InitConfig, Priority, ProposalStatus, Role, ThresholdStrategy, VaultDAO,
+    VaultDAOClient, VaultError,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, TryFromVal, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Mock oracle
+// ---------------------------------------------------------------------------
+
+mod mock_oracle {
+    use crate::types::{Asset, VaultPriceData};
+    use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+    /// SEP-40 oracle returning prices set by the test. Reports 7 decimals
+    /// unless configured otherwise. A broken oracle panics.
+    #[contract]
+    pub struct MockOracle;
+
+    #[contractimpl]
+    impl MockOracle {
+        pub fn set_price(env: Env, asset: Address, price: i128, timestamp: u64) {
+            Self::set_asset_price(env, Asset::Stellar(asset), price, timestamp);
+        }
+
+        pub fn set_asset_price(env: Env, asset: Asset, price: i128, timestamp: u64) {
+            env.storage()
+                .instance()
+                .set(&asset, &VaultPriceData { price, timestamp });
+        }
+
+        pub fn set_decimals(env: Env, decimals: u32) {
+            env.storage()
+                .instance()
+                .set(&Symbol::new(&env, "decimals"), &decimals);
+        }
+
+        pub fn set_resolution(env: Env, resolution: u32) {
+            env.storage()
+                .instance()
+                .set(&Symbol::new(&env, "resolution"), &resolution);
+        }
+
+        pub fn set_broken(env: Env, broken: bool) {
+            env.storage()
+                .instance()
+                .set(&Symbol::new(&env, "broken"), &broken);
+        }
+
+        pub fn decimals(env: Env) -> u32 {
+            env.storage()
+                .instance()
+                .get(&Symbol::new(&env, "decimals"))
+                .unwrap_or(7)
+        }
+
+        pub fn resolution(env: Env) -> u32 {
+            env.storage()
+                .instance()
+                .get(&Symbol::new(&env, "resolution"))
+                .unwrap_or(0)
+        }
+
+        pub fn lastprice(env: Env, asset: Asset) -> Option<VaultPriceData> {
+            if env
+                .storage()
+                .instance()
+                .get(&Symbol::new(&env, "broken"))
+                .unwrap_or(false)
+            {
+                panic!("oracle unavailable");
+            }
+            env.storage().instance().get(&asset)
+        }
+    }
+}
+
+use mock_oracle::{MockOracle, MockOracleClient};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn setup(env: &Env) -> (VaultDAOClient<'static>, Address, Address) {
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let treasurer = Address::generate(env);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(treasurer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 1_000_000,
+            timelock_delay: 0,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+    client.set_role(&admin, &treasurer, &Role::Treasurer);
+
+    (client, admin, treasurer)
+}
+
+fn create_oracle(env: &Env) -> MockOracleClient<'static> {
+    MockOracleClient::new(env, &env.register(MockOracle, ()))
+}
+
+fn stored_max_staleness(env: &Env, client: &VaultDAOClient) -> u32 {
+    env.as_contract(
+        &client.address,
+        || match crate::storage::get_oracle_config(env) {
+            crate::OptionalVaultOracleConfig::Some(cfg) => cfg.max_staleness,
+            crate::OptionalVaultOracleConfig::None => 0,
+        },
+    )
+}
+
+fn source_config(
+    env: &Env,
+    oracles: &[&MockOracleClient],
+    min_sources: u32,
+    max_deviation_bps: u32,
+) -> AssetOracleSources {
+    let mut sources = Vec::new(env);
+    for oracle in oracles {
+        sources.push_back(oracle.address.clone());
+    }
+    AssetOracleSources {
+        sources,
+        min_sources,
+        max_deviation_bps,
+        max_staleness: 100,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Aggregation
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_median_of_sources() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _) = setup(&env);
+    let asset = Address::generate(&env);
+    let (a, b, c) = (
+        create_oracle(&env),
+        create_oracle(&env),
+        create_oracle(&env),
+    );
+
+    a.set_price(&asset, &100, &0);
+    b.set_price(&asset, &105, &0);
+    c.set_price(&asset, &98, &0);
+    client.set_asset_oracle_sources(
+        &admin,
+        &asset,
+        &source_config(&env, &[&a, &b, &c], 2, 1_000),
+    );
+    assert_eq!(client.get_asset_price(&asset), 100);
+
+    // Even number of answers averages the middle two.
+    c.set_broken(&true);
+    assert_eq!(client.get_asset_price(&asset), 102);
+}
+
+#[test]
+fn test_deviation_guard_fails_closed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _) = setup(&env);
+    let asset = Address::generate(&env);
+    let (a, b, c) = (
+        create_oracle(&env),
+        create_oracle(&env),
+        create_oracle(&env),
+    );
+
+    a.set_price(&asset, &100, &0);
+    b.set_price(&asset, &101, &0);
+    c.set_price(&asset, &150, &0);
+    client.set_asset_oracle_sources(&admin, &asset, &source_config(&env, &[&a, &b, &c], 2, 500));
+
+    assert_eq!(
+        client.try_get_asset_price(&asset),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+
+    c.set_price(&asset, &104, &0);
+    assert_eq!(client.get_asset_price(&asset), 101);
+}
+
+#[test]
+fn test_check_oracle_sources_reports_deviation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _) = setup(&env);
+    let asset = Address::generate(&env);
+    let (a, b) = (create_oracle(&env), create_oracle(&env));
+
+    a.set_price(&asset, &100, &0);
+    b.set_price(&asset, &150, &0);
+    client.set_asset_oracle_sources(&admin, &asset, &source_config(&env, &[&a, &b], 2, 500));
+
+    assert!(!client.check_oracle_sources(&asset));
+    let deviation = Symbol::new(&env, "oracle_deviation");
+    let reported = env.events().all().iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|topic| Symbol::try_from_val(&env, &topic).ok())
+            == Some(deviation.clone())
+    });
+    assert!(reported);
+
+    b.set_price(&asset, &102, &0);
+    assert!(client.check_oracle_sources(&asset));
+    assert_eq!(
+        client.try_check_oracle_sources(&Address::generate(&env)),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+}
+
+#[test]
+fn test_stale_and_missing_answers_count_against_min_sources() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _) = setup(&env);
+    let asset = Address::generate(&env);
+    let (a, b, c) = (
+        create_oracle(&env),
+        create_oracle(&env),
+        create_oracle(&env),
+    );
+    client.set_asset_oracle_sources(&admin, &asset, &source_config(&env, &[&a, &b, &c], 2, 500));
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    a.set_price(&asset, &100, &10_000);
+    // b is stale and c has no answer for the asset.
+    b.set_price(&asset, &101, &9_800);
+    assert_eq!(
+        client.try_get_asset_price(&asset),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+
+    b.set_price(&asset, &102, &9_950);
+    assert_eq!(client.get_asset_price(&asset), 101);
+
+    // A source that errors is skipped like a missing answer.
+    c.set_broken(&true);
+    assert_eq!(client.get_asset_price(&asset), 101);
+}
+
+#[test]
+fn test_single_oracle_config_used_without_sources() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _) = setup(&env);
+    let asset = Address::generate(&env);
+    let (single, source) = (create_oracle(&env), create_oracle(&env));
+
+    single.set_price(&asset, &200, &0);
+    source.set_price(&asset, &300, &0);
+    client.set_oracle_config(
+        &admin,
+        &VaultOracleConfig {
+            address: single.address.clone(),
+            base_symbol: Symbol::new(&env, "USD"),
+            max_staleness: 100,
+        },
+    );
+    assert_eq!(client.get_asset_price(&asset), 200);
+
+    // An oracle that errors fails closed instead of aborting the call.
+    single.set_broken(&true);
+    assert_eq!(
+        client.try_get_asset_price(&asset),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+    single.set_broken(&false);
+
+    client.set_asset_oracle_sources(&admin, &asset, &source_config(&env, &[&source], 1, 0));
+    assert_eq!(client.get_asset_price(&asset), 300);
+    assert_eq!(
+        client.get_asset_oracle_sources(&asset).unwrap().min_sources,
+        1
+    );
+
+    client.remove_asset_oracle_sources(&admin, &asset);
+    assert_eq!(client.get_asset_price(&asset), 200);
+    assert!(client.get_asset_oracle_sources(&asset).is_none());
+}
+
+#[test]
+fn test_set_asset_oracle_sources_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, treasurer) = setup(&env);
+    let asset = Address::generate(&env);
+    let (a, b) = (create_oracle(&env), create_oracle(&env));
+
+    assert_eq!(
+        client.try_set_asset_oracle_sources(
+            &treasurer,
+            &asset,
+            &source_config(&env, &[&a, &b], 1, 500)
+        ),
+        Err(Ok(VaultError::InsufficientRole))
+    );
+    assert_eq!(
+        client.try_set_asset_oracle_sources(
+            &admin,
+            &asset,
+            &source_config(&env, &[&a, &b], 0, 500)
+        ),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_asset_oracle_sources(
+            &admin,
+            &asset,
+            &source_config(&env, &[&a, &b], 3, 500)
+        ),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_asset_oracle_sources(
+            &admin,
+            &asset,
+            &source_config(&env, &[&a, &a], 1, 500)
+        ),
+        Err(Ok(VaultError::AddressAlreadyOnList))
+    );
+}
+
+// ---------------------------------------------------------------------------
+// SEP-40 compatibility
+// ---------------------------------------------------------------------------
+
+fn single_oracle_config(
+    env: &Env,
+    oracle: &MockOracleClient,
+    max_staleness: u32,
+) -> VaultOracleConfig {
+    VaultOracleConfig {
+        address: oracle.address.clone(),
+        base_symbol: Symbol::new(env, "USD"),
+        max_staleness,
+    }
+}
+
+#[test]
+fn test_prices_normalized_to_seven_decimals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _) = setup(&env);
+    let asset = Address::generate(&env);
+    let (single, a, b) = (
+        create_oracle(&env),
+        create_oracle(&env),
+        create_oracle(&env),
+    );
+
+    // $1.50 reported with 18, 14 and 2 decimals.
+    single.set_decimals(&18);
+    single.set_price(&asset, &1_500_000_000_000_000_000, &0);
+    client.set_oracle_config(&admin, &single_oracle_config(&env, &single, 100));
+    assert_eq!(client.get_asset_price(&asset), 15_000_000);
+
+    a.set_decimals(&14);
+    a.set_price(&asset, &150_000_000_000_000, &0);
+    b.set_decimals(&2);
+    b.set_price(&asset, &150, &0);
+    client.set_asset_oracle_sources(&admin, &asset, &source_config(&env, &[&a, &b], 2, 0));
+    assert_eq!(client.get_asset_price(&asset), 15_000_000);
+}
+
+#[test]
+fn test_staleness_measured_in_seconds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _) = setup(&env);
+    let asset = Address::generate(&env);
+    let oracle = create_oracle(&env);
+    client.set_oracle_config(&admin, &single_oracle_config(&env, &oracle, 100));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000;
+        li.sequence_number = 5;
+    });
+    oracle.set_price(&asset, &200, &950);
+    assert_eq!(client.get_asset_price(&asset), 200);
+
+    // Ledger sequence has no bearing on freshness.
+    env.ledger().with_mut(|li| li.sequence_number = 3_000);
+    assert_eq!(client.get_asset_price(&asset), 200);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_051);
+    assert_eq!(
+        client.try_get_asset_price(&asset),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+
+    // A feed reporting a long update interval cannot relax the bound.
+    oracle.set_resolution(&u32::MAX);
+    assert_eq!(
+        client.try_get_asset_price(&asset),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+}
+
+#[test]
+fn test_migration_converts_staleness_from_ledgers_to_seconds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _) = setup(&env);
+    let oracle = create_oracle(&env);
+    client.set_oracle_config(&admin, &single_oracle_config(&env, &oracle, 60));
+
+    // A vault on version 3 stored the bound as a ledger count.
+    env.as_contract(&client.address, || {
+        crate::storage::set_contract_version(&env, 3);
+        env.storage()
+            .instance()
+            .remove(&crate::storage::OracleKey::StalenessInSeconds);
+    });
+    client.migrate(&admin);
+    assert_eq!(stored_max_staleness(&env, &client), 300);
+}
+
+#[test]
+fn test_migration_keeps_staleness_set_after_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _) = setup(&env);
+    let oracle = create_oracle(&env);
+    env.as_contract(&client.address, || {
+        crate::storage::set_contract_version(&env, 3);
+    });
+
+    // Set through the new WASM before `migrate` ran, so already in seconds.
+    client.set_oracle_config(&admin, &single_oracle_config(&env, &oracle, 60));
+    client.migrate(&admin);
+    assert_eq!(stored_max_staleness(&env, &client), 60);
+}
+
+#[test]
+fn test_token_priced_as_other_asset() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, treasurer) = setup(&env);
+    let token = Address::generate(&env);
+    let oracle = create_oracle(&env);
+    client.set_oracle_config(&admin, &single_oracle_config(&env, &oracle, 100));
+
+    let btc = Asset::Other(Symbol::new(&env, "BTC"));
+    oracle.set_asset_price(&btc, &600_000_000_000, &0);
+    oracle.set_price(&token, &10_000_000, &0);
+    assert_eq!(
+        client.get_oracle_asset(&token),
+        Asset::Stellar(token.clone())
+    );
+    assert_eq!(client.get_asset_price(&token), 10_000_000);
+
+    assert_eq!(
+        client.try_set_oracle_asset(&treasurer, &token, &btc),
+        Err(Ok(VaultError::InsufficientRole))
+    );
+    client.set_oracle_asset(&admin, &token, &btc);
+    assert_eq!(client.get_oracle_asset(&token), btc);
+    assert_eq!(client.get_asset_price(&token), 600_000_000_000);
+
+    client.set_oracle_asset(&admin, &token, &Asset::Stellar(token.clone()));
+    assert_eq!(client.get_asset_price(&token), 10_000_000);
+}
+
+// ---------------------------------------------------------------------------
+// Circuit breaker
+// ---------------------------------------------------------------------------
+
+fn breaker(max_move_bps: u32, window_ledgers: u64) -> CircuitBreakerConfig {
+    CircuitBreakerConfig {
+        max_move_bps,
+        window_ledgers,
+    }
+}
+
+/// Vault holding 1000 of a token priced at 100 by a single oracle.
+fn setup_priced_token(
+    env: &Env,
+) -> (
+    VaultDAOClient<'static>,
+    Address,
+    Address,
+    Address,
+    MockOracleClient<'static>,
+) {
+    let (client, admin, treasurer) = setup(env);
+    let oracle = create_oracle(env);
+    client.set_oracle_config(&admin, &single_oracle_config(env, &oracle, 100));
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &token).mint(&client.address, &1_000);
+    oracle.set_price(&token, &100, &0);
+
+    (client, admin, treasurer, token, oracle)
+}
+
+fn propose_payment(env: &Env, client: &VaultDAOClient, proposer: &Address, token: &Address) -> u64 {
+    client.propose_transfer(
+        proposer,
+        &Address::generate(env),
+        token,
+        &100,
+        &Symbol::new(env, "payment"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0i128,
+    )
+}
+
+#[test]
+fn test_circuit_breaker_blocks_execute_proposal_until_reset() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, treasurer, token, oracle) = setup_priced_token(&env);
+    client.set_circuit_breaker(&admin, &token, &breaker(1_000, 100));
+
+    let proposal_id = propose_payment(&env, &client, &treasurer, &token);
+    client.approve_proposal(&treasurer, &proposal_id);
+
+    // First check records the reference price.
+    assert!(!client.check_circuit_breaker(&token));
+    let state = client.get_circuit_breaker_state(&token).unwrap();
+    assert_eq!(state.reference_price, 100);
+    assert!(!state.tripped);
+
+    oracle.set_price(&token, &80, &0);
+    assert_eq!(
+        client.execute_proposal(&treasurer, &proposal_id),
+        ExecutionOutcome::CircuitBreakerTripped
+    );
+    assert!(client.check_circuit_breaker(&token));
+    let state = client.get_circuit_breaker_state(&token).unwrap();
+    assert!(state.tripped);
+    assert_eq!(state.trip_price, 80);
+
+    // The trip is latched even after the price recovers.
+    oracle.set_price(&token, &100, &0);
+    assert_eq!(
+        client.try_execute_proposal(&treasurer, &proposal_id),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+
+    assert_eq!(
+        client.try_reset_circuit_breaker(&treasurer, &token),
+        Err(Ok(VaultError::InsufficientRole))
+    );
+    client.reset_circuit_breaker(&admin, &token);
+    assert!(client.get_circuit_breaker_state(&token).is_none());
+    client.execute_proposal(&treasurer, &proposal_id);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+}
+
+#[test]
+fn test_circuit_breaker_tripped_by_execution_stays_latched() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, treasurer, token, oracle) = setup_priced_token(&env);
+    client.set_circuit_breaker(&admin, &token, &breaker(1_000, 100));
+    let proposal_id = propose_payment(&env, &client, &treasurer, &token);
+    client.approve_proposal(&treasurer, &proposal_id);
+    assert!(!client.check_circuit_breaker(&token));
+
+    // No keeper check: the execution itself records the trip and refuses.
+    oracle.set_price(&token, &80, &0);
+    assert_eq!(
+        client.execute_proposal(&treasurer, &proposal_id),
+        ExecutionOutcome::CircuitBreakerTripped
+    );
+    let state = client.get_circuit_breaker_state(&token).unwrap();
+    assert!(state.tripped);
+    assert_eq!(state.trip_price, 80);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Approved
+    );
+
+    // Recovery of the price alone does not reopen it.
+    oracle.set_price(&token, &100, &0);
+    assert_eq!(
+        client.try_execute_proposal(&treasurer, &proposal_id),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+
+    client.reset_circuit_breaker(&admin, &token);
+    assert_eq!(
+        client.execute_proposal(&treasurer, &proposal_id),
+        ExecutionOutcome::Executed
+    );
+}
+
+#[test]
+fn test_circuit_breaker_reference_rolls_forward_after_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _, token, oracle) = setup_priced_token(&env);
+    client.set_circuit_breaker(&admin, &token, &breaker(1_000, 100));
+    assert!(!client.check_circuit_breaker(&token));
+
+    oracle.set_price(&token, &105, &0);
+    env.ledger().with_mut(|li| li.sequence_number = 50);
+    assert!(!client.check_circuit_breaker(&token));
+    assert_eq!(
+        client
+            .get_circuit_breaker_state(&token)
+            .unwrap()
+            .reference_price,
+        100
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    assert!(!client.check_circuit_breaker(&token));
+    let state = client.get_circuit_breaker_state(&token).unwrap();
+    assert_eq!(state.reference_price, 105);
+    assert_eq!(state.reference_ledger, 100);
+
+    // 12% above the original reference but within 10% of the rolled one.
+    oracle.set_price(&token, &112, &0);
+    assert!(!client.check_circuit_breaker(&token));
+    oracle.set_price(&token, &116, &0);
+    assert!(client.check_circuit_breaker(&token));
+}
+
+#[test]
+fn test_circuit_breaker_replaces_reference_older_than_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _, token, oracle) = setup_priced_token(&env);
+    client.set_circuit_breaker(&admin, &token, &breaker(1_000, 100));
+    assert!(!client.check_circuit_breaker(&token));
+
+    // A move spread over a gap longer than the window does not trip against
+    // the stale reference; it starts a new window instead.
+    oracle.set_price(&token, &200, &0);
+    env.ledger().with_mut(|li| li.sequence_number = 1_000);
+    assert!(!client.check_circuit_breaker(&token));
+    let state = client.get_circuit_breaker_state(&token).unwrap();
+    assert_eq!(state.reference_price, 200);
+    assert_eq!(state.reference_ledger, 1_000);
+
+    oracle.set_price(&token, &230, &0);
+    assert!(client.check_circuit_breaker(&token));
+}
+
+#[test]
+fn test_circuit_breaker_blocks_recurring_payment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, treasurer, token, oracle) = setup_priced_token(&env);
+    let payment_id = client.schedule_payment(
+        &treasurer,
+        &Address::generate(&env),
+        &token,
+        &100i128,
+        &Symbol::new(&env, "payroll"),
+        &720u64,
+    );
+    client.set_circuit_breaker(&admin, &token, &breaker(1_000, 10_000));
+    assert!(!client.check_circuit_breaker(&token));
+
+    oracle.set_price(&token, &150, &0);
+    assert!(client.check_circuit_breaker(&token));
+    env.ledger().with_mut(|li| li.sequence_number = 720);
+    assert_eq!(
+        client.try_execute_recurring_payment(&payment_id),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+
+    client.remove_circuit_breaker(&admin, &token);
+    client.execute_recurring_payment(&payment_id);
+}
+
+#[test]
+fn test_circuit_breaker_reset_by_signer_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, treasurer, token, oracle) = setup_priced_token(&env);
+    client.set_circuit_breaker(&admin, &token, &breaker(1_000, 100));
+    assert!(!client.check_circuit_breaker(&token));
+
+    assert_eq!(
+        client.try_approve_circuit_breaker_reset(&treasurer, &token),
+        Err(Ok(VaultError::ConditionsNotMet))
+    );
+
+    oracle.set_price(&token, &50, &0);
+    assert!(client.check_circuit_breaker(&token));
+    assert_eq!(
+        client.try_approve_circuit_breaker_reset(&Address::generate(&env), &token),
+        Err(Ok(VaultError::NotASigner))
+    );
+
+    // Threshold is 1, so a single signer approval resets the breaker.
+    assert!(client.approve_circuit_breaker_reset(&treasurer, &token));
+    assert!(client.get_circuit_breaker_state(&token).is_none());
+
+    // The next check anchors a new reference at the current price.
+    assert!(!client.check_circuit_breaker(&token));
+    assert_eq!(
+        client
+            .get_circuit_breaker_state(&token)
+            .unwrap()
+            .reference_price,
+        50
+    );
+}
+
+#[test]
+fn test_circuit_breaker_reset_ignores_removed_signers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, treasurer, token, oracle) = setup_priced_token(&env);
+    let departing = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        let mut config = crate::storage::get_config(&env).unwrap();
+        config.signers.push_back(departing.clone());
+        crate::storage::set_config(&env, &config);
+    });
+    client.update_threshold(&admin, &2);
+    client.set_circuit_breaker(&admin, &token, &breaker(1_000, 100));
+    assert!(!client.check_circuit_breaker(&token));
+    oracle.set_price(&token, &50, &0);
+    assert!(client.check_circuit_breaker(&token));
+
+    assert!(!client.approve_circuit_breaker_reset(&departing, &token));
+    client.remove_signer(&admin, &departing);
+
+    // The removed signer's approval no longer counts toward the threshold
+    assert!(!client.approve_circuit_breaker_reset(&treasurer, &token));
+    assert!(client.get_circuit_breaker_state(&token).unwrap().tripped);
+    assert!(client.approve_circuit_breaker_reset(&admin, &token));
+    assert!(client.get_circuit_breaker_state(&token).is_none());
+}
+
+#[test]
+fn test_set_circuit_breaker_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, treasurer, token, _) = setup_priced_token(&env);
+
+    assert_eq!(
+        client.try_set_circuit_breaker(&treasurer, &token, &breaker(1_000, 100)),
+        Err(Ok(VaultError::InsufficientRole))
+    );
+    assert_eq!(
+        client.try_set_circuit_breaker(&admin, &token, &breaker(0, 100)),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_circuit_breaker(&admin, &token, &breaker(1_000, 0)),
+        Err(Ok(VaultError::IntervalTooShort))
+    );
+
+    client.set_circuit_breaker(&admin, &token, &breaker(1_000, 100));
+    assert_eq!(
+        client.get_circuit_breaker(&token),
+        Some(breaker(1_000, 100))
+    );
+    client.remove_circuit_breaker(&admin, &token);
+    assert!(client.get_circuit_breaker(&token).is_none());
+}
@@ -0,0 +1,404 @@
+//! Unit tests for the emergency pause.
+//!
+//! Covers: pause configuration validation, guardian-only pausing, category
+//! scoping, the signer threshold to unpause, automatic expiry, the re-pause
+//! cooldown, replacing guardians during a pause and the preflight report.
+
+use crate::types::{AuditAction, PauseCategory, PauseConfig, RetryConfig, VelocityConfig};
+use crate::{
+    ConditionLogic, InitConfig, Priority, ProposalStatus, Role, ThresholdStrategy, VaultDAO,
+    VaultDAOClient, VaultError,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+struct Setup {
+    client: VaultDAOClient<'static>,
+    admin: Address,
+    treasurer: Address,
+    signer: Address,
+    guardian: Address,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let treasurer = Address::generate(env);
+    let signer = Address::generate(env);
+    let guardian = Address::generate(env);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(treasurer.clone());
+    signers.push_back(signer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 500_000,
+            timelock_delay: 100,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+    client.set_role(&admin, &treasurer, &Role::Treasurer);
+    client.set_pause_config(
+        &admin,
+        &PauseConfig {
+            guardians: Vec::from_array(env, [guardian.clone()]),
+            unpause_threshold: 2,
+            max_duration: 1_000,
+        },
+    );
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &10_000);
+
+    Setup {
+        client,
+        admin,
+        treasurer,
+        signer,
+        guardian,
+        token,
+    }
+}
+
+fn propose(env: &Env, s: &Setup) -> Result<u64, VaultError> {
+    match s.client.try_propose_transfer(
+        &s.treasurer,
+        &Address::generate(env),
+        &s.token,
+        &100,
+        &Symbol::new(env, "payment"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0i128,
+    ) {
+        Ok(Ok(id)) => Ok(id),
+        Err(Ok(err)) => Err(err),
+        _ => panic!("unexpected propose_transfer result"),
+    }
+}
+
+fn pause(env: &Env, s: &Setup, category: PauseCategory, duration: u64) {
+    s.client.pause(
+        &s.guardian,
+        &Vec::from_array(env, [category]),
+        &duration,
+        &Symbol::new(env, "key_leak"),
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Configuration
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_pause_config_validation() {
+    let env = Env::default();
+    let s = setup(&env);
+    let config = |threshold: u32, max_duration: u64| PauseConfig {
+        guardians: Vec::from_array(&env, [s.guardian.clone()]),
+        unpause_threshold: threshold,
+        max_duration,
+    };
+
+    let res = s
+        .client
+        .try_set_pause_config(&s.treasurer, &config(2, 1_000));
+    assert_eq!(res.err(), Some(Ok(VaultError::Unauthorized)));
+    let res = s.client.try_set_pause_config(&s.admin, &config(0, 1_000));
+    assert_eq!(res.err(), Some(Ok(VaultError::ThresholdTooLow)));
+    let res = s.client.try_set_pause_config(&s.admin, &config(4, 1_000));
+    assert_eq!(res.err(), Some(Ok(VaultError::ThresholdTooHigh)));
+    let res = s.client.try_set_pause_config(&s.admin, &config(2, 0));
+    assert_eq!(res.err(), Some(Ok(VaultError::IntervalTooShort)));
+
+    assert_eq!(s.client.get_pause_config(), Some(config(2, 1_000)));
+}
+
+#[test]
+fn test_only_guardians_can_pause() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let res = s.client.try_pause(
+        &s.admin,
+        &Vec::from_array(&env, [PauseCategory::Outflows]),
+        &0,
+        &Symbol::new(&env, "key_leak"),
+    );
+    assert_eq!(res.err(), Some(Ok(VaultError::Unauthorized)));
+    assert!(!s.client.is_paused(&PauseCategory::Outflows));
+
+    pause(&env, &s, PauseCategory::Outflows, 0);
+    let state = s.client.get_pause_state(&PauseCategory::Outflows).unwrap();
+    assert_eq!(state.paused_by, s.guardian);
+    assert_eq!(state.expires_at, state.paused_at + 1_000);
+    let entry = s.client.get_audit_entry(&s.client.get_audit_entry_count());
+    assert_eq!(entry.action, AuditAction::EmergencyPause);
+    assert_eq!(entry.actor, s.guardian);
+    assert_eq!(entry.target, 0);
+
+    // An active pause cannot be restarted, which would reset its approvals.
+    let res = s.client.try_pause(
+        &s.guardian,
+        &Vec::from_array(&env, [PauseCategory::Outflows]),
+        &0,
+        &Symbol::new(&env, "key_leak"),
+    );
+    assert_eq!(res.err(), Some(Ok(VaultError::VaultPaused)));
+}
+
+// ---------------------------------------------------------------------------
+// Scoping
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_outflow_pause_blocks_execution_and_stream_claims() {
+    let env = Env::default();
+    let s = setup(&env);
+    StellarAssetClient::new(&env, &s.token).mint(&s.treasurer, &1_000);
+    let recipient = Address::generate(&env);
+    let stream_id = s
+        .client
+        .create_stream(&s.treasurer, &recipient, &s.token, &1, &1_000, &1_000);
+    let proposal_id = propose(&env, &s).unwrap();
+    s.client.approve_proposal(&s.treasurer, &proposal_id);
+
+    pause(&env, &s, PauseCategory::Outflows, 500);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+
+    let res = s.client.try_execute_proposal(&s.admin, &proposal_id);
+    assert_eq!(res.err(), Some(Ok(VaultError::VaultPaused)));
+    let res = s.client.try_claim_stream(&recipient, &stream_id);
+    assert_eq!(res.err(), Some(Ok(VaultError::VaultPaused)));
+
+    let report = s.client.preflight_proposal(&proposal_id);
+    assert!(!report.executable);
+    let paused = report.checks.get(0).unwrap();
+    assert_eq!(paused.name, Symbol::new(&env, "paused"));
+    assert_eq!(paused.error_code, VaultError::VaultPaused as u32);
+
+    // Other categories keep working.
+    propose(&env, &s).unwrap();
+    s.client
+        .update_limits(&s.admin, &2_000_000, &10_000_000, &50_000_000);
+}
+
+#[test]
+fn test_proposal_and_config_pauses() {
+    let env = Env::default();
+    let s = setup(&env);
+    let proposal_id = propose(&env, &s).unwrap();
+    s.client.approve_proposal(&s.treasurer, &proposal_id);
+
+    s.client.pause(
+        &s.guardian,
+        &Vec::from_array(&env, [PauseCategory::Proposals, PauseCategory::Config]),
+        &0,
+        &Symbol::new(&env, "key_leak"),
+    );
+
+    assert_eq!(propose(&env, &s), Err(VaultError::VaultPaused));
+    let res = s
+        .client
+        .try_update_limits(&s.admin, &2_000_000, &10_000_000, &50_000_000);
+    assert_eq!(res.err(), Some(Ok(VaultError::VaultPaused)));
+    let res = s.client.try_set_role(&s.admin, &s.signer, &Role::Admin);
+    assert_eq!(res.err(), Some(Ok(VaultError::VaultPaused)));
+
+    // Outflows are not paused, so approved proposals still execute.
+    s.client.execute_proposal(&s.admin, &proposal_id);
+    assert_eq!(
+        s.client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Lifting a pause
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_unpause_requires_signer_threshold() {
+    let env = Env::default();
+    let s = setup(&env);
+    let proposal_id = propose(&env, &s).unwrap();
+    s.client.approve_proposal(&s.treasurer, &proposal_id);
+
+    let res = s
+        .client
+        .try_approve_unpause(&s.admin, &PauseCategory::Outflows);
+    assert_eq!(res.err(), Some(Ok(VaultError::ConditionsNotMet)));
+
+    pause(&env, &s, PauseCategory::Outflows, 0);
+
+    let res = s
+        .client
+        .try_approve_unpause(&s.guardian, &PauseCategory::Outflows);
+    assert_eq!(res.err(), Some(Ok(VaultError::NotASigner)));
+
+    assert!(!s.client.approve_unpause(&s.admin, &PauseCategory::Outflows));
+    let res = s
+        .client
+        .try_approve_unpause(&s.admin, &PauseCategory::Outflows);
+    assert_eq!(res.err(), Some(Ok(VaultError::AlreadyApproved)));
+    assert!(s.client.is_paused(&PauseCategory::Outflows));
+
+    assert!(s
+        .client
+        .approve_unpause(&s.signer, &PauseCategory::Outflows));
+    assert!(!s.client.is_paused(&PauseCategory::Outflows));
+    s.client.execute_proposal(&s.admin, &proposal_id);
+}
+
+#[test]
+fn test_unpause_ignores_approvals_from_removed_signers() {
+    let env = Env::default();
+    let s = setup(&env);
+    pause(&env, &s, PauseCategory::Outflows, 0);
+
+    assert!(!s
+        .client
+        .approve_unpause(&s.signer, &PauseCategory::Outflows));
+    s.client.remove_signer(&s.admin, &s.signer);
+
+    assert!(!s
+        .client
+        .approve_unpause(&s.treasurer, &PauseCategory::Outflows));
+    assert!(s.client.is_paused(&PauseCategory::Outflows));
+    assert!(s.client.approve_unpause(&s.admin, &PauseCategory::Outflows));
+    assert!(!s.client.is_paused(&PauseCategory::Outflows));
+}
+
+#[test]
+fn test_pause_expires_automatically() {
+    let env = Env::default();
+    let s = setup(&env);
+    let proposal_id = propose(&env, &s).unwrap();
+    s.client.approve_proposal(&s.treasurer, &proposal_id);
+
+    // Requested durations are capped at the configured maximum.
+    pause(&env, &s, PauseCategory::Outflows, 5_000);
+    let state = s.client.get_pause_state(&PauseCategory::Outflows).unwrap();
+    assert_eq!(state.expires_at, state.paused_at + 1_000);
+
+    env.ledger().with_mut(|li| li.sequence_number += 1_000);
+    assert!(s.client.is_paused(&PauseCategory::Outflows));
+
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    assert!(!s.client.is_paused(&PauseCategory::Outflows));
+    assert!(s.client.get_pause_state(&PauseCategory::Outflows).is_none());
+    s.client.execute_proposal(&s.admin, &proposal_id);
+
+    // A lapsed pause can be started again once the cooldown has passed.
+    let res = s.client.try_pause(
+        &s.guardian,
+        &Vec::from_array(&env, [PauseCategory::Outflows]),
+        &10,
+        &Symbol::new(&env, "key_leak"),
+    );
+    assert_eq!(res.err(), Some(Ok(VaultError::IntervalTooShort)));
+    env.ledger().with_mut(|li| li.sequence_number += 999);
+    pause(&env, &s, PauseCategory::Outflows, 10);
+    assert!(s.client.is_paused(&PauseCategory::Outflows));
+}
+
+#[test]
+fn test_lifted_pause_cannot_be_restarted_during_cooldown() {
+    let env = Env::default();
+    let s = setup(&env);
+    pause(&env, &s, PauseCategory::Outflows, 0);
+    s.client.approve_unpause(&s.admin, &PauseCategory::Outflows);
+    s.client
+        .approve_unpause(&s.signer, &PauseCategory::Outflows);
+
+    env.ledger().with_mut(|li| li.sequence_number += 999);
+    let res = s.client.try_pause(
+        &s.guardian,
+        &Vec::from_array(&env, [PauseCategory::Outflows]),
+        &0,
+        &Symbol::new(&env, "key_leak"),
+    );
+    assert_eq!(res.err(), Some(Ok(VaultError::IntervalTooShort)));
+
+    // Other categories have their own cooldown.
+    pause(&env, &s, PauseCategory::Proposals, 0);
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    pause(&env, &s, PauseCategory::Outflows, 0);
+}
+
+#[test]
+fn test_config_pause_allows_replacing_guardians() {
+    let env = Env::default();
+    let s = setup(&env);
+    pause(&env, &s, PauseCategory::Config, 0);
+
+    let replacement = Address::generate(&env);
+    s.client.set_pause_config(
+        &s.admin,
+        &PauseConfig {
+            guardians: Vec::from_array(&env, [replacement.clone()]),
+            unpause_threshold: 2,
+            max_duration: 1_000,
+        },
+    );
+    let res = s.client.try_pause(
+        &s.guardian,
+        &Vec::from_array(&env, [PauseCategory::Outflows]),
+        &0,
+        &Symbol::new(&env, "key_leak"),
+    );
+    assert_eq!(res.err(), Some(Ok(VaultError::Unauthorized)));
+    assert!(s.client.is_paused(&PauseCategory::Config));
+}
+
+#[test]
+fn test_outflow_pause_blocks_rollback() {
+    let env = Env::default();
+    let s = setup(&env);
+    let proposal_id = propose(&env, &s).unwrap();
+    s.client.approve_proposal(&s.treasurer, &proposal_id);
+    s.client.execute_proposal(&s.admin, &proposal_id);
+
+    pause(&env, &s, PauseCategory::Outflows, 0);
+    let res = s.client.try_rollback_execution(&s.admin, &proposal_id);
+    assert_eq!(res.err(), Some(Ok(VaultError::VaultPaused)));
+}
@@ -0,0 +1,272 @@
+//! Unit tests for scoped permission grants and delegations.
+//!
+//! Covers: token, amount and recipient limits on proposal creation, tag
+//! limits on execution, scoped delegation, validation and grants stored
+//! before scopes existed.
+
+use crate::storage::FeatureKey;
+use crate::types::{
+    LegacyPermissionGrant, Permission, PermissionScope, RetryConfig, VelocityConfig,
+};
+use crate::{
+    ConditionLogic, InitConfig, Priority, Role, ThresholdStrategy, VaultDAO, VaultDAOClient,
+    VaultError,
+};
+use soroban_sdk::{testutils::Address as _, token, Address, Env, Symbol, Vec};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn setup(env: &Env) -> (VaultDAOClient<'static>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let member = Address::generate(env);
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 10_000,
+            daily_limit: 50_000,
+            weekly_limit: 100_000,
+            timelock_threshold: 50_000,
+            timelock_delay: 100,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+    client.set_role(&admin, &member, &Role::Member);
+    (client, admin, member)
+}
+
+fn propose(
+    env: &Env,
+    client: &VaultDAOClient,
+    proposer: &Address,
+    recipient: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<u64, VaultError> {
+    match client.try_propose_transfer(
+        proposer,
+        recipient,
+        token,
+        &amount,
+        &Symbol::new(env, "payout"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0i128,
+    ) {
+        Ok(Ok(id)) => Ok(id),
+        Err(Ok(err)) => Err(err),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Scoped grants
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_scoped_grant_limits_token_amount_and_recipients() {
+    let env = Env::default();
+    let (client, admin, member) = setup(&env);
+    let usdc = Address::generate(&env);
+    let other_token = Address::generate(&env);
+    let vendor = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let mut limits = PermissionScope::unrestricted(&env);
+    limits.token = Some(usdc.clone());
+    limits.max_amount = Some(5_000);
+    limits.recipients.push_back(vendor.clone());
+    client.grant_permission(&admin, &member, &Permission::CreateProposal, &None, &limits);
+
+    assert_eq!(
+        client.get_permissions(&member).get(0).unwrap().scope,
+        limits
+    );
+    assert!(client.has_permission(&member, &Permission::CreateProposal));
+    assert!(propose(&env, &client, &member, &vendor, &usdc, 5_000).is_ok());
+    assert_eq!(
+        propose(&env, &client, &member, &vendor, &usdc, 5_001),
+        Err(VaultError::InsufficientRole)
+    );
+    assert_eq!(
+        propose(&env, &client, &member, &stranger, &usdc, 100),
+        Err(VaultError::InsufficientRole)
+    );
+    assert_eq!(
+        propose(&env, &client, &member, &vendor, &other_token, 100),
+        Err(VaultError::InsufficientRole)
+    );
+
+    // Scoped holders cannot delegate the permission on.
+    assert_eq!(
+        client.try_delegate_permission(
+            &member,
+            &Address::generate(&env),
+            &Permission::CreateProposal,
+            &1_000,
+            &PermissionScope::unrestricted(&env)
+        ),
+        Err(Ok(VaultError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_tag_scoped_execution() {
+    let env = Env::default();
+    let (client, admin, member) = setup(&env);
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    token::StellarAssetClient::new(&env, &token).mint(&client.address, &10_000);
+    let recipient = Address::generate(&env);
+    let payroll = Symbol::new(&env, "payroll");
+
+    let mut payroll_only = PermissionScope::unrestricted(&env);
+    payroll_only.tags.push_back(payroll.clone());
+    client.grant_permission(
+        &admin,
+        &member,
+        &Permission::ExecuteProposal,
+        &None,
+        &payroll_only,
+    );
+
+    let proposal_id = propose(&env, &client, &admin, &recipient, &token, 300).unwrap();
+    client.approve_proposal(&admin, &proposal_id);
+    assert_eq!(
+        client.try_execute_proposal(&member, &proposal_id),
+        Err(Ok(VaultError::InsufficientRole))
+    );
+
+    client.add_proposal_tag(&admin, &proposal_id, &payroll);
+    client.execute_proposal(&member, &proposal_id);
+    assert_eq!(token::Client::new(&env, &token).balance(&recipient), 300);
+}
+
+#[test]
+fn test_scoped_delegation() {
+    let env = Env::default();
+    let (client, admin, member) = setup(&env);
+    let token = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let mut capped = PermissionScope::unrestricted(&env);
+    capped.max_amount = Some(100);
+    client.delegate_permission(
+        &admin,
+        &member,
+        &Permission::CreateProposal,
+        &1_000,
+        &capped,
+    );
+
+    assert!(propose(&env, &client, &member, &recipient, &token, 100).is_ok());
+    assert_eq!(
+        propose(&env, &client, &member, &recipient, &token, 101),
+        Err(VaultError::InsufficientRole)
+    );
+}
+
+#[test]
+fn test_invalid_scope_rejected() {
+    let env = Env::default();
+    let (client, admin, member) = setup(&env);
+
+    let mut zero_cap = PermissionScope::unrestricted(&env);
+    zero_cap.max_amount = Some(0);
+    assert_eq!(
+        client.try_grant_permission(
+            &admin,
+            &member,
+            &Permission::CreateProposal,
+            &None,
+            &zero_cap
+        ),
+        Err(Ok(VaultError::InvalidPermissionScope))
+    );
+
+    let mut too_many = PermissionScope::unrestricted(&env);
+    for _ in 0..=crate::MAX_SCOPE_ENTRIES {
+        too_many.recipients.push_back(Address::generate(&env));
+    }
+    assert_eq!(
+        client.try_grant_permission(
+            &admin,
+            &member,
+            &Permission::CreateProposal,
+            &None,
+            &too_many
+        ),
+        Err(Ok(VaultError::InvalidPermissionScope))
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Stored layouts
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_grant_stored_before_scopes_reads_unscoped() {
+    let env = Env::default();
+    let (client, admin, member) = setup(&env);
+
+    env.as_contract(&client.address, || {
+        let legacy = Vec::from_array(
+            &env,
+            [LegacyPermissionGrant {
+                permission: Permission::CreateProposal,
+                granted_by: admin.clone(),
+                granted_at: 1,
+                expires_at: None,
+            }],
+        );
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::Permissions(member.clone()), &legacy);
+    });
+
+    let grants = client.get_permissions(&member);
+    assert_eq!(grants.len(), 1);
+    assert_eq!(
+        grants.get(0).unwrap().scope,
+        PermissionScope::unrestricted(&env)
+    );
+    assert!(propose(
+        &env,
+        &client,
+        &member,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        9_000
+    )
+    .is_ok());
+}
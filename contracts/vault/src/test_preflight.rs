@@ -0,0 +1,257 @@
+//! Unit tests for the execution preflight report.
+//!
+//! Covers: preflight_proposal check coverage, reported numbers, informational
+//! checks and agreement with execute_proposal.
+
+use crate::types::{
+    Condition, ListMode, PreflightCheck, PreflightReport, RetryConfig, VelocityConfig,
+};
+use crate::{
+    ConditionLogic, InitConfig, Priority, ProposalStatus, Role, ThresholdStrategy, VaultDAO,
+    VaultDAOClient, VaultError,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn setup(env: &Env, threshold: u32) -> (VaultDAOClient<'static>, Address, Address, Address) {
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let treasurer = Address::generate(env);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(treasurer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 500,
+            timelock_delay: 100,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+    client.set_role(&admin, &treasurer, &Role::Treasurer);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &1_000);
+
+    (client, admin, treasurer, token)
+}
+
+fn propose(
+    env: &Env,
+    client: &VaultDAOClient,
+    treasurer: &Address,
+    token: &Address,
+    amount: i128,
+    conditions: &Vec<Condition>,
+) -> u64 {
+    client.propose_transfer(
+        treasurer,
+        &Address::generate(env),
+        token,
+        &amount,
+        &Symbol::new(env, "preflight"),
+        &Priority::Normal,
+        conditions,
+        &ConditionLogic::And,
+        &0i128,
+    )
+}
+
+fn check(env: &Env, report: &PreflightReport, name: &str) -> PreflightCheck {
+    let name = Symbol::new(env, name);
+    report
+        .checks
+        .iter()
+        .find(|check| check.name == name)
+        .expect("check missing from report")
+}
+
+// ---------------------------------------------------------------------------
+// preflight_proposal
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_preflight_reports_every_check_for_ready_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, treasurer, token) = setup(&env, 1);
+
+    let proposal_id = propose(&env, &client, &treasurer, &token, 100, &Vec::new(&env));
+    client.approve_proposal(&treasurer, &proposal_id);
+
+    let report = client.preflight_proposal(&proposal_id);
+    assert!(report.executable);
+    assert_eq!(report.proposal_id, proposal_id);
+    let names = [
+        "paused",
+        "status",
+        "expiry",
+        "timelock",
+        "circuit_breaker",
+        "dependencies",
+        "approvals",
+        "quorum",
+        "retry_limit",
+        "retry_backoff",
+        "pre_hooks",
+        "conditions",
+        "gas_limit",
+        "balance",
+        "daily_limit",
+        "weekly_limit",
+        "recipient",
+        "post_hooks",
+    ];
+    assert_eq!(report.checks.len(), names.len() as u32);
+    for (i, name) in names.iter().enumerate() {
+        let check = report.checks.get(i as u32).unwrap();
+        assert_eq!(check.name, Symbol::new(&env, name));
+        assert!(check.passed);
+        assert_eq!(check.error_code, 0);
+        let informational = ["daily_limit", "weekly_limit", "recipient"].contains(name);
+        assert_eq!(check.blocking, !informational);
+    }
+
+    let balance = check(&env, &report, "balance");
+    assert_eq!((balance.actual, balance.required), (1_000, 100));
+    let daily = check(&env, &report, "daily_limit");
+    assert_eq!((daily.actual, daily.required), (100, 10_000_000));
+
+    client.execute_proposal(&treasurer, &proposal_id);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+}
+
+#[test]
+fn test_preflight_reports_failures_with_numbers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, treasurer, token) = setup(&env, 2);
+
+    let mut conditions = Vec::new(&env);
+    conditions.push_back(Condition::BalanceAbove(500));
+    conditions.push_back(Condition::DateAfter(50));
+    let proposal_id = propose(&env, &client, &treasurer, &token, 600, &conditions);
+    client.approve_proposal(&treasurer, &proposal_id);
+
+    let report = client.preflight_proposal(&proposal_id);
+    assert!(!report.executable);
+
+    let status = check(&env, &report, "status");
+    assert!(!status.passed);
+    assert_eq!(status.error_code, VaultError::ProposalNotApproved as u32);
+
+    let approvals = check(&env, &report, "approvals");
+    assert!(!approvals.passed);
+    assert_eq!((approvals.actual, approvals.required), (1, 2));
+
+    let conditions = check(&env, &report, "conditions");
+    assert!(!conditions.passed);
+    assert_eq!((conditions.actual, conditions.required), (1, 2));
+    assert_eq!(report.condition_results.get(0), Some(true));
+    assert_eq!(report.condition_results.get(1), Some(false));
+
+    // The proposal is also timelocked since 600 >= the 500 threshold.
+    let timelock = check(&env, &report, "timelock");
+    assert!(!timelock.passed);
+    assert_eq!(timelock.error_code, VaultError::TimelockNotExpired as u32);
+    assert_eq!(timelock.required, 100);
+
+    assert!(check(&env, &report, "balance").passed);
+    assert!(check(&env, &report, "quorum").passed);
+}
+
+#[test]
+fn test_preflight_agrees_with_execution_once_checks_pass() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, treasurer, token) = setup(&env, 1);
+
+    let proposal_id = propose(&env, &client, &treasurer, &token, 600, &Vec::new(&env));
+    client.approve_proposal(&treasurer, &proposal_id);
+    assert!(!client.preflight_proposal(&proposal_id).executable);
+    assert_eq!(
+        client.try_execute_proposal(&admin, &proposal_id),
+        Err(Ok(VaultError::TimelockNotExpired))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    let report = client.preflight_proposal(&proposal_id);
+    assert!(report.executable);
+    assert_eq!(report.evaluated_at, 100);
+    client.execute_proposal(&admin, &proposal_id);
+
+    let report = client.preflight_proposal(&proposal_id);
+    let status = check(&env, &report, "status");
+    assert_eq!(
+        status.error_code,
+        VaultError::ProposalAlreadyExecuted as u32
+    );
+    assert_eq!(status.actual, ProposalStatus::Executed as i128);
+}
+
+#[test]
+fn test_preflight_informational_checks_do_not_block() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, treasurer, token) = setup(&env, 1);
+
+    let proposal_id = propose(&env, &client, &treasurer, &token, 100, &Vec::new(&env));
+    client.approve_proposal(&treasurer, &proposal_id);
+
+    // The recipient was validated when the proposal was created; a later
+    // list change is reported but does not stop execution.
+    client.set_list_mode(&admin, &ListMode::Whitelist);
+    let report = client.preflight_proposal(&proposal_id);
+    let recipient = check(&env, &report, "recipient");
+    assert!(!recipient.passed);
+    assert!(!recipient.blocking);
+    assert_eq!(
+        recipient.error_code,
+        VaultError::RecipientNotWhitelisted as u32
+    );
+    assert!(report.executable);
+
+    client.execute_proposal(&admin, &proposal_id);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+}
@@ -0,0 +1,405 @@
+//! Unit tests for versioned storage records and batched migration.
+//!
+//! Covers: reading entries written before schema versioning, the keeper-driven
+//! `migrate_batch` routine, re-linking the audit chain and vault operation
+//! while a migration is running.
+
+use crate::storage::{self, AuditKey, DataKey};
+use crate::types::{
+    AuditFilter, LegacyAuditEntry, MigrationProgress, RetryConfig, VelocityConfig, VersionedConfig,
+    VersionedProposal,
+};
+use crate::{
+    ConditionLogic, InitConfig, Priority, ProposalStatus, Role, ThresholdStrategy, VaultDAO,
+    VaultDAOClient, VaultError,
+};
+use soroban_sdk::{
+    testutils::Address as _, token::StellarAssetClient, Address, Env, Symbol, TryFromVal, Val, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+struct Setup {
+    client: VaultDAOClient<'static>,
+    admin: Address,
+    treasurer: Address,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let treasurer = Address::generate(env);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(treasurer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 500_000,
+            timelock_delay: 100,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+    client.set_role(&admin, &treasurer, &Role::Treasurer);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &10_000);
+
+    Setup {
+        client,
+        admin,
+        treasurer,
+        token,
+    }
+}
+
+fn propose(env: &Env, s: &Setup) -> u64 {
+    s.client.propose_transfer(
+        &s.treasurer,
+        &Address::generate(env),
+        &s.token,
+        &100,
+        &Symbol::new(env, "payment"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0i128,
+    )
+}
+
+/// Rewrite the config, the given proposals and the audit trail the way
+/// version 1 stored them (unwrapped, 64-bit audit hashes, no secondary
+/// indexes) and roll the schema version back to 1.
+fn downgrade_to_v1(env: &Env, s: &Setup, proposal_ids: &[u64]) {
+    env.as_contract(&s.client.address, || {
+        let config = storage::get_config(env).unwrap();
+        env.storage().instance().set(&DataKey::Config, &config);
+        for id in proposal_ids {
+            let proposal = storage::get_proposal(env, *id).unwrap();
+            env.storage()
+                .persistent()
+                .set(&DataKey::Proposal(*id), &proposal);
+        }
+        let last_audit_id = storage::get_next_audit_id(env) - 1;
+        let mut prev_hash = 0u64;
+        for id in 1..=last_audit_id {
+            let entry = storage::get_audit_entry(env, id).unwrap();
+            for index in storage::audit_indexes(env, &entry).iter() {
+                env.storage()
+                    .persistent()
+                    .remove(&AuditKey::IndexLen(index.clone()));
+                env.storage()
+                    .persistent()
+                    .remove(&AuditKey::IndexPage(index, 0));
+            }
+            let mut legacy = LegacyAuditEntry {
+                id,
+                action: entry.action,
+                actor: entry.actor,
+                target: entry.target,
+                timestamp: entry.timestamp,
+                prev_hash,
+                hash: 0,
+            };
+            legacy.hash = storage::compute_legacy_audit_hash(&legacy);
+            prev_hash = legacy.hash;
+            env.storage()
+                .persistent()
+                .set(&DataKey::AuditEntry(id), &legacy);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::LastAuditHash, &prev_hash);
+        env.storage().instance().remove(&AuditKey::IndexedThrough);
+        storage::set_contract_version(env, 1);
+    });
+}
+
+fn legacy_head(env: &Env, s: &Setup) -> u64 {
+    env.as_contract(&s.client.address, || {
+        storage::get_legacy_audit_head(env).unwrap()
+    })
+}
+
+fn is_versioned(env: &Env, s: &Setup, id: u64) -> bool {
+    env.as_contract(&s.client.address, || {
+        let raw: Val = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Proposal(id))
+            .unwrap();
+        VersionedProposal::try_from_val(env, &raw).is_ok()
+    })
+}
+
+fn config_is_versioned(env: &Env, s: &Setup) -> bool {
+    env.as_contract(&s.client.address, || {
+        let raw: Val = env.storage().instance().get(&DataKey::Config).unwrap();
+        VersionedConfig::try_from_val(env, &raw).is_ok()
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Versioned records
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_new_records_are_versioned() {
+    let env = Env::default();
+    let s = setup(&env);
+    let id = propose(&env, &s);
+
+    assert!(is_versioned(&env, &s, id));
+    assert!(config_is_versioned(&env, &s));
+    assert_eq!(s.client.get_migration_progress(), None);
+    let res = s.client.try_migrate_batch(&10);
+    assert_eq!(res.err(), Some(Ok(VaultError::AlreadyMigrated)));
+}
+
+#[test]
+fn test_legacy_records_read_before_migration() {
+    let env = Env::default();
+    let s = setup(&env);
+    let id = propose(&env, &s);
+    downgrade_to_v1(&env, &s, &[id]);
+    assert!(!is_versioned(&env, &s, id));
+    assert!(!config_is_versioned(&env, &s));
+
+    assert_eq!(s.client.get_proposal(&id).amount, 100);
+    assert_eq!(s.client.get_config().threshold, 1);
+
+    // Writing a legacy record stores it in the versioned format.
+    s.client.approve_proposal(&s.treasurer, &id);
+    assert!(is_versioned(&env, &s, id));
+    assert_eq!(s.client.get_proposal(&id).status, ProposalStatus::Approved);
+}
+
+// ---------------------------------------------------------------------------
+// Batched migration
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_migrate_batch_rewrites_entries_in_bounded_batches() {
+    let env = Env::default();
+    let s = setup(&env);
+    let ids = [propose(&env, &s), propose(&env, &s), propose(&env, &s)];
+    let audit_count = s.client.get_audit_entry_count();
+    downgrade_to_v1(&env, &s, &ids);
+    let head = legacy_head(&env, &s);
+
+    assert_eq!(s.client.migrate(&s.admin), 4);
+    assert!(config_is_versioned(&env, &s));
+    assert_eq!(
+        s.client.get_migration_progress(),
+        Some(MigrationProgress {
+            target_version: 3,
+            next_proposal_id: 1,
+            last_proposal_id: 3,
+            next_audit_id: 1,
+            last_audit_id: audit_count,
+            migrated: 1,
+            legacy_audit_head: Some(head),
+            legacy_audit_hash: 0,
+            complete: false,
+        })
+    );
+
+    let progress = s.client.migrate_batch(&2);
+    assert_eq!((progress.next_proposal_id, progress.migrated), (3, 3));
+    assert!(!progress.complete);
+    assert!(is_versioned(&env, &s, ids[1]));
+    assert!(!is_versioned(&env, &s, ids[2]));
+
+    // The last proposal and the first audit entry share the next batch.
+    let progress = s.client.migrate_batch(&2);
+    assert_eq!((progress.next_proposal_id, progress.next_audit_id), (4, 2));
+    assert!(is_versioned(&env, &s, ids[2]));
+
+    // The entry recording `migrate` itself is re-linked as well.
+    let progress = s.client.migrate_batch(&0);
+    assert_eq!(progress.next_audit_id, audit_count + 2);
+    assert_eq!(progress.migrated, 5 + audit_count);
+    assert!(progress.complete);
+
+    let res = s.client.try_migrate_batch(&10);
+    assert_eq!(res.err(), Some(Ok(VaultError::AlreadyMigrated)));
+}
+
+#[test]
+fn test_migration_relinks_audit_chain() {
+    let env = Env::default();
+    let s = setup(&env);
+    let audit_count = s.client.get_audit_entry_count();
+    downgrade_to_v1(&env, &s, &[]);
+
+    // Legacy entries still read, but fail SHA-256 verification.
+    assert_eq!(s.client.get_audit_entry(&2).actor, s.admin);
+    assert_eq!(s.client.verify_audit_trail_full(), Some(1));
+
+    s.client.migrate(&s.admin);
+    s.client.migrate_batch(&0);
+    assert!(s.client.verify_audit_chain(&1, &audit_count));
+    assert_eq!(s.client.verify_audit_trail_full(), None);
+
+    // New entries extend the re-linked chain.
+    propose(&env, &s);
+    let last = s.client.get_audit_entry_count();
+    assert_eq!(
+        s.client.get_audit_entry(&last).prev_hash,
+        s.client.get_audit_entry(&(last - 1)).hash
+    );
+    assert_eq!(s.client.verify_audit_trail_full(), None);
+}
+
+#[test]
+fn test_migration_rejects_tampered_legacy_audit_chain() {
+    let env = Env::default();
+    let s = setup(&env);
+    downgrade_to_v1(&env, &s, &[]);
+
+    // Rewrite entry 2 with a different actor and a matching 64-bit hash; the
+    // link from entry 3 no longer holds.
+    env.as_contract(&s.client.address, || {
+        let mut legacy = storage::get_legacy_audit_entry(&env, 2).unwrap();
+        legacy.actor = s.treasurer.clone();
+        legacy.target += 1;
+        legacy.hash = storage::compute_legacy_audit_hash(&legacy);
+        env.storage()
+            .persistent()
+            .set(&DataKey::AuditEntry(2), &legacy);
+    });
+
+    s.client.migrate(&s.admin);
+    let res = s.client.try_migrate_batch(&0);
+    assert_eq!(res.err(), Some(Ok(VaultError::AuditChainBroken)));
+    assert_eq!(s.client.get_migration_progress().unwrap().next_audit_id, 1);
+}
+
+#[test]
+fn test_migration_rejects_legacy_chain_not_ending_at_stored_head() {
+    let env = Env::default();
+    let s = setup(&env);
+    downgrade_to_v1(&env, &s, &[]);
+
+    // Rewriting the last entry with a matching hash keeps every link intact,
+    // but the chain no longer ends at the head stored alongside it.
+    env.as_contract(&s.client.address, || {
+        let last = storage::get_next_audit_id(&env) - 1;
+        let mut legacy = storage::get_legacy_audit_entry(&env, last).unwrap();
+        legacy.target += 1;
+        legacy.hash = storage::compute_legacy_audit_hash(&legacy);
+        env.storage()
+            .persistent()
+            .set(&DataKey::AuditEntry(last), &legacy);
+    });
+
+    s.client.migrate(&s.admin);
+    let res = s.client.try_migrate_batch(&0);
+    assert_eq!(res.err(), Some(Ok(VaultError::AuditChainBroken)));
+}
+
+#[test]
+fn test_migration_backfills_audit_indexes() {
+    let env = Env::default();
+    let s = setup(&env);
+    downgrade_to_v1(&env, &s, &[]);
+
+    let by_admin = AuditFilter {
+        actor: Some(s.admin.clone()),
+        actions: Vec::new(&env),
+        target: None,
+        from_ledger: 0,
+        to_ledger: 0,
+    };
+    assert_eq!(
+        s.client.query_audit_trail(&by_admin, &0, &0).entries.len(),
+        0
+    );
+
+    // Entries written before the backfill catches up wait for it.
+    s.client
+        .set_role(&s.admin, &Address::generate(&env), &Role::Member);
+    assert_eq!(
+        s.client.query_audit_trail(&by_admin, &0, &0).entries.len(),
+        0
+    );
+
+    s.client.migrate(&s.admin);
+    s.client.migrate_batch(&0);
+    let page = s.client.query_audit_trail(&by_admin, &0, &0);
+    let admin_entries = s.client.get_audit_entry_count();
+    assert_eq!(page.entries.len() as u64, admin_entries);
+    assert_eq!(page.entries.last().unwrap().id, admin_entries);
+
+    // Once caught up, new entries are indexed as they are written.
+    propose(&env, &s);
+    let by_treasurer = AuditFilter {
+        actor: Some(s.treasurer.clone()),
+        ..by_admin
+    };
+    let page = s.client.query_audit_trail(&by_treasurer, &0, &0);
+    assert_eq!(page.entries.len(), 1);
+}
+
+#[test]
+fn test_vault_operates_during_migration() {
+    let env = Env::default();
+    let s = setup(&env);
+    let ids = [propose(&env, &s), propose(&env, &s)];
+    downgrade_to_v1(&env, &s, &ids);
+    s.client.migrate(&s.admin);
+    s.client.migrate_batch(&1);
+
+    // Proposal 2 is still unwrapped but can be approved and executed.
+    assert!(!is_versioned(&env, &s, ids[1]));
+    s.client.approve_proposal(&s.treasurer, &ids[1]);
+    s.client.execute_proposal(&s.admin, &ids[1]);
+    assert_eq!(
+        s.client.get_proposal(&ids[1]).status,
+        ProposalStatus::Executed
+    );
+    let new_id = propose(&env, &s);
+
+    // Already-versioned proposals are skipped and IDs created after the
+    // migration started are left to normal writes. Audit entries written
+    // meanwhile are re-linked along with the old ones.
+    let progress = s.client.migrate_batch(&0);
+    assert!(progress.complete);
+    assert_eq!(progress.last_proposal_id, 2);
+    assert_eq!(progress.last_audit_id, s.client.get_audit_entry_count());
+    assert_eq!(progress.migrated, 2 + progress.last_audit_id);
+    assert!(is_versioned(&env, &s, new_id));
+    assert_eq!(s.client.verify_audit_trail_full(), None);
+}
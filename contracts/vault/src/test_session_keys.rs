@@ -0,0 +1,292 @@
+//! Unit tests for signer-registered session keys.
+//!
+//! Covers: keepers executing through a key, the entrypoint allow-list,
+//! expiry, use and spend limits, revocation and registration checks.
+
+use crate::types::{RetryConfig, VelocityConfig};
+use crate::{
+    ConditionLogic, InitConfig, Priority, ThresholdStrategy, VaultDAO, VaultDAOClient, VaultError,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, Symbol, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+struct Setup {
+    client: VaultDAOClient<'static>,
+    admin: Address,
+    signer: Address,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let signer = Address::generate(env);
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 1,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 10_000,
+            daily_limit: 50_000,
+            weekly_limit: 100_000,
+            timelock_threshold: 50_000,
+            timelock_delay: 100,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    token::StellarAssetClient::new(env, &token).mint(&contract_id, &10_000);
+    Setup {
+        client,
+        admin,
+        signer,
+        token,
+    }
+}
+
+fn entrypoints(env: &Env, names: &[&str]) -> Vec<Symbol> {
+    let mut symbols = Vec::new(env);
+    for name in names {
+        symbols.push_back(Symbol::new(env, name));
+    }
+    symbols
+}
+
+/// An approved proposal paying `amount` to a fresh recipient.
+fn approved_proposal(env: &Env, s: &Setup, amount: i128) -> u64 {
+    let proposal_id = s.client.propose_transfer(
+        &s.admin,
+        &Address::generate(env),
+        &s.token,
+        &amount,
+        &Symbol::new(env, "keeper"),
+        &Priority::Normal,
+        &Vec::new(env),
+        &ConditionLogic::And,
+        &0i128,
+    );
+    s.client.approve_proposal(&s.admin, &proposal_id);
+    proposal_id
+}
+
+// ---------------------------------------------------------------------------
+// Using a key
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_keeper_executes_within_spend_cap() {
+    let env = Env::default();
+    let s = setup(&env);
+    let keeper = Address::generate(&env);
+
+    // A plain address cannot execute.
+    let first = approved_proposal(&env, &s, 300);
+    assert_eq!(
+        s.client.try_execute_proposal(&keeper, &first),
+        Err(Ok(VaultError::InsufficientRole))
+    );
+
+    s.client.register_session_key(
+        &s.admin,
+        &keeper,
+        &entrypoints(&env, &["execute_proposal"]),
+        &500,
+        &1_000,
+        &5,
+    );
+    s.client.execute_proposal(&keeper, &first);
+
+    let session = s.client.get_session_key(&keeper);
+    assert_eq!(session.uses, 1);
+    assert_eq!(session.spent, 300);
+
+    // The second transfer would take the key past its cap; nothing is recorded.
+    let second = approved_proposal(&env, &s, 300);
+    assert_eq!(
+        s.client.try_execute_proposal(&keeper, &second),
+        Err(Ok(VaultError::SessionKeyExhausted))
+    );
+    let session = s.client.get_session_key(&keeper);
+    assert_eq!((session.uses, session.spent), (1, 300));
+}
+
+#[test]
+fn test_key_limits_entrypoints_uses_and_expiry() {
+    let env = Env::default();
+    let s = setup(&env);
+    let scheduler_only = Address::generate(&env);
+    let single_use = Address::generate(&env);
+
+    s.client.register_session_key(
+        &s.admin,
+        &scheduler_only,
+        &entrypoints(&env, &["execute_scheduled_proposal"]),
+        &10_000,
+        &1_000,
+        &5,
+    );
+    s.client.register_session_key(
+        &s.admin,
+        &single_use,
+        &entrypoints(&env, &["execute_proposal"]),
+        &10_000,
+        &1_000,
+        &1,
+    );
+
+    let first = approved_proposal(&env, &s, 100);
+    assert_eq!(
+        s.client.try_execute_proposal(&scheduler_only, &first),
+        Err(Ok(VaultError::SessionKeyNotAllowed))
+    );
+    s.client.execute_proposal(&single_use, &first);
+
+    let second = approved_proposal(&env, &s, 100);
+    assert_eq!(
+        s.client.try_execute_proposal(&single_use, &second),
+        Err(Ok(VaultError::SessionKeyExhausted))
+    );
+
+    // Past expiry the key stops working even with uses left.
+    let keeper = Address::generate(&env);
+    s.client.register_session_key(
+        &s.admin,
+        &keeper,
+        &entrypoints(&env, &["execute_proposal"]),
+        &10_000,
+        &1_000,
+        &5,
+    );
+    env.ledger().set_sequence_number(1_001);
+    assert_eq!(
+        s.client.try_execute_proposal(&keeper, &second),
+        Err(Ok(VaultError::SessionKeyExpired))
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Registration and revocation
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_revoke_session_key() {
+    let env = Env::default();
+    let s = setup(&env);
+    let keeper = Address::generate(&env);
+    let other = Address::generate(&env);
+    let allowed = entrypoints(&env, &["execute_proposal"]);
+
+    s.client
+        .register_session_key(&s.admin, &keeper, &allowed, &1_000, &1_000, &5);
+    s.client
+        .register_session_key(&s.admin, &other, &allowed, &1_000, &1_000, &5);
+    assert_eq!(
+        s.client.get_session_keys(&s.admin),
+        Vec::from_array(&env, [keeper.clone(), other.clone()])
+    );
+
+    assert_eq!(
+        s.client
+            .try_revoke_session_key(&Address::generate(&env), &keeper),
+        Err(Ok(VaultError::Unauthorized))
+    );
+    s.client.revoke_session_key(&s.admin, &keeper);
+    assert_eq!(
+        s.client.get_session_keys(&s.admin),
+        Vec::from_array(&env, [other.clone()])
+    );
+    assert_eq!(
+        s.client.try_get_session_key(&keeper),
+        Err(Ok(VaultError::SessionKeyNotFound))
+    );
+
+    // A revoked key is just an unprivileged address again.
+    let proposal_id = approved_proposal(&env, &s, 100);
+    assert_eq!(
+        s.client.try_execute_proposal(&keeper, &proposal_id),
+        Err(Ok(VaultError::InsufficientRole))
+    );
+}
+
+#[test]
+fn test_register_session_key_validation() {
+    let env = Env::default();
+    let s = setup(&env);
+    let keeper = Address::generate(&env);
+    let allowed = entrypoints(&env, &["execute_proposal"]);
+
+    assert_eq!(
+        s.client.try_register_session_key(
+            &Address::generate(&env),
+            &keeper,
+            &allowed,
+            &1_000,
+            &1_000,
+            &5
+        ),
+        Err(Ok(VaultError::NotASigner))
+    );
+    assert_eq!(
+        s.client.try_register_session_key(
+            &s.admin,
+            &keeper,
+            &entrypoints(&env, &["set_role"]),
+            &1_000,
+            &1_000,
+            &5
+        ),
+        Err(Ok(VaultError::InvalidSessionKey))
+    );
+    assert_eq!(
+        s.client
+            .try_register_session_key(&s.admin, &s.signer, &allowed, &1_000, &1_000, &5),
+        Err(Ok(VaultError::InvalidSessionKey))
+    );
+    assert_eq!(
+        s.client
+            .try_register_session_key(&s.admin, &keeper, &allowed, &1_000, &1_000, &0),
+        Err(Ok(VaultError::InvalidSessionKey))
+    );
+
+    // Another signer cannot take over a registered key.
+    s.client
+        .register_session_key(&s.admin, &keeper, &allowed, &1_000, &1_000, &5);
+    assert_eq!(
+        s.client
+            .try_register_session_key(&s.signer, &keeper, &allowed, &1_000, &1_000, &5),
+        Err(Ok(VaultError::InvalidSessionKey))
+    );
+}
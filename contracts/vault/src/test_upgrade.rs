@@ -0,0 +1,296 @@
+//! Unit tests for governed contract upgrades.
+//!
+//! Covers: upgrade proposal approval, the mandatory timelock, expiry,
+//! cancellation through the admin council, signer-only access, the pause
+//! guard and the versioned migration entrypoint.
+
+use crate::storage;
+use crate::types::{PauseCategory, PauseConfig, RetryConfig, UpgradeStatus, VelocityConfig};
+use crate::{InitConfig, Role, ThresholdStrategy, VaultDAO, VaultDAOClient, VaultError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+struct Setup {
+    client: VaultDAOClient<'static>,
+    admin: Address,
+    signer: Address,
+    outsider: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    // The upgrade timelock and expiry span days of ledgers; keep storage
+    // live across them.
+    env.ledger().with_mut(|li| {
+        li.min_persistent_entry_ttl = 200_000;
+        li.max_entry_ttl = 300_000;
+    });
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let signer = Address::generate(env);
+    let outsider = Address::generate(env);
+
+    let mut signers = Vec::new(env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer.clone());
+
+    client.initialize(
+        &admin,
+        &InitConfig {
+            signers,
+            threshold: 2,
+            quorum: 0,
+            quorum_percentage: 0,
+            spending_limit: 1_000_000,
+            daily_limit: 10_000_000,
+            weekly_limit: 50_000_000,
+            timelock_threshold: 500_000,
+            timelock_delay: 100,
+            velocity_limit: VelocityConfig {
+                limit: 100,
+                window: 3600,
+            },
+            threshold_strategy: ThresholdStrategy::Fixed,
+            default_voting_deadline: 0,
+            veto_addresses: Vec::new(env),
+            retry_config: RetryConfig {
+                enabled: false,
+                max_retries: 0,
+                initial_backoff_ledgers: 0,
+            },
+            recovery_config: crate::types::RecoveryConfig::default(env),
+            staking_config: crate::types::StakingConfig::default(),
+            pre_execution_hooks: Vec::new(env),
+            post_execution_hooks: Vec::new(env),
+        },
+    );
+
+    Setup {
+        client,
+        admin,
+        signer,
+        outsider,
+    }
+}
+
+fn wasm_hash(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[7; 32])
+}
+
+// ---------------------------------------------------------------------------
+// Upgrade proposals
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_upgrade_requires_signer_approvals_and_timelock() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let id = s.client.propose_upgrade(&s.admin, &wasm_hash(&env));
+    let res = s.client.try_execute_upgrade(&s.admin, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::ProposalNotApproved)));
+
+    s.client.approve_upgrade(&s.admin, &id);
+    let res = s.client.try_approve_upgrade(&s.admin, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::AlreadyApproved)));
+    assert_eq!(
+        s.client.get_upgrade_proposal(&id).status,
+        UpgradeStatus::Pending
+    );
+
+    s.client.approve_upgrade(&s.signer, &id);
+    let proposal = s.client.get_upgrade_proposal(&id);
+    assert_eq!(proposal.status, UpgradeStatus::Approved);
+    // The upgrade timelock applies even though the vault's own delay is shorter.
+    assert_eq!(proposal.execution_after, 17_280);
+
+    let res = s.client.try_execute_upgrade(&s.admin, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::TimelockNotExpired)));
+
+    // Past the timelock the WASM swap itself is attempted; the hash was never
+    // uploaded, so the host rejects it and the proposal stays approved.
+    env.ledger().with_mut(|li| li.sequence_number = 17_280);
+    assert!(s.client.try_execute_upgrade(&s.admin, &id).is_err());
+    assert_eq!(
+        s.client.get_upgrade_proposal(&id).status,
+        UpgradeStatus::Approved
+    );
+}
+
+#[test]
+fn test_upgrade_is_signer_only() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let res = s.client.try_propose_upgrade(&s.outsider, &wasm_hash(&env));
+    assert_eq!(res.err(), Some(Ok(VaultError::NotASigner)));
+
+    let id = s.client.propose_upgrade(&s.signer, &wasm_hash(&env));
+    let res = s.client.try_approve_upgrade(&s.outsider, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::NotASigner)));
+    let res = s.client.try_execute_upgrade(&s.outsider, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::NotASigner)));
+    let res = s.client.try_cancel_upgrade(&s.outsider, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::Unauthorized)));
+}
+
+#[test]
+fn test_cancel_upgrade() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let id = s.client.propose_upgrade(&s.signer, &wasm_hash(&env));
+    s.client.approve_upgrade(&s.admin, &id);
+    s.client.approve_upgrade(&s.signer, &id);
+
+    // The admin can cancel another signer's approved upgrade.
+    s.client.cancel_upgrade(&s.admin, &id);
+    assert_eq!(
+        s.client.get_upgrade_proposal(&id).status,
+        UpgradeStatus::Cancelled
+    );
+    let res = s.client.try_execute_upgrade(&s.admin, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::ProposalNotApproved)));
+    let res = s.client.try_cancel_upgrade(&s.signer, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::ProposalNotPending)));
+}
+
+#[test]
+fn test_cancel_approved_upgrade_goes_through_admin_council() {
+    let env = Env::default();
+    let s = setup(&env);
+    let second_admin = Address::generate(&env);
+    s.client.set_role(&s.admin, &second_admin, &Role::Admin);
+    s.client.set_admin_council(&s.admin, &2, &Vec::new(&env));
+
+    // The proposer can withdraw its own pending upgrade alone.
+    let withdrawn = s.client.propose_upgrade(&s.signer, &wasm_hash(&env));
+    s.client.cancel_upgrade(&s.signer, &withdrawn);
+    assert_eq!(
+        s.client.get_upgrade_proposal(&withdrawn).status,
+        UpgradeStatus::Cancelled
+    );
+
+    let id = s.client.propose_upgrade(&s.signer, &wasm_hash(&env));
+    s.client.approve_upgrade(&s.admin, &id);
+    s.client.approve_upgrade(&s.signer, &id);
+    let res = s.client.try_cancel_upgrade(&s.signer, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::Unauthorized)));
+    let res = s.client.try_cancel_upgrade(&s.admin, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::AdminActionNotConfirmed)));
+
+    let action = Symbol::new(&env, "cancel_upgrade");
+    let action_id = s
+        .client
+        .queue_admin_action(&s.admin, &action, &(id,).into_val(&env));
+    s.client.confirm_admin_action(&second_admin, &action_id);
+    s.client.cancel_upgrade(&s.admin, &id);
+    assert_eq!(
+        s.client.get_upgrade_proposal(&id).status,
+        UpgradeStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_upgrade_expires() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    // Not approved within the expiry window.
+    let stale = s.client.propose_upgrade(&s.admin, &wasm_hash(&env));
+    env.ledger().with_mut(|li| li.sequence_number = 120_961);
+    let res = s.client.try_approve_upgrade(&s.signer, &stale);
+    assert_eq!(res.err(), Some(Ok(VaultError::ProposalExpired)));
+
+    // Approved but not executed within the window after the timelock.
+    let id = s.client.propose_upgrade(&s.admin, &wasm_hash(&env));
+    s.client.approve_upgrade(&s.admin, &id);
+    s.client.approve_upgrade(&s.signer, &id);
+    let execution_after = s.client.get_upgrade_proposal(&id).execution_after;
+    env.ledger()
+        .with_mut(|li| li.sequence_number = (execution_after + 120_961) as u32);
+    let res = s.client.try_execute_upgrade(&s.admin, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::ProposalExpired)));
+}
+
+#[test]
+fn test_upgrade_counts_only_current_signers() {
+    let env = Env::default();
+    let s = setup(&env);
+    let id = s.client.propose_upgrade(&s.admin, &wasm_hash(&env));
+    s.client.approve_upgrade(&s.admin, &id);
+    s.client.approve_upgrade(&s.signer, &id);
+
+    // `signer` is rotated out after approving; its approval no longer counts.
+    let newcomer = Address::generate(&env);
+    env.as_contract(&s.client.address, || {
+        let mut config = storage::get_config(&env).unwrap();
+        config.signers = Vec::from_array(&env, [s.admin.clone(), newcomer.clone()]);
+        storage::set_config(&env, &config);
+    });
+    env.ledger().with_mut(|li| li.sequence_number = 17_280);
+    let res = s.client.try_execute_upgrade(&s.admin, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::ProposalNotApproved)));
+}
+
+#[test]
+fn test_config_pause_blocks_upgrades() {
+    let env = Env::default();
+    let s = setup(&env);
+    let guardian = Address::generate(&env);
+    s.client.set_pause_config(
+        &s.admin,
+        &PauseConfig {
+            guardians: Vec::from_array(&env, [guardian.clone()]),
+            unpause_threshold: 2,
+            max_duration: 1_000,
+        },
+    );
+    let id = s.client.propose_upgrade(&s.admin, &wasm_hash(&env));
+
+    s.client.pause(
+        &guardian,
+        &Vec::from_array(&env, [PauseCategory::Config]),
+        &0,
+        &Symbol::new(&env, "key_leak"),
+    );
+    let res = s.client.try_propose_upgrade(&s.admin, &wasm_hash(&env));
+    assert_eq!(res.err(), Some(Ok(VaultError::VaultPaused)));
+    let res = s.client.try_approve_upgrade(&s.admin, &id);
+    assert_eq!(res.err(), Some(Ok(VaultError::VaultPaused)));
+}
+
+// ---------------------------------------------------------------------------
+// Migration
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_migrate_runs_once_per_version() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    // Fresh vaults start at the current layout.
+    assert_eq!(s.client.get_contract_version(), 4);
+    let res = s.client.try_migrate(&s.admin);
+    assert_eq!(res.err(), Some(Ok(VaultError::AlreadyMigrated)));
+
+    // Simulate a vault deployed before versioning was introduced.
+    env.as_contract(&s.client.address, || {
+        storage::set_contract_version(&env, 0);
+    });
+    let res = s.client.try_migrate(&s.outsider);
+    assert_eq!(res.err(), Some(Ok(VaultError::NotASigner)));
+
+    assert_eq!(s.client.migrate(&s.signer), 4);
+    assert_eq!(s.client.get_contract_version(), 4);
+    let res = s.client.try_migrate(&s.signer);
+    assert_eq!(res.err(), Some(Ok(VaultError::AlreadyMigrated)));
+}
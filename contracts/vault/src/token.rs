@@ -28,6 +28,15 @@ pub fn balance(env: &Env, token_addr: &Address) -> i128 {
     client.balance(&vault_address)
 }
 
+/// Decimals a token reports, or `None` if the call fails
+pub fn decimals(env: &Env, token_addr: &Address) -> Option<u32> {
+    let client = token::Client::new(env, token_addr);
+    match client.try_decimals() {
+        Ok(Ok(decimals)) => Some(decimals),
+        _ => None,
+    }
+}
+
 /// Transfer tokens FROM a user INTO the vault (for insurance stake locking).
 /// Requires the `from` address to have already authorized (via require_auth in the caller).
 pub fn transfer_to_vault(env: &Env, token_addr: &Address, from: &Address, amount: i128) {
@@ -17,7 +17,7 @@
 //!
 //! 4. **Bit Packing**: Boolean flags are combined into a single u8 bitfield where possible.
 
-use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Symbol, Val, Vec};
 
 /// Oracle configuration for price feeds
 #[contracttype]
@@ -27,7 +27,7 @@ pub struct VaultOracleConfig {
     pub address: Address,
     /// Asset symbol for the base currency (e.g., USD)
     pub base_symbol: Symbol,
-    /// Maximum ledgers before price is considered stale
+    /// Maximum age in seconds before price is considered stale
     pub max_staleness: u32,
 }
 
@@ -38,11 +38,66 @@ pub enum OptionalVaultOracleConfig {
     Some(VaultOracleConfig),
 }
 
-/// Price data from an oracle
+/// Oracle sources aggregated into a median price for a single asset.
+/// Takes precedence over `VaultOracleConfig` for that asset.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssetOracleSources {
+    /// SEP-40 oracle contracts
+    pub sources: Vec<Address>,
+    /// Minimum number of non-stale answers required to produce a price
+    pub min_sources: u32,
+    /// Maximum deviation of any answer from the median, in basis points
+    pub max_deviation_bps: u32,
+    /// Maximum age in seconds before an answer is considered stale
+    pub max_staleness: u32,
+}
+
+/// Per-asset oracle circuit breaker configuration
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Maximum price move from the reference price, in basis points
+    pub max_move_bps: u32,
+    /// Ledgers after which the reference price is rolled forward
+    pub window_ledgers: u64,
+}
+
+/// Circuit breaker reference price and trip state for an asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircuitBreakerState {
+    /// Price the current move is measured against
+    pub reference_price: i128,
+    /// Ledger at which `reference_price` was recorded
+    pub reference_ledger: u64,
+    /// Whether executions involving the asset are blocked
+    pub tripped: bool,
+    /// Ledger at which the breaker tripped (0 if not tripped)
+    pub tripped_at: u64,
+    /// Price that tripped the breaker (0 if not tripped)
+    pub trip_price: i128,
+    /// Signers who approved resetting a tripped breaker
+    pub reset_approvals: Vec<Address>,
+}
+
+/// SEP-40 asset identifier
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Asset {
+    /// Stellar asset or token contract
+    Stellar(Address),
+    /// Off-chain asset identified by symbol (e.g. BTC, USD)
+    Other(Symbol),
+}
+
+/// Price data from a SEP-40 oracle (`PriceData`)
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct VaultPriceData {
+    /// Price scaled by the oracle's `decimals()`
     pub price: i128,
+    /// Unix timestamp (seconds) of the price
     pub timestamp: u64,
 }
 
@@ -254,6 +309,156 @@ pub struct PermissionGrant {
     pub granted_by: Address,
     pub granted_at: u64,
     pub expires_at: Option<u64>,
+    /// Restricts which operations the grant covers
+    pub scope: PermissionScope,
+}
+
+/// Permission grant layout before permission scopes
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LegacyPermissionGrant {
+    pub permission: Permission,
+    pub granted_by: Address,
+    pub granted_at: u64,
+    pub expires_at: Option<u64>,
+}
+
+/// Limits on the operations a grant or delegation covers (e.g. "proposals up
+/// to 5,000 USDC to whitelisted recipients"). `None` and empty lists leave
+/// that dimension unrestricted.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PermissionScope {
+    /// Only operations moving this token
+    pub token: Option<Address>,
+    /// Largest amount a single operation may move
+    pub max_amount: Option<i128>,
+    /// Only transfers whose recipients are all in this set
+    pub recipients: Vec<Address>,
+    /// Only proposals carrying at least one of these tags
+    pub tags: Vec<Symbol>,
+}
+
+impl PermissionScope {
+    /// Scope covering every operation.
+    pub fn unrestricted(env: &Env) -> Self {
+        PermissionScope {
+            token: None,
+            max_amount: None,
+            recipients: Vec::new(env),
+            tags: Vec::new(env),
+        }
+    }
+}
+
+/// Permission carried by a custom role, optionally capped per operation
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RolePermission {
+    pub permission: Permission,
+    /// Largest amount a single operation may move under this permission;
+    /// `None` leaves it uncapped
+    pub max_amount: Option<i128>,
+}
+
+/// M-of-N council formed by every Admin. With a threshold above 1, council
+/// actions only take effect once enough admins confirmed the exact call.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminCouncil {
+    /// Admin confirmations a council action needs (1 = any single admin)
+    pub threshold: u32,
+    /// Council actions any single admin may still take, by entrypoint name
+    pub single_admin_actions: Vec<Symbol>,
+}
+
+/// Admin action lifecycle
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum AdminActionStatus {
+    Pending = 0,
+    Executed = 1,
+    Cancelled = 2,
+}
+
+/// Council action queued for confirmation by other admins
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminAction {
+    pub id: u64,
+    /// Entrypoint the action calls
+    pub action: Symbol,
+    /// Arguments the action will be called with, after the admin
+    pub args: Vec<Val>,
+    /// SHA-256 over the XDR of `[action, args...]`; see `admin_action_hash`
+    pub call_hash: BytesN<32>,
+    pub proposer: Address,
+    pub confirmations: Vec<Address>,
+    pub status: AdminActionStatus,
+    pub created_at: u64,
+    /// Last ledger the action can be confirmed or executed at
+    pub expires_at: u64,
+}
+
+/// Lifecycle of a timelocked configuration change
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ConfigChangeStatus {
+    Pending = 0,
+    Applied = 1,
+    Cancelled = 2,
+}
+
+/// Configuration change waiting out the config timelock
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigChange {
+    pub id: u64,
+    /// Entrypoint that queued the change
+    pub action: Symbol,
+    /// The entrypoint's arguments after the admin, in order
+    pub args: Vec<Val>,
+    /// Admin the change is applied on behalf of
+    pub proposer: Address,
+    pub status: ConfigChangeStatus,
+    pub queued_at: u64,
+    /// First ledger the change can be applied at
+    pub eta: u64,
+}
+
+/// Restricted key a signer registers for automation (keepers, bots). Calls
+/// made by the key act with its owner's permissions, limited by the fields
+/// below.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionKey {
+    pub key: Address,
+    /// Signer that registered the key and whose permissions it uses
+    pub owner: Address,
+    /// Entrypoints the key may call, by name
+    pub allowed_entrypoints: Vec<Symbol>,
+    /// Total amount the key may move over its lifetime
+    pub spend_cap: i128,
+    pub spent: i128,
+    /// Last ledger sequence the key can be used at
+    pub expires_at: u64,
+    /// Number of calls the key may make
+    pub max_uses: u32,
+    pub uses: u32,
+    pub created_at: u64,
+}
+
+/// Governance-defined named role (e.g. "Auditor", "PayrollOperator")
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomRole {
+    pub name: Symbol,
+    pub permissions: Vec<RolePermission>,
+    pub defined_by: Address,
+    /// Ledger sequence the role was last defined at
+    pub defined_at: u64,
 }
 
 /// Delegated permission with expiry
@@ -265,6 +470,19 @@ pub struct DelegatedPermission {
     pub delegatee: Address,
     pub granted_at: u64,
     pub expires_at: u64,
+    /// Restricts which operations the delegation covers
+    pub scope: PermissionScope,
+}
+
+/// Delegated permission layout before permission scopes
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LegacyDelegatedPermission {
+    pub permission: Permission,
+    pub delegator: Address,
+    pub delegatee: Address,
+    pub granted_at: u64,
+    pub expires_at: u64,
 }
 
 #[contracttype]
@@ -335,6 +553,22 @@ pub enum Condition {
     PriceAbove(Address, i128),
     /// Execute only when asset price is below threshold (in USD)
     PriceBelow(Address, i128),
+    /// Execute only when balance is below threshold
+    BalanceBelow(i128),
+    /// Execute only when the vault's balance of a token is above threshold
+    TokenBalanceAbove(Address, i128),
+    /// Execute only when the vault's balance of a token is below threshold
+    TokenBalanceBelow(Address, i128),
+    /// Execute only once another proposal has been executed
+    ProposalExecuted(u64),
+    /// Execute only after this Unix timestamp (seconds)
+    TimeAfter(u64),
+    /// Execute only before this Unix timestamp (seconds)
+    TimeBefore(u64),
+    /// Execute only when the contract's `check_condition(vault, proposal_id)` returns true
+    Predicate(Address),
+    /// Nested conditions combined with their own logic
+    Group(ConditionLogic, Vec<Condition>),
 }
 
 /// Logic for combining multiple conditions
@@ -687,6 +921,222 @@ pub struct ExecutionFeeEstimate {
     pub operation_count: u32,
 }
 
+/// Outcome of a single execution preflight check.
+///
+/// `actual` and `required` carry the numbers the check compared, e.g. the
+/// current ledger against an unlock ledger or approvals against a threshold.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreflightCheck {
+    /// Check name (e.g. `timelock`, `quorum`, `balance`)
+    pub name: Symbol,
+    /// Whether execution would pass this check
+    pub passed: bool,
+    /// Whether a failure stops execution; informational checks do not
+    pub blocking: bool,
+    /// `VaultError` code execution would fail with (0 if passed)
+    pub error_code: u32,
+    /// Observed value
+    pub actual: i128,
+    /// Value required to pass
+    pub required: i128,
+}
+
+/// Structured dry-run of `execute_proposal`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PreflightReport {
+    pub proposal_id: u64,
+    /// Whether every blocking check passed
+    pub executable: bool,
+    /// Checks in the order execution runs them
+    pub checks: Vec<PreflightCheck>,
+    /// Result of each top-level condition, in order
+    pub condition_results: Vec<bool>,
+    /// Ledger the report was produced at
+    pub evaluated_at: u64,
+}
+
+// ============================================================================
+// Typed Execution Hooks (Issue: feature/typed-hooks)
+// ============================================================================
+
+/// Point at which a typed hook runs
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum HookStage {
+    /// Before the transfer; the hook's decision can block execution
+    PreExecute = 0,
+    /// After a successful transfer
+    PostExecute = 1,
+    /// A proposal was created
+    ProposalCreated = 2,
+    /// A proposal reached its approval threshold and quorum
+    ProposalApproved = 3,
+    /// A proposal was cancelled
+    ProposalCancelled = 4,
+    /// A proposal was vetoed
+    ProposalVetoed = 5,
+    /// A proposal expired
+    ProposalExpired = 6,
+    /// A stream was created, claimed, paused, resumed or cancelled
+    StreamChanged = 7,
+    /// An escrow was created, released, disputed or resolved
+    EscrowChanged = 8,
+    /// A subscription was created, renewed, cancelled or upgraded
+    SubscriptionChanged = 9,
+}
+
+/// What happens when a typed hook call fails (panics or returns garbage).
+/// Lifecycle notifications are always fail-open, so `FailClosed` is only
+/// accepted for `PreExecute` and `PostExecute` hooks.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum HookFailurePolicy {
+    /// Ignore the failure and continue
+    FailOpen = 0,
+    /// Deny a pre-execution hook; revert a post-execution hook's execution
+    FailClosed = 1,
+}
+
+/// Subjects a typed hook runs for. Empty lists and zero bounds match all.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HookFilter {
+    /// Tokens the hook runs for
+    pub tokens: Vec<Address>,
+    /// Minimum proposal amount
+    pub min_amount: i128,
+    /// Maximum proposal amount
+    pub max_amount: i128,
+    /// Tags of which the proposal must carry at least one
+    pub tags: Vec<Symbol>,
+}
+
+/// A typed hook registration
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionHook {
+    /// Contract implementing `before_execute` / `after_execute`
+    pub hook: Address,
+    pub stage: HookStage,
+    pub failure_policy: HookFailurePolicy,
+    pub filter: HookFilter,
+}
+
+/// Proposal details passed to typed hooks
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HookContext {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub memo: Symbol,
+    pub tags: Vec<Symbol>,
+}
+
+/// Notification passed to hooks subscribed to a lifecycle stage
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LifecycleEvent {
+    pub stage: HookStage,
+    /// Proposal, stream, escrow or subscription ID
+    pub subject_id: u64,
+    /// What happened to the subject (e.g. `created`, `claimed`)
+    pub action: Symbol,
+    /// Address that triggered the change
+    pub actor: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Proposal tags (empty for other subjects)
+    pub tags: Vec<Symbol>,
+    pub ledger: u64,
+}
+
+/// Decision returned by a typed pre-execution hook
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HookDecision {
+    pub allow: bool,
+    /// Hook-defined reason for a denial
+    pub reason_code: u32,
+}
+
+/// Most recent hook denial recorded for a proposal
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HookDenial {
+    pub hook: Address,
+    /// Reason code returned by the hook (0 if the call failed)
+    pub reason_code: u32,
+    /// Whether the denial came from a failed call under `FailClosed`
+    pub hook_failed: bool,
+    pub denied_at: u64,
+}
+
+/// How an `execute_proposal`, `execute_swap` or `execute_recurring_payment`
+/// call that did not fail ended
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExecutionOutcome {
+    /// The transfer was made and the proposal is `Executed`
+    Executed,
+    /// A retryable failure was recorded; see `get_retry_state`
+    RetryScheduled,
+    /// A typed pre-execution hook denied it; see `get_hook_denial`
+    HookDenied,
+    /// The call tripped a token's circuit breaker, which stays latched until
+    /// `reset_circuit_breaker`; see `get_circuit_breaker_state`
+    CircuitBreakerTripped,
+}
+
+// ============================================================================
+// Emergency Pause (Issue: feature/emergency-pause)
+// ============================================================================
+
+/// Group of entrypoints that can be paused independently
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum PauseCategory {
+    /// Anything that moves tokens out of the vault
+    Outflows = 0,
+    /// Creating proposals and new payment obligations
+    Proposals = 1,
+    /// Admin configuration changes
+    Config = 2,
+}
+
+/// Who may pause the vault and what it takes to unpause
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PauseConfig {
+    /// Addresses allowed to pause any category on their own
+    pub guardians: Vec<Address>,
+    /// Signer approvals needed to lift a pause early
+    pub unpause_threshold: u32,
+    /// Longest a pause can last, in ledgers
+    pub max_duration: u64,
+}
+
+/// An active pause of one category
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PauseState {
+    pub category: PauseCategory,
+    pub paused_by: Address,
+    pub reason: Symbol,
+    pub paused_at: u64,
+    /// Ledger after which the pause lapses on its own
+    pub expires_at: u64,
+    /// Signers who approved lifting the pause
+    pub unpause_approvals: Vec<Address>,
+}
+
 // ============================================================================
 // Performance Metrics (Issue: feature/performance-metrics)
 // ============================================================================
@@ -740,9 +1190,13 @@ impl VaultMetrics {
 pub struct DexConfig {
     /// Enabled DEX protocols
     pub enabled_dexs: Vec<Address>,
-    /// Maximum slippage tolerance in basis points (e.g., 100 = 1%)
+    /// Maximum slippage tolerance in basis points (e.g., 100 = 1%).
+    /// Realized swap output may fall short of the oracle-implied output by at
+    /// most `max_price_impact_bps + max_slippage_bps`.
     pub max_slippage_bps: u32,
-    /// Maximum price impact in basis points (e.g., 500 = 5%)
+    /// Maximum price impact in basis points (e.g., 500 = 5%).
+    /// The router quote may deviate from the oracle-implied output by at most
+    /// this, in either direction.
     pub max_price_impact_bps: u32,
     /// Minimum liquidity required for swaps
     pub min_liquidity: i128,
@@ -764,18 +1218,176 @@ pub enum SwapProposal {
     UnstakeLp(Address, Address, i128),
     /// Claim farming rewards: (farm)
     ClaimRewards(Address),
+    /// DCA order: (dex, token_in, token_out, total_amount_in, slices, interval_ledgers,
+    /// min_amount_out_per_slice)
+    Dca(Address, Address, Address, i128, u32, u64, i128),
 }
 
-/// DEX operation result
+/// DEX operation result, measured from vault balances around the call
+///
+/// `amount_in` is what left the vault and `amount_out` what arrived:
+/// token_in/token_out for `Swap`, token_a/LP for `AddLiquidity`, LP/token_a for
+/// `RemoveLiquidity`, LP for `StakeLp`/`UnstakeLp` and rewards for `ClaimRewards`.
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct SwapResult {
     pub amount_in: i128,
     pub amount_out: i128,
+    /// Second pool token of a liquidity operation: deposited by AddLiquidity,
+    /// received by RemoveLiquidity (0 otherwise)
+    pub amount_b: i128,
+    /// Shortfall of the realized output against the oracle-implied output (swaps only)
     pub price_impact_bps: u32,
+    /// Oracle price of token_in denominated in token_out, scaled by 10^7 (swaps only)
+    pub oracle_price: i128,
     pub executed_at: u64,
 }
 
+/// Swap result layout before `amount_b` and `oracle_price`; read back with
+/// both set to 0
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LegacySwapResult {
+    pub amount_in: i128,
+    pub amount_out: i128,
+    pub price_impact_bps: u32,
+    pub executed_at: u64,
+}
+
+impl From<LegacySwapResult> for SwapResult {
+    fn from(v: LegacySwapResult) -> Self {
+        SwapResult {
+            amount_in: v.amount_in,
+            amount_out: v.amount_out,
+            amount_b: 0,
+            price_impact_bps: v.price_impact_bps,
+            oracle_price: 0,
+            executed_at: v.executed_at,
+        }
+    }
+}
+
+/// Liquidity position in a single pool, tracked from executed DEX proposals.
+///
+/// Underlying amounts are ordered as the pair's `token_0` / `token_1`. Cost
+/// basis is released pro rata to LP burned on removal, and the difference
+/// between what removal returned and the basis released is realized.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LpPosition {
+    /// Pair contract, which is also the LP token
+    pub lp_token: Address,
+    pub token_a: Address,
+    pub token_b: Address,
+    /// LP tokens held by the vault and not staked
+    pub lp_held: i128,
+    /// LP tokens staked in farms
+    pub lp_staked: i128,
+    /// token_a deposited into the LP still held or staked
+    pub cost_basis_a: i128,
+    /// token_b deposited into the LP still held or staked
+    pub cost_basis_b: i128,
+    /// Realized gain (or loss if negative) in token_a from removals
+    pub realized_a: i128,
+    /// Realized gain (or loss if negative) in token_b from removals
+    pub realized_b: i128,
+    pub updated_at: u64,
+}
+
+/// Farm position tracked from executed stake/unstake/claim proposals
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FarmPosition {
+    pub farm: Address,
+    /// LP tokens currently staked in the farm
+    pub staked: i128,
+    pub reward_token: Option<Address>,
+    /// Total rewards claimed from the farm
+    pub rewards_claimed: i128,
+    pub updated_at: u64,
+}
+
+/// Status of a DCA order
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DcaStatus {
+    /// Slices remain to be executed
+    Active,
+    /// All slices have been executed
+    Completed,
+    /// Cancelled by governance before completion
+    Cancelled,
+}
+
+/// DCA (TWAP) order that swaps `total_amount` of token_in in equal slices,
+/// one every `interval` ledgers, executed by keepers via `execute_dca_slice`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DcaOrder {
+    /// Order ID (the swap proposal that created it)
+    pub id: u64,
+    pub dex: Address,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub total_amount: i128,
+    pub slices: u32,
+    /// Ledgers between slices
+    pub interval: u64,
+    /// Minimum token_out each slice must return
+    pub min_amount_out_per_slice: i128,
+    pub slices_executed: u32,
+    /// Total token_in spent so far
+    pub amount_in_filled: i128,
+    /// Total token_out received so far
+    pub amount_out_filled: i128,
+    /// token_in not yet swapped
+    pub remaining_amount: i128,
+    /// Average fill price: token_out per token_in, scaled by 10^7
+    pub average_price: i128,
+    /// Earliest ledger at which the next slice may execute
+    pub next_slice_ledger: u64,
+    pub status: DcaStatus,
+    pub created_at: u64,
+}
+
+/// Status of a price-triggered limit order
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LimitOrderStatus {
+    /// Waiting for the trigger to hold
+    Open,
+    /// Swap executed
+    Filled,
+    /// `max_attempts` fills failed
+    Exhausted,
+    /// Passed `expires_at` without filling
+    Expired,
+    /// Cancelled by governance
+    Cancelled,
+}
+
+/// Standing stop-loss / take-profit order backed by an approved swap proposal.
+///
+/// Any keeper may call `execute_limit_order` once `trigger` holds. A fill that
+/// fails after the trigger held (quote or output out of bounds) uses up one of
+/// `max_attempts`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LimitOrder {
+    /// Order ID (the swap proposal backing it)
+    pub id: u64,
+    /// `PriceAbove` or `PriceBelow` condition that arms the order
+    pub trigger: Condition,
+    /// Ledger after which the order can no longer fill
+    pub expires_at: u64,
+    pub max_attempts: u32,
+    /// Failed fill attempts so far
+    pub attempts: u32,
+    pub status: LimitOrderStatus,
+    /// Ledger at which the order filled (0 if not filled)
+    pub filled_at: u64,
+}
+
 // ============================================================================
 // Cross-Chain Bridge (Issue: feature/cross-chain-bridge)
 // ============================================================================
@@ -789,6 +1401,7 @@ pub enum AuditAction {
     ProposeTransfer = 1,
     ApproveProposal = 2,
     ExecuteProposal = 3,
+    /// Rejections, cancellations and expiries
     RejectProposal = 4,
     SetRole = 5,
     AddSigner = 6,
@@ -796,6 +1409,67 @@ pub enum AuditAction {
     UpdateLimits = 8,
     UpdateThreshold = 9,
     AbstainProposal = 10,
+    // Related operations share a variant (contracttype enums are limited to
+    // 50 cases); the payload hash tells them apart.
+    VetoProposal = 11,
+    /// Amendments, priority, attachments, metadata and tags
+    UpdateProposal = 12,
+    DelegateVoting = 13,
+    /// Quorum, voting strategy, deadlines and time-weighted voting
+    UpdateVotingConfig = 14,
+    Comment = 15,
+    /// Insurance, stake and fee pool withdrawals
+    WithdrawPool = 16,
+    /// Staking, insurance, fee and gas configuration
+    UpdateTreasuryConfig = 17,
+    SchedulePayment = 18,
+    ExecuteRecurringPayment = 19,
+    CreateStream = 20,
+    ClaimStream = 21,
+    /// Stream pause, resume and cancel
+    UpdateStream = 22,
+    /// List mode and whitelist/blacklist changes
+    UpdateList = 23,
+    UpdateNotificationPreferences = 24,
+    /// Pause configuration, pausing and unpause approvals
+    EmergencyPause = 25,
+    UpdateOracleConfig = 26,
+    UpdateCircuitBreaker = 27,
+    UpdateDexConfig = 28,
+    /// Swap, DCA and limit order proposals
+    ProposeSwap = 29,
+    /// Swaps, DCA slices and limit order fills
+    ExecuteSwap = 30,
+    /// DCA and limit order cancellations
+    CancelOrder = 31,
+    /// Legacy and typed hook registration and removal
+    UpdateHooks = 32,
+    UpdateTemplate = 33,
+    CreateEscrow = 34,
+    /// Milestone completion and disputes
+    UpdateEscrow = 35,
+    /// Releases, refunds and dispute resolution
+    ReleaseEscrow = 36,
+    CreateBatch = 37,
+    /// Batch execution and rollback
+    ExecuteBatch = 38,
+    /// Locking and lock extension
+    LockTokens = 39,
+    UnlockTokens = 40,
+    /// Recovery configuration and proposals
+    Recovery = 41,
+    /// Upgrade proposals and migrations
+    Upgrade = 42,
+    /// Permission grants, revocations and delegations
+    UpdatePermissions = 43,
+    FundingRound = 44,
+    CrossVault = 45,
+    Dispute = 46,
+    Subscription = 47,
+    /// Admin council configuration and queued admin actions
+    AdminCouncil = 48,
+    /// Config timelock delay and queued configuration changes
+    ConfigTimelock = 49,
 }
 
 /// Audit trail entry with cryptographic verification
@@ -812,11 +1486,78 @@ pub struct AuditEntry {
     pub target: u64,
     /// Ledger timestamp
     pub timestamp: u64,
-    /// Hash of previous entry (chain integrity)
+    /// SHA-256 over the XDR of the action's payload (old/new values, amounts,
+    /// recipients); all zeroes for entries migrated from before version 3
+    pub payload_hash: BytesN<32>,
+    /// Hash of previous entry (chain integrity); all zeroes for the first entry
+    pub prev_hash: BytesN<32>,
+    /// SHA-256 over the XDR of every other field
+    pub hash: BytesN<32>,
+}
+
+/// Audit entry layout before schema version 3, with 64-bit chain hashes
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LegacyAuditEntry {
+    pub id: u64,
+    pub action: AuditAction,
+    pub actor: Address,
+    pub target: u64,
+    pub timestamp: u64,
     pub prev_hash: u64,
-    /// Hash of this entry
     pub hash: u64,
 }
+
+/// Merkle root committed over a consecutive range of audit entry hashes
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditCheckpoint {
+    pub id: u64,
+    /// First audit entry ID covered
+    pub start_id: u64,
+    /// Last audit entry ID covered (inclusive)
+    pub end_id: u64,
+    /// Root over the covered entries' hashes, in ID order
+    pub root: BytesN<32>,
+    /// Ledger sequence the checkpoint was committed at
+    pub created_at: u64,
+}
+
+/// Filter for audit trail queries; unset fields match every entry
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AuditFilter {
+    pub actor: Option<Address>,
+    /// Matching action types; empty matches all
+    pub actions: Vec<AuditAction>,
+    /// Matching target ID. IDs are not typed by entity kind (proposal 3 and
+    /// stream 3 both match 3); add `actions` to narrow to one kind.
+    pub target: Option<u64>,
+    /// First ledger to include
+    pub from_ledger: u64,
+    /// Last ledger to include; 0 means no upper bound
+    pub to_ledger: u64,
+}
+
+/// One page of audit query results
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AuditPage {
+    pub entries: Vec<AuditEntry>,
+    /// Cursor to pass for the next page; `None` once the query is exhausted
+    pub next_cursor: Option<u64>,
+}
+
+/// Sibling path from an audit entry's hash up to its checkpoint root
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditProof {
+    pub checkpoint_id: u64,
+    pub entry_id: u64,
+    /// Sibling hashes from the leaf level upwards; levels where the node has
+    /// no sibling (odd node carried up) contribute nothing
+    pub siblings: Vec<BytesN<32>>,
+}
 /// Comment on a proposal
 // Proposal Templates (Issue: feature/contract-templates)
 // ============================================================================
@@ -965,6 +1706,50 @@ pub struct SubscriptionPayment {
     pub period_end: u64,
 }
 
+// ============================================================================
+// Treasury Runway (Issue: feature/treasury-runway)
+// ============================================================================
+
+/// Projected outflow for one time bucket of a runway projection
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RunwayBucket {
+    /// First ledger covered by this bucket (inclusive)
+    pub start_ledger: u64,
+    /// Last ledger covered by this bucket (inclusive)
+    pub end_ledger: u64,
+    /// Total scheduled outflow falling due within the bucket
+    pub outflow: i128,
+}
+
+/// Runway projection for a single token
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TokenRunway {
+    /// Token contract address
+    pub token: Address,
+    /// Vault balance of the token at projection time
+    pub balance: i128,
+    /// Total scheduled outflow over the whole horizon
+    pub total_outflow: i128,
+    /// Time-bucketed outflow totals, in ascending ledger order
+    pub buckets: Vec<RunwayBucket>,
+    /// First ledger at which scheduled outflows exhaust the balance
+    /// (None if the balance lasts beyond the horizon)
+    pub depletion_ledger: Option<u64>,
+}
+
+/// Runway projection across every token with a scheduled outflow
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreasuryRunway {
+    /// One projection per token with an outflow inside the horizon
+    pub tokens: Vec<TokenRunway>,
+    /// Some schedules were older than the scan window and not read, so
+    /// outflows may be understated
+    pub truncated: bool,
+}
+
 // ============================================================================
 // Cross-Vault Proposal Coordination (Issue: feature/cross-vault-coordination)
 // ============================================================================
@@ -1140,6 +1925,272 @@ pub struct RecoveryProposal {
     /// Earliest ledger when this recovery can be executed
     pub execution_after: u64,
 }
+// ============================================================================
+// Contract Upgrades (Issue: feature/contract-upgrades)
+// ============================================================================
+
+/// Upgrade proposal status
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum UpgradeStatus {
+    Pending = 0,
+    Approved = 1,
+    Executed = 2,
+    Cancelled = 3,
+}
+
+/// Proposal to replace the vault's WASM
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeProposal {
+    pub id: u64,
+    pub proposer: Address,
+    /// Hash of the uploaded WASM to switch to
+    pub new_wasm_hash: BytesN<32>,
+    /// Signers who have approved this proposal
+    pub approvals: Vec<Address>,
+    pub status: UpgradeStatus,
+    /// Ledger when the proposal was created
+    pub created_at: u64,
+    /// Earliest ledger when the upgrade can be executed (0 until approved)
+    pub execution_after: u64,
+}
+
+// ============================================================================
+// Schema Versioning (Issue: feature/schema-versioning)
+// ============================================================================
+
+/// Versioned envelope for stored proposals.
+///
+/// Each variant wraps a frozen layout. A layout change adds `ProposalV2`
+/// and a `V2` variant and converts older variants on read, so entries
+/// written by older code keep decoding.
+///
+/// Only the config and proposals are versioned. Escrows, streams,
+/// recurring payments, subscriptions and other records are stored as-is;
+/// a layout change to one of them needs its own `run_migration` step or a
+/// legacy decode on read (see `LegacySwapResult`).
+#[contracttype]
+#[derive(Clone, Debug)]
+pub enum VersionedProposal {
+    V1(ProposalV1),
+}
+
+/// Versioned envelope for the stored vault configuration.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub enum VersionedConfig {
+    V1(ConfigV1),
+}
+
+/// Proposal layout stored under `VersionedProposal::V1`.
+///
+/// Frozen: never edit these fields or the types they use. Entries written
+/// before versioning are stored unwrapped in this layout too.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProposalV1 {
+    pub id: u64,
+    pub proposer: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub memo: Symbol,
+    pub metadata: Map<Symbol, String>,
+    pub tags: Vec<Symbol>,
+    pub approvals: Vec<Address>,
+    pub abstentions: Vec<Address>,
+    pub attachments: Vec<String>,
+    pub status: ProposalStatus,
+    pub priority: Priority,
+    pub conditions: Vec<Condition>,
+    pub condition_logic: ConditionLogic,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub unlock_ledger: u64,
+    pub execution_time: Option<u64>,
+    pub insurance_amount: i128,
+    pub stake_amount: i128,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub snapshot_ledger: u64,
+    pub snapshot_signers: Vec<Address>,
+    pub depends_on: Vec<u64>,
+    pub is_swap: bool,
+    pub voting_deadline: u64,
+}
+
+impl From<ProposalV1> for Proposal {
+    fn from(v: ProposalV1) -> Self {
+        Proposal {
+            id: v.id,
+            proposer: v.proposer,
+            recipient: v.recipient,
+            token: v.token,
+            amount: v.amount,
+            memo: v.memo,
+            metadata: v.metadata,
+            tags: v.tags,
+            approvals: v.approvals,
+            abstentions: v.abstentions,
+            attachments: v.attachments,
+            status: v.status,
+            priority: v.priority,
+            conditions: v.conditions,
+            condition_logic: v.condition_logic,
+            created_at: v.created_at,
+            expires_at: v.expires_at,
+            unlock_ledger: v.unlock_ledger,
+            execution_time: v.execution_time,
+            insurance_amount: v.insurance_amount,
+            stake_amount: v.stake_amount,
+            gas_limit: v.gas_limit,
+            gas_used: v.gas_used,
+            snapshot_ledger: v.snapshot_ledger,
+            snapshot_signers: v.snapshot_signers,
+            depends_on: v.depends_on,
+            is_swap: v.is_swap,
+            voting_deadline: v.voting_deadline,
+        }
+    }
+}
+
+impl From<Proposal> for ProposalV1 {
+    fn from(v: Proposal) -> Self {
+        ProposalV1 {
+            id: v.id,
+            proposer: v.proposer,
+            recipient: v.recipient,
+            token: v.token,
+            amount: v.amount,
+            memo: v.memo,
+            metadata: v.metadata,
+            tags: v.tags,
+            approvals: v.approvals,
+            abstentions: v.abstentions,
+            attachments: v.attachments,
+            status: v.status,
+            priority: v.priority,
+            conditions: v.conditions,
+            condition_logic: v.condition_logic,
+            created_at: v.created_at,
+            expires_at: v.expires_at,
+            unlock_ledger: v.unlock_ledger,
+            execution_time: v.execution_time,
+            insurance_amount: v.insurance_amount,
+            stake_amount: v.stake_amount,
+            gas_limit: v.gas_limit,
+            gas_used: v.gas_used,
+            snapshot_ledger: v.snapshot_ledger,
+            snapshot_signers: v.snapshot_signers,
+            depends_on: v.depends_on,
+            is_swap: v.is_swap,
+            voting_deadline: v.voting_deadline,
+        }
+    }
+}
+
+/// Config layout stored under `VersionedConfig::V1`. Frozen like
+/// [`ProposalV1`].
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ConfigV1 {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+    pub quorum: u32,
+    pub quorum_percentage: u32,
+    pub spending_limit: i128,
+    pub daily_limit: i128,
+    pub weekly_limit: i128,
+    pub timelock_threshold: i128,
+    pub timelock_delay: u64,
+    pub velocity_limit: VelocityConfig,
+    pub threshold_strategy: ThresholdStrategy,
+    pub pre_execution_hooks: Vec<Address>,
+    pub post_execution_hooks: Vec<Address>,
+    pub default_voting_deadline: u64,
+    pub veto_addresses: Vec<Address>,
+    pub retry_config: RetryConfig,
+    pub recovery_config: RecoveryConfig,
+    pub staking_config: StakingConfig,
+}
+
+impl From<ConfigV1> for Config {
+    fn from(v: ConfigV1) -> Self {
+        Config {
+            signers: v.signers,
+            threshold: v.threshold,
+            quorum: v.quorum,
+            quorum_percentage: v.quorum_percentage,
+            spending_limit: v.spending_limit,
+            daily_limit: v.daily_limit,
+            weekly_limit: v.weekly_limit,
+            timelock_threshold: v.timelock_threshold,
+            timelock_delay: v.timelock_delay,
+            velocity_limit: v.velocity_limit,
+            threshold_strategy: v.threshold_strategy,
+            pre_execution_hooks: v.pre_execution_hooks,
+            post_execution_hooks: v.post_execution_hooks,
+            default_voting_deadline: v.default_voting_deadline,
+            veto_addresses: v.veto_addresses,
+            retry_config: v.retry_config,
+            recovery_config: v.recovery_config,
+            staking_config: v.staking_config,
+        }
+    }
+}
+
+impl From<Config> for ConfigV1 {
+    fn from(v: Config) -> Self {
+        ConfigV1 {
+            signers: v.signers,
+            threshold: v.threshold,
+            quorum: v.quorum,
+            quorum_percentage: v.quorum_percentage,
+            spending_limit: v.spending_limit,
+            daily_limit: v.daily_limit,
+            weekly_limit: v.weekly_limit,
+            timelock_threshold: v.timelock_threshold,
+            timelock_delay: v.timelock_delay,
+            velocity_limit: v.velocity_limit,
+            threshold_strategy: v.threshold_strategy,
+            pre_execution_hooks: v.pre_execution_hooks,
+            post_execution_hooks: v.post_execution_hooks,
+            default_voting_deadline: v.default_voting_deadline,
+            veto_addresses: v.veto_addresses,
+            retry_config: v.retry_config,
+            recovery_config: v.recovery_config,
+            staking_config: v.staking_config,
+        }
+    }
+}
+
+/// Progress of a batched storage migration
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationProgress {
+    /// Schema version the entries are being rewritten for
+    pub target_version: u32,
+    /// Next proposal ID to inspect
+    pub next_proposal_id: u64,
+    /// Highest proposal ID that existed when the migration started
+    pub last_proposal_id: u64,
+    /// Next audit entry ID to re-link into the SHA-256 chain
+    pub next_audit_id: u64,
+    /// Highest audit entry ID seen so far; grows if entries are added mid-migration
+    pub last_audit_id: u64,
+    /// Entries rewritten so far
+    pub migrated: u64,
+    /// 64-bit chain head stored before version 3, if no SHA-256 entry had
+    /// overwritten it yet; the last legacy audit entry must hash to it
+    pub legacy_audit_head: Option<u64>,
+    /// 64-bit hash of the last legacy audit entry verified so far
+    pub legacy_audit_hash: u64,
+    /// Whether every proposal and audit entry has been inspected
+    pub complete: bool,
+}
+
 // ============================================================================
 // Escrow System (Issue: feature/escrow-system)
 // ============================================================================